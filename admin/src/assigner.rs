@@ -1,18 +1,27 @@
 //! RustSec Advisory DB tool to assign ids
 
 use std::{
-    fs::{self, File},
+    ffi::OsStr,
+    fs::{self, File, OpenOptions},
     io::{BufRead, BufReader, LineWriter, Write},
-    path::Path,
-    process::exit,
+    path::{Path, PathBuf},
+    process::{Command, exit},
 };
 
 use rustsec::{
     Advisory, Collection,
-    advisory::{IdKind, Parts},
+    advisory::{Id, IdKind, Parts},
 };
 
-use crate::{Map, prelude::*};
+use crate::{
+    Map,
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// File in the advisory DB repo listing IDs reserved for advisories which
+/// haven't been published yet (e.g. embargoed ones), one per line
+pub const RESERVED_IDS_FILE: &str = "reserved-ids.txt";
 
 /// What sort of output should be generated on stdout.
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -21,10 +30,228 @@ pub enum OutputMode {
     HumanReadable,
     /// Output designed for use in the github action that runs this in prod
     GithubAction,
+    /// JSON mapping of advisory files to the IDs assigned to them
+    Json,
+}
+
+/// How to commit the changes made when assigning or reserving IDs
+#[derive(Clone, Debug, Default)]
+pub struct CommitOptions {
+    /// Branch to create for the commit (default: commit to the current branch)
+    pub branch: Option<String>,
+
+    /// Remote to push the branch to after committing (if any)
+    pub push: Option<String>,
+}
+
+/// An ID assigned to a draft advisory
+struct Assignment {
+    /// Path of the draft advisory, relative to the repo
+    file: PathBuf,
+
+    /// Path of the advisory named after its new ID, relative to the repo
+    renamed: PathBuf,
+
+    /// Package the advisory is for
+    package: String,
+
+    /// Assigned ID
+    id: String,
+}
+
+/// assign ids to advisories in a particular repo_path, committing the renamed
+/// advisories if `commit` is set
+pub fn assign_ids(repo_path: &Path, output_mode: OutputMode, commit: Option<&CommitOptions>) {
+    let mut highest_id = highest_ids(repo_path, output_mode);
+
+    let mut assignments = vec![];
+    for collection in [Collection::Crates, Collection::Rust] {
+        assign_ids_across_directory(
+            collection,
+            repo_path,
+            &mut highest_id,
+            output_mode,
+            &mut assignments,
+        )
+        .unwrap_or_else(|error| {
+            status_err!(
+                "Error assigning ids for {collection} in {}: {error}",
+                repo_path.display(),
+            );
+            exit(1);
+        });
+    }
+
+    let assigned = assignments
+        .iter()
+        .map(|assignment| format!("{} to {}", assignment.id, assignment.package))
+        .collect::<Vec<_>>();
+    let title = commit_title("Assigned", &assigned);
+
+    if let Some(options) = commit {
+        if assignments.is_empty() {
+            status_warn!("no IDs assigned, nothing to commit");
+        } else {
+            let paths = assignments
+                .iter()
+                .flat_map(|assignment| [&assignment.file, &assignment.renamed])
+                .collect::<Vec<_>>();
+            commit_changes(repo_path, options, &title, &paths, output_mode);
+        }
+    }
+
+    match output_mode {
+        OutputMode::HumanReadable => (),
+        OutputMode::GithubAction => println!("{title}"),
+        OutputMode::Json => {
+            let mapping = assignments
+                .into_iter()
+                .map(|assignment| (assignment.file, assignment.id))
+                .collect::<Map<_, _>>();
+
+            println!("{}", serde_json::to_string_pretty(&mapping).unwrap());
+        }
+    }
+}
+
+/// Reserve a contiguous block of `count` IDs for the given year, so that
+/// advisories can be prepared offline (e.g. under embargo) with their final IDs.
+///
+/// Reserved IDs are recorded in [`RESERVED_IDS_FILE`] and are never assigned
+/// by [`assign_ids`]. The updated file is committed if `commit` is set.
+pub fn reserve_ids(
+    repo_path: &Path,
+    year: u32,
+    count: u32,
+    output_mode: OutputMode,
+    commit: Option<&CommitOptions>,
+) {
+    let highest_id = highest_ids(repo_path, output_mode);
+    let first = highest_id.get(&year).copied().unwrap_or_default() + 1;
+    let ids = (first..first + count)
+        .map(|number| format!("RUSTSEC-{year}-{number:04}"))
+        .collect::<Vec<_>>();
+
+    let reserved_path = repo_path.join(RESERVED_IDS_FILE);
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&reserved_path)
+        .and_then(|mut file| ids.iter().try_for_each(|id| writeln!(file, "{id}")));
+
+    if let Err(e) = result {
+        status_err!("couldn't write {}: {}", reserved_path.display(), e);
+        exit(1);
+    }
+
+    let title = commit_title("Reserved", &ids);
+    if let Some(options) = commit
+        && !ids.is_empty()
+    {
+        let paths = [Path::new(RESERVED_IDS_FILE)];
+        commit_changes(repo_path, options, &title, &paths, output_mode);
+    }
+
+    match output_mode {
+        OutputMode::HumanReadable => match (ids.first(), ids.last()) {
+            (Some(first), Some(last)) => status_ok!("Reserved", "{} to {}", first, last),
+            _ => status_warn!("no IDs reserved"),
+        },
+        OutputMode::GithubAction => println!("{title}"),
+        OutputMode::Json => println!("{}", serde_json::to_string_pretty(&ids).unwrap()),
+    }
+}
+
+/// Title for the commit or PR made with the assigned or reserved IDs, which
+/// is limited to 255 characters
+fn commit_title(verb: &str, items: &[String]) -> String {
+    let mut title = format!("{verb} {}", items.join(", "));
+    let mut dropped = 0;
+    while title.len() > 255 {
+        dropped += 1;
+        let new = title.rsplit_once(", ").unwrap().0;
+        title = format!("{new} and {dropped} more");
+    }
+
+    title
 }
 
-/// assign ids to advisories in a particular repo_path
-pub fn assign_ids(repo_path: &Path, output_mode: OutputMode) {
+/// Commit the changes to the given paths (relative to the repo), exiting on failure
+fn commit_changes(
+    repo_path: &Path,
+    options: &CommitOptions,
+    title: &str,
+    paths: &[impl AsRef<OsStr>],
+    output_mode: OutputMode,
+) {
+    let result = (|| {
+        if let Some(branch) = &options.branch {
+            git(repo_path, ["checkout", "-b", branch])?;
+        }
+
+        let paths = paths.iter().map(AsRef::as_ref);
+        git(
+            repo_path,
+            [OsStr::new("add"), OsStr::new("--all"), OsStr::new("--")]
+                .into_iter()
+                .chain(paths.clone()),
+        )?;
+        git(
+            repo_path,
+            [OsStr::new("commit"), OsStr::new("-m"), OsStr::new(title)]
+                .into_iter()
+                .chain([OsStr::new("--")])
+                .chain(paths),
+        )?;
+
+        if let Some(remote) = &options.push {
+            git(repo_path, ["push", "--set-upstream", remote, "HEAD"])?;
+        }
+
+        Ok::<_, Error>(())
+    })();
+
+    if let Err(e) = result {
+        status_err!("couldn't commit the changes: {}", e);
+        exit(1);
+    }
+
+    if output_mode == OutputMode::HumanReadable {
+        status_ok!("Committed", "{}", title);
+
+        if let Some(remote) = &options.push {
+            let branch = options.branch.as_deref().unwrap_or("the current branch");
+            status_ok!("Pushed", "{} to {}", branch, remote);
+        }
+    }
+}
+
+/// Run a `git` command in the repo
+fn git<I, S>(repo_path: &Path, args: I) -> Result<(), Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        fail!(
+            ErrorKind::Git,
+            "{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(())
+}
+
+/// Find the highest RUSTSEC ID number for each year, among both the
+/// advisories in the DB and the reserved IDs
+fn highest_ids(repo_path: &Path, output_mode: OutputMode) -> Map<u32, u32> {
     let db = rustsec::Database::open(repo_path).unwrap_or_else(|e| {
         status_err!(
             "couldn't open advisory DB repo from {}: {}",
@@ -52,57 +279,55 @@ pub fn assign_ids(repo_path: &Path, output_mode: OutputMode) {
     }
 
     let mut highest_id = Map::new();
+    let mut record = |year: u32, id_num: u32| {
+        let number = highest_id.entry(year).or_default();
+        if *number < id_num {
+            *number = id_num;
+        }
+    };
 
     for advisory in advisories {
-        let advisory_clone = advisory.clone();
-        let metadata = advisory_clone.metadata;
-        let id = metadata.id;
-        let year = metadata.date.year();
-
-        if let IdKind::RustSec = id.kind() {
-            let id_num = id.numerical_part().unwrap();
+        let metadata = &advisory.metadata;
 
-            if let Some(&number) = highest_id.get(&year) {
-                if number < id_num {
-                    highest_id.insert(year, id_num);
-                }
-            } else {
-                highest_id.insert(year, id_num);
-            }
+        if let IdKind::RustSec = metadata.id.kind() {
+            record(metadata.date.year(), metadata.id.numerical_part().unwrap());
         }
     }
 
-    let mut assignments = vec![];
-    for collection in [Collection::Crates, Collection::Rust] {
-        assign_ids_across_directory(
-            collection,
-            repo_path,
-            &mut highest_id,
-            output_mode,
-            &mut assignments,
-        )
-        .unwrap_or_else(|error| {
-            status_err!(
-                "Error assigning ids for {collection} in {}: {error}",
-                repo_path.display(),
-            );
-            exit(1);
-        });
+    // IDs are reserved for the year in the ID, which is also the year of the
+    // advisory's date once it's published
+    for id in reserved_ids(repo_path) {
+        if let (Some(year), Some(id_num)) = (id.year(), id.numerical_part()) {
+            record(year, id_num);
+        }
     }
 
-    if output_mode != OutputMode::GithubAction {
-        return;
-    }
+    highest_id
+}
 
-    let mut title = format!("Assigned {}", assignments.join(", "));
-    let mut dropped = 0;
-    while title.len() > 255 {
-        dropped += 1;
-        let new = title.rsplit_once(", ").unwrap().0;
-        title = format!("{new} and {dropped} more");
-    }
+/// Load the reserved IDs (if any)
+fn reserved_ids(repo_path: &Path) -> Vec<Id> {
+    let reserved_path = repo_path.join(RESERVED_IDS_FILE);
+    let Ok(reserved) = fs::read_to_string(&reserved_path) else {
+        return vec![];
+    };
 
-    println!("{title}");
+    reserved
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| match line.parse::<Id>() {
+            Ok(id) if id.kind() == IdKind::RustSec => Some(id),
+            _ => {
+                status_warn!(
+                    "ignoring invalid ID in {}: {}",
+                    reserved_path.display(),
+                    line
+                );
+                None
+            }
+        })
+        .collect()
 }
 
 ///Assign ids to files with placeholder IDs within the directory defined by dir_path
@@ -111,7 +336,7 @@ fn assign_ids_across_directory(
     repo_path: &Path,
     highest_ids: &mut Map<u32, u32>,
     output_mode: OutputMode,
-    assignments: &mut Vec<String>,
+    assignments: &mut Vec<Assignment>,
 ) -> Result<(), rustsec::Error> {
     let dir_path = repo_path.join(collection.to_string());
     let Ok(collection_entry) = fs::read_dir(dir_path) else {
@@ -190,9 +415,16 @@ fn assign_ids_across_directory(
             fs::remove_file(&advisory_path)?;
             if output_mode == OutputMode::HumanReadable {
                 status_ok!("Assignment", "Assigned {} to {}", string_id, dir_name);
-            } else {
-                assignments.push(format!("{string_id} to {dir_name}"))
             }
+
+            let relative_path =
+                |path: &Path| path.strip_prefix(repo_path).unwrap_or(path).to_owned();
+            assignments.push(Assignment {
+                file: relative_path(&advisory_path),
+                renamed: relative_path(&dir_path.join(format!("{string_id}.md"))),
+                package: dir_name.clone(),
+                id: string_id,
+            });
         }
     }
 
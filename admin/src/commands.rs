@@ -10,16 +10,21 @@
 #![allow(non_local_definitions)]
 
 mod assign_id;
+mod check_osv;
+mod export;
 mod lint;
 mod list_affected_versions;
+mod new;
 mod osv;
+mod stats;
 mod sync;
 mod version;
 mod web;
 
 use self::{
-    assign_id::AssignIdCmd, lint::LintCmd, list_affected_versions::ListAffectedVersionsCmd,
-    osv::OsvCmd, sync::SyncCmd, version::VersionCmd, web::WebCmd,
+    assign_id::AssignIdCmd, check_osv::CheckOsvCmd, export::ExportCmd, lint::LintCmd,
+    list_affected_versions::ListAffectedVersionsCmd, new::NewCmd, osv::OsvCmd, stats::StatsCmd,
+    sync::SyncCmd, version::VersionCmd, web::WebCmd,
 };
 use crate::config::AppConfig;
 use abscissa_core::{Command, Configurable, Runnable};
@@ -41,10 +46,18 @@ pub enum AdminSubCmd {
     #[command(about = "render advisory Markdown files for the rustsec.org web site")]
     Web(WebCmd),
 
+    /// The `stats` subcommand
+    #[command(about = "compute statistics about the advisory database")]
+    Stats(StatsCmd),
+
     /// The `version` subcommand
     #[command(about = "display version information")]
     Version(VersionCmd),
 
+    /// The `new` subcommand
+    #[command(about = "generate the skeleton of a new advisory")]
+    New(NewCmd),
+
     /// The `assign-id` subcommand
     #[command(about = "assigning RUSTSEC ids to new vulnerabilities")]
     AssignId(AssignIdCmd),
@@ -53,6 +66,14 @@ pub enum AdminSubCmd {
     #[command(about = "export advisories to OSV format")]
     Osv(OsvCmd),
 
+    /// The `check-osv` subcommand
+    #[command(about = "check that advisories round-trip through the OSV format without loss")]
+    CheckOsv(CheckOsvCmd),
+
+    /// The `export` subcommand
+    #[command(about = "export the advisory database to other formats")]
+    Export(ExportCmd),
+
     /// The `version` subcommand
     #[command(about = "list affected crate versions")]
     ListAffectedVersions(ListAffectedVersionsCmd),
@@ -3,14 +3,68 @@
 //! Assigns RUSTSEC ids to new vulnerabilities
 
 use abscissa_core::{Command, Runnable};
+use chrono::{Datelike, Utc};
 use clap::Parser;
 use std::path::{Path, PathBuf};
 
+use crate::assigner::{CommitOptions, OutputMode};
+
 /// `rustsec-admin assign-id` subcommand
 #[derive(Command, Debug, Default, Parser)]
 pub struct AssignIdCmd {
-    #[arg(long = "github-actions-output")]
+    #[arg(long = "github-actions-output", conflicts_with = "json")]
     github_action_output: bool,
+
+    /// Print a JSON mapping of advisory files to assigned IDs
+    #[arg(
+        long = "json",
+        help = "print a JSON mapping of advisory files to the IDs assigned to them"
+    )]
+    json: bool,
+
+    /// Reserve a block of IDs instead of assigning them
+    #[arg(
+        long = "reserve",
+        value_name = "COUNT",
+        help = "reserve a contiguous block of IDs for advisories prepared offline"
+    )]
+    reserve: Option<u32>,
+
+    /// Year to reserve IDs for
+    #[arg(
+        long = "year",
+        requires = "reserve",
+        help = "year to reserve IDs for (default: the current year)"
+    )]
+    year: Option<u32>,
+
+    /// Commit the changes
+    #[arg(
+        long = "commit",
+        help = "commit the renamed advisories (or reserved IDs) with a standard message"
+    )]
+    commit: bool,
+
+    /// Branch to create for the commit
+    #[arg(
+        long = "branch",
+        requires = "commit",
+        help = "create this branch for the commit"
+    )]
+    branch: Option<String>,
+
+    /// Remote to push the commit to
+    #[arg(
+        long = "push",
+        value_name = "REMOTE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "origin",
+        requires = "commit",
+        help = "push the branch to REMOTE (default: origin) after committing"
+    )]
+    push: Option<String>,
+
     /// Path to the advisory database
     #[arg(
         num_args = 1..,
@@ -27,11 +81,24 @@ impl Runnable for AssignIdCmd {
             _ => unreachable!(),
         };
         let output_mode = if self.github_action_output {
-            crate::assigner::OutputMode::GithubAction
+            OutputMode::GithubAction
+        } else if self.json {
+            OutputMode::Json
         } else {
-            crate::assigner::OutputMode::HumanReadable
+            OutputMode::HumanReadable
         };
 
-        crate::assigner::assign_ids(repo_path, output_mode);
+        let commit = self.commit.then(|| CommitOptions {
+            branch: self.branch.clone(),
+            push: self.push.clone(),
+        });
+
+        match self.reserve {
+            Some(count) => {
+                let year = self.year.unwrap_or_else(|| Utc::now().year() as u32);
+                crate::assigner::reserve_ids(repo_path, year, count, output_mode, commit.as_ref());
+            }
+            None => crate::assigner::assign_ids(repo_path, output_mode, commit.as_ref()),
+        }
     }
 }
@@ -0,0 +1,54 @@
+//! `rustsec-admin check-osv` subcommand
+
+use std::{path::PathBuf, process::exit};
+
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+
+use crate::{osv_check::OsvChecker, prelude::*};
+
+/// `rustsec-admin check-osv` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct CheckOsvCmd {
+    /// Path to the advisory database
+    #[arg(
+        long = "db",
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    repo_path: Option<PathBuf>,
+}
+
+impl Runnable for CheckOsvCmd {
+    fn run(&self) {
+        let checker = OsvChecker::new(self.repo_path.as_deref()).unwrap_or_else(|e| {
+            status_err!("failed to load the advisory database: {}", e);
+            exit(1);
+        });
+
+        let (count, mismatches) = checker.check_all().unwrap_or_else(|e| {
+            status_err!("failed to round-trip advisories through OSV: {}", e);
+            exit(1);
+        });
+
+        for mismatch in &mismatches {
+            status_err!(
+                "{}: `{}` doesn't survive the OSV round trip: {} became {}",
+                mismatch.id,
+                mismatch.field,
+                mismatch.original,
+                mismatch.round_tripped
+            );
+        }
+
+        if !mismatches.is_empty() {
+            status_err!(
+                "{} fields of {} advisories don't survive the OSV round trip",
+                mismatches.len(),
+                count
+            );
+            exit(1);
+        }
+
+        status_ok!("Success", "{} advisories round-trip through OSV", count);
+    }
+}
@@ -0,0 +1,39 @@
+//! `rustsec-admin export` subcommand
+
+mod csaf;
+mod json;
+mod sqlite;
+
+use self::{csaf::ExportCsafCmd, json::ExportJsonCmd, sqlite::ExportSqliteCmd};
+use abscissa_core::{Command, Runnable};
+use clap::{Parser, Subcommand};
+
+/// `rustsec-admin export` subcommand
+#[derive(Command, Debug, Parser)]
+pub struct ExportCmd {
+    /// Format to export to
+    #[command(subcommand)]
+    format: ExportSubCmd,
+}
+
+/// `rustsec-admin export` formats
+#[derive(Command, Debug, Runnable, Subcommand)]
+pub enum ExportSubCmd {
+    /// The `export csaf` subcommand
+    #[command(about = "export every advisory to a CSAF 2.0 document, along with provider metadata")]
+    Csaf(ExportCsafCmd),
+
+    /// The `export json` subcommand
+    #[command(about = "export the whole database to a single all.json file (and all.json.gz)")]
+    Json(ExportJsonCmd),
+
+    /// The `export sqlite` subcommand
+    #[command(about = "export the whole database to a SQLite database")]
+    Sqlite(ExportSqliteCmd),
+}
+
+impl Runnable for ExportCmd {
+    fn run(&self) {
+        self.format.run()
+    }
+}
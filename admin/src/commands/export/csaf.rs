@@ -0,0 +1,58 @@
+//! `rustsec-admin export csaf` subcommand
+
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+
+use crate::{
+    csaf_export::{CsafExporter, DEFAULT_BASE_URL},
+    prelude::*,
+};
+
+/// `rustsec-admin export csaf` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct ExportCsafCmd {
+    /// Path to the advisory database
+    #[arg(
+        long = "db",
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    repo_path: Option<PathBuf>,
+
+    /// URL the export will be published at
+    #[arg(
+        long = "base-url",
+        help = "URL the output directory will be published at (defaults to https://rustsec.org/.well-known/csaf)"
+    )]
+    base_url: Option<String>,
+
+    /// Path to the output directory
+    #[arg(help = "filesystem directory where the CSAF documents will be written")]
+    path: Option<PathBuf>,
+}
+
+impl Runnable for ExportCsafCmd {
+    fn run(&self) {
+        let out_path = match &self.path {
+            None => Path::new("."),
+            Some(path) => path,
+        };
+        let base_url = self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        let exporter = CsafExporter::new(self.repo_path.as_deref()).unwrap_or_else(|e| {
+            status_err!("failed to load the advisory database: {}", e);
+            exit(1);
+        });
+
+        let count = exporter.export(out_path, base_url).unwrap_or_else(|e| {
+            status_err!("failed to export to '{}': {}", out_path.display(), e);
+            exit(1);
+        });
+
+        status_ok!("Exported", "{} advisories to {}", count, out_path.display());
+    }
+}
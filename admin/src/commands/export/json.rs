@@ -0,0 +1,47 @@
+//! `rustsec-admin export json` subcommand
+
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+
+use crate::{json_export::JsonExporter, prelude::*};
+
+/// `rustsec-admin export json` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct ExportJsonCmd {
+    /// Path to the advisory database
+    #[arg(
+        long = "db",
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    repo_path: Option<PathBuf>,
+
+    /// Path to the output directory
+    #[arg(help = "filesystem directory where all.json and all.json.gz will be written")]
+    path: Option<PathBuf>,
+}
+
+impl Runnable for ExportJsonCmd {
+    fn run(&self) {
+        let out_path = match &self.path {
+            None => Path::new("."),
+            Some(path) => path,
+        };
+
+        let exporter = JsonExporter::new(self.repo_path.as_deref()).unwrap_or_else(|e| {
+            status_err!("failed to load the advisory database: {}", e);
+            exit(1);
+        });
+
+        let count = exporter.export(out_path).unwrap_or_else(|e| {
+            status_err!("failed to export to '{}': {}", out_path.display(), e);
+            exit(1);
+        });
+
+        status_ok!("Exported", "{} advisories to {}", count, out_path.display());
+    }
+}
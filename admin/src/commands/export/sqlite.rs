@@ -0,0 +1,52 @@
+//! `rustsec-admin export sqlite` subcommand
+
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+
+use crate::{prelude::*, sqlite_export::SqliteExporter};
+
+/// Default path of the SQLite database
+const DEFAULT_PATH: &str = "advisory-db.sqlite";
+
+/// `rustsec-admin export sqlite` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct ExportSqliteCmd {
+    /// Path to the advisory database
+    #[arg(
+        long = "db",
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    repo_path: Option<PathBuf>,
+
+    /// Path to the SQLite database
+    #[arg(
+        help = "filesystem path of the SQLite database to write (defaults to advisory-db.sqlite)"
+    )]
+    path: Option<PathBuf>,
+}
+
+impl Runnable for ExportSqliteCmd {
+    fn run(&self) {
+        let out_path = match &self.path {
+            None => Path::new(DEFAULT_PATH),
+            Some(path) => path,
+        };
+
+        let exporter = SqliteExporter::new(self.repo_path.as_deref()).unwrap_or_else(|e| {
+            status_err!("failed to load the advisory database: {}", e);
+            exit(1);
+        });
+
+        let count = exporter.export(out_path).unwrap_or_else(|e| {
+            status_err!("failed to export to '{}': {}", out_path.display(), e);
+            exit(1);
+        });
+
+        status_ok!("Exported", "{} advisories to {}", count, out_path.display());
+    }
+}
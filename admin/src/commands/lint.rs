@@ -8,7 +8,11 @@ use std::{
 use abscissa_core::{Command, Runnable};
 use clap::Parser;
 
-use crate::{display_err_with_source, linter::Linter, prelude::*};
+use crate::{
+    display_err_with_source,
+    linter::{LintConfig, Linter, Preset},
+    prelude::*,
+};
 
 /// `rustsec-admin lint` subcommand
 #[derive(Command, Debug, Default, Parser)]
@@ -19,6 +23,76 @@ pub struct LintCmd {
         help = "filesystem path to the RustSec advisory DB git repo"
     )]
     path: Vec<PathBuf>,
+
+    /// Check aliases upstream
+    #[arg(
+        long = "check-aliases",
+        help = "check that CVE and GHSA aliases exist and are about the same crate"
+    )]
+    check_aliases: bool,
+
+    /// Check URLs for liveness
+    #[arg(
+        long = "check-urls",
+        help = "check that advisory URLs are live and haven't moved"
+    )]
+    check_urls: bool,
+
+    /// Dictionaries to spell check advisories against
+    #[arg(
+        long = "dictionary",
+        value_name = "PATH",
+        help = "spell check advisories against this word list (can be specified multiple times)"
+    )]
+    dictionary: Vec<PathBuf>,
+
+    /// Lint configuration file
+    #[arg(
+        long = "config",
+        value_name = "PATH",
+        help = "TOML file setting the level (error, warn or off) of each check"
+    )]
+    config: Option<PathBuf>,
+
+    /// Fail on any problem
+    #[arg(
+        long = "strict",
+        conflicts_with = "contributor",
+        help = "report all problems as errors (e.g. in CI)"
+    )]
+    strict: bool,
+
+    /// Report problems without failing
+    #[arg(
+        long = "contributor",
+        help = "report all problems as warnings, without failing"
+    )]
+    contributor: bool,
+}
+
+impl LintCmd {
+    /// Get the lint configuration from the preset and configuration file
+    fn lint_config(&self) -> LintConfig {
+        let preset = if self.strict {
+            Preset::Strict
+        } else if self.contributor {
+            Preset::Contributor
+        } else {
+            Preset::Default
+        };
+
+        let mut config = LintConfig::preset(preset);
+
+        if let Some(path) = &self.config {
+            let file = LintConfig::load(path).unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(1);
+            });
+            config.merge(file);
+        }
+
+        config
+    }
 }
 
 impl Runnable for LintCmd {
@@ -29,11 +103,36 @@ impl Runnable for LintCmd {
             _ => unreachable!(),
         };
 
-        let linter = Linter::new(repo_path).unwrap_or_else(|e| {
+        let mut linter = Linter::new(repo_path).unwrap_or_else(|e| {
             status_err!("{}", display_err_with_source(&e));
             exit(1);
         });
 
+        if self.check_aliases {
+            linter.enable_alias_checks().unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(1);
+            });
+        }
+
+        linter.set_config(self.lint_config());
+
+        if !self.dictionary.is_empty() {
+            linter
+                .enable_spell_checks(&self.dictionary)
+                .unwrap_or_else(|e| {
+                    status_err!("{}", display_err_with_source(&e));
+                    exit(1);
+                });
+        }
+
+        if self.check_urls {
+            linter.enable_url_checks().unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(1);
+            });
+        }
+
         let advisories = linter.advisory_db().iter();
 
         // Ensure we're parsing some advisories
@@ -2,9 +2,8 @@
 //!
 //! Can be used to verify that the version specification in the advisory
 //! had the desired effect and matches only the versions you want it to.
-
-#![allow(clippy::never_loop)]
-#![allow(unused_variables)] //TODO
+//!
+//! Exits with an error if no published version of a crate is vulnerable.
 
 use std::{
     path::{Path, PathBuf},
@@ -14,12 +13,24 @@ use std::{
 use abscissa_core::{Command, Runnable};
 use clap::Parser;
 
-use crate::list_versions::AffectedVersionLister;
+use crate::list_versions::{AdvisoryVersions, AffectedVersionLister, Summary, VersionStatus};
 use crate::prelude::*;
 
 /// `rustsec-admin list-affected-versions` subcommand
 #[derive(Command, Debug, Default, Parser)]
 pub struct ListAffectedVersionsCmd {
+    /// Print the versions as JSON
+    #[arg(long = "json", help = "print the versions as JSON")]
+    json: bool,
+
+    /// Advisory to list the versions for
+    #[arg(
+        long = "advisory",
+        value_name = "ID|PATH",
+        help = "only list the versions for this advisory, given its ID or the path to its file"
+    )]
+    advisory: Option<String>,
+
     /// Path to the advisory database
     #[arg(
         num_args = 1..,
@@ -45,20 +56,91 @@ impl Runnable for ListAffectedVersionsCmd {
             exit(1);
         });
 
-        // Ensure we're parsing some advisories
-        let advisories = lister.advisory_db().iter();
-        if advisories.len() == 0 {
-            status_err!("no advisories found in {}", repo_path.display());
-            exit(1);
+        let summary = match &self.advisory {
+            Some(id_or_path) => {
+                let advisory = lister.find_advisory(id_or_path).unwrap_or_else(|e| {
+                    status_err!("error loading advisory {}: {}", id_or_path, e);
+                    exit(1);
+                });
+                let versions = lister.process_one_advisory(&advisory).unwrap_or_else(|e| {
+                    status_err!("{}: {}", advisory.id(), e);
+                    exit(1);
+                });
+                Summary {
+                    advisories: vec![versions],
+                    failures: vec![],
+                }
+            }
+            None => {
+                // Ensure we're parsing some advisories
+                let advisories = lister.advisory_db().iter();
+                if advisories.len() == 0 {
+                    status_err!("no advisories found in {}", repo_path.display());
+                    exit(1);
+                }
+
+                lister.process_all_advisories()
+            }
+        };
+        let all_versions = &summary.advisories;
+
+        if self.json {
+            let json = match &self.advisory {
+                Some(_) => serde_json::to_string_pretty(&all_versions[0]),
+                None => serde_json::to_string_pretty(&all_versions),
+            };
+            println!("{}", json.unwrap());
+        } else {
+            for advisory_versions in all_versions {
+                print_versions(advisory_versions);
+            }
         }
 
-        lister.process_all_advisories().unwrap_or_else(|e| {
+        let not_vulnerable = all_versions
+            .iter()
+            .filter(|advisory_versions| !advisory_versions.any_vulnerable())
+            .collect::<Vec<_>>();
+        for advisory_versions in &not_vulnerable {
             status_err!(
-                "error listing affected versions for DB {}: {}",
-                repo_path.display(),
-                e
+                "{}: no published version of '{}' is vulnerable",
+                advisory_versions.id,
+                advisory_versions.package
+            );
+        }
+
+        for (id, error) in &summary.failures {
+            status_err!("{}: {}", id, error);
+        }
+        if !summary.failures.is_empty() {
+            status_err!(
+                "couldn't list the versions for {} of {} advisories",
+                summary.failures.len(),
+                summary.failures.len() + all_versions.len()
             );
+        }
+
+        if !not_vulnerable.is_empty() || !summary.failures.is_empty() {
             exit(1);
-        });
+        }
+    }
+}
+
+/// Print the versions of an advisory as text
+fn print_versions(advisory_versions: &AdvisoryVersions) {
+    status_ok!(
+        "Loaded",
+        "{} for '{}'",
+        advisory_versions.id,
+        advisory_versions.package
+    );
+    for version in &advisory_versions.versions {
+        let status = match version.status {
+            VersionStatus::Vulnerable => "vulnerable",
+            VersionStatus::Ok => "OK",
+        };
+        match version.yanked {
+            true => println!("{} {} (yanked)", version.version, status),
+            false => println!("{} {}", version.version, status),
+        }
     }
 }
@@ -0,0 +1,291 @@
+//! `rustsec-admin new` subcommand
+//!
+//! Generates the skeleton of a new draft advisory
+
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use abscissa_core::{Command, Runnable};
+use chrono::Utc;
+use clap::Parser;
+use cvss::{Cvss, Severity};
+use rustsec::advisory::Informational;
+
+use crate::{crates_index, prelude::*, scaffold::Scaffold};
+
+/// `rustsec-admin new` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct NewCmd {
+    /// Name of the affected crate
+    #[arg(long = "package", help = "name of the affected crate")]
+    package: Option<String>,
+
+    /// Title of the advisory
+    #[arg(long = "title", help = "one-line title of the advisory")]
+    title: Option<String>,
+
+    /// Date the issue was reported
+    #[arg(
+        long = "date",
+        value_name = "YYYY-MM-DD",
+        help = "date the issue was reported (default: today)"
+    )]
+    date: Option<String>,
+
+    /// Kind of informational advisory
+    #[arg(
+        long = "informational",
+        value_name = "KIND",
+        help = "file an informational advisory (unmaintained, unsound or notice) instead of a vulnerability"
+    )]
+    informational: Option<Informational>,
+
+    /// URL of the issue
+    #[arg(long = "url", help = "URL of the issue or report")]
+    url: Option<String>,
+
+    /// Categories of the vulnerability
+    #[arg(
+        long = "category",
+        help = "RustSec category of the vulnerability (can be specified multiple times)"
+    )]
+    categories: Vec<String>,
+
+    /// Keywords
+    #[arg(long = "keyword", help = "keyword (can be specified multiple times)")]
+    keywords: Vec<String>,
+
+    /// Aliases
+    #[arg(
+        long = "alias",
+        help = "CVE or GHSA ID of the same issue (can be specified multiple times)"
+    )]
+    aliases: Vec<String>,
+
+    /// CVSS vector
+    #[arg(
+        long = "cvss",
+        value_name = "VECTOR",
+        help = "CVSS vector of the vulnerability"
+    )]
+    cvss: Option<String>,
+
+    /// Qualitative severity
+    #[arg(
+        long = "severity",
+        conflicts_with = "cvss",
+        help = "severity of the vulnerability (low, medium, high or critical) if there's no CVSS vector"
+    )]
+    severity: Option<Severity>,
+
+    /// Patched versions
+    #[arg(
+        long = "patched",
+        value_name = "REQ",
+        help = "version requirement for patched versions, e.g. \">= 1.2.3\" (can be specified multiple times)"
+    )]
+    patched: Vec<String>,
+
+    /// Unaffected versions
+    #[arg(
+        long = "unaffected",
+        value_name = "REQ",
+        help = "version requirement for versions which were never affected (can be specified multiple times)"
+    )]
+    unaffected: Vec<String>,
+
+    /// Don't prompt for missing fields
+    #[arg(
+        long = "no-input",
+        help = "don't prompt for fields which weren't given as options"
+    )]
+    no_input: bool,
+
+    /// Don't query crates.io
+    #[arg(
+        long = "offline",
+        help = "don't check the package and versions against the crates.io index"
+    )]
+    offline: bool,
+
+    /// Print the advisory instead of writing it
+    #[arg(
+        long = "stdout",
+        help = "print the advisory instead of writing it to the advisory DB"
+    )]
+    stdout: bool,
+
+    /// Path to the advisory database
+    #[arg(
+        num_args = 1..,
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    path: Vec<PathBuf>,
+}
+
+impl Runnable for NewCmd {
+    fn run(&self) {
+        let repo_path = match self.path.len() {
+            0 => Path::new("."),
+            1 => self.path[0].as_path(),
+            _ => unreachable!(),
+        };
+
+        let mut scaffold = self.scaffold().unwrap_or_else(|e| {
+            status_err!("couldn't read advisory fields: {}", e);
+            exit(1);
+        });
+
+        if !self.offline {
+            let warnings = crates_index()
+                .map_err(Into::into)
+                .and_then(|index| scaffold.check_crates_io(&index))
+                .unwrap_or_else(|e| {
+                    status_err!("{}", e);
+                    exit(1);
+                });
+
+            for warning in warnings {
+                status_warn!("{}", warning);
+            }
+        }
+
+        let (advisory, warnings) = scaffold.render().unwrap_or_else(|e| {
+            status_err!("{}", e);
+            exit(1);
+        });
+
+        for warning in warnings {
+            status_warn!("{}", warning);
+        }
+
+        if self.stdout {
+            print!("{advisory}");
+            return;
+        }
+
+        let advisory_path = scaffold.write(repo_path, &advisory).unwrap_or_else(|e| {
+            status_err!("couldn't write the advisory: {}", e);
+            exit(1);
+        });
+
+        status_ok!("Created", "{}", advisory_path.display());
+    }
+}
+
+impl NewCmd {
+    /// Get the fields of the advisory from the options, prompting for the
+    /// missing ones when running interactively
+    fn scaffold(&self) -> io::Result<Scaffold> {
+        let interactive = !self.no_input && io::stdin().is_terminal();
+        let prompter = Prompter { interactive };
+
+        let package = prompter.required("Crate name", self.package.as_deref())?;
+        let title = prompter.required("Title", self.title.as_deref())?;
+        let informational = match &self.informational {
+            Some(informational) => Some(informational.clone()),
+            None => prompter
+                .optional("Informational kind (empty for a vulnerability)")?
+                .map(|kind| kind.parse().unwrap()),
+        };
+
+        let categories = match informational {
+            Some(_) => self.categories.clone(),
+            None => prompter.list("Categories", &self.categories)?,
+        };
+
+        Ok(Scaffold {
+            package,
+            date: self
+                .date
+                .clone()
+                .unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string()),
+            title,
+            description: String::new(),
+            url: match &self.url {
+                Some(url) => Some(url.clone()),
+                None => prompter.optional("Issue URL")?,
+            },
+            categories,
+            keywords: prompter.list("Keywords", &self.keywords)?,
+            aliases: prompter.list("Aliases", &self.aliases)?,
+            cvss: self
+                .cvss
+                .as_deref()
+                .map(str::parse::<Cvss>)
+                .transpose()
+                .map_err(io::Error::other)?,
+            severity: self.severity,
+            patched: prompter.list("Patched versions", &self.patched)?,
+            unaffected: prompter.list("Unaffected versions", &self.unaffected)?,
+            informational,
+        })
+    }
+}
+
+/// Prompts for the fields which weren't given as options
+struct Prompter {
+    /// Whether to prompt at all
+    interactive: bool,
+}
+
+impl Prompter {
+    /// Get a field which must be given
+    fn required(&self, question: &str, given: Option<&str>) -> io::Result<String> {
+        if let Some(given) = given {
+            return Ok(given.to_owned());
+        }
+
+        if !self.interactive {
+            return Err(io::Error::other(format!(
+                "{} is required",
+                question.to_lowercase()
+            )));
+        }
+
+        loop {
+            if let Some(answer) = self.ask(question)? {
+                return Ok(answer);
+            }
+        }
+    }
+
+    /// Get a field which may be left empty
+    fn optional(&self, question: &str) -> io::Result<Option<String>> {
+        match self.interactive {
+            true => self.ask(question),
+            false => Ok(None),
+        }
+    }
+
+    /// Get a comma-separated list, unless one was given
+    fn list(&self, question: &str, given: &[String]) -> io::Result<Vec<String>> {
+        if !given.is_empty() {
+            return Ok(given.to_vec());
+        }
+
+        let answer = self.optional(&format!("{question} (comma-separated)"))?;
+        Ok(answer
+            .iter()
+            .flat_map(|answer| answer.split(','))
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Ask a question on the terminal, returning the non-empty answer
+    fn ask(&self, question: &str) -> io::Result<Option<String>> {
+        print!("{question}: ");
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer)?;
+
+        let answer = answer.trim();
+        Ok((!answer.is_empty()).then(|| answer.to_owned()))
+    }
+}
@@ -1,7 +1,7 @@
 //! `rustsec-admin osv` subcommand
 //!
-//! Exports all advisories to the OSV format defined at
-//! <https://github.com/google/osv>
+//! Exports all advisories (or the ones changed since a given commit) to the
+//! OSV format defined at <https://github.com/google/osv>
 
 use std::{
     path::{Path, PathBuf},
@@ -24,6 +24,20 @@ pub struct OsvCmd {
     /// Path to the output directory
     #[arg(help = "filesystem directory where OSV JSON files will be written")]
     path: Option<PathBuf>,
+    /// Revision to export the changes since
+    #[arg(
+        long = "since",
+        value_name = "REV",
+        help = "only export the advisories changed since this git revision (e.g. the commit of the previous export)"
+    )]
+    since: Option<String>,
+    /// Path to the manifest
+    #[arg(
+        long = "manifest",
+        value_name = "PATH",
+        help = "write a JSON manifest of the exported commit and the written and removed files here"
+    )]
+    manifest: Option<PathBuf>,
 }
 
 impl Runnable for OsvCmd {
@@ -38,9 +52,24 @@ impl Runnable for OsvCmd {
             status_err!("Failed to fetch the advisory database: {}", e);
             exit(1);
         });
-        exporter.export_all(out_path).unwrap_or_else(|e| {
+        let manifest = match &self.since {
+            Some(rev) => exporter.export_since(out_path, rev),
+            None => exporter.export_all(out_path),
+        }
+        .unwrap_or_else(|e| {
             status_err!("failed not export to '{}': {}", out_path.display(), e);
             exit(1);
         });
+
+        if let Some(manifest_path) = &self.manifest {
+            manifest.write(manifest_path).unwrap_or_else(|e| {
+                status_err!(
+                    "failed to write the manifest to '{}': {}",
+                    manifest_path.display(),
+                    e
+                );
+                exit(1);
+            });
+        }
     }
 }
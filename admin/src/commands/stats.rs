@@ -0,0 +1,85 @@
+//! `rustsec-admin stats` subcommand
+
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use rustsec::Database;
+
+use crate::{
+    prelude::*,
+    stats::{Stats, fetch_cve_dates},
+};
+
+/// `rustsec-admin stats` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct StatsCmd {
+    /// Print the statistics as JSON
+    #[arg(long = "json", help = "print the statistics as JSON")]
+    json: bool,
+
+    /// Number of crates to list
+    #[arg(
+        long = "top",
+        value_name = "COUNT",
+        default_value_t = 10,
+        help = "number of crates with the most advisories to list"
+    )]
+    top: usize,
+
+    /// Fetch CVE publication dates
+    #[arg(
+        long = "cve-dates",
+        help = "fetch the publication dates of CVE aliases to compute the time from CVE publication to RustSec entry"
+    )]
+    cve_dates: bool,
+
+    /// Path to the advisory database
+    #[arg(
+        num_args = 1..,
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    path: Vec<PathBuf>,
+}
+
+impl Runnable for StatsCmd {
+    fn run(&self) {
+        let repo_path = match self.path.len() {
+            0 => Path::new("."),
+            1 => self.path[0].as_path(),
+            _ => unreachable!(),
+        };
+
+        let advisory_db = Database::open(repo_path).unwrap_or_else(|e| {
+            status_err!(
+                "error loading advisory DB repo from {}: {}",
+                repo_path.display(),
+                e
+            );
+            exit(1);
+        });
+
+        let cve_dates = match self.cve_dates {
+            true => Some(fetch_cve_dates(&advisory_db).unwrap_or_else(|e| {
+                status_err!("couldn't fetch CVE publication dates: {}", e);
+                exit(1);
+            })),
+            false => None,
+        };
+
+        let stats = Stats::compute(repo_path, &advisory_db, self.top, cve_dates.as_ref())
+            .unwrap_or_else(|e| {
+                status_err!("couldn't compute statistics: {}", e);
+                exit(1);
+            });
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&stats).unwrap());
+        } else {
+            print!("{stats}");
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! `rustsec-admin sync` subcommand
 
+mod ghsa;
+mod nvd;
+
+use self::{ghsa::SyncGhsaCmd, nvd::SyncNvdCmd};
 use crate::{prelude::*, synchronizer::Synchronizer};
 use abscissa_core::{Command, Runnable};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::{
     path::{Path, PathBuf},
     process::exit,
@@ -10,7 +14,12 @@ use std::{
 
 /// `rustsec-admin sync` subcommand
 #[derive(Command, Debug, Default, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct SyncCmd {
+    /// Source to synchronize from, other than the OSV export
+    #[command(subcommand)]
+    source: Option<SyncSubCmd>,
+
     /// Path to the advisory database
     #[arg(
         num_args = 1..,
@@ -27,28 +36,46 @@ pub struct SyncCmd {
     // wget https://osv-vulnerabilities.storage.googleapis.com/crates.io/all.zip
     #[clap(
         long = "osv",
+        required = true,
         help = "filesystem path to the OSV crates.io data export"
     )]
-    osv: PathBuf,
+    osv: Option<PathBuf>,
+}
+
+/// `rustsec-admin sync` sources
+#[derive(Command, Debug, Runnable, Subcommand)]
+pub enum SyncSubCmd {
+    /// The `sync ghsa` subcommand
+    #[command(about = "draft advisories from GHSAs and report divergences from them")]
+    Ghsa(SyncGhsaCmd),
+
+    /// The `sync nvd` subcommand
+    #[command(about = "synchronize aliases and CVSS vectors from NVD CVE records")]
+    Nvd(SyncNvdCmd),
 }
 
 impl Runnable for SyncCmd {
     fn run(&self) {
+        if let Some(source) = &self.source {
+            return source.run();
+        }
+
         let repo_path = match self.path.len() {
             0 => Path::new("."),
             1 => self.path[0].as_path(),
             _ => unreachable!(),
         };
 
-        let mut synchronizer = Synchronizer::new(repo_path, &self.osv).unwrap_or_else(|e| {
-            status_err!(
-                "error loading advisory DB repo from {}: {}",
-                repo_path.display(),
-                e
-            );
+        let mut synchronizer = Synchronizer::new(repo_path, self.osv.as_ref().unwrap())
+            .unwrap_or_else(|e| {
+                status_err!(
+                    "error loading advisory DB repo from {}: {}",
+                    repo_path.display(),
+                    e
+                );
 
-            exit(1);
-        });
+                exit(1);
+            });
 
         let advisories = synchronizer.advisory_db().iter();
 
@@ -0,0 +1,115 @@
+//! `rustsec-admin sync ghsa` subcommand
+
+use crate::{prelude::*, synchronizer::ghsa::GhsaSynchronizer};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Environment variable the GitHub token can be set in
+const TOKEN_VAR: &str = "GITHUB_TOKEN";
+
+/// `rustsec-admin sync ghsa` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct SyncGhsaCmd {
+    /// Path to the advisory database
+    #[arg(
+        num_args = 1..,
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    path: Vec<PathBuf>,
+
+    /// Saved GitHub API responses
+    #[arg(
+        long = "file",
+        value_name = "PATH",
+        help = "read GHSAs from this saved GitHub API response instead of fetching them (can be specified multiple times)"
+    )]
+    files: Vec<PathBuf>,
+}
+
+impl Runnable for SyncGhsaCmd {
+    fn run(&self) {
+        let repo_path = match self.path.len() {
+            0 => Path::new("."),
+            1 => self.path[0].as_path(),
+            _ => unreachable!(),
+        };
+
+        let token = env::var(TOKEN_VAR).ok();
+        let synchronizer = GhsaSynchronizer::new(repo_path, token).unwrap_or_else(|e| {
+            status_err!(
+                "error loading advisory DB repo from {}: {}",
+                repo_path.display(),
+                e
+            );
+
+            exit(1);
+        });
+
+        let advisories = synchronizer.advisory_db().iter();
+
+        // Ensure we're parsing some advisories
+        if advisories.len() == 0 {
+            status_err!("no advisories found!");
+            exit(1);
+        }
+
+        status_ok!(
+            "Loaded",
+            "{} security advisories (from {})",
+            advisories.len(),
+            repo_path.display()
+        );
+
+        let ghsas = if self.files.is_empty() {
+            synchronizer.fetch()
+        } else {
+            self.files
+                .iter()
+                .map(|path| GhsaSynchronizer::load(path))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|ghsas| ghsas.concat())
+        };
+
+        let result = ghsas
+            .and_then(|ghsas| synchronizer.sync(&ghsas))
+            .unwrap_or_else(|e| {
+                status_err!(
+                    "error synchronizing advisory DB {} with GHSA: {}",
+                    repo_path.display(),
+                    e
+                );
+
+                exit(1);
+            });
+
+        if result.drafts.is_empty() {
+            status_ok!("Success", "no new advisories to import");
+        } else {
+            status_ok!(
+                "Success",
+                "{} drafts written for GHSAs missing in RustSec",
+                result.drafts.len()
+            );
+            for draft in &result.drafts {
+                println!("{draft}");
+            }
+        }
+
+        if result.divergences.is_empty() {
+            status_ok!("Success", "no divergences from GHSA");
+        } else {
+            status_warn!(
+                "{} divergences between GHSA and RustSec advisories",
+                result.divergences.len()
+            );
+            for divergence in &result.divergences {
+                println!("{divergence}");
+            }
+        }
+    }
+}
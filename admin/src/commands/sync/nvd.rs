@@ -0,0 +1,132 @@
+//! `rustsec-admin sync nvd` subcommand
+
+use crate::{prelude::*, synchronizer::nvd::NvdSynchronizer};
+use abscissa_core::{Command, Runnable};
+use chrono::{Duration, Utc};
+use clap::Parser;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Environment variable the NVD API key can be set in
+const API_KEY_VAR: &str = "NVD_API_KEY";
+
+/// `rustsec-admin sync nvd` subcommand
+#[derive(Command, Debug, Default, Parser)]
+pub struct SyncNvdCmd {
+    /// Path to the advisory database
+    #[arg(
+        num_args = 1..,
+        help = "filesystem path to the RustSec advisory DB git repo"
+    )]
+    path: Vec<PathBuf>,
+
+    /// Number of days to fetch modified CVE records for
+    #[arg(
+        long = "days",
+        default_value_t = 30,
+        help = "fetch the CVE records modified in this many past days"
+    )]
+    days: u32,
+
+    /// Saved NVD API responses
+    #[arg(
+        long = "file",
+        value_name = "PATH",
+        help = "read CVE records from this saved NVD API response instead of fetching them (can be specified multiple times)"
+    )]
+    files: Vec<PathBuf>,
+}
+
+impl Runnable for SyncNvdCmd {
+    fn run(&self) {
+        let repo_path = match self.path.len() {
+            0 => Path::new("."),
+            1 => self.path[0].as_path(),
+            _ => unreachable!(),
+        };
+
+        let api_key = env::var(API_KEY_VAR).ok();
+        let mut synchronizer = NvdSynchronizer::new(repo_path, api_key).unwrap_or_else(|e| {
+            status_err!(
+                "error loading advisory DB repo from {}: {}",
+                repo_path.display(),
+                e
+            );
+
+            exit(1);
+        });
+
+        let advisories = synchronizer.advisory_db().iter();
+
+        // Ensure we're parsing some advisories
+        if advisories.len() == 0 {
+            status_err!("no advisories found!");
+            exit(1);
+        }
+
+        status_ok!(
+            "Loaded",
+            "{} security advisories (from {})",
+            advisories.len(),
+            repo_path.display()
+        );
+
+        let records = if self.files.is_empty() {
+            let end = Utc::now();
+            synchronizer.fetch(end - Duration::days(self.days.into()), end)
+        } else {
+            self.files
+                .iter()
+                .map(|path| NvdSynchronizer::load(path))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|records| records.concat())
+        };
+
+        let result = records
+            .and_then(|records| synchronizer.sync(&records))
+            .unwrap_or_else(|e| {
+                status_err!(
+                    "error synchronizing advisory DB {} with the NVD: {}",
+                    repo_path.display(),
+                    e
+                );
+
+                exit(1);
+            });
+
+        if result.untracked.is_empty() {
+            status_ok!("Success", "no CVEs without an advisory");
+        } else {
+            status_ok!(
+                "Success",
+                "{} CVEs about crates have no advisory in RustSec",
+                result.untracked.len()
+            );
+
+            let mut untracked = result.untracked;
+            untracked.sort_by(|a, b| a.published().cmp(b.published()));
+            for record in untracked {
+                println!(
+                    "{:.10}: https://nvd.nist.gov/vuln/detail/{} for {:?}",
+                    record.published(),
+                    record.id(),
+                    record.crates()
+                );
+            }
+        }
+
+        if result.aliases_added == 0 && result.cvss_added == 0 {
+            status_ok!("Success", "all advisories are up to date");
+        } else {
+            status_ok!(
+                "Success",
+                "added {} aliases and {} CVSS vectors",
+                result.aliases_added,
+                result.cvss_added
+            );
+        }
+    }
+}
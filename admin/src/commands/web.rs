@@ -10,6 +10,14 @@ use clap::Parser;
 /// `rustsec-admin web` subcommand
 #[derive(Command, Debug, Default, Parser)]
 pub struct WebCmd {
+    /// Folder overriding the default templates and static assets
+    #[arg(
+        long = "theme",
+        value_name = "DIR",
+        help = "folder whose templates/ and static/ subfolders override the default templates and static assets"
+    )]
+    theme: Option<PathBuf>,
+
     #[arg(
         num_args = 1..,
         help = "path to output the generated website (defaults to _site/)"
@@ -24,6 +32,6 @@ impl Runnable for WebCmd {
             1 => self.path[0].clone(),
             _ => unreachable!(),
         };
-        crate::web::render_advisories(output_folder);
+        crate::web::render_advisories(output_folder, self.theme.as_deref());
     }
 }
@@ -0,0 +1,472 @@
+//! Backend for the `export csaf` subcommand.
+//!
+//! Writes one [CSAF 2.0] document per advisory, following the directory-based
+//! distribution layout of a CSAF provider:
+//!
+//! ```text
+//! provider-metadata.json
+//! white/index.txt
+//! white/changes.csv
+//! white/<year>/<id>.json
+//! ```
+//!
+//! [CSAF 2.0]: https://docs.oasis-open.org/csaf/csaf/v2.0/csaf-v2.0.html
+
+use std::path::Path;
+
+use fs_err as fs;
+use rustsec::{
+    Advisory, Database,
+    advisory::IdKind,
+    repository::git::{GitModificationTimes, GitPath, Repository},
+};
+use serde_json::{Value, json};
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// Default URL the export is published at
+pub const DEFAULT_BASE_URL: &str = "https://rustsec.org/.well-known/csaf";
+
+/// Name of the directory of the TLP:WHITE distribution
+const DISTRIBUTION: &str = "white";
+
+/// Exports the advisory database to CSAF documents
+pub struct CsafExporter {
+    /// Loaded git repository
+    repository: Repository,
+
+    /// Loaded modification times for files in Git
+    mod_times: GitModificationTimes,
+
+    /// Loaded advisory database
+    advisory_db: Database,
+}
+
+/// CSAF document of an advisory
+struct Document {
+    /// Path of the document relative to the distribution directory
+    path: String,
+
+    /// `tracking/current_release_date` of the document
+    current_release_date: String,
+
+    /// The document itself
+    json: Value,
+}
+
+impl CsafExporter {
+    /// Load the database at the given path
+    pub fn new(repo_path: Option<&Path>) -> Result<Self, Error> {
+        let repository = match repo_path {
+            Some(path) => Repository::open(path)?,
+            None => Repository::fetch_default_repo()?,
+        };
+        let mod_times = GitModificationTimes::new(&repository)?;
+        let advisory_db = Database::load_from_repo(&repository)?;
+        Ok(Self {
+            repository,
+            mod_times,
+            advisory_db,
+        })
+    }
+
+    /// Exports all advisories to CSAF documents in the specified directory,
+    /// along with the metadata of the provider publishing them at `base_url`,
+    /// and returns the number of documents written.
+    pub fn export(&self, destination_folder: &Path, base_url: &str) -> Result<usize, Error> {
+        let base_url = base_url.trim_end_matches('/');
+        let distribution_folder = destination_folder.join(DISTRIBUTION);
+        let distribution_url = format!("{base_url}/{DISTRIBUTION}");
+
+        let mut advisories = self.advisory_db.iter().collect::<Vec<_>>();
+        advisories.sort_by_key(|advisory| advisory.id());
+
+        let mut documents = vec![];
+        for advisory in advisories {
+            let document = self.document(advisory, &distribution_url)?;
+            let output_path = distribution_folder.join(&document.path);
+            fs::create_dir_all(output_path.parent().unwrap())?;
+            let json = serde_json::to_string_pretty(&document.json)
+                .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+            fs::write(output_path, json)?;
+            documents.push(document);
+        }
+
+        let index = documents
+            .iter()
+            .map(|document| format!("{}\n", document.path))
+            .collect::<String>();
+        fs::write(distribution_folder.join("index.txt"), index)?;
+
+        // Most recent changes first, as required for `changes.csv`
+        documents.sort_by(|a, b| {
+            b.current_release_date
+                .cmp(&a.current_release_date)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        let changes = documents
+            .iter()
+            .map(|document| {
+                format!(
+                    "\"{}\",\"{}\"\n",
+                    document.path, document.current_release_date
+                )
+            })
+            .collect::<String>();
+        fs::write(distribution_folder.join("changes.csv"), changes)?;
+
+        let last_updated = documents
+            .first()
+            .map(|document| document.current_release_date.as_str())
+            .unwrap_or("1970-01-01T00:00:00Z");
+        let provider_metadata = json!({
+            "canonical_url": format!("{base_url}/provider-metadata.json"),
+            "distributions": [{ "directory_url": format!("{distribution_url}/") }],
+            "last_updated": last_updated,
+            "list_on_CSAF_aggregators": true,
+            "metadata_version": "2.0",
+            "mirror_on_CSAF_aggregators": true,
+            "publisher": publisher(),
+            "role": "csaf_provider",
+        });
+        let json = serde_json::to_string_pretty(&provider_metadata)
+            .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+        fs::write(destination_folder.join("provider-metadata.json"), json)?;
+
+        Ok(documents.len())
+    }
+
+    /// Convert an advisory to a CSAF document
+    fn document(&self, advisory: &Advisory, distribution_url: &str) -> Result<Document, Error> {
+        let metadata = &advisory.metadata;
+        let id = advisory.id();
+        let package = metadata.package.as_str();
+
+        let collection = metadata
+            .collection
+            .ok_or_else(|| format_err!(ErrorKind::RustSec, "advisory {} has no collection", id))?;
+        let relative_path = Path::new(collection.as_str())
+            .join(package)
+            .join(format!("{id}.md"));
+        let git_path = GitPath::new(&self.repository, &relative_path)?;
+        let initial_release_date = to_date_time(self.mod_times.cdate_for_path(git_path).as_str());
+        let current_release_date = to_date_time(self.mod_times.mdate_for_path(git_path).as_str());
+
+        let path = format!(
+            "{}/{}.json",
+            &initial_release_date[..4],
+            id.as_str().to_lowercase()
+        );
+
+        let mut revision_history = vec![json!({
+            "date": initial_release_date,
+            "number": "1",
+            "summary": "Initial version",
+        })];
+        if current_release_date != initial_release_date {
+            revision_history.push(json!({
+                "date": current_release_date,
+                "number": "2",
+                "summary": "Latest version",
+            }));
+        }
+
+        let mut references = vec![
+            json!({
+                "category": "self",
+                "summary": "Canonical URL of this document",
+                "url": format!("{distribution_url}/{path}"),
+            }),
+            json!({
+                "category": "external",
+                "summary": format!("RustSec advisory {id}"),
+                "url": format!("https://rustsec.org/advisories/{id}.html"),
+            }),
+        ];
+        references.extend(metadata.url.iter().chain(&metadata.references).map(
+            |url| json!({ "category": "external", "summary": url.as_str(), "url": url.as_str() }),
+        ));
+        references.extend(metadata.cwe_ids.iter().map(|cwe_id| {
+            json!({ "category": "external", "summary": cwe_id.to_string(), "url": cwe_id.url() })
+        }));
+
+        let mut notes = vec![json!({
+            "category": "description",
+            "title": "Description",
+            "text": metadata.description,
+        })];
+        if metadata.withdrawn.is_some() {
+            notes.push(json!({
+                "category": "general",
+                "title": "Withdrawn",
+                "text": "This advisory has been withdrawn and no longer applies.",
+            }));
+        }
+        notes.push(json!({
+            "category": "legal_disclaimer",
+            "title": "License",
+            "text": format!("This advisory is licensed under {}.", metadata.license.spdx()),
+        }));
+
+        let (product_tree, affected, fixed) = product_tree(advisory, collection.as_str());
+
+        let mut document = json!({
+            "document": {
+                "category": match metadata.informational {
+                    Some(_) => "csaf_informational_advisory",
+                    None => "csaf_security_advisory",
+                },
+                "csaf_version": "2.0",
+                "distribution": { "tlp": { "label": "WHITE" } },
+                "lang": "en",
+                "notes": notes,
+                "publisher": publisher(),
+                "references": references,
+                "title": metadata.title,
+                "tracking": {
+                    "current_release_date": current_release_date,
+                    "generator": {
+                        "engine": {
+                            "name": "rustsec-admin",
+                            "version": env!("CARGO_PKG_VERSION"),
+                        },
+                    },
+                    "id": id.as_str(),
+                    "initial_release_date": initial_release_date,
+                    "revision_history": revision_history,
+                    "status": "final",
+                    "version": revision_history.len().to_string(),
+                },
+            },
+            "product_tree": product_tree,
+        });
+
+        if let Some(severity) = advisory.severity() {
+            document["document"]["aggregate_severity"] = json!({ "text": severity.to_string() });
+        }
+
+        // Informational advisories must not have any vulnerabilities
+        if metadata.informational.is_none() {
+            document["vulnerabilities"] = json!([vulnerability(advisory, affected, fixed)]);
+        }
+
+        Ok(Document {
+            path,
+            current_release_date,
+            json: document,
+        })
+    }
+}
+
+/// Build the product tree of an advisory, returning it along with the IDs of
+/// the affected and fixed products
+fn product_tree(advisory: &Advisory, collection: &str) -> (Value, Vec<String>, Vec<String>) {
+    let package = advisory.metadata.package.as_str();
+    let identification_helper = match collection {
+        "crates" => json!({ "purl": format!("pkg:cargo/{package}") }),
+        _ => Value::Null,
+    };
+
+    let mut branches = vec![];
+    let mut affected = vec![];
+    let mut fixed = vec![];
+
+    // Withdrawn advisories don't affect any version
+    if advisory.metadata.withdrawn.is_none() {
+        for range in advisory.versions.affected_ranges() {
+            let mut constraints = vec![];
+            if let Some(introduced) = &range.introduced {
+                constraints.push(format!(">={introduced}"));
+            }
+            if let Some(fixed) = &range.fixed {
+                constraints.push(format!("<{fixed}"));
+            }
+
+            let (name, description) = match constraints.is_empty() {
+                true => ("vers:cargo/*".to_owned(), "all versions".to_owned()),
+                false => (
+                    format!("vers:cargo/{}", constraints.join("|")),
+                    constraints.join(", "),
+                ),
+            };
+            let product_id = format!("{package}:affected:{}", affected.len() + 1);
+            branches.push(branch(
+                "product_version_range",
+                name,
+                format!("{package} {description}"),
+                &product_id,
+                &identification_helper,
+            ));
+            affected.push(product_id);
+
+            if let Some(version) = range.fixed {
+                let product_id = format!("{package}:fixed:{version}");
+                if !fixed.contains(&product_id) {
+                    let identification_helper = match collection {
+                        "crates" => json!({ "purl": format!("pkg:cargo/{package}@{version}") }),
+                        _ => Value::Null,
+                    };
+                    branches.push(branch(
+                        "product_version",
+                        version.to_string(),
+                        format!("{package} {version}"),
+                        &product_id,
+                        &identification_helper,
+                    ));
+                    fixed.push(product_id);
+                }
+            }
+        }
+    }
+
+    let package_branch = match branches.is_empty() {
+        true => json!({
+            "category": "product_name",
+            "name": package,
+            "product": product(package.to_owned(), package, &identification_helper),
+        }),
+        false => json!({
+            "category": "product_name",
+            "name": package,
+            "branches": branches,
+        }),
+    };
+
+    let product_tree = json!({
+        "branches": [{
+            "category": "vendor",
+            "name": match collection {
+                "crates" => "crates.io",
+                _ => "The Rust Project",
+            },
+            "branches": [package_branch],
+        }],
+    });
+
+    (product_tree, affected, fixed)
+}
+
+/// Build a branch of the product tree leading to a single product
+fn branch(
+    category: &str,
+    name: String,
+    product_name: String,
+    product_id: &str,
+    identification_helper: &Value,
+) -> Value {
+    json!({
+        "category": category,
+        "name": name,
+        "product": product(product_name, product_id, identification_helper),
+    })
+}
+
+/// Build a product of the product tree
+fn product(name: String, product_id: &str, identification_helper: &Value) -> Value {
+    let mut product = json!({ "name": name, "product_id": product_id });
+    if !identification_helper.is_null() {
+        product["product_identification_helper"] = identification_helper.clone();
+    }
+    product
+}
+
+/// Build the vulnerability described by an advisory
+fn vulnerability(advisory: &Advisory, affected: Vec<String>, fixed: Vec<String>) -> Value {
+    let metadata = &advisory.metadata;
+
+    let ids = std::iter::once(advisory.id())
+        .chain(metadata.aliases.iter().filter(|alias| !alias.is_cve()))
+        .map(|id| {
+            json!({
+                "system_name": match id.kind() {
+                    IdKind::RustSec => "RustSec",
+                    IdKind::Ghsa => "GitHub Security Advisory",
+                    IdKind::Talos => "Cisco Talos",
+                    _ => "Other",
+                },
+                "text": id.as_str(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut vulnerability = json!({
+        "ids": ids,
+        "notes": [{
+            "category": "description",
+            "title": "Description",
+            "text": metadata.description,
+        }],
+        "title": metadata.title,
+    });
+
+    if let Some(cve) = metadata.aliases.iter().find(|alias| alias.is_cve()) {
+        vulnerability["cve"] = json!(cve.as_str());
+    }
+
+    if !affected.is_empty() {
+        let mut product_status = json!({ "known_affected": affected });
+        if !fixed.is_empty() {
+            product_status["fixed"] = json!(fixed);
+        }
+        vulnerability["product_status"] = product_status;
+
+        vulnerability["remediations"] = json!([match advisory.versions.patched() {
+            [] => json!({
+                "category": "none_available",
+                "details": "No patched versions are available.",
+                "product_ids": affected,
+            }),
+            patched => json!({
+                "category": "vendor_fix",
+                "details": format!(
+                    "Upgrade to a version matching {}.",
+                    patched
+                        .iter()
+                        .map(|requirement| format!("`{requirement}`"))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+                "product_ids": affected,
+            }),
+        }]);
+
+        // CSAF 2.0 only supports CVSS v2 and v3
+        if let Some(cvss) = &metadata.cvss {
+            let version = match cvss {
+                cvss::Cvss::CvssV30(_) => Some("3.0"),
+                cvss::Cvss::CvssV31(_) => Some("3.1"),
+                _ => None,
+            };
+            if let Some(version) = version {
+                vulnerability["scores"] = json!([{
+                    "cvss_v3": {
+                        "version": version,
+                        "vectorString": cvss.to_string(),
+                        "baseScore": cvss.score(),
+                        "baseSeverity": cvss.severity().to_string().to_uppercase(),
+                    },
+                    "products": affected,
+                }]);
+            }
+        }
+    }
+
+    vulnerability
+}
+
+/// Publisher of the documents
+fn publisher() -> Value {
+    json!({
+        "category": "coordinator",
+        "name": "RustSec",
+        "namespace": "https://rustsec.org",
+    })
+}
+
+/// Convert a `YYYY-MM-DD` date to a CSAF date-time
+fn to_date_time(date: &str) -> String {
+    format!("{date}T00:00:00Z")
+}
@@ -20,6 +20,10 @@ pub enum ErrorKind {
     #[error("crates.io index error")]
     CratesIo,
 
+    /// Git error
+    #[error("git error")]
+    Git,
+
     /// Input/output error
     #[error("I/O error")]
     Io,
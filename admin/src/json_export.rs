@@ -0,0 +1,89 @@
+//! Backend for the `export json` subcommand.
+
+use std::{
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use flate2::{Compression, write::GzEncoder};
+use fs_err as fs;
+use rustsec::{Advisory, Database, repository::git::Repository};
+use serde::Serialize;
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// Version of the format of the export, incremented on breaking changes
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Name of the file the export is written to
+pub const FILE_NAME: &str = "all.json";
+
+/// Exports the whole advisory database to a single JSON file
+pub struct JsonExporter {
+    /// Loaded git repository
+    repository: Repository,
+
+    /// Loaded advisory database
+    advisory_db: Database,
+}
+
+/// Export of the whole advisory database
+#[derive(Debug, Serialize)]
+struct Export<'a> {
+    /// Version of the format of the export
+    format_version: u32,
+
+    /// Commit of the advisory database which was exported
+    commit: String,
+
+    /// All of the advisories, including withdrawn ones, sorted by ID
+    advisories: Vec<&'a Advisory>,
+}
+
+impl JsonExporter {
+    /// Load the database at the given path
+    pub fn new(repo_path: Option<&Path>) -> Result<Self, Error> {
+        let repository = match repo_path {
+            Some(path) => Repository::open(path)?,
+            None => Repository::fetch_default_repo()?,
+        };
+        let advisory_db = Database::load_from_repo(&repository)?;
+        Ok(Self {
+            repository,
+            advisory_db,
+        })
+    }
+
+    /// Exports all advisories to `all.json` in the specified directory, along
+    /// with a gzipped `all.json.gz`, returning the number of advisories.
+    pub fn export(&self, destination_folder: &Path) -> Result<usize, Error> {
+        let mut advisories = self.advisory_db.iter().collect::<Vec<_>>();
+        advisories.sort_by_key(|advisory| advisory.id());
+
+        let export = Export {
+            format_version: FORMAT_VERSION,
+            commit: self.repository.latest_commit()?.commit_id.to_hex(),
+            advisories,
+        };
+
+        let json = serde_json::to_vec_pretty(&export)
+            .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+
+        fs::create_dir_all(destination_folder)?;
+        let json_path = destination_folder.join(FILE_NAME);
+        fs::write(&json_path, &json)?;
+
+        let gz_path = destination_folder.join(format!("{FILE_NAME}.gz"));
+        let mut encoder = GzEncoder::new(
+            BufWriter::new(fs::File::create(&gz_path)?),
+            Compression::best(),
+        );
+        encoder.write_all(&json)?;
+        encoder.finish()?.flush()?;
+
+        Ok(export.advisories.len())
+    }
+}
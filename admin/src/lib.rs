@@ -9,12 +9,18 @@ pub mod application;
 pub mod assigner;
 pub mod commands;
 pub mod config;
+pub mod csaf_export;
 pub mod error;
+pub mod json_export;
 pub mod linter;
 pub mod list_versions;
 pub mod lock;
+pub mod osv_check;
 pub mod osv_export;
 pub mod prelude;
+pub mod scaffold;
+pub mod sqlite_export;
+pub mod stats;
 pub mod synchronizer;
 pub mod web;
 
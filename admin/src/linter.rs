@@ -1,14 +1,31 @@
 //! RustSec Advisory DB Linter
 
+mod aliases;
+mod config;
+mod description;
+mod duplicates;
+pub(crate) mod ranges;
+mod urls;
+
 use std::{
     collections::BTreeSet,
-    fs,
+    fmt, fs,
     path::{Path, PathBuf},
 };
 
-use rustsec::{Advisory, Collection, Database};
+use rustsec::{
+    Advisory, Collection, Database, Version,
+    semver::{Comparator, Op},
+};
 use tame_index::index::RemoteSparseIndex;
 
+pub use self::config::{Check, Level, LintConfig, Preset};
+
+use self::{
+    aliases::AliasChecker,
+    description::SpellChecker,
+    urls::{UrlChecker, UrlProblem},
+};
 use crate::{
     crates_index,
     error::{Error, ErrorKind},
@@ -31,6 +48,18 @@ pub struct Linter {
     /// Loaded Advisory DB
     advisory_db: Database,
 
+    /// Levels the checks are reported at
+    config: LintConfig,
+
+    /// Checker for the aliases of advisories, if enabled
+    alias_checker: Option<AliasChecker>,
+
+    /// Checker for the URLs referenced by advisories, if enabled
+    url_checker: Option<UrlChecker>,
+
+    /// Spell checker for advisory titles and descriptions, if enabled
+    spell_checker: Option<SpellChecker>,
+
     /// Total number of invalid advisories encountered
     invalid_advisories: usize,
 }
@@ -45,10 +74,45 @@ impl Linter {
             repo_path,
             crates_index: crates_index()?,
             advisory_db,
+            config: LintConfig::default(),
+            alias_checker: None,
+            url_checker: None,
+            spell_checker: None,
             invalid_advisories: 0,
         })
     }
 
+    /// Set the levels the checks are reported at
+    pub fn set_config(&mut self, config: LintConfig) {
+        self.config = config;
+    }
+
+    /// Check that the CVE and GHSA aliases of advisories are well-formed,
+    /// exist upstream and are about the same crate
+    pub fn enable_alias_checks(&mut self) -> Result<(), Error> {
+        self.alias_checker = Some(AliasChecker::new()?);
+        Ok(())
+    }
+
+    /// Spell check the titles and descriptions of advisories against the
+    /// given dictionaries, allowing the names of crates with advisories
+    pub fn enable_spell_checks(&mut self, dictionaries: &[PathBuf]) -> Result<(), Error> {
+        let crate_names = self
+            .advisory_db
+            .iter()
+            .map(|advisory| advisory.metadata.package.as_str().to_owned());
+
+        self.spell_checker = Some(SpellChecker::load(dictionaries, crate_names)?);
+        Ok(())
+    }
+
+    /// Check that the URLs referenced by advisories are live, and suggest
+    /// replacements for the ones which have moved
+    pub fn enable_url_checks(&mut self) -> Result<(), Error> {
+        self.url_checker = Some(UrlChecker::new()?);
+        Ok(())
+    }
+
     /// Borrow the loaded advisory database
     pub fn advisory_db(&self) -> &Database {
         &self.advisory_db
@@ -77,9 +141,15 @@ impl Linter {
                 }
             }
 
-            if collection == &Collection::Crates {
-                self.crates_io_lints(&advisories)?;
+            if collection == &Collection::Crates
+                && (self.config.is_enabled(Check::CratesIo)
+                    || self.config.is_enabled(Check::PublishedVersions))
+            {
+                self.crates_io_lints(&advisories);
             }
+
+            self.alias_lints(&advisories);
+            self.url_lints(&advisories);
         }
 
         Ok(self.invalid_advisories)
@@ -103,26 +173,150 @@ impl Linter {
 
         let advisory = Advisory::load_file(advisory_path)?;
         let lint_result = rustsec::advisory::Linter::lint_file(advisory_path)?;
-        if lint_result.errors().is_empty() {
-            status_ok!("Linted", "ok: {}", advisory_path.display());
-        } else {
-            self.invalid_advisories += 1;
 
-            status_err!(
-                "{} contained the following lint errors:",
-                advisory_path.display()
+        let mut problems = lint_result
+            .errors()
+            .iter()
+            .map(|error| (Check::Format, error.to_string()))
+            .collect::<Vec<_>>();
+
+        if self.config.is_enabled(Check::Ranges) {
+            problems.extend(
+                ranges::check(&advisory)
+                    .into_iter()
+                    .map(|problem| (Check::Ranges, problem)),
+            );
+        }
+
+        if self.config.is_enabled(Check::Duplicates) {
+            problems.extend(
+                duplicates::check(&advisory, &self.advisory_db)
+                    .into_iter()
+                    .map(|problem| (Check::Duplicates, problem)),
+            );
+        }
+
+        if self.config.is_enabled(Check::Markdown) {
+            problems.extend(
+                description::check_markdown(advisory.description())
+                    .into_iter()
+                    .map(|problem| (Check::Markdown, problem)),
             );
+        }
+
+        if let Some(checker) = &self.spell_checker
+            && self.config.is_enabled(Check::Spelling)
+        {
+            let mut unknown = checker.check(advisory.title());
+            unknown.extend(checker.check(advisory.description()));
 
-            for error in lint_result.errors() {
-                println!("  - {error}");
+            if !unknown.is_empty() {
+                let words = unknown.into_iter().collect::<Vec<_>>().join(", ");
+                problems.push((Check::Spelling, format!("possibly misspelled: {words}")));
             }
         }
 
+        if !self.report(advisory_path.display(), problems) {
+            status_ok!("Linted", "ok: {}", advisory_path.display());
+        }
+
         Ok(advisory)
     }
 
+    /// Check the aliases of advisories (if enabled)
+    fn alias_lints(&mut self, advisories: &[Advisory]) {
+        let Some(checker) = &self.alias_checker else {
+            return;
+        };
+
+        if !self.config.is_enabled(Check::Aliases) {
+            return;
+        }
+
+        let reports = advisories
+            .iter()
+            .map(|advisory| {
+                let problems = checker
+                    .check(advisory)
+                    .into_iter()
+                    .map(|problem| (Check::Aliases, problem))
+                    .collect();
+
+                (&advisory.metadata.id, problems)
+            })
+            .collect::<Vec<_>>();
+
+        for (id, problems) in reports {
+            self.report(id, problems);
+        }
+    }
+
+    /// Check the URLs referenced by advisories (if enabled)
+    fn url_lints(&mut self, advisories: &[Advisory]) {
+        let Some(checker) = &self.url_checker else {
+            return;
+        };
+
+        if !self.config.is_enabled(Check::DeadUrls) && !self.config.is_enabled(Check::MovedUrls) {
+            return;
+        }
+
+        let reports = advisories
+            .iter()
+            .map(|advisory| {
+                let problems = checker
+                    .check(advisory)
+                    .into_iter()
+                    .map(|problem| match problem {
+                        UrlProblem::Dead { url, reason } => {
+                            (Check::DeadUrls, format!("{url}: {reason}"))
+                        }
+                        UrlProblem::Moved { url, location } => (
+                            Check::MovedUrls,
+                            format!("{url} has moved permanently, use {location} instead"),
+                        ),
+                    })
+                    .collect();
+
+                (&advisory.metadata.id, problems)
+            })
+            .collect::<Vec<_>>();
+
+        for (id, problems) in reports {
+            self.report(id, problems);
+        }
+    }
+
+    /// Report the problems found in an advisory according to the levels of
+    /// the checks which found them, returning whether any were reported
+    fn report(&mut self, advisory: impl fmt::Display, problems: Vec<(Check, String)>) -> bool {
+        let (errors, warnings): (Vec<_>, Vec<_>) = problems
+            .into_iter()
+            .filter(|(check, _)| self.config.is_enabled(*check))
+            .partition(|(check, _)| self.config.level(*check) == Level::Error);
+
+        if !errors.is_empty() {
+            self.invalid_advisories += 1;
+            status_err!("{} contained the following lint errors:", advisory);
+
+            for (check, error) in &errors {
+                println!("  - {error} ({check})");
+            }
+        }
+
+        if !warnings.is_empty() {
+            status_warn!("{} contained the following lint warnings:", advisory);
+
+            for (check, warning) in &warnings {
+                println!("  - {warning} ({check})");
+            }
+        }
+
+        !errors.is_empty() || !warnings.is_empty()
+    }
+
     /// Perform lints that connect to https://crates.io
-    fn crates_io_lints(&mut self, advisories: &[Advisory]) -> Result<(), Error> {
+    fn crates_io_lints(&mut self, advisories: &[Advisory]) {
         let names = BTreeSet::from_iter(advisories.iter().filter_map(|advisory| {
             match advisory.metadata.expect_deleted {
                 false => Some(advisory.metadata.package.as_str().to_owned()),
@@ -135,61 +329,109 @@ impl Linter {
             Err(error) => {
                 status_err!("Failed to acquire cargo package lock: {}", error);
                 status_err!("Skipping crates.io lints");
-                return Ok(());
+                return;
             }
         };
 
         // Check if crates with these names exist on crates.io
 
         let metadata = self.crates_index.krates(names, true, &lock);
+        let mut reports = vec![];
         for advisory in advisories {
             if advisory.metadata.expect_deleted {
                 continue;
             }
 
+            let mut problems = vec![];
             match metadata.get(advisory.metadata.package.as_str()) {
                 Some(Ok(Some(crate_))) => {
                     // This check verifies name normalization.
                     // A request for "serde-json" might return "serde_json",
                     // and we want to catch use a non-canonical name and report it as an error.
                     if crate_.name() != advisory.metadata.package.as_str() {
-                        self.invalid_advisories += 1;
-
-                        fail!(
-                            ErrorKind::CratesIo,
-                            "crates.io package name does not match package name in advisory for {} in {}",
-                            advisory.metadata.package.as_str(),
-                            advisory.metadata.id
-                        );
+                        problems.push((
+                            Check::CratesIo,
+                            format!(
+                                "crates.io package name does not match package name in advisory: {}",
+                                crate_.name()
+                            ),
+                        ));
                     }
-                }
-                Some(Ok(None)) | None => {
-                    self.invalid_advisories += 1;
 
-                    fail!(
-                        ErrorKind::CratesIo,
-                        "crates.io package name does not exist for {} in {}",
-                        advisory.metadata.package.as_str(),
-                        advisory.metadata.id
-                    );
-                }
-                Some(Err(error)) => {
-                    self.invalid_advisories += 1;
+                    // This check catches typos in the versions an advisory
+                    // is patched or unaffected in, e.g. 1.2.4 instead of 1.2.5
+                    let published = crate_
+                        .versions
+                        .iter()
+                        .filter_map(|version| Version::parse(&version.version).ok())
+                        .collect::<Vec<_>>();
 
-                    fail!(
-                        ErrorKind::CratesIo,
-                        "failed to fetch crates.io metadata for {} in {}: {error}",
-                        advisory.metadata.package.as_str(),
-                        advisory.metadata.id
-                    );
+                    let unpublished = advisory
+                        .versions
+                        .patched()
+                        .iter()
+                        .chain(advisory.versions.unaffected())
+                        .flat_map(|req| &req.comparators)
+                        .find(|comparator| !is_published(comparator, &published));
+
+                    if let Some(comparator) = unpublished {
+                        problems.push((
+                            Check::PublishedVersions,
+                            format!(
+                                "version `{}` of {} was never published on crates.io",
+                                comparator,
+                                advisory.metadata.package.as_str()
+                            ),
+                        ));
+                    }
                 }
+                Some(Ok(None)) | None => problems.push((
+                    Check::CratesIo,
+                    format!(
+                        "crates.io package name does not exist: {}",
+                        advisory.metadata.package.as_str()
+                    ),
+                )),
+                // Fetch failures are usually transient, so they don't fail the lint
+                Some(Err(error)) => status_warn!(
+                    "failed to fetch crates.io metadata for {} in {}: {}",
+                    advisory.metadata.package.as_str(),
+                    advisory.metadata.id,
+                    error
+                ),
             }
+
+            reports.push((&advisory.metadata.id, problems));
         }
 
-        Ok(())
+        for (id, problems) in reports {
+            self.report(id, problems);
+        }
     }
 }
 
+/// Was the release a `patched` or `unaffected` version requirement refers to
+/// published?
+///
+/// Requirements like `>= 1.2.4` start at a release which must exist, whereas
+/// upper bounds like `< 1.3.0` needn't refer to one. Partial versions (e.g.
+/// `>= 1.2`) refer to any release in the series.
+pub(crate) fn is_published(comparator: &Comparator, published: &[Version]) -> bool {
+    if !matches!(
+        comparator.op,
+        Op::Exact | Op::GreaterEq | Op::Tilde | Op::Caret
+    ) {
+        return true;
+    }
+
+    published.iter().any(|version| {
+        version.major == comparator.major
+            && comparator.minor.is_none_or(|minor| minor == version.minor)
+            && comparator.patch.is_none_or(|patch| patch == version.patch)
+            && version.pre == comparator.pre
+    })
+}
+
 fn read_dir_sorted<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<fs::DirEntry>> {
     let read_dir = fs::read_dir(path)?;
     let mut crate_entries = read_dir.into_iter().collect::<Result<Vec<_>, _>>()?;
@@ -0,0 +1,135 @@
+//! Checks for the aliases (CVE and GHSA IDs) of advisories
+
+use rustsec::{
+    Advisory, Collection,
+    advisory::{Id, IdKind},
+    osv::OsvAdvisory,
+};
+use tame_index::external::reqwest::{StatusCode, blocking::Client};
+
+use crate::{error::Error, prelude::*};
+
+/// CVE Services API endpoint for looking up CVE records
+const CVE_API_URL: &str = "https://cveawg.mitre.org/api/cve/";
+
+/// osv.dev API endpoint for looking up advisories (including GHSAs)
+const OSV_API_URL: &str = "https://api.osv.dev/v1/vulns/";
+
+/// Characters GHSA IDs are made of
+const GHSA_ALPHABET: &str = "23456789cfghjmpqrvwx";
+
+/// Checks that the aliases of advisories exist upstream
+pub struct AliasChecker {
+    /// HTTP client for upstream lookups
+    client: Client,
+}
+
+impl AliasChecker {
+    /// Create a new alias checker
+    pub fn new() -> Result<Self, Error> {
+        let client = Client::builder().build().map_err(tame_index::Error::from)?;
+
+        Ok(Self { client })
+    }
+
+    /// Check the aliases of the given advisory, returning the problems found
+    pub fn check(&self, advisory: &Advisory) -> Vec<String> {
+        let mut problems = vec![];
+
+        for alias in &advisory.metadata.aliases {
+            if !is_well_formed(alias) {
+                problems.push(format!("malformed alias: {alias}"));
+                continue;
+            }
+
+            let problem = match alias.kind() {
+                IdKind::Cve => self.check_cve(alias),
+                IdKind::Ghsa => self.check_ghsa(alias, advisory),
+                _ => None,
+            };
+            problems.extend(problem);
+        }
+
+        problems
+    }
+
+    /// Check that a CVE has been published
+    fn check_cve(&self, alias: &Id) -> Option<String> {
+        self.fetch(CVE_API_URL, alias)?
+            .is_none()
+            .then(|| format!("{alias} does not exist"))
+    }
+
+    /// Check that a GHSA exists and is about the same crate as the advisory
+    fn check_ghsa(&self, alias: &Id, advisory: &Advisory) -> Option<String> {
+        let Some(body) = self.fetch(OSV_API_URL, alias)? else {
+            return Some(format!("{alias} does not exist"));
+        };
+
+        let osv = match serde_json::from_str::<OsvAdvisory>(&body) {
+            Ok(osv) => osv,
+            Err(e) => {
+                status_warn!("couldn't parse {}: {}", alias, e);
+                return None;
+            }
+        };
+
+        let crates = osv.crates();
+        let package = advisory.metadata.package.as_str();
+        let is_crate = advisory.metadata.collection == Some(Collection::Crates);
+
+        (is_crate && !crates.is_empty() && !crates.iter().any(|name| name == package))
+            .then(|| format!("{alias} is about {}, not {package}", crates.join(", ")))
+    }
+
+    /// Fetch the record of the given ID from an API endpoint.
+    ///
+    /// Returns `Some(None)` if it doesn't exist, and `None` if the lookup
+    /// failed (after printing a warning).
+    fn fetch(&self, api_url: &str, id: &Id) -> Option<Option<String>> {
+        let result = self
+            .client
+            .get(format!("{api_url}{id}"))
+            .send()
+            .and_then(|response| match response.status() {
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => response.error_for_status()?.text().map(Some),
+            });
+
+        match result {
+            Ok(body) => Some(body),
+            Err(e) => {
+                status_warn!("couldn't look up {}: {}", id, e);
+                None
+            }
+        }
+    }
+}
+
+/// Is the given alias well-formed? (only CVE and GHSA IDs are checked)
+fn is_well_formed(alias: &Id) -> bool {
+    match alias.kind() {
+        IdKind::Cve => {
+            let mut parts = alias.as_str().split('-').skip(1);
+            let (Some(year), Some(number), None) = (parts.next(), parts.next(), parts.next())
+            else {
+                return false;
+            };
+
+            year.len() == 4
+                && number.len() >= 4
+                && [year, number]
+                    .iter()
+                    .all(|part| part.bytes().all(|b| b.is_ascii_digit()))
+        }
+        IdKind::Ghsa => {
+            let parts = alias.as_str().split('-').skip(1).collect::<Vec<_>>();
+
+            parts.len() == 3
+                && parts
+                    .iter()
+                    .all(|part| part.len() == 4 && part.chars().all(|c| GHSA_ALPHABET.contains(c)))
+        }
+        _ => true,
+    }
+}
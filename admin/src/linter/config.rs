@@ -0,0 +1,188 @@
+//! Configuration of how strict linting is
+
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// Checks performed by the linter
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Check {
+    /// Advisories are well-formed and only use known fields
+    Format,
+
+    /// Version ranges make sense
+    Ranges,
+
+    /// Advisories aren't likely duplicates of others
+    Duplicates,
+
+    /// Descriptions are valid Markdown
+    Markdown,
+
+    /// Titles and descriptions are spelled correctly (with `--dictionary`)
+    Spelling,
+
+    /// Packages exist on crates.io under the same name
+    CratesIo,
+
+    /// Patched and unaffected versions were published on crates.io
+    PublishedVersions,
+
+    /// CVE and GHSA aliases exist and are about the same crate (with `--check-aliases`)
+    Aliases,
+
+    /// Links aren't dead (with `--check-urls`)
+    DeadUrls,
+
+    /// Links haven't moved permanently (with `--check-urls`)
+    MovedUrls,
+}
+
+impl Check {
+    /// All of the checks
+    pub const ALL: &[Check] = &[
+        Check::Format,
+        Check::Ranges,
+        Check::Duplicates,
+        Check::Markdown,
+        Check::Spelling,
+        Check::CratesIo,
+        Check::PublishedVersions,
+        Check::Aliases,
+        Check::DeadUrls,
+        Check::MovedUrls,
+    ];
+
+    /// Level of this check when it isn't configured.
+    ///
+    /// Heuristic checks only warn by default, as they can be wrong about
+    /// existing advisories. The `strict` preset makes them fail linting too.
+    fn default_level(self) -> Level {
+        match self {
+            Check::Ranges
+            | Check::Duplicates
+            | Check::Markdown
+            | Check::Spelling
+            | Check::PublishedVersions
+            | Check::MovedUrls => Level::Warn,
+            _ => Level::Error,
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Check::Format => "format",
+            Check::Ranges => "ranges",
+            Check::Duplicates => "duplicates",
+            Check::Markdown => "markdown",
+            Check::Spelling => "spelling",
+            Check::CratesIo => "crates-io",
+            Check::PublishedVersions => "published-versions",
+            Check::Aliases => "aliases",
+            Check::DeadUrls => "dead-urls",
+            Check::MovedUrls => "moved-urls",
+        })
+    }
+}
+
+/// How problems found by a check are reported
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// Problems make linting fail
+    Error,
+
+    /// Problems are reported, but linting still succeeds
+    Warn,
+
+    /// The check isn't performed
+    Off,
+}
+
+/// Presets for the levels of all checks
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Preset {
+    /// Use the default level of each check
+    #[default]
+    Default,
+
+    /// Fail on any problem, e.g. in the CI of the advisory database
+    Strict,
+
+    /// Report problems without failing, e.g. when working on an advisory
+    Contributor,
+}
+
+/// Linter configuration, mapping each check to the level it's reported at
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Levels of the checks which aren't left at their default
+    #[serde(default)]
+    pub checks: BTreeMap<Check, Level>,
+}
+
+impl LintConfig {
+    /// Create a configuration from a preset
+    pub fn preset(preset: Preset) -> Self {
+        let level = match preset {
+            Preset::Default => return Self::default(),
+            Preset::Strict => Level::Error,
+            Preset::Contributor => Level::Warn,
+        };
+
+        Self {
+            checks: Check::ALL.iter().map(|check| (*check, level)).collect(),
+        }
+    }
+
+    /// Load a configuration file in the TOML format, e.g.
+    ///
+    /// ```toml
+    /// [checks]
+    /// spelling = "error"
+    /// moved-urls = "off"
+    /// ```
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let config = match fs::read_to_string(path) {
+            Ok(config) => config,
+            Err(e) => fail!(ErrorKind::Config, "couldn't read {}: {}", path.display(), e),
+        };
+
+        match toml::from_str(&config) {
+            Ok(config) => Ok(config),
+            Err(e) => fail!(
+                ErrorKind::Config,
+                "invalid lint configuration in {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    /// Override the levels of checks with the ones configured in `other`
+    pub fn merge(&mut self, other: LintConfig) {
+        self.checks.extend(other.checks);
+    }
+
+    /// Get the level of the given check
+    pub fn level(&self, check: Check) -> Level {
+        self.checks
+            .get(&check)
+            .copied()
+            .unwrap_or_else(|| check.default_level())
+    }
+
+    /// Is the given check performed?
+    pub fn is_enabled(&self, check: Check) -> bool {
+        self.level(check) != Level::Off
+    }
+}
@@ -0,0 +1,177 @@
+//! Checks for the Markdown descriptions of advisories
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use comrak::{
+    Arena, Node, Options,
+    nodes::{NodeHeading, NodeValue},
+    parse_document,
+};
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// URL schemes links in descriptions may use
+const LINK_SCHEMES: &[&str] = &["https://", "http://", "mailto:"];
+
+/// Check the Markdown of an advisory description, returning the problems found
+pub fn check_markdown(description: &str) -> Vec<String> {
+    let mut problems = vec![];
+
+    let start = description.trim_start();
+    if start.starts_with("---") || start.starts_with("+++") {
+        problems.push("description starts with stray front matter".to_owned());
+    }
+
+    let arena = Arena::new();
+    let root = parse_document(&arena, description, &Options::default());
+
+    for node in root.descendants() {
+        match &node.data().value {
+            NodeValue::Link(link) => {
+                if link.url.is_empty() {
+                    problems.push(format!("link `{}` has no URL", text_of(node)));
+                } else if !link.url.starts_with('#')
+                    && !LINK_SCHEMES
+                        .iter()
+                        .any(|scheme| link.url.starts_with(scheme))
+                {
+                    problems.push(format!(
+                        "link to `{}` isn't an absolute URL, so it won't work outside of the repository",
+                        link.url
+                    ));
+                }
+            }
+            NodeValue::Text(text) => {
+                // Links which failed to parse are left as text
+                if text.contains("][") || text.contains("](") {
+                    problems.push(format!("broken link: `{text}`"));
+                }
+
+                // So are headings without a space after the `#`
+                let is_paragraph_start = node.previous_sibling().is_none()
+                    && node
+                        .parent()
+                        .is_some_and(|parent| matches!(parent.data().value, NodeValue::Paragraph));
+                let heading_text = text.trim_start_matches('#');
+                if is_paragraph_start
+                    && heading_text.len() < text.len()
+                    && heading_text.starts_with(|c: char| c.is_alphabetic())
+                {
+                    problems.push(format!(
+                        "malformed heading (missing space after `#`): `{text}`"
+                    ));
+                }
+            }
+            NodeValue::Heading(NodeHeading { level: 1, .. }) => problems.push(format!(
+                "level 1 heading `{}` in the description (only the title should be one)",
+                text_of(node)
+            )),
+            NodeValue::CodeBlock(block)
+                if block.info.starts_with("toml")
+                    && ["[advisory]", "[versions]"]
+                        .iter()
+                        .any(|section| block.literal.contains(section)) =>
+            {
+                problems.push("description contains stray advisory front matter".to_owned());
+            }
+            _ => (),
+        }
+    }
+
+    problems
+}
+
+/// Spell checker for advisory titles and descriptions
+pub struct SpellChecker {
+    /// Known words (in lowercase)
+    words: BTreeSet<String>,
+}
+
+impl SpellChecker {
+    /// Load the dictionaries at the given paths, which have one word per line
+    /// (Hunspell `.dic` files can be used as well), along with additional
+    /// words to allow such as crate names
+    pub fn load(
+        dictionaries: &[impl AsRef<Path>],
+        allowed: impl IntoIterator<Item = String>,
+    ) -> Result<Self, Error> {
+        let mut words = BTreeSet::new();
+
+        for path in dictionaries {
+            let path = path.as_ref();
+            let dictionary = match fs::read_to_string(path) {
+                Ok(dictionary) => dictionary,
+                Err(e) => fail!(
+                    ErrorKind::Io,
+                    "couldn't read dictionary {}: {}",
+                    path.display(),
+                    e
+                ),
+            };
+
+            words.extend(dictionary.lines().filter_map(|line| {
+                let word = line.split('/').next()?.trim();
+                (!word.is_empty()).then(|| word.to_lowercase())
+            }));
+        }
+
+        for word in allowed {
+            words.extend(split_words(&word).map(str::to_lowercase));
+        }
+
+        Ok(Self { words })
+    }
+
+    /// Find the words in the given Markdown text which aren't in the
+    /// dictionary. Code isn't checked.
+    pub fn check(&self, markdown: &str) -> BTreeSet<String> {
+        let arena = Arena::new();
+        let root = parse_document(&arena, markdown, &Options::default());
+
+        let mut unknown = BTreeSet::new();
+        for node in root.descendants() {
+            if let NodeValue::Text(text) = &node.data().value {
+                unknown.extend(
+                    split_words(text)
+                        .filter(|word| self.is_unknown(word))
+                        .map(str::to_owned),
+                );
+            }
+        }
+
+        unknown
+    }
+
+    /// Is the given word unknown?
+    fn is_unknown(&self, word: &str) -> bool {
+        // Acronyms and identifiers (e.g. `HashMap`) aren't checked
+        if word.chars().skip(1).any(char::is_uppercase) {
+            return false;
+        }
+
+        let word = word.to_lowercase();
+        let word = word.strip_suffix("'s").unwrap_or(&word);
+        !self.words.contains(word)
+    }
+}
+
+/// Split text into words
+fn split_words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphabetic() && c != '\'')
+        .map(|word| word.trim_matches('\''))
+        .filter(|word| !word.is_empty())
+}
+
+/// Get the text within a node
+fn text_of<'a>(node: Node<'a>) -> String {
+    node.descendants()
+        .filter_map(|node| match &node.data().value {
+            NodeValue::Text(text) => Some(text.to_string()),
+            NodeValue::Code(code) => Some(code.literal.clone()),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,83 @@
+//! Detection of advisories which are likely duplicates of others
+//!
+//! Two advisories are likely about the same bug when they are about the same
+//! crate, affect overlapping versions and either share an alias or have
+//! similar titles.
+
+use std::collections::BTreeSet;
+
+use rustsec::{Advisory, Database, advisory::Id};
+
+/// Share of the words of two titles they must have in common to be similar
+const TITLE_SIMILARITY: f64 = 0.6;
+
+/// Words which don't say anything about the bug
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "by", "can", "for", "from", "in", "is", "may", "of", "on", "or", "the", "to",
+    "when", "with",
+];
+
+/// Find the advisories in the database which the given advisory likely
+/// duplicates, returning the problems found.
+///
+/// Draft advisories are compared with all of the advisories, whereas
+/// advisories which already have an ID are only compared with the earlier
+/// ones, so that each pair of duplicates is reported once.
+pub fn check(advisory: &Advisory, advisory_db: &Database) -> Vec<String> {
+    let is_draft = advisory.id().is_placeholder();
+
+    advisory_db
+        .iter()
+        .filter(|other| is_draft || other.id() < advisory.id())
+        .filter(|other| other.metadata.package == advisory.metadata.package)
+        .filter(|other| other.metadata.informational == advisory.metadata.informational)
+        .filter(|other| other.versions.overlaps(&advisory.versions))
+        .filter_map(|other| {
+            let reason = match shared_alias(advisory, other) {
+                Some(alias) => format!("both have the alias {alias}"),
+                None if similar_titles(advisory.title(), other.title()) => {
+                    "their titles are similar".to_owned()
+                }
+                None => return None,
+            };
+
+            Some(format!(
+                "likely duplicate of {} (same crate and overlapping versions, and {})",
+                other.id(),
+                reason
+            ))
+        })
+        .collect()
+}
+
+/// Find an alias two advisories have in common
+fn shared_alias<'a>(advisory: &'a Advisory, other: &Advisory) -> Option<&'a Id> {
+    advisory
+        .metadata
+        .aliases
+        .iter()
+        .find(|alias| other.metadata.aliases.contains(alias))
+}
+
+/// Do two titles have most of their words in common?
+fn similar_titles(title: &str, other: &str) -> bool {
+    let words = title_words(title);
+    let other_words = title_words(other);
+
+    if words.is_empty() || other_words.is_empty() {
+        return false;
+    }
+
+    let common = words.intersection(&other_words).count();
+    let all = words.union(&other_words).count();
+    common as f64 / all as f64 >= TITLE_SIMILARITY
+}
+
+/// Get the meaningful words of a title, in lowercase
+fn title_words(title: &str) -> BTreeSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .map(str::to_lowercase)
+        .filter(|word| !word.is_empty() && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
@@ -0,0 +1,114 @@
+//! Sanity checks for the version ranges of advisories
+//!
+//! Overlapping `patched` and `unaffected` ranges are rejected when parsing
+//! advisories, but ranges can be well-formed and still not make sense.
+
+use rustsec::{Advisory, advisory::Versions, osv};
+
+/// Check the `[versions]` of the given advisory, returning the problems found
+pub fn check(advisory: &Advisory) -> Vec<String> {
+    let versions = &advisory.versions;
+    let affected = versions.affected_ranges();
+
+    if affected.is_empty() {
+        return vec![
+            "no version is vulnerable: the patched and unaffected versions cover all of them"
+                .to_owned(),
+        ];
+    }
+
+    // Ranges of each patched requirement, which are merged when adjacent
+    // (e.g. `^0.2.2` and `>= 0.3.0`)
+    let patched: Vec<_> = versions
+        .patched()
+        .iter()
+        .filter_map(|req| Versions::new(vec![req.clone()], vec![]).ok())
+        .map(|versions| (versions.patched()[0].clone(), versions.unaffected_ranges()))
+        .collect();
+    let merged = osv::normalize(
+        patched
+            .iter()
+            .flat_map(|(_, ranges)| ranges.iter().cloned())
+            .collect(),
+    );
+
+    let mut problems = vec![];
+
+    // A patch fixes a vulnerability in the versions before it, so each
+    // patched range has to start right where a range of affected versions ends
+    for range in merged {
+        let reqs = patched
+            .iter()
+            .filter(|(_, ranges)| {
+                ranges.iter().any(|r| match &r.introduced {
+                    Some(start) => range.affects(start),
+                    None => range.introduced.is_none(),
+                })
+            })
+            .map(|(req, _)| format!("`{req}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match &range.introduced {
+            None => problems.push(format!(
+                "patched versions {reqs} include the earliest releases, \
+                 which were never vulnerable (should they be unaffected?)"
+            )),
+            Some(start) if !affected.iter().any(|a| a.fixed.as_ref() == Some(start)) => problems
+                .push(format!(
+                    "patched versions {reqs} don't follow any vulnerable version: \
+                     the versions before {start} are unaffected \
+                     (should these be unaffected too?)"
+                )),
+            Some(_) => (),
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check;
+    use rustsec::Advisory;
+
+    /// Parse an advisory with the given `[versions]`
+    fn advisory(patched: &[&str], unaffected: &[&str]) -> Advisory {
+        format!(
+            "```toml\n\
+             [advisory]\n\
+             id = \"RUSTSEC-2001-2101\"\n\
+             package = \"base\"\n\
+             date = \"2001-02-03\"\n\
+             [versions]\n\
+             patched = {patched:?}\n\
+             unaffected = {unaffected:?}\n\
+             ```\n\n\
+             # Title\n\n\
+             Description\n"
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn adjacent_patched_ranges() {
+        assert!(check(&advisory(&["^0.2.2", ">= 0.3.0"], &[])).is_empty());
+        assert!(check(&advisory(&["^0.2.2", ">= 0.3.1"], &["< 0.1.0"])).is_empty());
+    }
+
+    #[test]
+    fn orphaned_patched_range() {
+        let problems = check(&advisory(&[">= 1.2.0"], &["< 1.0.0", ">= 1.1.0, < 1.2.0"]));
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("`>=1.2.0`"), "{}", problems[0]);
+        assert!(problems[0].contains("before 1.2.0"), "{}", problems[0]);
+    }
+
+    #[test]
+    fn no_vulnerable_version() {
+        let problems = check(&advisory(&[">= 1.0.0"], &["< 1.0.0"]));
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("no version is vulnerable"));
+    }
+}
@@ -0,0 +1,123 @@
+//! Checks for the URLs referenced by advisories
+
+use std::time::Duration;
+
+use rustsec::Advisory;
+use tame_index::external::reqwest::{
+    self, Method, StatusCode,
+    blocking::{Client, Response},
+    header::LOCATION,
+    redirect,
+};
+
+use crate::error::Error;
+
+/// How long to wait for a response from a server
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Problem found with a URL
+#[derive(Debug)]
+pub enum UrlProblem {
+    /// The URL can't be fetched
+    Dead {
+        /// Dead URL
+        url: String,
+
+        /// Why it can't be fetched
+        reason: String,
+    },
+
+    /// The URL redirects permanently to another one
+    Moved {
+        /// Old URL
+        url: String,
+
+        /// URL it redirects to, which should be used instead
+        location: String,
+    },
+}
+
+/// Checks that the URLs referenced by advisories are still live
+pub struct UrlChecker {
+    /// HTTP client which doesn't follow redirects
+    client: Client,
+}
+
+impl UrlChecker {
+    /// Create a new URL checker
+    pub fn new() -> Result<Self, Error> {
+        let client = Client::builder()
+            .redirect(redirect::Policy::none())
+            .timeout(TIMEOUT)
+            .user_agent(concat!("rustsec-admin/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(tame_index::Error::from)?;
+
+        Ok(Self { client })
+    }
+
+    /// Check the `url` and `references` of the given advisory
+    pub fn check(&self, advisory: &Advisory) -> Vec<UrlProblem> {
+        advisory
+            .metadata
+            .url
+            .iter()
+            .chain(&advisory.metadata.references)
+            .filter_map(|url| self.check_url(url.as_str()))
+            .collect()
+    }
+
+    /// Check a single URL
+    fn check_url(&self, url: &str) -> Option<UrlProblem> {
+        // Some servers don't support `HEAD` requests (or answer them
+        // differently), so fall back to `GET` before declaring a URL dead
+        let response = match self.request(Method::HEAD, url) {
+            Ok(response) if !is_error(response.status()) => Ok(response),
+            _ => self.request(Method::GET, url),
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                return Some(UrlProblem::Dead {
+                    url: url.to_owned(),
+                    reason: e.to_string(),
+                });
+            }
+        };
+
+        let status = response.status();
+        match status {
+            StatusCode::MOVED_PERMANENTLY | StatusCode::PERMANENT_REDIRECT => {
+                let location = response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .and_then(|location| response.url().join(location).ok())?;
+
+                Some(UrlProblem::Moved {
+                    url: url.to_owned(),
+                    location: location.to_string(),
+                })
+            }
+            _ if is_error(status) => Some(UrlProblem::Dead {
+                url: url.to_owned(),
+                reason: status.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Send a request for the given URL
+    fn request(&self, method: Method, url: &str) -> reqwest::Result<Response> {
+        self.client.request(method, url).send()
+    }
+}
+
+/// Does the status mean the URL is dead?
+///
+/// Rate limiting doesn't, as the URL may work when retried later.
+fn is_error(status: StatusCode) -> bool {
+    (status.is_client_error() || status.is_server_error())
+        && status != StatusCode::TOO_MANY_REQUESTS
+}
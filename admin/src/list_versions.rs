@@ -1,11 +1,18 @@
 //! Backend for the `list-affected-versions` subcommand.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use rustsec::{Advisory, Database};
+use rustsec::{Advisory, Database, advisory::Id};
+use serde::Serialize;
 use tame_index::index::RemoteSparseIndex;
+use thiserror::Error;
 
-use crate::{crates_index, error::Error, lock::acquire_cargo_package_lock, prelude::*};
+use crate::{
+    crates_index,
+    error::{Error, ErrorKind},
+    lock::acquire_cargo_package_lock,
+    prelude::*,
+};
 
 /// Lists all versions for a crate and prints info on which ones are affected
 pub struct AffectedVersionLister {
@@ -16,6 +23,101 @@ pub struct AffectedVersionLister {
     advisory_db: Database,
 }
 
+/// Errors listing the versions of the crate an advisory is about
+#[derive(Debug, Error)]
+pub enum ListVersionsError {
+    /// The name of the crate isn't valid in the index
+    #[error("invalid crate name '{name}': {source}")]
+    InvalidCrateName {
+        /// Name of the crate
+        name: String,
+
+        /// Error validating the name
+        source: tame_index::Error,
+    },
+
+    /// The crate isn't in the index, e.g. because it was renamed or deleted
+    #[error("crate '{name}' not found on crates.io")]
+    MissingCrate {
+        /// Name of the crate
+        name: String,
+    },
+
+    /// A published version of the crate couldn't be parsed
+    #[error("invalid version {version} of crate '{name}': {source}")]
+    InvalidVersion {
+        /// Name of the crate
+        name: String,
+
+        /// The version
+        version: String,
+
+        /// Error parsing the version
+        source: rustsec::semver::Error,
+    },
+
+    /// Error locking or querying the index
+    #[error("crates.io index error: {0}")]
+    Index(#[from] tame_index::Error),
+}
+
+/// Versions of all of the advisories, along with the advisories they
+/// couldn't be listed for
+#[derive(Debug, Default)]
+pub struct Summary {
+    /// Versions of the advisories which could be listed
+    pub advisories: Vec<AdvisoryVersions>,
+
+    /// Advisories the versions couldn't be listed for
+    pub failures: Vec<(Id, ListVersionsError)>,
+}
+
+/// Published versions of the crate an advisory is about
+#[derive(Debug, Serialize)]
+pub struct AdvisoryVersions {
+    /// ID of the advisory
+    pub id: Id,
+
+    /// Name of the crate
+    pub package: String,
+
+    /// All published versions of the crate
+    pub versions: Vec<PublishedVersion>,
+}
+
+impl AdvisoryVersions {
+    /// Whether any published version is vulnerable
+    pub fn any_vulnerable(&self) -> bool {
+        self.versions
+            .iter()
+            .any(|version| version.status == VersionStatus::Vulnerable)
+    }
+}
+
+/// Published version of a crate
+#[derive(Debug, Serialize)]
+pub struct PublishedVersion {
+    /// The version
+    pub version: String,
+
+    /// Whether the version is affected by the advisory
+    pub status: VersionStatus,
+
+    /// Whether the version was yanked
+    pub yanked: bool,
+}
+
+/// Whether a version is affected by an advisory
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionStatus {
+    /// The version is affected
+    Vulnerable,
+
+    /// The version isn't affected
+    Ok,
+}
+
 impl AffectedVersionLister {
     /// Load the database at the given path
     pub fn new(repo_path: impl Into<PathBuf>) -> Result<Self, Error> {
@@ -33,40 +135,90 @@ impl AffectedVersionLister {
         &self.advisory_db
     }
 
+    /// Find an advisory given either its ID in the loaded database or the
+    /// path to its file
+    pub fn find_advisory(&self, id_or_path: &str) -> Result<Advisory, Error> {
+        let path = Path::new(id_or_path);
+        if path.is_file() {
+            return Ok(Advisory::load_file(path)?);
+        }
+
+        let id = id_or_path.parse::<Id>()?;
+        match self.advisory_db.get(&id) {
+            Some(advisory) => Ok(advisory.clone()),
+            None => fail!(
+                ErrorKind::RustSec,
+                "no advisory with ID {} or file at this path",
+                id_or_path
+            ),
+        }
+    }
+
     /// List affected and unaffected crate versions for a given advisory
-    pub fn process_one_advisory(&self, advisory: &Advisory) {
-        status_ok!(
-            "Loaded",
-            "{} for '{}'",
-            advisory.id(),
-            advisory.metadata.package
-        );
+    pub fn process_one_advisory(
+        &self,
+        advisory: &Advisory,
+    ) -> Result<AdvisoryVersions, ListVersionsError> {
         let crate_name = advisory.metadata.package.as_str();
-        let lock = acquire_cargo_package_lock().unwrap();
+        let lock = acquire_cargo_package_lock()?;
+        let krate_name =
+            crate_name
+                .try_into()
+                .map_err(|source| ListVersionsError::InvalidCrateName {
+                    name: crate_name.to_owned(),
+                    source,
+                })?;
         let crate_info = self
             .crates_index
-            .krate(crate_name.try_into().unwrap(), true, &lock)
-            .unwrap()
-            .unwrap_or_else(|| panic!("expected crate {crate_name} to exist"));
-        for version in crate_info.versions {
-            let parsed_version = rustsec::Version::parse(&version.version).unwrap();
-            if advisory.versions.is_vulnerable(&parsed_version) {
-                println!("{} vulnerable", version.version)
-            } else {
-                println!("{} OK", version.version)
-            }
-        }
+            .krate(krate_name, true, &lock)?
+            .ok_or_else(|| ListVersionsError::MissingCrate {
+                name: crate_name.to_owned(),
+            })?;
+
+        let versions = crate_info
+            .versions
+            .iter()
+            .map(|version| {
+                let parsed_version =
+                    rustsec::Version::parse(&version.version).map_err(|source| {
+                        ListVersionsError::InvalidVersion {
+                            name: crate_name.to_owned(),
+                            version: version.version.to_string(),
+                            source,
+                        }
+                    })?;
+                Ok(PublishedVersion {
+                    version: version.version.to_string(),
+                    status: match advisory.versions.is_vulnerable(&parsed_version) {
+                        true => VersionStatus::Vulnerable,
+                        false => VersionStatus::Ok,
+                    },
+                    yanked: version.yanked,
+                })
+            })
+            .collect::<Result<_, ListVersionsError>>()?;
+
+        Ok(AdvisoryVersions {
+            id: advisory.id().clone(),
+            package: crate_name.to_owned(),
+            versions,
+        })
     }
 
-    /// List affected and unaffected crate versions for all advisories
-    pub fn process_all_advisories(&self) -> Result<(), Error> {
+    /// List affected and unaffected crate versions for all advisories,
+    /// carrying on past the advisories they can't be listed for
+    pub fn process_all_advisories(&self) -> Summary {
+        let mut summary = Summary::default();
         for advisory in self.advisory_db.iter() {
             // We currently only support crate versions, not advisories against Rust versions
-            if advisory.metadata.collection.unwrap() != rustsec::Collection::Crates {
+            if advisory.metadata.collection != Some(rustsec::Collection::Crates) {
                 continue;
             }
-            self.process_one_advisory(advisory);
+            match self.process_one_advisory(advisory) {
+                Ok(versions) => summary.advisories.push(versions),
+                Err(error) => summary.failures.push((advisory.id().clone(), error)),
+            }
         }
-        Ok(())
+        summary
     }
 }
@@ -0,0 +1,195 @@
+//! Backend for the `check-osv` subcommand.
+//!
+//! Exports each advisory to OSV, parses the OSV document back into a RustSec
+//! advisory and compares the result with the original, to catch field
+//! mappings of the OSV export which lose information or can't be reversed.
+
+use std::{collections::BTreeSet, fmt::Debug, path::Path};
+
+use rustsec::{
+    Advisory, Collection, Database,
+    advisory::{Id, Informational},
+    osv::OsvAdvisory,
+    repository::git::{GitModificationTimes, GitPath, Repository},
+};
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// Checks that advisories survive a round trip through the OSV format
+pub struct OsvChecker {
+    /// Loaded git repository
+    repository: Repository,
+
+    /// Loaded modification times for files in Git
+    mod_times: GitModificationTimes,
+
+    /// Loaded advisory database
+    advisory_db: Database,
+}
+
+/// Field of an advisory which didn't survive the round trip
+#[derive(Debug)]
+pub struct Mismatch {
+    /// ID of the advisory
+    pub id: Id,
+
+    /// Name of the field
+    pub field: &'static str,
+
+    /// Value in the original advisory
+    pub original: String,
+
+    /// Value after the round trip
+    pub round_tripped: String,
+}
+
+impl OsvChecker {
+    /// Load the database at the given path
+    pub fn new(repo_path: Option<&Path>) -> Result<Self, Error> {
+        let repository = match repo_path {
+            Some(path) => Repository::open(path)?,
+            None => Repository::fetch_default_repo()?,
+        };
+        let mod_times = GitModificationTimes::new(&repository)?;
+        let advisory_db = Database::load_from_repo(&repository)?;
+        Ok(Self {
+            repository,
+            mod_times,
+            advisory_db,
+        })
+    }
+
+    /// Round-trip all advisories exported to OSV, returning the number of
+    /// advisories checked and the fields which didn't survive
+    pub fn check_all(&self) -> Result<(usize, Vec<Mismatch>), Error> {
+        let mut count = 0;
+        let mut mismatches = vec![];
+
+        for advisory in self.advisory_db.iter() {
+            if !is_exported(advisory) {
+                continue;
+            }
+
+            mismatches.extend(self.check(advisory)?);
+            count += 1;
+        }
+
+        Ok((count, mismatches))
+    }
+
+    /// Round-trip a single advisory
+    fn check(&self, advisory: &Advisory) -> Result<Vec<Mismatch>, Error> {
+        let id = advisory.id();
+        let relative_path = Path::new(Collection::Crates.as_str())
+            .join(advisory.metadata.package.as_str())
+            .join(format!("{id}.md"));
+        let gitpath = GitPath::new(&self.repository, &relative_path)?;
+        let osv = OsvAdvisory::from_rustsec(advisory.clone(), &self.mod_times, gitpath);
+
+        // Go through JSON to also cover the (de)serialization of OSV documents
+        let json =
+            serde_json::to_string(&osv).map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+        let osv: OsvAdvisory = serde_json::from_str(&json).map_err(|err| {
+            format_err!(ErrorKind::Parse, "invalid OSV document for {}: {}", id, err)
+        })?;
+
+        let round_tripped = match osv.to_advisories()?.as_slice() {
+            [round_tripped] => round_tripped.clone(),
+            advisories => fail!(
+                ErrorKind::Parse,
+                "OSV document for {} converts to {} advisories instead of one",
+                id,
+                advisories.len()
+            ),
+        };
+
+        Ok(compare(advisory, &round_tripped))
+    }
+}
+
+/// Whether the advisory is exported to OSV, following the rules of the
+/// `osv` subcommand
+fn is_exported(advisory: &Advisory) -> bool {
+    advisory.metadata.collection == Some(Collection::Crates)
+        && matches!(
+            advisory.metadata.informational,
+            None | Some(
+                Informational::Unmaintained | Informational::Unsound | Informational::Notice
+            )
+        )
+}
+
+/// Compare an advisory with its round-tripped version.
+///
+/// OSV has no place for the `keywords`, `source` and `expect_deleted` fields,
+/// nor for the versions in which each affected function is affected, so they
+/// aren't compared. The main URL is exported as one of the references, so the
+/// URLs are compared as a whole, and versions are compared by the ranges they
+/// affect since the requirements are rebuilt from OSV ranges.
+fn compare(original: &Advisory, round_tripped: &Advisory) -> Vec<Mismatch> {
+    let (a, b) = (&original.metadata, &round_tripped.metadata);
+    let mut mismatches = vec![];
+    let mut check = |field, original: &dyn Debug, round_tripped: &dyn Debug| {
+        let (original, round_tripped) = (format!("{original:?}"), format!("{round_tripped:?}"));
+        if original != round_tripped {
+            mismatches.push(Mismatch {
+                id: a.id.clone(),
+                field,
+                original,
+                round_tripped,
+            });
+        }
+    };
+
+    check("id", &a.id, &b.id);
+    check("package", &a.package, &b.package);
+    check("title", &a.title, &b.title);
+    check("description", &a.description, &b.description);
+    check("date", &a.date, &b.date);
+    check("aliases", &a.aliases, &b.aliases);
+    check("related", &a.related, &b.related);
+    check("collection", &a.collection, &b.collection);
+    check("categories", &a.categories, &b.categories);
+    check("cwe", &a.cwe_ids, &b.cwe_ids);
+    check("cvss", &a.cvss, &b.cvss);
+    check("severity", &a.severity, &b.severity);
+    check("informational", &a.informational, &b.informational);
+    check("withdrawn", &a.withdrawn, &b.withdrawn);
+    check("license", &a.license, &b.license);
+    check("url", &urls(original), &urls(round_tripped));
+    check(
+        "versions",
+        &original.versions.affected_ranges(),
+        &round_tripped.versions.affected_ranges(),
+    );
+
+    let affected = original.affected.clone().unwrap_or_default();
+    let round_tripped_affected = round_tripped.affected.clone().unwrap_or_default();
+    check(
+        "affected.arch",
+        &affected.arch,
+        &round_tripped_affected.arch,
+    );
+    check("affected.os", &affected.os, &round_tripped_affected.os);
+    check(
+        "affected.functions",
+        &affected.functions.keys().collect::<Vec<_>>(),
+        &round_tripped_affected.functions.keys().collect::<Vec<_>>(),
+    );
+
+    mismatches
+}
+
+/// All URLs of an advisory, whether its main URL or other references
+fn urls(advisory: &Advisory) -> BTreeSet<&str> {
+    let metadata = &advisory.metadata;
+    metadata
+        .url
+        .iter()
+        .chain(&metadata.references)
+        .map(|url| url.as_str())
+        .collect()
+}
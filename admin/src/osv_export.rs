@@ -4,17 +4,27 @@ use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use rustsec::{
-    Advisory, Collection,
-    advisory::Informational,
+    Advisory, Collection, Database,
+    advisory::{Id, Informational},
     osv::OsvAdvisory,
     repository::git::{GitModificationTimes, GitPath, Repository},
 };
 
+use jsonschema::Validator;
+use serde::Serialize;
+use serde_json::Value;
+
 use crate::{
     error::{Error, ErrorKind},
     prelude::*,
 };
 
+/// Version of the OSV schema exported documents are validated against
+pub const OSV_SCHEMA_VERSION: &str = "1.7.0";
+
+/// OSV JSON Schema, vendored from the `ossf/osv-schema` repo
+const OSV_SCHEMA: &str = include_str!("osv_export/schema.json");
+
 /// Lists all versions for a crate and prints info on which ones are affected
 pub struct OsvExporter {
     /// Loaded git repository
@@ -22,6 +32,9 @@ pub struct OsvExporter {
 
     /// Loaded modification times for files in Git
     mod_times: GitModificationTimes,
+
+    /// Validator for the OSV schema
+    validator: Validator,
 }
 
 impl OsvExporter {
@@ -32,17 +45,24 @@ impl OsvExporter {
             None => Repository::fetch_default_repo()?,
         };
         let mod_times = GitModificationTimes::new(&repository)?;
+        let schema = serde_json::from_str(OSV_SCHEMA)
+            .map_err(|err| format_err!(ErrorKind::Parse, "invalid OSV schema: {}", err))?;
+        let validator = jsonschema::validator_for(&schema)
+            .map_err(|err| format_err!(ErrorKind::Parse, "invalid OSV schema: {}", err))?;
         Ok(Self {
             repository,
             mod_times,
+            validator,
         })
     }
 
     /// Exports all advisories to OSV JSON format to the specified directory.
-    pub fn export_all(&self, destination_folder: &Path) -> Result<(), Error> {
+    pub fn export_all(&self, destination_folder: &Path) -> Result<Manifest, Error> {
         let repo_path = self.repository.path();
         let collection_path = repo_path.join(Collection::Crates.as_str());
         let mut found_at_least_one_advisory = false;
+        let mut manifest = self.manifest(None)?;
+        let mut invalid = vec![];
 
         let collection_entry = fs::read_dir(&collection_path).map_err(|err| {
             format_err!(
@@ -55,42 +75,20 @@ impl OsvExporter {
             for advisory_entry in fs::read_dir(dir_entry?.path())? {
                 found_at_least_one_advisory = true;
 
-                // Load the RustSec advisory
                 let advisory_path = advisory_entry?.path();
-                let advisory = Advisory::load_file(&advisory_path)?;
-                let id = advisory.id().clone();
-
-                if let Some(kind) = &advisory.metadata.informational {
-                    match kind {
-                        // If not `Unmaintained` or `Unsound` or `Notice`, don't export it to OSV
-                        // to make the output format stable.
-                        // Adding new types should be accompanied by a version bump.
-                        Informational::Unmaintained => (),
-                        Informational::Unsound => (),
-                        Informational::Notice => (),
-                        _ => continue,
-                    }
-                }
-
-                // Transform the advisory to OSV format
-                // We've been simply pushing things to the end of the path, so in theory
-                // it *should* reverse cleanly, hence the `.unwrap()`
-                let relative_path = advisory_path.strip_prefix(repo_path).unwrap();
-                let gitpath = GitPath::new(&self.repository, relative_path)?;
-                let osv = OsvAdvisory::from_rustsec(advisory, &self.mod_times, gitpath);
-
-                // Serialize the OSV advisory to JSON and write it to file
-                let mut output_path: PathBuf = destination_folder.join(id.as_str());
-                output_path.set_extension("json");
-                let output_file = fs::File::create(output_path)?;
-                let writer = std::io::BufWriter::new(output_file);
-                serde_json::to_writer_pretty(writer, &osv)
-                    .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?
+                manifest.written.extend(self.export_advisory(
+                    &advisory_path,
+                    destination_folder,
+                    &mut invalid,
+                )?);
             }
         }
 
+        check_invalid(invalid)?;
+
         if found_at_least_one_advisory {
-            Ok(())
+            manifest.written.sort();
+            Ok(manifest)
         } else {
             Err(format_err!(
                 ErrorKind::Io,
@@ -99,4 +97,181 @@ impl OsvExporter {
             .into())
         }
     }
+
+    /// Exports the advisories which changed since the given revision (e.g. the
+    /// commit of the previous export) to the specified directory, removing the
+    /// files of the advisories which were deleted since then.
+    pub fn export_since(&self, destination_folder: &Path, rev: &str) -> Result<Manifest, Error> {
+        let old = Database::load_from_commit(&self.repository, rev)?;
+        let new = Database::load_from_commit(&self.repository, "HEAD")?;
+        let diff = old.diff(&new);
+        let mut manifest = self.manifest(Some(rev))?;
+        let mut invalid = vec![];
+
+        let changed = diff
+            .added
+            .iter()
+            .chain(&diff.modified)
+            .chain(&diff.withdrawn)
+            .filter_map(|id| new.get(id));
+
+        for advisory in changed {
+            if advisory.metadata.collection != Some(Collection::Crates) {
+                continue;
+            }
+
+            let advisory_path = self
+                .repository
+                .path()
+                .join(Collection::Crates.as_str())
+                .join(advisory.metadata.package.as_str())
+                .join(format!("{}.md", advisory.id()));
+
+            manifest.written.extend(self.export_advisory(
+                &advisory_path,
+                destination_folder,
+                &mut invalid,
+            )?);
+        }
+
+        check_invalid(invalid)?;
+
+        for id in diff.removed {
+            let output_path = output_path(destination_folder, &id);
+            if output_path.exists() {
+                fs::remove_file(output_path)?;
+                manifest.removed.push(id);
+            }
+        }
+
+        manifest.written.sort();
+        Ok(manifest)
+    }
+
+    /// Exports a single advisory to the specified directory, returning its ID
+    /// unless it isn't exported to OSV.
+    ///
+    /// Advisories whose OSV document doesn't conform to the OSV schema aren't
+    /// written, and the schema violations are added to `invalid` instead.
+    fn export_advisory(
+        &self,
+        advisory_path: &Path,
+        destination_folder: &Path,
+        invalid: &mut Vec<String>,
+    ) -> Result<Option<Id>, Error> {
+        // Load the RustSec advisory
+        let advisory = Advisory::load_file(advisory_path)?;
+        let id = advisory.id().clone();
+
+        if let Some(kind) = &advisory.metadata.informational {
+            match kind {
+                // If not `Unmaintained` or `Unsound` or `Notice`, don't export it to OSV
+                // to make the output format stable.
+                // Adding new types should be accompanied by a version bump.
+                Informational::Unmaintained => (),
+                Informational::Unsound => (),
+                Informational::Notice => (),
+                _ => return Ok(None),
+            }
+        }
+
+        // Transform the advisory to OSV format
+        // We've been simply pushing things to the end of the path, so in theory
+        // it *should* reverse cleanly, hence the `.unwrap()`
+        let relative_path = advisory_path.strip_prefix(self.repository.path()).unwrap();
+        let gitpath = GitPath::new(&self.repository, relative_path)?;
+        let osv = OsvAdvisory::from_rustsec(advisory, &self.mod_times, gitpath);
+
+        // Serialize the OSV advisory to JSON, validate it and write it to file
+        let output_path = output_path(destination_folder, &id);
+        let json =
+            serde_json::to_value(&osv).map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+
+        let errors = self.validate(&json);
+        if !errors.is_empty() {
+            invalid.extend(
+                errors
+                    .into_iter()
+                    .map(|error| format!("{}: {}", output_path.display(), error)),
+            );
+            return Ok(None);
+        }
+
+        let output_file = fs::File::create(output_path)?;
+        let writer = std::io::BufWriter::new(output_file);
+        serde_json::to_writer_pretty(writer, &json)
+            .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+
+        Ok(Some(id))
+    }
+
+    /// Validate an OSV document against the OSV schema, returning the
+    /// violations found
+    fn validate(&self, json: &Value) -> Vec<String> {
+        self.validator
+            .iter_errors(json)
+            .map(|error| match error.instance_path().as_str() {
+                "" => error.to_string(),
+                path => format!("{path}: {error}"),
+            })
+            .collect()
+    }
+
+    /// Start the manifest of an export of the latest commit
+    fn manifest(&self, since: Option<&str>) -> Result<Manifest, Error> {
+        Ok(Manifest {
+            commit: self.repository.latest_commit()?.commit_id.to_hex(),
+            since: since.map(ToOwned::to_owned),
+            written: vec![],
+            removed: vec![],
+        })
+    }
+}
+
+/// Manifest of an export, listing the files which were written or removed,
+/// for the publishing pipeline to only upload those
+#[derive(Debug, Serialize)]
+pub struct Manifest {
+    /// Commit of the advisory database which was exported
+    pub commit: String,
+
+    /// Revision the export was incremental from, if any
+    pub since: Option<String>,
+
+    /// IDs of the advisories whose files were written
+    pub written: Vec<Id>,
+
+    /// IDs of the advisories whose files were removed
+    pub removed: Vec<Id>,
+}
+
+impl Manifest {
+    /// Write the manifest as JSON to the given path
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|err| format_err!(ErrorKind::Io, "{}", err))?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Fail with the schema violations found, if any
+fn check_invalid(invalid: Vec<String>) -> Result<(), Error> {
+    if !invalid.is_empty() {
+        fail!(
+            ErrorKind::Parse,
+            "exported OSV documents don't conform to OSV schema {}:\n{}",
+            OSV_SCHEMA_VERSION,
+            invalid.join("\n")
+        );
+    }
+
+    Ok(())
+}
+
+/// Path of the OSV file of an advisory
+fn output_path(destination_folder: &Path, id: &Id) -> PathBuf {
+    let mut output_path = destination_folder.join(id.as_str());
+    output_path.set_extension("json");
+    output_path
 }
@@ -0,0 +1,229 @@
+//! Scaffolding for new advisories
+//!
+//! Generates the skeleton of a draft advisory, with the package information
+//! and version ranges checked against the crates.io index, for contributors to
+//! fill in (rather than copying an existing advisory with stale fields).
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cvss::{Cvss, Severity};
+use rustsec::{
+    Advisory, Collection, Version,
+    advisory::{Informational, Linter},
+};
+use tame_index::index::RemoteSparseIndex;
+use toml_edit::{Array, DocumentMut, Item, Table, value};
+
+use crate::{
+    error::{Error, ErrorKind},
+    linter::{is_published, ranges},
+    lock::acquire_cargo_package_lock,
+    prelude::*,
+};
+
+/// Placeholder ID of draft advisories, replaced by `assign-id`
+const DRAFT_ID: &str = "RUSTSEC-0000-0000";
+
+/// Placeholder for the description of the advisory
+const DESCRIPTION_PLACEHOLDER: &str =
+    "Describe the issue: what is affected, how it can be exploited and how to fix it.";
+
+/// Information to fill the skeleton of a new advisory with
+#[derive(Clone, Debug, Default)]
+pub struct Scaffold {
+    /// Name of the affected crate
+    pub package: String,
+
+    /// Date the issue was reported (`YYYY-MM-DD`)
+    pub date: String,
+
+    /// One-line title of the advisory
+    pub title: String,
+
+    /// Markdown description of the issue (a placeholder is used if empty)
+    pub description: String,
+
+    /// Kind of informational advisory, if this isn't a vulnerability
+    pub informational: Option<Informational>,
+
+    /// URL of the issue or report
+    pub url: Option<String>,
+
+    /// RustSec categories of the vulnerability
+    pub categories: Vec<String>,
+
+    /// Freeform keywords
+    pub keywords: Vec<String>,
+
+    /// IDs of the advisory in other databases (e.g. CVE or GHSA IDs)
+    pub aliases: Vec<String>,
+
+    /// CVSS vector of the vulnerability
+    pub cvss: Option<Cvss>,
+
+    /// Qualitative severity of the vulnerability, if it has no CVSS vector
+    pub severity: Option<Severity>,
+
+    /// Version requirements for the patched versions
+    pub patched: Vec<String>,
+
+    /// Version requirements for the versions which were never affected
+    pub unaffected: Vec<String>,
+}
+
+impl Scaffold {
+    /// Check the package and version ranges against the crates.io index.
+    ///
+    /// The package name is replaced by its canonical name on crates.io.
+    /// Returns warnings about version ranges which are unlikely to be right.
+    pub fn check_crates_io(&mut self, index: &RemoteSparseIndex) -> Result<Vec<String>, Error> {
+        let lock = acquire_cargo_package_lock()?;
+        let name = self.package.as_str().try_into()?;
+
+        let Some(crate_) = index.krate(name, true, &lock)? else {
+            fail!(
+                ErrorKind::CratesIo,
+                "crate {} does not exist on crates.io",
+                self.package
+            );
+        };
+
+        if crate_.name() != self.package {
+            status_warn!(
+                "using the crates.io name {} instead of {}",
+                crate_.name(),
+                self.package
+            );
+            self.package = crate_.name().to_owned();
+        }
+
+        let published = crate_
+            .versions
+            .iter()
+            .filter_map(|version| Version::parse(&version.version).ok())
+            .collect::<Vec<_>>();
+
+        let advisory = self.to_advisory()?;
+        let mut warnings = vec![];
+
+        for comparator in advisory
+            .versions
+            .patched()
+            .iter()
+            .chain(advisory.versions.unaffected())
+            .flat_map(|req| &req.comparators)
+            .filter(|comparator| !is_published(comparator, &published))
+        {
+            warnings.push(format!(
+                "version `{}` of {} was never published on crates.io",
+                comparator, self.package
+            ));
+        }
+
+        if !published
+            .iter()
+            .any(|version| advisory.versions.is_vulnerable(version))
+        {
+            warnings.push(format!(
+                "none of the published versions of {} are affected",
+                self.package
+            ));
+        }
+
+        Ok(warnings)
+    }
+
+    /// Render the draft advisory, ensuring it is well-formed.
+    ///
+    /// Returns the advisory along with warnings about its version ranges.
+    pub fn render(&self) -> Result<(String, Vec<String>), Error> {
+        let mut advisory = Table::new();
+        advisory["id"] = value(DRAFT_ID);
+        advisory["package"] = value(&self.package);
+        advisory["date"] = value(&self.date);
+
+        if let Some(url) = &self.url {
+            advisory["url"] = value(url);
+        }
+
+        if let Some(informational) = &self.informational {
+            advisory["informational"] = value(informational.as_str());
+        } else {
+            advisory["categories"] = value(Array::from_iter(&self.categories));
+        }
+
+        advisory["keywords"] = value(Array::from_iter(&self.keywords));
+        advisory["aliases"] = value(Array::from_iter(&self.aliases));
+
+        if let Some(cvss) = &self.cvss {
+            advisory["cvss"] = value(cvss.to_string());
+        } else if let Some(severity) = &self.severity {
+            advisory["severity"] = value(severity.as_str());
+        }
+
+        let mut versions = Table::new();
+        versions["patched"] = value(Array::from_iter(&self.patched));
+        if !self.unaffected.is_empty() {
+            versions["unaffected"] = value(Array::from_iter(&self.unaffected));
+        }
+
+        let mut front_matter = DocumentMut::new();
+        front_matter["advisory"] = Item::Table(advisory);
+        front_matter["versions"] = Item::Table(versions);
+
+        let description = match self.description.trim() {
+            "" => DESCRIPTION_PLACEHOLDER,
+            description => description,
+        };
+
+        let rendered = format!(
+            "```toml\n{}```\n\n# {}\n\n{}\n",
+            front_matter,
+            self.title.trim(),
+            description
+        );
+
+        let lint = Linter::lint_string(&rendered, Some(true))?;
+        if let Some(error) = lint.errors().first() {
+            fail!(ErrorKind::Parse, "invalid advisory: {}", error);
+        }
+
+        Ok((rendered, ranges::check(lint.advisory())))
+    }
+
+    /// Path of the draft advisory in the given advisory DB repo
+    pub fn path(&self, repo_path: &Path) -> PathBuf {
+        repo_path
+            .join(Collection::Crates.as_str())
+            .join(&self.package)
+            .join(format!("{DRAFT_ID}.md"))
+    }
+
+    /// Write the rendered draft advisory to the given advisory DB repo,
+    /// unless a draft for the same crate is already waiting for an ID.
+    ///
+    /// Returns the path of the draft.
+    pub fn write(&self, repo_path: &Path, advisory: &str) -> Result<PathBuf, Error> {
+        let advisory_path = self.path(repo_path);
+        if advisory_path.exists() {
+            fail!(
+                ErrorKind::Io,
+                "{} already exists: assign it an ID with `rustsec-admin assign-id` first",
+                advisory_path.display()
+            );
+        }
+
+        fs::create_dir_all(advisory_path.parent().unwrap())?;
+        fs::write(&advisory_path, advisory)?;
+        Ok(advisory_path)
+    }
+
+    /// Parse the advisory the fields currently describe
+    fn to_advisory(&self) -> Result<Advisory, Error> {
+        let (rendered, _) = self.render()?;
+        Ok(rendered.parse()?)
+    }
+}
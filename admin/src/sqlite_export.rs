@@ -0,0 +1,284 @@
+//! Backend for the `export sqlite` subcommand.
+
+use std::path::Path;
+
+use fs_err as fs;
+use rusqlite::{Connection, Transaction, params};
+use rustsec::{Advisory, Database, repository::git::Repository};
+
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// Version of the schema of the export, incremented on breaking changes
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Relational schema of the export
+const SCHEMA: &str = "
+CREATE TABLE metadata (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+
+CREATE TABLE advisories (
+    id TEXT PRIMARY KEY,
+    package TEXT NOT NULL,
+    collection TEXT,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    date TEXT NOT NULL,
+    withdrawn TEXT,
+    informational TEXT,
+    cvss TEXT,
+    cvss_score REAL,
+    severity TEXT,
+    url TEXT,
+    source TEXT,
+    license TEXT NOT NULL,
+    expect_deleted INTEGER NOT NULL
+);
+CREATE INDEX advisories_package ON advisories (package);
+CREATE INDEX advisories_date ON advisories (date);
+
+CREATE TABLE aliases (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    alias TEXT NOT NULL,
+    PRIMARY KEY (advisory_id, alias)
+);
+CREATE INDEX aliases_alias ON aliases (alias);
+
+CREATE TABLE related (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    related_id TEXT NOT NULL,
+    PRIMARY KEY (advisory_id, related_id)
+);
+CREATE INDEX related_related_id ON related (related_id);
+
+CREATE TABLE categories (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    category TEXT NOT NULL,
+    PRIMARY KEY (advisory_id, category)
+);
+CREATE INDEX categories_category ON categories (category);
+
+CREATE TABLE keywords (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    keyword TEXT NOT NULL,
+    PRIMARY KEY (advisory_id, keyword)
+);
+CREATE INDEX keywords_keyword ON keywords (keyword);
+
+CREATE TABLE cwe_ids (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    cwe_id INTEGER NOT NULL,
+    PRIMARY KEY (advisory_id, cwe_id)
+);
+CREATE INDEX cwe_ids_cwe_id ON cwe_ids (cwe_id);
+
+CREATE TABLE advisory_references (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    url TEXT NOT NULL,
+    PRIMARY KEY (advisory_id, url)
+);
+
+-- `kind` is either `patched` or `unaffected`
+CREATE TABLE versions (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    kind TEXT NOT NULL CHECK (kind IN ('patched', 'unaffected')),
+    requirement TEXT NOT NULL
+);
+CREATE INDEX versions_advisory_id ON versions (advisory_id);
+
+-- `kind` is either `arch` or `os`
+CREATE TABLE affected_platforms (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    kind TEXT NOT NULL CHECK (kind IN ('arch', 'os')),
+    name TEXT NOT NULL
+);
+CREATE INDEX affected_platforms_advisory_id ON affected_platforms (advisory_id);
+
+CREATE TABLE affected_functions (
+    advisory_id TEXT NOT NULL REFERENCES advisories (id),
+    path TEXT NOT NULL,
+    requirement TEXT NOT NULL
+);
+CREATE INDEX affected_functions_advisory_id ON affected_functions (advisory_id);
+CREATE INDEX affected_functions_path ON affected_functions (path);
+";
+
+/// Exports the advisory database to a SQLite database
+pub struct SqliteExporter {
+    /// Loaded git repository
+    repository: Repository,
+
+    /// Loaded advisory database
+    advisory_db: Database,
+}
+
+impl SqliteExporter {
+    /// Load the database at the given path
+    pub fn new(repo_path: Option<&Path>) -> Result<Self, Error> {
+        let repository = match repo_path {
+            Some(path) => Repository::open(path)?,
+            None => Repository::fetch_default_repo()?,
+        };
+        let advisory_db = Database::load_from_repo(&repository)?;
+        Ok(Self {
+            repository,
+            advisory_db,
+        })
+    }
+
+    /// Exports all advisories to a new SQLite database at the given path,
+    /// replacing any existing file, and returns the number of advisories.
+    pub fn export(&self, output_path: &Path) -> Result<usize, Error> {
+        if output_path.exists() {
+            fs::remove_file(output_path)?;
+        }
+
+        let mut connection = Connection::open(output_path).map_err(sqlite_error)?;
+        let transaction = connection.transaction().map_err(sqlite_error)?;
+        transaction.execute_batch(SCHEMA).map_err(sqlite_error)?;
+
+        let commit = self.repository.latest_commit()?.commit_id.to_hex();
+        for (key, value) in [
+            ("schema_version", SCHEMA_VERSION.to_string()),
+            ("commit", commit.to_string()),
+        ] {
+            transaction
+                .execute(
+                    "INSERT INTO metadata (key, value) VALUES (?1, ?2)",
+                    params![key, value],
+                )
+                .map_err(sqlite_error)?;
+        }
+
+        let mut count = 0;
+        for advisory in self.advisory_db.iter() {
+            insert_advisory(&transaction, advisory).map_err(sqlite_error)?;
+            count += 1;
+        }
+
+        transaction.commit().map_err(sqlite_error)?;
+        Ok(count)
+    }
+}
+
+/// Insert an advisory and the data related to it
+fn insert_advisory(transaction: &Transaction<'_>, advisory: &Advisory) -> rusqlite::Result<()> {
+    let metadata = &advisory.metadata;
+    let id = advisory.id().as_str();
+
+    transaction.execute(
+        "INSERT INTO advisories (id, package, collection, title, description, date, withdrawn,
+            informational, cvss, cvss_score, severity, url, source, license, expect_deleted)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        params![
+            id,
+            metadata.package.as_str(),
+            metadata.collection.map(|collection| collection.to_string()),
+            metadata.title,
+            metadata.description,
+            metadata.date.as_str(),
+            metadata.withdrawn.as_ref().map(|date| date.as_str()),
+            metadata.informational.as_ref().map(ToString::to_string),
+            metadata.cvss.as_ref().map(ToString::to_string),
+            metadata.cvss.as_ref().map(|cvss| cvss.score()),
+            advisory.severity().map(|severity| severity.to_string()),
+            metadata.url.as_ref().map(|url| url.as_str()),
+            metadata.source.as_ref().map(ToString::to_string),
+            metadata.license.spdx(),
+            metadata.expect_deleted,
+        ],
+    )?;
+
+    let lists: [(&str, Vec<String>); 6] = [
+        (
+            "INSERT OR IGNORE INTO aliases (advisory_id, alias) VALUES (?1, ?2)",
+            to_strings(&metadata.aliases),
+        ),
+        (
+            "INSERT OR IGNORE INTO related (advisory_id, related_id) VALUES (?1, ?2)",
+            to_strings(&metadata.related),
+        ),
+        (
+            "INSERT OR IGNORE INTO categories (advisory_id, category) VALUES (?1, ?2)",
+            to_strings(&metadata.categories),
+        ),
+        (
+            "INSERT OR IGNORE INTO keywords (advisory_id, keyword) VALUES (?1, ?2)",
+            metadata
+                .keywords
+                .iter()
+                .map(|keyword| keyword.as_str().to_owned())
+                .collect(),
+        ),
+        (
+            "INSERT OR IGNORE INTO cwe_ids (advisory_id, cwe_id) VALUES (?1, ?2)",
+            metadata
+                .cwe_ids
+                .iter()
+                .map(|cwe_id| cwe_id.number().to_string())
+                .collect(),
+        ),
+        (
+            "INSERT OR IGNORE INTO advisory_references (advisory_id, url) VALUES (?1, ?2)",
+            to_strings(&metadata.references),
+        ),
+    ];
+
+    for (sql, values) in lists {
+        let mut statement = transaction.prepare_cached(sql)?;
+        for value in values {
+            statement.execute(params![id, value])?;
+        }
+    }
+
+    let mut statement = transaction.prepare_cached(
+        "INSERT INTO versions (advisory_id, kind, requirement) VALUES (?1, ?2, ?3)",
+    )?;
+    for (kind, requirements) in [
+        ("patched", advisory.versions.patched()),
+        ("unaffected", advisory.versions.unaffected()),
+    ] {
+        for requirement in requirements {
+            statement.execute(params![id, kind, requirement.to_string()])?;
+        }
+    }
+
+    if let Some(affected) = &advisory.affected {
+        let mut statement = transaction.prepare_cached(
+            "INSERT INTO affected_platforms (advisory_id, kind, name) VALUES (?1, ?2, ?3)",
+        )?;
+        for (kind, names) in [
+            ("arch", to_strings(&affected.arch)),
+            ("os", to_strings(&affected.os)),
+        ] {
+            for name in names {
+                statement.execute(params![id, kind, name])?;
+            }
+        }
+
+        let mut statement = transaction.prepare_cached(
+            "INSERT INTO affected_functions (advisory_id, path, requirement) VALUES (?1, ?2, ?3)",
+        )?;
+        for (path, requirements) in &affected.functions {
+            for requirement in requirements {
+                statement.execute(params![id, path.to_string(), requirement.to_string()])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert items to their string representation
+fn to_strings<T: ToString>(items: &[T]) -> Vec<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+/// Convert a SQLite error to an error of this crate
+fn sqlite_error(err: rusqlite::Error) -> Error {
+    format_err!(ErrorKind::Io, "SQLite error: {}", err).into()
+}
@@ -0,0 +1,320 @@
+//! Statistics about the advisory database, e.g. for yearly reports
+
+use std::{cmp::Reverse, fmt, path::Path, process::Command};
+
+use chrono::NaiveDate;
+use rustsec::{Advisory, Database, advisory::Id};
+use serde::{Deserialize, Serialize};
+use tame_index::external::reqwest::{StatusCode, blocking::Client};
+
+use crate::{
+    Map,
+    error::{Error, ErrorKind},
+    prelude::*,
+};
+
+/// CVE Services API endpoint for looking up CVE records
+const CVE_API_URL: &str = "https://cveawg.mitre.org/api/cve/";
+
+/// Statistics about the advisory database
+#[derive(Debug, Default, Serialize)]
+pub struct Stats {
+    /// Number of advisories (excluding withdrawn ones)
+    pub advisories: usize,
+
+    /// Number of advisories per year they were reported in
+    pub per_year: Map<u32, usize>,
+
+    /// Number of advisories per kind (vulnerability or informational kind)
+    pub per_kind: Map<String, usize>,
+
+    /// Number of advisories per category
+    pub per_category: Map<String, usize>,
+
+    /// Number of advisories per collection
+    pub per_collection: Map<String, usize>,
+
+    /// Crates with the most advisories
+    pub top_crates: Vec<CrateCount>,
+
+    /// Time from CVE publication to RustSec entry, if CVE dates were fetched
+    pub cve_lag: Option<CveLag>,
+}
+
+/// Number of advisories about a crate
+#[derive(Debug, Serialize)]
+pub struct CrateCount {
+    /// Name of the crate
+    pub name: String,
+
+    /// Number of advisories about it
+    pub advisories: usize,
+}
+
+/// Time from the publication of CVEs to the entry of the corresponding
+/// advisories into the database
+#[derive(Debug, Serialize)]
+pub struct CveLag {
+    /// Number of advisories with a CVE alias both dates are known for
+    pub advisories: usize,
+
+    /// Mean number of days from CVE publication to RustSec entry, which is
+    /// negative when advisories are usually entered first
+    pub mean_days: f64,
+}
+
+impl Stats {
+    /// Compute statistics about the advisories of the database at the given
+    /// path, listing the `top` crates with the most advisories.
+    ///
+    /// If `cve_dates` are given (see [`fetch_cve_dates`]), the time from CVE
+    /// publication to RustSec entry is computed as well, using the date an
+    /// advisory was committed to the repo as its entry date.
+    pub fn compute(
+        repo_path: &Path,
+        advisory_db: &Database,
+        top: usize,
+        cve_dates: Option<&Map<Id, NaiveDate>>,
+    ) -> Result<Self, Error> {
+        let mut stats = Stats::default();
+        let mut per_crate = Map::<String, usize>::new();
+
+        for advisory in active_advisories(advisory_db) {
+            let metadata = &advisory.metadata;
+            stats.advisories += 1;
+            *stats.per_year.entry(metadata.date.year()).or_default() += 1;
+            *stats.per_kind.entry(kind(advisory)).or_default() += 1;
+            for category in &metadata.categories {
+                *stats.per_category.entry(category.to_string()).or_default() += 1;
+            }
+            if let Some(collection) = metadata.collection {
+                *stats
+                    .per_collection
+                    .entry(collection.to_string())
+                    .or_default() += 1;
+            }
+            *per_crate
+                .entry(metadata.package.as_str().to_owned())
+                .or_default() += 1;
+        }
+
+        let mut top_crates = per_crate
+            .into_iter()
+            .map(|(name, advisories)| CrateCount { name, advisories })
+            .collect::<Vec<_>>();
+        top_crates.sort_by_key(|crate_count| Reverse(crate_count.advisories));
+        top_crates.truncate(top);
+        stats.top_crates = top_crates;
+
+        if let Some(cve_dates) = cve_dates {
+            stats.cve_lag = cve_lag(repo_path, advisory_db, cve_dates)?;
+        }
+
+        Ok(stats)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Advisories: {}", self.advisories)?;
+
+        let tables: [(&str, Vec<(String, usize)>); 4] = [
+            (
+                "Per year",
+                self.per_year
+                    .iter()
+                    .map(|(year, count)| (year.to_string(), *count))
+                    .collect(),
+            ),
+            ("Per kind", counts(&self.per_kind)),
+            ("Per category", counts(&self.per_category)),
+            ("Per collection", counts(&self.per_collection)),
+        ];
+
+        for (title, rows) in tables {
+            writeln!(f, "\n{title}:")?;
+            for (name, count) in rows {
+                writeln!(f, "  {name:<24} {count:>6}")?;
+            }
+        }
+
+        writeln!(f, "\nTop crates:")?;
+        for crate_count in &self.top_crates {
+            writeln!(
+                f,
+                "  {:<24} {:>6}",
+                crate_count.name, crate_count.advisories
+            )?;
+        }
+
+        if let Some(lag) = &self.cve_lag {
+            writeln!(
+                f,
+                "\nMean time from CVE publication to RustSec entry: {:.1} days ({} advisories)",
+                lag.mean_days, lag.advisories
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Fetch the publication dates of the CVE aliases of the advisories from the
+/// CVE Services API
+pub fn fetch_cve_dates(advisory_db: &Database) -> Result<Map<Id, NaiveDate>, Error> {
+    let client = Client::builder().build().map_err(tame_index::Error::from)?;
+    let mut dates = Map::new();
+
+    let cves = active_advisories(advisory_db)
+        .flat_map(|advisory| &advisory.metadata.aliases)
+        .filter(|alias| alias.is_cve());
+
+    for cve in cves {
+        if dates.contains_key(cve) {
+            continue;
+        }
+
+        match fetch_cve_date(&client, cve) {
+            Ok(Some(date)) => {
+                dates.insert(cve.clone(), date);
+            }
+            Ok(None) => status_warn!("{} has not been published", cve),
+            Err(e) => status_warn!("couldn't fetch {}: {}", cve, e),
+        }
+    }
+
+    Ok(dates)
+}
+
+/// Fetch the publication date of a CVE
+fn fetch_cve_date(client: &Client, cve: &Id) -> Result<Option<NaiveDate>, Error> {
+    /// CVE record in the CVE JSON 5 format
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CveRecord {
+        cve_metadata: CveMetadata,
+    }
+
+    /// Metadata of a CVE record
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CveMetadata {
+        date_published: Option<String>,
+    }
+
+    let response = client
+        .get(format!("{CVE_API_URL}{cve}"))
+        .send()
+        .map_err(|e| format_err!(ErrorKind::Io, "{}", e))?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let body = response
+        .error_for_status()
+        .and_then(|response| response.text())
+        .map_err(|e| format_err!(ErrorKind::Io, "{}", e))?;
+
+    let record: CveRecord = serde_json::from_str(&body)
+        .map_err(|e| format_err!(ErrorKind::Parse, "error parsing {}: {}", cve, e))?;
+
+    Ok(record
+        .cve_metadata
+        .date_published
+        .and_then(|date| NaiveDate::parse_from_str(date.get(..10)?, "%Y-%m-%d").ok()))
+}
+
+/// Compute the mean time from CVE publication to RustSec entry
+fn cve_lag(
+    repo_path: &Path,
+    advisory_db: &Database,
+    cve_dates: &Map<Id, NaiveDate>,
+) -> Result<Option<CveLag>, Error> {
+    let entry_dates = entry_dates(repo_path)?;
+
+    let lags = active_advisories(advisory_db)
+        .filter_map(|advisory| {
+            let entered = entry_dates.get(advisory.id())?;
+            let published = advisory
+                .metadata
+                .aliases
+                .iter()
+                .filter_map(|alias| cve_dates.get(alias))
+                .min()?;
+
+            Some((*entered - *published).num_days())
+        })
+        .collect::<Vec<_>>();
+
+    Ok((!lags.is_empty()).then(|| CveLag {
+        advisories: lags.len(),
+        mean_days: lags.iter().sum::<i64>() as f64 / lags.len() as f64,
+    }))
+}
+
+/// Get the dates advisories were committed to the repo under their ID
+fn entry_dates(repo_path: &Path) -> Result<Map<Id, NaiveDate>, Error> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["log", "--diff-filter=A", "--name-only", "--format=%x00%cs"])
+        .output()?;
+
+    if !output.status.success() {
+        fail!(
+            ErrorKind::Git,
+            "{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let mut dates = Map::new();
+    let mut date = None;
+
+    // Commits are listed from the newest to the oldest, so the date an
+    // advisory was first added to the repo is the one which is kept
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(commit_date) = line.strip_prefix('\0') {
+            date = NaiveDate::parse_from_str(commit_date, "%Y-%m-%d").ok();
+            continue;
+        }
+
+        let id = Path::new(line)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<Id>().ok())
+            .filter(Id::is_rustsec);
+
+        if let (Some(id), Some(date)) = (id, date) {
+            dates.insert(id, date);
+        }
+    }
+
+    Ok(dates)
+}
+
+/// Iterate over the advisories which haven't been withdrawn
+fn active_advisories(advisory_db: &Database) -> impl Iterator<Item = &Advisory> {
+    advisory_db
+        .iter()
+        .filter(|advisory| advisory.metadata.withdrawn.is_none())
+}
+
+/// Get the kind of an advisory: `vulnerability` or its informational kind
+fn kind(advisory: &Advisory) -> String {
+    match &advisory.metadata.informational {
+        Some(informational) => informational.to_string(),
+        None => "vulnerability".to_owned(),
+    }
+}
+
+/// Get the counts of a map, from the highest to the lowest
+fn counts(map: &Map<String, usize>) -> Vec<(String, usize)> {
+    let mut counts = map
+        .iter()
+        .map(|(name, count)| (name.clone(), *count))
+        .collect::<Vec<_>>();
+    counts.sort_by_key(|(_, count)| Reverse(*count));
+    counts
+}
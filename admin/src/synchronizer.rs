@@ -68,6 +68,19 @@
 //!
 //! When an advisory contains no reference to an existing RustSec advisory, it is likely
 //! missing.
+//!
+//! ## NVD
+//!
+//! CVE records from the [NVD](https://nvd.nist.gov/vuln) are synchronized separately
+//! (see [`nvd`]), as they are fetched from the NVD API rather than from an export.
+//!
+//! ## GitHub Advisory Database API
+//!
+//! GHSAs can also be fetched directly from the GitHub API (see [`ghsa`]) to generate
+//! drafts for the missing ones and compare the others with our advisories.
+
+pub mod ghsa;
+pub mod nvd;
 
 use std::fs::read_to_string;
 use std::iter::FromIterator;
@@ -277,11 +290,7 @@ impl Synchronizer {
         }
         if !missing_aliases.is_empty() || !missing_related.is_empty() {
             self.update_aliases(
-                &self
-                    .repo_path
-                    .join(Collection::Crates.to_string())
-                    .join(advisory.metadata.package.as_str())
-                    .join(format!("{}.md", advisory.id())),
+                &advisory_path(&self.repo_path, advisory),
                 &missing_aliases,
                 &missing_related,
             )?;
@@ -296,56 +305,10 @@ impl Synchronizer {
         missing_aliases: &[Id],
         missing_related: &[Id],
     ) -> Result<(), Error> {
-        let content = read_to_string(advisory_path)?;
-        // First extract toml and markdown content
-        // We can't parse as Advisory as we want to preserve formatting
-        let parts = Parts::parse(&content)?;
-        // Parse toml
-        let mut metadata = parts
-            .front_matter
-            .parse::<DocumentMut>()
-            .expect("invalid TOML front matter");
-
-        // Aliases
-        let mut aliases: Vec<String> = metadata["advisory"]
-            .get("aliases")
-            .map(|i| {
-                i.as_array()
-                    .unwrap()
-                    .into_iter()
-                    .map(|v| v.as_str().unwrap().to_string())
-                    .collect()
-            })
-            .unwrap_or_else(Vec::new);
-        aliases.extend(missing_aliases.iter().map(|a| a.to_string()));
-        aliases.sort();
-        aliases.dedup();
-        if !aliases.is_empty() {
-            metadata["advisory"]["aliases"] = value(toml_edit::Array::from_iter(aliases.iter()));
-        }
-
-        // Related
-        // FIXME: dedup implementation
-        let mut related: Vec<String> = metadata["advisory"]
-            .get("related")
-            .map(|i| {
-                i.as_array()
-                    .unwrap()
-                    .into_iter()
-                    .map(|v| v.as_str().unwrap().to_string())
-                    .collect()
-            })
-            .unwrap_or_else(Vec::new);
-        related.extend(missing_related.iter().map(|a| a.to_string()));
-        related.sort();
-        related.dedup();
-        if !related.is_empty() {
-            metadata["advisory"]["related"] = value(toml_edit::Array::from_iter(related.iter()));
-        }
-
-        let updated = format!("```toml\n{}```\n\n{}", metadata, parts.markdown);
-        fs::write(advisory_path, updated)?;
-        status_info!("Info", "Written {}", advisory_path.display());
+        edit_front_matter(advisory_path, |metadata| {
+            extend_ids(metadata, "aliases", missing_aliases);
+            extend_ids(metadata, "related", missing_related);
+        })?;
         self.updated_advisories += 1;
         Ok(())
     }
@@ -383,3 +346,54 @@ impl Synchronizer {
         Ok(result)
     }
 }
+
+/// Path of the file of an advisory about a crate
+fn advisory_path(repo_path: &Path, advisory: &Advisory) -> PathBuf {
+    repo_path
+        .join(Collection::Crates.to_string())
+        .join(advisory.metadata.package.as_str())
+        .join(format!("{}.md", advisory.id()))
+}
+
+/// Edit the TOML front matter of an advisory file
+fn edit_front_matter(
+    advisory_path: &Path,
+    edit: impl FnOnce(&mut DocumentMut),
+) -> Result<(), Error> {
+    let content = read_to_string(advisory_path)?;
+    // First extract toml and markdown content
+    // We can't parse as Advisory as we want to preserve formatting
+    let parts = Parts::parse(&content)?;
+    // Parse toml
+    let mut metadata = parts
+        .front_matter
+        .parse::<DocumentMut>()
+        .expect("invalid TOML front matter");
+
+    edit(&mut metadata);
+
+    let updated = format!("```toml\n{}```\n\n{}", metadata, parts.markdown);
+    fs::write(advisory_path, updated)?;
+    status_info!("Info", "Written {}", advisory_path.display());
+    Ok(())
+}
+
+/// Add IDs to a list of IDs (e.g. `aliases`) of the `[advisory]` section
+fn extend_ids(metadata: &mut DocumentMut, key: &str, ids: &[Id]) {
+    let mut all_ids: Vec<String> = metadata["advisory"]
+        .get(key)
+        .map(|i| {
+            i.as_array()
+                .unwrap()
+                .into_iter()
+                .map(|v| v.as_str().unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    all_ids.extend(ids.iter().map(|a| a.to_string()));
+    all_ids.sort();
+    all_ids.dedup();
+    if !all_ids.is_empty() {
+        metadata["advisory"][key] = value(toml_edit::Array::from_iter(all_ids.iter()));
+    }
+}
@@ -0,0 +1,521 @@
+//! Synchronization with the GitHub Advisory Database (GHSA)
+//!
+//! The reviewed advisories of the Rust ecosystem are fetched from the
+//! [GitHub REST API](https://docs.github.com/en/rest/security-advisories/global-advisories).
+//!
+//! Drafts are generated for the advisories missing from RustSec, to be
+//! reviewed (and assigned an ID with `assign-id`) before importing them.
+//!
+//! For the advisories RustSec already has, the GHSA is compared with the
+//! RustSec advisory, and divergences in affected versions or severity are
+//! reported to be looked into: they may be mistakes on either side.
+
+use std::{
+    fs, iter,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use cvss::Cvss;
+use rustsec::{
+    Advisory, Version, VersionReq,
+    advisory::{Id, IdKind},
+    semver::{Comparator, Op},
+};
+use serde::Deserialize;
+use tame_index::{
+    KrateName,
+    external::reqwest::{
+        blocking::{Client, RequestBuilder},
+        header::{ACCEPT, AUTHORIZATION, LINK},
+    },
+    index::RemoteSparseIndex,
+};
+
+use crate::{
+    crates_index,
+    error::{Error, ErrorKind},
+    lock::acquire_cargo_package_lock,
+    prelude::*,
+    scaffold::Scaffold,
+};
+
+/// GitHub REST API endpoint for the reviewed advisories of the Rust ecosystem
+const GHSA_API_URL: &str =
+    "https://api.github.com/advisories?ecosystem=rust&type=reviewed&per_page=100";
+
+/// Ecosystem of crates.io packages in GHSAs
+const ECOSYSTEM: &str = "rust";
+
+/// Maximum number of diverging versions to list
+const MAX_LISTED_VERSIONS: usize = 5;
+
+/// GitHub security advisory, as returned by the GitHub REST API
+#[derive(Clone, Debug, Deserialize)]
+pub struct GhsaAdvisory {
+    /// GHSA ID
+    ghsa_id: Id,
+
+    /// CVE ID, if one was assigned
+    cve_id: Option<Id>,
+
+    /// URL of the advisory on GitHub
+    html_url: String,
+
+    /// One-line summary
+    summary: String,
+
+    /// Markdown description
+    #[serde(default)]
+    description: Option<String>,
+
+    /// Severity (`low`, `medium`, `high`, `critical` or `unknown`)
+    severity: String,
+
+    /// Publication date
+    published_at: String,
+
+    /// Withdrawal date, if the advisory was withdrawn
+    withdrawn_at: Option<String>,
+
+    /// Reference URLs
+    #[serde(default)]
+    references: Vec<String>,
+
+    /// Affected packages
+    #[serde(default)]
+    vulnerabilities: Vec<GhsaVulnerability>,
+
+    /// CVSS vectors
+    #[serde(default)]
+    cvss_severities: Option<GhsaCvssSeverities>,
+}
+
+impl GhsaAdvisory {
+    /// Get the GHSA ID
+    pub fn id(&self) -> &Id {
+        &self.ghsa_id
+    }
+
+    /// Get the publication date
+    pub fn published(&self) -> &str {
+        &self.published_at
+    }
+
+    /// Get the CVSS vector, preferring CVSS 3.1 (which most advisories use)
+    fn cvss(&self) -> Option<Cvss> {
+        let severities = self.cvss_severities.as_ref()?;
+        [&severities.cvss_v3, &severities.cvss_v4]
+            .into_iter()
+            .flatten()
+            .find_map(|cvss| cvss.vector_string.as_deref()?.parse().ok())
+    }
+
+    /// Get the RustSec advisories the GHSA references (e.g. for the GHSAs
+    /// imported from RustSec)
+    fn rustsec_refs(&self) -> Vec<Id> {
+        self.references
+            .iter()
+            .filter_map(|url| {
+                let start = url.find("RUSTSEC-")?;
+                let id = Id::from_str(url.get(start..start + 17)?).ok()?;
+                (id.kind() == IdKind::RustSec).then_some(id)
+            })
+            .collect()
+    }
+}
+
+/// Package affected by a GHSA
+#[derive(Clone, Debug, Deserialize)]
+struct GhsaVulnerability {
+    /// Affected package
+    package: GhsaPackage,
+
+    /// Range of vulnerable versions, e.g. `>= 1.0.0, < 1.2.3`
+    vulnerable_version_range: Option<String>,
+
+    /// First version in which the vulnerability is fixed
+    first_patched_version: Option<String>,
+}
+
+/// Package of a GHSA
+#[derive(Clone, Debug, Deserialize)]
+struct GhsaPackage {
+    /// Ecosystem of the package
+    ecosystem: String,
+
+    /// Name of the package
+    name: String,
+}
+
+/// CVSS vectors of a GHSA
+#[derive(Clone, Debug, Deserialize)]
+struct GhsaCvssSeverities {
+    /// CVSS 3.x vector
+    cvss_v3: Option<GhsaCvss>,
+
+    /// CVSS 4.0 vector
+    cvss_v4: Option<GhsaCvss>,
+}
+
+/// CVSS vector of a GHSA
+#[derive(Clone, Debug, Deserialize)]
+struct GhsaCvss {
+    /// CVSS vector, if any
+    vector_string: Option<String>,
+}
+
+/// Results of the synchronization with the GHSA database
+#[derive(Debug, Default)]
+pub struct GhsaSync {
+    /// Paths of the draft advisories generated for GHSAs missing from RustSec
+    pub drafts: Vec<String>,
+
+    /// Divergences between GHSAs and the corresponding RustSec advisories
+    pub divergences: Vec<String>,
+}
+
+/// Synchronizer for GitHub security advisories
+pub struct GhsaSynchronizer {
+    /// Path to the advisory database
+    repo_path: PathBuf,
+
+    /// Loaded crates.io index
+    crates_index: RemoteSparseIndex,
+
+    /// Loaded Advisory DB
+    advisory_db: rustsec::Database,
+
+    /// HTTP client for the GitHub API
+    client: Client,
+
+    /// GitHub token, which raises the rate limit
+    token: Option<String>,
+}
+
+impl GhsaSynchronizer {
+    /// Create a new GHSA synchronizer for the database at the given path
+    pub fn new(repo_path: impl Into<PathBuf>, token: Option<String>) -> Result<Self, Error> {
+        let repo_path = repo_path.into();
+        let advisory_db = rustsec::Database::open(&repo_path)?;
+        let client = Client::builder()
+            .user_agent(concat!("rustsec-admin/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(tame_index::Error::from)?;
+
+        Ok(Self {
+            repo_path,
+            crates_index: crates_index()?,
+            advisory_db,
+            client,
+            token,
+        })
+    }
+
+    /// Borrow the loaded advisory database
+    pub fn advisory_db(&self) -> &rustsec::Database {
+        &self.advisory_db
+    }
+
+    /// Fetch the reviewed GHSAs of the Rust ecosystem
+    pub fn fetch(&self) -> Result<Vec<GhsaAdvisory>, Error> {
+        let mut advisories = vec![];
+        let mut next_url = Some(GHSA_API_URL.to_owned());
+
+        while let Some(url) = next_url {
+            let response = self
+                .request(&url)
+                .send()
+                .and_then(|response| response.error_for_status())
+                .map_err(|e| format_err!(ErrorKind::Io, "couldn't fetch GHSAs: {}", e))?;
+
+            // Pages are linked to with cursors
+            next_url = response
+                .headers()
+                .get(LINK)
+                .and_then(|link| link.to_str().ok())
+                .and_then(next_page_url);
+
+            let body = response
+                .text()
+                .map_err(|e| format_err!(ErrorKind::Io, "couldn't fetch GHSAs: {}", e))?;
+            advisories.extend(parse_advisories(&body, "GitHub API response")?);
+        }
+
+        status_info!("Info", "Fetched {} GHSAs", advisories.len());
+        Ok(advisories)
+    }
+
+    /// Load GHSAs from a response of the GitHub API saved to a file
+    pub fn load(path: &Path) -> Result<Vec<GhsaAdvisory>, Error> {
+        let data = fs::read_to_string(path)
+            .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
+
+        parse_advisories(&data, &path.display().to_string())
+    }
+
+    /// Synchronize the advisories with the given GHSAs
+    pub fn sync(&self, ghsas: &[GhsaAdvisory]) -> Result<GhsaSync, Error> {
+        let mut result = GhsaSync::default();
+
+        for ghsa in ghsas {
+            if ghsa.withdrawn_at.is_some() {
+                continue;
+            }
+
+            for vulnerability in &ghsa.vulnerabilities {
+                if vulnerability.package.ecosystem != ECOSYSTEM {
+                    continue;
+                }
+
+                let advisories = self.rustsec_advisories(ghsa, &vulnerability.package.name);
+                if advisories.is_empty() {
+                    if let Some(path) = self.draft(ghsa, vulnerability) {
+                        result.drafts.push(path);
+                    }
+                    continue;
+                }
+
+                for advisory in advisories {
+                    result
+                        .divergences
+                        .extend(self.divergences(ghsa, vulnerability, advisory));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Find the RustSec advisories about the same vulnerability of a package
+    fn rustsec_advisories(&self, ghsa: &GhsaAdvisory, package: &str) -> Vec<&Advisory> {
+        let mut advisories = self.advisory_db.find_by_alias(ghsa.id());
+        if let Some(cve_id) = &ghsa.cve_id {
+            advisories.extend(self.advisory_db.find_by_alias(cve_id));
+        }
+        advisories.extend(
+            ghsa.rustsec_refs()
+                .iter()
+                .filter_map(|id| self.advisory_db.get(id)),
+        );
+
+        advisories.retain(|advisory| advisory.metadata.package.as_str() == package);
+        advisories.sort_by_key(|advisory| advisory.id());
+        advisories.dedup_by_key(|advisory| advisory.id());
+        advisories
+    }
+
+    /// Generate a draft advisory for a GHSA missing from RustSec, returning
+    /// its path
+    fn draft(&self, ghsa: &GhsaAdvisory, vulnerability: &GhsaVulnerability) -> Option<String> {
+        let mut scaffold = Scaffold {
+            package: vulnerability.package.name.clone(),
+            date: ghsa.published_at.chars().take(10).collect(),
+            title: ghsa.summary.clone(),
+            description: ghsa.description.clone().unwrap_or_default(),
+            url: Some(ghsa.html_url.clone()),
+            aliases: ghsa_ids(ghsa).map(ToString::to_string).collect(),
+            cvss: ghsa.cvss(),
+            severity: ghsa.severity.parse().ok(),
+            patched: vulnerability
+                .first_patched_version
+                .iter()
+                .map(|version| format!(">= {version}"))
+                .collect(),
+            unaffected: vulnerability
+                .vulnerable_version_range
+                .as_deref()
+                .map(unaffected_versions)
+                .unwrap_or_default(),
+            ..Default::default()
+        };
+
+        let result = scaffold
+            .check_crates_io(&self.crates_index)
+            .and_then(|warnings| {
+                let (advisory, range_warnings) = scaffold.render()?;
+                let path = scaffold.write(&self.repo_path, &advisory)?;
+                Ok((path, warnings.into_iter().chain(range_warnings)))
+            });
+
+        match result {
+            Ok((path, warnings)) => {
+                for warning in warnings {
+                    status_warn!("{} (draft for {})", warning, ghsa.id());
+                }
+                status_info!(
+                    "Info",
+                    "Written draft for {} to {}",
+                    ghsa.id(),
+                    path.display()
+                );
+                Some(path.display().to_string())
+            }
+            Err(e) => {
+                status_info!("Info", "Skipping draft for {}: {}", ghsa.id(), e);
+                None
+            }
+        }
+    }
+
+    /// Compare a GHSA with a RustSec advisory about the same vulnerability
+    fn divergences(
+        &self,
+        ghsa: &GhsaAdvisory,
+        vulnerability: &GhsaVulnerability,
+        advisory: &Advisory,
+    ) -> Vec<String> {
+        let mut divergences = vec![];
+        let ids = format!("{} and {}", ghsa.id(), advisory.id());
+
+        if let Some(range) = &vulnerability.vulnerable_version_range {
+            match VersionReq::parse(range) {
+                Ok(req) => divergences.extend(self.range_divergences(&req, ghsa, advisory)),
+                Err(e) => status_warn!("invalid range {:?} in {}: {}", range, ghsa.id(), e),
+            }
+        }
+
+        let severity = advisory.severity().map(|severity| severity.to_string());
+
+        match (&severity, ghsa.cvss()) {
+            (None, Some(cvss)) => {
+                divergences.push(format!("{ids}: only {} has a CVSS: {cvss}", ghsa.id()))
+            }
+            (Some(severity), _) if ghsa.severity != "unknown" && *severity != ghsa.severity => {
+                divergences.push(format!(
+                    "{ids}: severity is {} in {} but {} in {}",
+                    severity,
+                    advisory.id(),
+                    ghsa.severity,
+                    ghsa.id()
+                ))
+            }
+            _ => (),
+        }
+
+        divergences
+    }
+
+    /// Compare the published versions affected according to the GHSA range
+    /// and according to the RustSec advisory
+    fn range_divergences(
+        &self,
+        req: &VersionReq,
+        ghsa: &GhsaAdvisory,
+        advisory: &Advisory,
+    ) -> Vec<String> {
+        let Some(published) = self.published_versions(advisory.metadata.package.as_str()) else {
+            return vec![];
+        };
+
+        let (only_ghsa, only_rustsec): (Vec<_>, Vec<_>) = published
+            .iter()
+            .filter(|version| version.pre.is_empty())
+            .filter(|version| req.matches(version) != advisory.versions.is_vulnerable(version))
+            .partition(|version| req.matches(version));
+
+        [(only_ghsa, ghsa.id()), (only_rustsec, advisory.id())]
+            .into_iter()
+            .filter(|(versions, _)| !versions.is_empty())
+            .map(|(versions, id)| {
+                let listed = versions
+                    .iter()
+                    .take(MAX_LISTED_VERSIONS)
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let more = match versions.len().saturating_sub(MAX_LISTED_VERSIONS) {
+                    0 => String::new(),
+                    more => format!(" and {more} more"),
+                };
+                format!(
+                    "{} and {}: only {} considers {}{} vulnerable",
+                    ghsa.id(),
+                    advisory.id(),
+                    id,
+                    listed,
+                    more
+                )
+            })
+            .collect()
+    }
+
+    /// Get the versions of a crate published on crates.io
+    fn published_versions(&self, name: &str) -> Option<Vec<Version>> {
+        let crate_name = KrateName::try_from(name).ok()?;
+        let lock = acquire_cargo_package_lock().ok()?;
+        let crate_ = self.crates_index.krate(crate_name, true, &lock).ok()??;
+
+        Some(
+            crate_
+                .versions
+                .iter()
+                .filter_map(|version| Version::parse(&version.version).ok())
+                .collect(),
+        )
+    }
+
+    /// Build a request to the GitHub API
+    fn request(&self, url: &str) -> RequestBuilder {
+        let request = self
+            .client
+            .get(url)
+            .header(ACCEPT, "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+
+        match &self.token {
+            Some(token) => request.header(AUTHORIZATION, format!("Bearer {token}")),
+            None => request,
+        }
+    }
+}
+
+/// Parse a list of GHSAs from the JSON returned by the GitHub API
+fn parse_advisories(data: &str, source: &str) -> Result<Vec<GhsaAdvisory>, Error> {
+    serde_json::from_str(data)
+        .map_err(|e| format_err!(ErrorKind::Parse, "error parsing {}: {}", source, e).into())
+}
+
+/// Get the URL of the next page from a `Link` header
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|link| {
+        let (url, rel) = link.split_once(';')?;
+        (rel.trim() == r#"rel="next""#).then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_owned()
+        })
+    })
+}
+
+/// Get the IDs of a GHSA, to be used as aliases
+fn ghsa_ids(ghsa: &GhsaAdvisory) -> impl Iterator<Item = &Id> {
+    iter::once(&ghsa.ghsa_id).chain(&ghsa.cve_id)
+}
+
+/// Get the versions which were never affected from the lower bound of a
+/// range of vulnerable versions, e.g. `< 1.0.0` for `>= 1.0.0, < 1.2.3`
+fn unaffected_versions(range: &str) -> Vec<String> {
+    let Ok(req) = VersionReq::parse(range) else {
+        return vec![];
+    };
+
+    req.comparators
+        .iter()
+        .filter_map(|comparator| {
+            let op = match comparator.op {
+                Op::GreaterEq => Op::Less,
+                Op::Greater => Op::LessEq,
+                _ => return None,
+            };
+            Some(
+                Comparator {
+                    op,
+                    ..comparator.clone()
+                }
+                .to_string(),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,481 @@
+//! Synchronization with the National Vulnerability Database (NVD)
+//!
+//! CVE records are fetched from the [NVD API](https://nvd.nist.gov/developers/vulnerabilities)
+//! by their last modification date. The records which matter to us are the
+//! ones referencing crates.io packages (through links to crates.io, docs.rs
+//! or lib.rs) or RustSec advisories, and the ones which are already aliases of
+//! RustSec advisories.
+//!
+//! For the records about existing advisories, the CVE ID is added to the
+//! aliases of the advisory, and its CVSS vector is added to advisories which
+//! don't have one yet. These edits are made to the advisory files, to be
+//! reviewed before committing them.
+//!
+//! The other records are reported for triage, as RustSec advisories may be
+//! missing for them.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use cvss::Cvss;
+use rustsec::{
+    Advisory,
+    advisory::{Id, IdKind},
+};
+use serde::Deserialize;
+use tame_index::{
+    KrateName,
+    external::reqwest::{Url, blocking::Client},
+    index::RemoteSparseIndex,
+};
+use toml_edit::value;
+
+use super::{advisory_path, edit_front_matter, extend_ids};
+use crate::{
+    crates_index,
+    error::{Error, ErrorKind},
+    lock::acquire_cargo_package_lock,
+    prelude::*,
+};
+
+/// NVD API endpoint for CVE records
+const NVD_API_URL: &str = "https://services.nvd.nist.gov/rest/json/cves/2.0";
+
+/// Maximum number of records the NVD API returns at once
+const RESULTS_PER_PAGE: usize = 2000;
+
+/// Longest time range the NVD API can be queried for at once
+const MAX_RANGE_DAYS: i64 = 120;
+
+/// Sites whose URLs identify crates.io packages, followed by the crate name
+const CRATE_URL_PREFIXES: &[&str] = &[
+    "https://crates.io/crates/",
+    "https://docs.rs/",
+    "https://lib.rs/crates/",
+];
+
+/// CVE record, as returned by the NVD API
+#[derive(Clone, Debug, Deserialize)]
+pub struct CveRecord {
+    /// CVE ID
+    id: Id,
+
+    /// Publication date
+    published: String,
+
+    /// CVSS metrics assigned by NVD and CNAs
+    #[serde(default)]
+    metrics: Metrics,
+
+    /// Reference URLs
+    #[serde(default)]
+    references: Vec<Reference>,
+}
+
+impl CveRecord {
+    /// Get the CVE ID
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Get the publication date
+    pub fn published(&self) -> &str {
+        &self.published
+    }
+
+    /// Get the crates.io packages the record references
+    pub fn crates(&self) -> Vec<String> {
+        let mut crates: Vec<String> = self
+            .references
+            .iter()
+            .filter_map(|reference| {
+                CRATE_URL_PREFIXES.iter().find_map(|prefix| {
+                    let name = reference.url.strip_prefix(prefix)?;
+                    let name = name.split(['/', '?', '#']).next()?;
+                    (!name.is_empty()).then(|| name.to_owned())
+                })
+            })
+            .collect();
+        crates.sort();
+        crates.dedup();
+        crates
+    }
+
+    /// Get the RustSec advisories the record references
+    pub fn rustsec_refs(&self) -> Vec<Id> {
+        let mut refs: Vec<Id> = self
+            .references
+            .iter()
+            .filter_map(|reference| {
+                let start = reference.url.find("RUSTSEC-")?;
+                let id = Id::from_str(reference.url.get(start..start + 17)?).ok()?;
+                (id.kind() == IdKind::RustSec).then_some(id)
+            })
+            .collect();
+        refs.sort();
+        refs.dedup();
+        refs
+    }
+
+    /// Get the CVSS vector of the record, preferring CVSS 3.1 (which most
+    /// advisories use) and the scores assigned by NVD
+    pub fn cvss(&self) -> Option<Cvss> {
+        [&self.metrics.v31, &self.metrics.v40, &self.metrics.v30]
+            .into_iter()
+            .find_map(|metrics| {
+                metrics
+                    .iter()
+                    .filter(|metric| metric.kind == "Primary")
+                    .chain(metrics)
+                    .find_map(|metric| metric.cvss_data.vector_string.parse().ok())
+            })
+    }
+}
+
+/// CVSS metrics of a CVE record
+#[derive(Clone, Debug, Default, Deserialize)]
+struct Metrics {
+    /// CVSS 4.0 metrics
+    #[serde(rename = "cvssMetricV40", default)]
+    v40: Vec<Metric>,
+
+    /// CVSS 3.1 metrics
+    #[serde(rename = "cvssMetricV31", default)]
+    v31: Vec<Metric>,
+
+    /// CVSS 3.0 metrics
+    #[serde(rename = "cvssMetricV30", default)]
+    v30: Vec<Metric>,
+}
+
+/// CVSS metric of a CVE record
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Metric {
+    /// Whether the metric was assigned by NVD (`Primary`) or a CNA (`Secondary`)
+    #[serde(rename = "type")]
+    kind: String,
+
+    /// CVSS data
+    cvss_data: CvssData,
+}
+
+/// CVSS data of a metric
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CvssData {
+    /// CVSS vector
+    vector_string: String,
+}
+
+/// Reference of a CVE record
+#[derive(Clone, Debug, Deserialize)]
+struct Reference {
+    /// URL of the reference
+    url: String,
+}
+
+/// Page of results of the NVD API
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Page {
+    /// Index of the first result of the page
+    start_index: usize,
+
+    /// Total number of results
+    total_results: usize,
+
+    /// CVE records of the page
+    vulnerabilities: Vec<Vulnerability>,
+}
+
+/// Result of the NVD API
+#[derive(Debug, Deserialize)]
+struct Vulnerability {
+    /// CVE record
+    cve: CveRecord,
+}
+
+/// Results of the synchronization with the NVD
+#[derive(Debug, Default)]
+pub struct NvdSync {
+    /// Number of aliases added to advisories
+    pub aliases_added: usize,
+
+    /// Number of CVSS vectors added to advisories
+    pub cvss_added: usize,
+
+    /// Records about crates.io packages without a corresponding advisory
+    pub untracked: Vec<CveRecord>,
+}
+
+/// Synchronizer for CVE records from the NVD
+pub struct NvdSynchronizer {
+    /// Path to the advisory database
+    repo_path: PathBuf,
+
+    /// Loaded crates.io index
+    crates_index: RemoteSparseIndex,
+
+    /// Loaded Advisory DB
+    advisory_db: rustsec::Database,
+
+    /// HTTP client for the NVD API
+    client: Client,
+
+    /// NVD API key, which raises the rate limit
+    api_key: Option<String>,
+}
+
+impl NvdSynchronizer {
+    /// Create a new NVD synchronizer for the database at the given path
+    pub fn new(repo_path: impl Into<PathBuf>, api_key: Option<String>) -> Result<Self, Error> {
+        let repo_path = repo_path.into();
+        let advisory_db = rustsec::Database::open(&repo_path)?;
+        let client = Client::builder()
+            .user_agent(concat!("rustsec-admin/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(tame_index::Error::from)?;
+
+        Ok(Self {
+            repo_path,
+            crates_index: crates_index()?,
+            advisory_db,
+            client,
+            api_key,
+        })
+    }
+
+    /// Borrow the loaded advisory database
+    pub fn advisory_db(&self) -> &rustsec::Database {
+        &self.advisory_db
+    }
+
+    /// Fetch the CVE records modified in the given time range
+    pub fn fetch(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<CveRecord>, Error> {
+        // The rate limit is 5 requests per 30 seconds without an API key,
+        // and 50 with one
+        let delay = match self.api_key {
+            Some(_) => Duration::from_millis(600),
+            None => Duration::from_secs(6),
+        };
+
+        let mut records = vec![];
+        let mut range_start = start;
+        while range_start < end {
+            let range_end = end.min(range_start + chrono::Duration::days(MAX_RANGE_DAYS));
+            let mut start_index = 0;
+
+            loop {
+                let page = self.fetch_page(range_start, range_end, start_index)?;
+                let fetched = page.vulnerabilities.len();
+                records.extend(page.vulnerabilities.into_iter().map(|v| v.cve));
+
+                start_index = page.start_index + fetched;
+                if fetched == 0 || start_index >= page.total_results {
+                    break;
+                }
+                thread::sleep(delay);
+            }
+
+            range_start = range_end;
+        }
+
+        status_info!("Info", "Fetched {} CVE records from the NVD", records.len());
+        Ok(records)
+    }
+
+    /// Load CVE records from a response of the NVD API saved to a file
+    pub fn load(path: &Path) -> Result<Vec<CveRecord>, Error> {
+        let data = fs::read_to_string(path)
+            .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
+
+        let page: Page = serde_json::from_str(&data).map_err(|e| {
+            format_err!(ErrorKind::Parse, "error parsing {}: {}", path.display(), e)
+        })?;
+
+        Ok(page.vulnerabilities.into_iter().map(|v| v.cve).collect())
+    }
+
+    /// Synchronize the advisories with the given CVE records
+    ///
+    /// Records are deduplicated by ID (keeping the last one), since fetched
+    /// time ranges and saved responses may overlap.
+    pub fn sync(&mut self, records: &[CveRecord]) -> Result<NvdSync, Error> {
+        let mut result = NvdSync::default();
+
+        let mut seen = BTreeSet::new();
+        let mut unique: Vec<&CveRecord> = records
+            .iter()
+            .rev()
+            .filter(|record| seen.insert(record.id()))
+            .collect();
+        unique.reverse();
+
+        // Advisories edited in this run, as they are on disk now
+        let mut edited: BTreeMap<Id, Advisory> = BTreeMap::new();
+
+        for record in unique {
+            let crates = record.crates();
+            let referenced = record.rustsec_refs();
+
+            let advisories: Vec<Advisory> = self
+                .advisory_db
+                .iter()
+                .filter(|advisory| {
+                    advisory.metadata.aliases.contains(record.id())
+                        || (referenced.contains(advisory.id())
+                            && (crates.is_empty()
+                                || crates
+                                    .iter()
+                                    .any(|c| c == advisory.metadata.package.as_str())))
+                })
+                .cloned()
+                .collect();
+
+            if advisories.is_empty() {
+                if crates.iter().any(|name| self.crate_exists(name, record)) {
+                    result.untracked.push(record.clone());
+                }
+                continue;
+            }
+
+            for advisory in advisories {
+                let advisory = edited.remove(advisory.id()).unwrap_or(advisory);
+                let advisory = self.update_advisory(advisory, record, &mut result)?;
+                edited.insert(advisory.id().clone(), advisory);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Add the CVE alias and CVSS vector of a record to an advisory, if missing,
+    /// and return the updated advisory
+    fn update_advisory(
+        &self,
+        mut advisory: Advisory,
+        record: &CveRecord,
+        result: &mut NvdSync,
+    ) -> Result<Advisory, Error> {
+        let missing_alias = !advisory.metadata.aliases.contains(record.id());
+        let missing_cvss = match advisory.metadata.cvss {
+            Some(_) => None,
+            None => record.cvss(),
+        };
+
+        if missing_alias {
+            status_info!(
+                "Info",
+                "Adding missing alias {} for {}",
+                record.id(),
+                advisory.id()
+            );
+            result.aliases_added += 1;
+        }
+
+        if let Some(cvss) = &missing_cvss {
+            status_info!(
+                "Info",
+                "Adding CVSS {} from {} to {}",
+                cvss,
+                record.id(),
+                advisory.id()
+            );
+            result.cvss_added += 1;
+        }
+
+        if !missing_alias && missing_cvss.is_none() {
+            return Ok(advisory);
+        }
+
+        edit_front_matter(&advisory_path(&self.repo_path, &advisory), |metadata| {
+            if missing_alias {
+                extend_ids(metadata, "aliases", std::slice::from_ref(record.id()));
+            }
+            if let Some(cvss) = &missing_cvss {
+                metadata["advisory"]["cvss"] = value(cvss.to_string());
+            }
+        })?;
+
+        if missing_alias {
+            advisory.metadata.aliases.push(record.id().clone());
+        }
+        if missing_cvss.is_some() {
+            advisory.metadata.cvss = missing_cvss;
+        }
+        Ok(advisory)
+    }
+
+    /// Does the crate a record references exist on crates.io?
+    fn crate_exists(&self, name: &str, record: &CveRecord) -> bool {
+        let Ok(crate_name) = KrateName::try_from(name) else {
+            status_info!(
+                "Info",
+                "Crate name {} in {} is invalid, skipping",
+                name,
+                record.id()
+            );
+            return false;
+        };
+
+        let exists = acquire_cargo_package_lock()
+            .ok()
+            .and_then(|lock| self.crates_index.krate(crate_name, true, &lock).ok())
+            .flatten()
+            .is_some();
+
+        if !exists {
+            status_info!(
+                "Info",
+                "Unknown crate {} in {}, skipping",
+                name,
+                record.id()
+            );
+        }
+        exists
+    }
+
+    /// Fetch a page of CVE records modified in the given time range
+    fn fetch_page(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        start_index: usize,
+    ) -> Result<Page, Error> {
+        const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f+00:00";
+
+        let url = Url::parse_with_params(
+            NVD_API_URL,
+            [
+                ("lastModStartDate", start.format(DATE_FORMAT).to_string()),
+                ("lastModEndDate", end.format(DATE_FORMAT).to_string()),
+                ("resultsPerPage", RESULTS_PER_PAGE.to_string()),
+                ("startIndex", start_index.to_string()),
+            ],
+        )
+        .expect("valid NVD API URL");
+
+        let mut request = self.client.get(url);
+
+        if let Some(api_key) = &self.api_key {
+            request = request.header("apiKey", api_key);
+        }
+
+        let body = request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .map_err(|e| format_err!(ErrorKind::Io, "couldn't fetch CVE records: {}", e))?;
+
+        serde_json::from_str(&body)
+            .map_err(|e| format_err!(ErrorKind::Parse, "error parsing NVD response: {}", e).into())
+    }
+}
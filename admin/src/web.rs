@@ -2,105 +2,294 @@
 
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Write,
     fs::{self, File},
     iter,
     ops::Deref,
     path::{Path, PathBuf},
+    process::Command,
     str::FromStr,
 };
 
-use crate::prelude::*;
-use askama::Template;
+use crate::{Map, prelude::*};
 use atom_syndication::{
     CategoryBuilder, ContentBuilder, Entry, EntryBuilder, FeedBuilder, FixedDateTime, LinkBuilder,
     PersonBuilder, Text,
 };
 use chrono::{Duration, NaiveDate, Utc};
 use comrak::markdown_to_html;
+use minijinja::{AutoEscape, Environment};
 use rust_embed::RustEmbed;
 use rustsec::advisory::{Category, Id};
 use rustsec::osv::OsvAdvisory;
 use rustsec::repository::git::GitModificationTimes;
 use rustsec::repository::git::GitPath;
 use rustsec::{Repository, advisory};
-use xml::escape::escape_str_attribute;
+use serde::{Serialize, Serializer};
+use xml::escape::{escape_str_attribute, escape_str_pcdata};
 
 // TODO(tarcieri): replace with `DateTime`
 #[allow(deprecated)]
 use chrono::Date;
 
-#[derive(Template)]
-#[template(path = "index.html")]
+/// Default templates of the web site
+#[derive(RustEmbed)]
+#[folder = "src/web/templates/"]
+struct TemplateAsset;
+
+/// Templates of the web site, loaded from the `templates` folder of a theme
+/// when it has them and from the default templates otherwise
+struct Templates {
+    env: Environment<'static>,
+}
+
+impl Templates {
+    /// Load the templates, overridden by the ones of the given theme folder
+    fn new(theme: Option<&Path>) -> Self {
+        let mut env = Environment::new();
+
+        let theme_templates = theme.map(|theme| theme.join("templates"));
+        env.set_loader(move |name| {
+            if let Some(path) = theme_templates.as_ref().map(|dir| dir.join(name))
+                && path.is_file()
+            {
+                return fs::read_to_string(&path).map(Some).map_err(|err| {
+                    minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        format!("couldn't read {}", path.display()),
+                    )
+                    .with_source(err)
+                });
+            }
+
+            Ok(TemplateAsset::get(name)
+                .map(|file| String::from_utf8_lossy(&file.data).into_owned()))
+        });
+
+        env.set_auto_escape_callback(|name| match name.rsplit('.').next() {
+            Some("html" | "svg" | "xml") => AutoEscape::Html,
+            _ => AutoEscape::None,
+        });
+        env.set_formatter(|out, state, value| match value.as_str() {
+            // Only escape the characters which are special in HTML, leaving
+            // e.g. the slashes of URLs as they are
+            Some(s) if state.auto_escape() == AutoEscape::Html && !value.is_safe() => {
+                for c in s.chars() {
+                    match c {
+                        '&' => out.write_str("&amp;"),
+                        '<' => out.write_str("&lt;"),
+                        '>' => out.write_str("&gt;"),
+                        '"' => out.write_str("&quot;"),
+                        '\'' => out.write_str("&#39;"),
+                        c => out.write_char(c),
+                    }
+                    .map_err(minijinja::Error::from)?;
+                }
+                Ok(())
+            }
+            _ => minijinja::escape_formatter(out, state, value),
+        });
+        env.add_filter("friendly_date", filters::friendly_date);
+        env.add_filter("safe_keyword", filters::safe_keyword);
+
+        Self { env }
+    }
+
+    /// Render a template, exiting with an error if it fails
+    fn render<T: Serialize>(&self, path: &str, context: &T) -> String {
+        self.env
+            .get_template(path)
+            .and_then(|template| template.render(context))
+            .unwrap_or_else(|e| {
+                status_err!("error rendering template {}: {:#}", path, e);
+                std::process::exit(1);
+            })
+    }
+}
+
+/// Data rendered with a template
+trait Template: Serialize + Sized {
+    /// Path of the template in the templates folder
+    const PATH: &'static str;
+
+    /// Render the data with the template
+    fn render(&self, templates: &Templates) -> String {
+        templates.render(Self::PATH, self)
+    }
+}
+
+#[derive(Serialize)]
 struct IndexTemplate;
 
-#[derive(Template)]
-#[template(path = "search.html")]
+impl Template for IndexTemplate {
+    const PATH: &'static str = "index.html";
+}
+
+#[derive(Serialize)]
 struct SearchTemplate;
 
-#[derive(Template)]
-#[template(path = "static.html")]
+impl Template for SearchTemplate {
+    const PATH: &'static str = "search.html";
+}
+
+#[derive(Serialize)]
 struct StaticTemplate {
     title: String,
+    url: String,
     content: String,
 }
 
-#[derive(Template)]
-#[template(path = "advisories.html")]
+impl Template for StaticTemplate {
+    const PATH: &'static str = "static.html";
+}
+
+#[derive(Serialize)]
 struct AdvisoriesListTemplate<'a> {
     /// `Vec<(advisory data, rendered_title, advisory_title_type)>`
     advisories: Vec<(&'a AdvisoryData, String, String)>,
 }
 
-#[derive(Template)]
-#[template(path = "advisories-sublist.html")]
+impl<'a> Template for AdvisoriesListTemplate<'a> {
+    const PATH: &'static str = "advisories.html";
+}
+
+#[derive(Serialize)]
 struct AdvisoriesSubList<'a> {
     title: String,
+    url: String,
     group_by: String,
+    /// URL of the Atom feed of the list, if any
+    feed: Option<String>,
+    /// URL of the badge of the package the list is about, if any
+    badge: Option<String>,
     /// `Vec<(advisory data, rendered_title, advisory_title_type)>`
     advisories: Vec<(&'a AdvisoryData, String, String)>,
 }
 
-#[derive(Template)]
-#[template(path = "advisory.html")]
+impl<'a> Template for AdvisoriesSubList<'a> {
+    const PATH: &'static str = "advisories-sublist.html";
+}
+
+#[derive(Serialize)]
 struct AdvisoryTemplate<'a> {
     advisory: &'a AdvisoryData,
     rendered_description: String,
     rendered_title: String,
 }
 
+impl<'a> Template for AdvisoryTemplate<'a> {
+    const PATH: &'static str = "advisory.html";
+}
+
 // Used for feed and included by `AdvisoryTemplate`
-#[derive(Template)]
-#[template(path = "advisory-content.html")]
+#[derive(Serialize)]
 struct AdvisoryContentTemplate<'a> {
     advisory: &'a AdvisoryData,
     rendered_description: String,
     rendered_title: String,
 }
 
-#[derive(Template)]
-#[template(path = "sublist-index.html")]
+impl<'a> Template for AdvisoryContentTemplate<'a> {
+    const PATH: &'static str = "advisory-content.html";
+}
+
+#[derive(Serialize)]
+struct BadgeTemplate {
+    label: &'static str,
+    message: String,
+    color: &'static str,
+    label_width: usize,
+    message_width: usize,
+}
+
+impl Template for BadgeTemplate {
+    const PATH: &'static str = "badge.svg";
+}
+
+impl BadgeTemplate {
+    /// Badge summarizing the advisories of a package: how many of them are
+    /// open (i.e. have no patched versions), or how many were patched if
+    /// none are open
+    fn new(advisories: &[&AdvisoryData]) -> Self {
+        let active = advisories
+            .iter()
+            .filter(|data| !data.withdrawn())
+            .collect::<Vec<_>>();
+        let open = active
+            .iter()
+            .filter(|data| data.versions.patched().is_empty())
+            .count();
+
+        let (message, color) = match (open, active.len()) {
+            (0, 0) => ("none".to_owned(), "#4c1"),
+            (0, patched) => (format!("{patched} patched"), "#dfb317"),
+            (open, _) => (format!("{open} open"), "#e05d44"),
+        };
+
+        let label = "advisories";
+        Self {
+            label,
+            label_width: badge_text_width(label),
+            message_width: badge_text_width(&message),
+            message,
+            color,
+        }
+    }
+}
+
+/// Approximate width of a badge text in Verdana 11px, including padding
+fn badge_text_width(text: &str) -> usize {
+    text.chars().count() * 7 + 10
+}
+
+#[derive(Serialize)]
+struct RedirectTemplate {
+    url: String,
+}
+
+impl Template for RedirectTemplate {
+    const PATH: &'static str = "redirect.html";
+}
+
+#[derive(Serialize)]
 struct ItemsList {
     title: String,
+    url: String,
     /// `Vec<(name, url, option(count))>`
     items: Vec<(String, String, Option<usize>)>,
 }
 
-fn render_list_index(title: &str, mut items: Vec<(String, String, Option<usize>)>, folder: &Path) {
+impl Template for ItemsList {
+    const PATH: &'static str = "sublist-index.html";
+}
+
+fn render_list_index(
+    templates: &Templates,
+    title: &str,
+    url: &str,
+    mut items: Vec<(String, String, Option<usize>)>,
+    folder: &Path,
+) {
     items.sort_by(|a, b| a.0.to_lowercase().partial_cmp(&b.0.to_lowercase()).unwrap());
     let index_data = ItemsList {
         title: title.to_owned(),
+        url: url.to_owned(),
         items,
     };
     let index_path = folder.join("index.html");
-    fs::write(&index_path, index_data.render().unwrap()).unwrap();
+    fs::write(&index_path, index_data.render(templates)).unwrap();
     status_ok!("Rendered", "{}", index_path.display());
 }
 
-/// Render all advisories using the Markdown template
-pub fn render_advisories(output_folder: PathBuf) {
+/// Render all advisories using the Markdown template, with the templates and
+/// static assets of the given theme folder overriding the default ones
+pub fn render_advisories(output_folder: PathBuf, theme: Option<&Path>) {
     // Create dest
     fs::create_dir_all(&output_folder).unwrap();
 
+    // Load templates
+    let templates = &Templates::new(theme);
+
     // Get static pages from repository
     let repo = Repository::fetch_default_repo().unwrap();
     let contributing_path = repo.path().join("CONTRIBUTING.md");
@@ -119,9 +308,10 @@ pub fn render_advisories(output_folder: PathBuf) {
     let contributing_md = fs::read_to_string(contributing_path).unwrap();
     let static_template = StaticTemplate {
         title: "Reporting Vulnerabilities".to_string(),
+        url: "/contributing.html".to_string(),
         content: markdown_to_html(&contributing_md, &comrak::Options::default()),
     };
-    let contributing_page = static_template.render().unwrap();
+    let contributing_page = static_template.render(templates);
     fs::write(output_folder.join("contributing.html"), contributing_page).unwrap();
 
     // Render individual advisory pages (/advisories/${id}.html)
@@ -140,21 +330,21 @@ pub fn render_advisories(output_folder: PathBuf) {
             rendered_description,
             rendered_title,
         };
-        fs::write(&output_path, advisory_tmpl.render().unwrap()).unwrap();
+        fs::write(&output_path, advisory_tmpl.render(templates)).unwrap();
 
         status_ok!("Rendered", "{}", output_path.display());
     }
 
     // Copy all the static assets.
-    copy_static_assets(&output_folder);
+    copy_static_assets(&output_folder, theme);
 
     // Render the index.html (/) page.
     let index_template = IndexTemplate;
-    let index_page = index_template.render().unwrap();
+    let index_page = index_template.render(templates);
     fs::write(output_folder.join("index.html"), index_page).unwrap();
 
     // Render the search.html page.
-    let search_page = SearchTemplate.render().unwrap();
+    let search_page = SearchTemplate.render(templates);
     fs::write(output_folder.join("search.html"), search_page).unwrap();
 
     // Render the advisories.html (/advisories) page.
@@ -173,7 +363,7 @@ pub fn render_advisories(output_folder: PathBuf) {
     let advisories_page_tmpl = AdvisoriesListTemplate {
         advisories: advisories_index,
     };
-    let advisories_page = advisories_page_tmpl.render().unwrap();
+    let advisories_page = advisories_page_tmpl.render(templates);
     fs::write(advisories_folder.join("index.html"), advisories_page).unwrap();
 
     status_ok!(
@@ -182,7 +372,7 @@ pub fn render_advisories(output_folder: PathBuf) {
         advisories.len()
     );
 
-    // Render the per-package pages (/packages/${package}.html).
+    // Render the per-package pages (/packages/${package}/).
     let mut advisories_per_package = Vec::<AdvisoriesSubList>::new();
     let mut packages = Vec::<(String, String, Option<usize>)>::new();
     for data in &advisories {
@@ -194,7 +384,7 @@ pub fn render_advisories(output_folder: PathBuf) {
             Some(p) => p.2 = Some(p.2.unwrap() + 1),
             None => packages.push((
                 package.clone(),
-                format!("/packages/{}.html", package.clone()),
+                format!("/packages/{}/", package.clone()),
                 Some(1),
             )),
         }
@@ -209,7 +399,10 @@ pub fn render_advisories(output_folder: PathBuf) {
             }
             None => advisories_per_package.push(AdvisoriesSubList {
                 title: format!("Advisories for package '{}'", data.metadata.package),
+                url: format!("/packages/{}/", data.metadata.package),
                 group_by: data.metadata.package.to_string(),
+                feed: None,
+                badge: Some(format!("/packages/{}/badge.svg", data.metadata.package)),
                 advisories: vec![(data, rendered_title, advisory_title_type)],
             }),
         }
@@ -217,14 +410,40 @@ pub fn render_advisories(output_folder: PathBuf) {
     let folder = output_folder.join("packages");
     fs::create_dir_all(&folder).unwrap();
     // index
-    render_list_index("Packages", packages, folder.as_ref());
+    render_list_index(
+        templates,
+        "Packages",
+        "/packages/",
+        packages,
+        folder.as_ref(),
+    );
     // per package page
     for tpl in &advisories_per_package {
         // Write HTML
-        let output_path = folder.join(tpl.group_by.clone() + ".html");
-        fs::write(&output_path, tpl.render().unwrap()).unwrap();
+        let package_folder = folder.join(&tpl.group_by);
+        fs::create_dir_all(&package_folder).unwrap();
+        let output_path = package_folder.join("index.html");
+        fs::write(&output_path, tpl.render(templates)).unwrap();
         status_ok!("Rendered", "{}", output_path.display());
 
+        // Write the badge (/packages/${package}/badge.svg)
+        let package_advisories = tpl
+            .advisories
+            .iter()
+            .map(|(data, _, _)| *data)
+            .collect::<Vec<_>>();
+        let badge_path = package_folder.join("badge.svg");
+        let badge = BadgeTemplate::new(&package_advisories);
+        fs::write(&badge_path, badge.render(templates)).unwrap();
+        status_ok!("Rendered", "{}", badge_path.display());
+
+        // Redirect the former location of the page to it
+        let redirect = RedirectTemplate {
+            url: format!("/packages/{}/", tpl.group_by),
+        };
+        let redirect_path = folder.join(tpl.group_by.clone() + ".html");
+        fs::write(&redirect_path, redirect.render(templates)).unwrap();
+
         // Write OSV JSON
         let osv_path = folder.join(tpl.group_by.clone() + ".json");
         let mut osv_advisories = Vec::new();
@@ -259,11 +478,7 @@ pub fn render_advisories(output_folder: PathBuf) {
             .keywords
             .as_slice()
             .iter()
-            .map(|k| {
-                filters::safe_keyword::default()
-                    .execute(k.as_str(), askama::NO_VALUES)
-                    .unwrap()
-            })
+            .map(|k| filters::safe_keyword(k.as_str()))
             .collect::<Vec<String>>();
         slug_keywords.sort();
         slug_keywords.dedup();
@@ -288,7 +503,10 @@ pub fn render_advisories(output_folder: PathBuf) {
                 )),
                 None => advisories_per_keyword.push(AdvisoriesSubList {
                     title: format!("Advisories with keyword '{}'", keyword.as_str()),
+                    url: format!("/keywords/{}.html", keyword.as_str()),
                     group_by: keyword.as_str().to_string(),
+                    feed: None,
+                    badge: None,
                     advisories: vec![(data, rendered_title.clone(), advisory_title_type.clone())],
                 }),
             }
@@ -296,10 +514,16 @@ pub fn render_advisories(output_folder: PathBuf) {
     }
     let folder = output_folder.join("keywords");
     fs::create_dir_all(&folder).unwrap();
-    render_list_index("Keywords", keywords, folder.as_ref());
+    render_list_index(
+        templates,
+        "Keywords",
+        "/keywords/",
+        keywords,
+        folder.as_ref(),
+    );
     for tpl in &advisories_per_keyword {
         let output_path = folder.join(tpl.group_by.clone() + ".html");
-        fs::write(&output_path, tpl.render().unwrap()).unwrap();
+        fs::write(&output_path, tpl.render(templates)).unwrap();
         status_ok!("Rendered", "{}", output_path.display());
     }
     status_ok!(
@@ -335,7 +559,10 @@ pub fn render_advisories(output_folder: PathBuf) {
                 )),
                 None => advisories_per_category.push(AdvisoriesSubList {
                     title: format!("Advisories in category '{}'", category.name()),
+                    url: format!("/categories/{}.html", category.name()),
                     group_by: category.name().to_string(),
+                    feed: Some(format!("/categories/{}.xml", category.name())),
+                    badge: None,
                     advisories: vec![(data, rendered_title.clone(), advisory_title_type.clone())],
                 }),
             }
@@ -345,11 +572,17 @@ pub fn render_advisories(output_folder: PathBuf) {
     fs::create_dir_all(&folder).unwrap();
 
     // index
-    render_list_index("Categories", categories, folder.as_ref());
+    render_list_index(
+        templates,
+        "Categories",
+        "/categories/",
+        categories,
+        folder.as_ref(),
+    );
     // par value page
     for tpl in &advisories_per_category {
         let output_path = folder.join(tpl.group_by.clone() + ".html");
-        fs::write(&output_path, tpl.render().unwrap()).unwrap();
+        fs::write(&output_path, tpl.render(templates)).unwrap();
         status_ok!("Rendered", "{}", output_path.display());
     }
     status_ok!(
@@ -368,8 +601,281 @@ pub fn render_advisories(output_folder: PathBuf) {
         advisories.len()
     );
 
-    // Feed
+    // Redirect stubs for advisories whose ID changed (/advisories/${old_id}.html)
+    let ids = advisories
+        .iter()
+        .map(|data| data.id().clone())
+        .collect::<HashSet<_>>();
+    let mut redirects = 0;
+    for (old_id, new_id) in renamed_advisories(repo.path(), &ids) {
+        let redirect = RedirectTemplate {
+            url: format!("/advisories/{new_id}.html"),
+        };
+        let redirect_path = advisories_folder.join(old_id.as_str().to_owned() + ".html");
+        fs::write(&redirect_path, redirect.render(templates)).unwrap();
+        status_ok!("Rendered", "{}", redirect_path.display());
+        redirects += 1;
+    }
+    status_ok!("Completed", "{} advisory redirects rendered", redirects);
+
+    // Sitemap
+    let mut pages: Vec<(String, Option<&str>)> = ["/", "/advisories/", "/contributing.html"]
+        .into_iter()
+        .chain(["/packages/", "/keywords/", "/categories/"])
+        .map(|url| (url.to_owned(), None))
+        .collect();
+    pages.extend(advisories.iter().map(|data| {
+        (
+            format!("/advisories/{}.html", data.id()),
+            Some(data.modified.as_str()),
+        )
+    }));
+    pages.extend(
+        advisories_per_package
+            .iter()
+            .chain(&advisories_per_keyword)
+            .chain(&advisories_per_category)
+            .map(|tpl| (tpl.url.clone(), None)),
+    );
+    let sitemap_path = output_folder.join("sitemap.xml");
+    render_sitemap(&sitemap_path, &pages);
+    status_ok!("Rendered", "{}", sitemap_path.display());
+    status_ok!("Completed", "{} pages listed in sitemap", pages.len());
+
+    // Feeds
     let feed_path = output_folder.join("feed.xml");
+    let all_advisories = advisories.iter().collect::<Vec<_>>();
+    let len = feed_len(&all_advisories);
+    render_feed(
+        templates,
+        &feed_path,
+        "https://rustsec.org/feed.xml",
+        "RustSec Advisories",
+        "Security advisories filed against Rust crates",
+        &all_advisories[..len],
+    );
+    status_ok!("Rendered", "{}", feed_path.display());
+    status_ok!("Completed", "{} advisories rendered in atom feed", len);
+
+    // Per-category feeds (/categories/${category}.xml)
+    let folder = output_folder.join("categories");
+    for tpl in &advisories_per_category {
+        let feed_path = folder.join(tpl.group_by.clone() + ".xml");
+        let category_advisories = tpl
+            .advisories
+            .iter()
+            .map(|(data, _, _)| *data)
+            .collect::<Vec<_>>();
+        let len = feed_len(&category_advisories);
+        render_feed(
+            templates,
+            &feed_path,
+            &format!("https://rustsec.org/categories/{}.xml", tpl.group_by),
+            &format!("RustSec Advisories: {}", tpl.group_by),
+            &format!(
+                "Security advisories filed against Rust crates in category '{}'",
+                tpl.group_by
+            ),
+            &category_advisories[..len],
+        );
+        status_ok!("Rendered", "{}", feed_path.display());
+    }
+    status_ok!(
+        "Completed",
+        "{} categories rendered as atom feeds",
+        advisories_per_category.len()
+    );
+
+    // JSON API (/api/v1/)
+    let api_folder = output_folder.join("api").join(API_VERSION);
+    render_api(&api_folder, &advisories);
+    status_ok!(
+        "Completed",
+        "{} advisories rendered as JSON in {}",
+        advisories.len(),
+        api_folder.display()
+    );
+}
+
+/// Version of the JSON API, part of its paths and incremented on breaking changes
+const API_VERSION: &str = "v1";
+
+/// Advisory as returned by the JSON API
+#[derive(Serialize)]
+struct ApiAdvisory<'a> {
+    /// Date the advisory was added to the database
+    created: &'a str,
+
+    /// Date the advisory was last modified
+    modified: &'a str,
+
+    /// URL of the page of the advisory on the web site
+    html_url: String,
+
+    /// The advisory itself
+    #[serde(flatten)]
+    advisory: &'a rustsec::Advisory,
+}
+
+impl<'a> ApiAdvisory<'a> {
+    fn new(data: &'a AdvisoryData) -> Self {
+        Self {
+            created: data.created.as_str(),
+            modified: data.modified.as_str(),
+            html_url: format!("https://rustsec.org/advisories/{}.html", data.id()),
+            advisory: &data.advisory,
+        }
+    }
+}
+
+/// Entry of an index of the JSON API, linking to the full data
+#[derive(Serialize)]
+struct ApiIndexEntry<'a> {
+    /// Advisory ID or crate name
+    id: &'a str,
+
+    /// Path of the full data, relative to the root of the web site
+    url: String,
+}
+
+/// Renders the JSON API, making the web site double as a read-only API:
+///
+/// - `advisories.json`: the IDs of all advisories
+/// - `advisories/${id}.json`: a single advisory
+/// - `crates.json`: the names of all crates with advisories
+/// - `crates/${crate}.json`: all of the advisories for a crate
+fn render_api(output_folder: &Path, advisories: &[AdvisoryData]) {
+    let mut advisories = advisories.iter().collect::<Vec<_>>();
+    advisories.sort_by(|a, b| a.id().cmp(b.id()));
+
+    fn write_json<T: Serialize + ?Sized>(path: &Path, value: &T) {
+        fs::write(path, serde_json::to_string_pretty(value).unwrap()).unwrap();
+    }
+
+    // Per-advisory documents
+    let advisories_folder = output_folder.join("advisories");
+    fs::create_dir_all(&advisories_folder).unwrap();
+    let mut index = vec![];
+    for data in &advisories {
+        let id = data.id().as_str();
+        write_json(
+            &advisories_folder.join(format!("{id}.json")),
+            &ApiAdvisory::new(data),
+        );
+        index.push(ApiIndexEntry {
+            id,
+            url: format!("/api/{API_VERSION}/advisories/{id}.json"),
+        });
+    }
+    write_json(&output_folder.join("advisories.json"), &index);
+
+    // Per-crate documents
+    let mut crates = Map::<&str, Vec<ApiAdvisory<'_>>>::new();
+    for data in &advisories {
+        crates
+            .entry(data.metadata.package.as_str())
+            .or_default()
+            .push(ApiAdvisory::new(data));
+    }
+
+    let crates_folder = output_folder.join("crates");
+    fs::create_dir_all(&crates_folder).unwrap();
+    let mut index = vec![];
+    for (name, advisories) in &crates {
+        write_json(&crates_folder.join(format!("{name}.json")), advisories);
+        index.push(ApiIndexEntry {
+            id: name,
+            url: format!("/api/{API_VERSION}/crates/{name}.json"),
+        });
+    }
+    write_json(&output_folder.join("crates.json"), &index);
+}
+
+/// Find the advisories whose file was renamed to another ID in the history of
+/// the repo, returning a map of `old ID -> current ID`.
+///
+/// Only the old IDs which don't belong to a current advisory are returned,
+/// and placeholder IDs of drafts are ignored.
+fn renamed_advisories(repo_path: &Path, ids: &HashSet<Id>) -> Map<Id, Id> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["log", "--diff-filter=R", "--name-status", "--format="])
+        .output()
+        .unwrap();
+
+    if !output.status.success() {
+        status_warn!(
+            "couldn't find renamed advisories: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Map::new();
+    }
+
+    let advisory_id = |path: &str| {
+        Path::new(path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<Id>().ok())
+            .filter(|id| !id.is_placeholder())
+    };
+
+    let mut renames = Map::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        // Renames are listed as `R<similarity>\t<old path>\t<new path>`
+        let mut fields = line.split('\t').skip(1);
+        let (Some(old_id), Some(new_id)) = (
+            fields.next().and_then(advisory_id),
+            fields.next().and_then(advisory_id),
+        ) else {
+            continue;
+        };
+
+        if old_id != new_id && !ids.contains(&old_id) {
+            renames.insert(old_id, new_id);
+        }
+    }
+
+    // Follow advisories renamed several times to their current ID
+    renames
+        .keys()
+        .filter_map(|old_id| {
+            let mut new_id = &renames[old_id];
+            for _ in 0..renames.len() {
+                match renames.get(new_id) {
+                    Some(id) => new_id = id,
+                    None => break,
+                }
+            }
+            ids.contains(new_id)
+                .then(|| (old_id.clone(), new_id.clone()))
+        })
+        .collect()
+}
+
+/// Renders a sitemap of the given pages, with their last modification date
+fn render_sitemap(output_path: &Path, pages: &[(String, Option<&str>)]) {
+    let mut sitemap = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+
+    for (url, lastmod) in pages {
+        let url = escape_str_pcdata(&format!("https://rustsec.org{url}")).into_owned();
+        sitemap.push_str(&format!("  <url>\n    <loc>{url}</loc>\n"));
+        if let Some(lastmod) = lastmod {
+            sitemap.push_str(&format!("    <lastmod>{lastmod}</lastmod>\n"));
+        }
+        sitemap.push_str("  </url>\n");
+    }
+
+    sitemap.push_str("</urlset>\n");
+    fs::write(output_path, sitemap).unwrap();
+}
+
+/// Number of the latest advisories (sorted by date in descending order) to
+/// include in a feed
+fn feed_len(advisories: &[&AdvisoryData]) -> usize {
     let min_feed_len = 10;
 
     // TODO(tarcieri): replace with `DateTime`
@@ -387,16 +893,13 @@ pub fn render_advisories(output_folder: PathBuf) {
     // include max(latest week of advisories, 10 latest advisories)
     // the goal is not to miss a vulnerability in case of burst
     // and to never have an empty feed.
-    let len = if advisories.len() < min_feed_len {
+    if advisories.len() < min_feed_len {
         advisories.len()
     } else if last_week_len > min_feed_len {
         last_week_len
     } else {
         min_feed_len
-    };
-    render_feed(&feed_path, &advisories[..len]);
-    status_ok!("Rendered", "{}", feed_path.display());
-    status_ok!("Completed", "{} advisories rendered in atom feed", len);
+    }
 }
 
 /// Title with the id, the package name and the advisory type
@@ -446,13 +949,70 @@ fn render_index(output_path: &Path, advisories: &[AdvisoryData]) {
     }
     let ids_json = serde_json::to_string(&ids).unwrap();
     let package_json = serde_json::to_string(&packages).unwrap();
+    let (documents, terms) = full_text_index(advisories);
+    let documents_json = serde_json::to_string(&documents).unwrap();
+    let terms_json = serde_json::to_string(&terms).unwrap();
 
-    let js = format!("var ids = {ids_json}\nvar packages = {package_json}\n");
+    let js = format!(
+        "var ids = {ids_json}\nvar packages = {package_json}\nvar documents = {documents_json}\nvar terms = {terms_json}\n"
+    );
     fs::write(output_path, js).unwrap();
 }
 
+/// Builds the full-text part of the search index, covering the IDs, crates,
+/// titles and keywords of the advisories.
+///
+/// It consists of the `[ID, package, title]` documents, in the order of the
+/// given advisories, and a map of `lowercase(term) -> document indices`.
+fn full_text_index(advisories: &[AdvisoryData]) -> (Vec<[&str; 3]>, Map<String, Vec<usize>>) {
+    let mut documents = Vec::with_capacity(advisories.len());
+    let mut terms: Map<String, Vec<usize>> = Map::new();
+
+    for (index, data) in advisories.iter().enumerate() {
+        documents.push([
+            data.id().as_str(),
+            data.metadata.package.as_str(),
+            data.title(),
+        ]);
+
+        let mut words = HashSet::new();
+        words.insert(data.id().as_str().to_lowercase());
+        words.insert(data.metadata.package.as_str().to_lowercase());
+        for alias in &data.metadata.aliases {
+            words.insert(alias.as_str().to_lowercase());
+        }
+        for text in
+            iter::once(data.title()).chain(data.metadata.keywords.iter().map(|k| k.as_str()))
+        {
+            for word in text.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-') {
+                // Index hyphenated words both whole and by part, so that e.g.
+                // "use-after-free" is found when searching "after free"
+                words.extend(
+                    iter::once(word)
+                        .chain(word.split('-'))
+                        .map(|word| word.trim_matches('-').to_lowercase())
+                        .filter(|word| word.chars().count() > 1),
+                );
+            }
+        }
+
+        for word in words {
+            terms.entry(word).or_default().push(index);
+        }
+    }
+
+    (documents, terms)
+}
+
 /// Renders an Atom feed of advisories
-fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
+fn render_feed(
+    templates: &Templates,
+    output_path: &Path,
+    self_url: &str,
+    title: &str,
+    subtitle: &str,
+    advisories: &[&AdvisoryData],
+) {
     let mut entries: Vec<Entry> = vec![];
     let author = PersonBuilder::default().name("RustSec").build();
 
@@ -462,7 +1022,7 @@ fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
 
     for data in advisories {
         let escaped_title_type = escape_str_attribute(&title_type(data)).into_owned();
-        let escaped_title = escape_str_attribute(data.title()).into_owned();
+        let escaped_excerpt = escape_str_attribute(&excerpt(data.description())).into_owned();
         let cdate_time = data.created.as_str().to_owned() + "T12:00:00+00:00";
         let mdate_time = data.modified.as_str().to_owned() + "T12:00:00+00:00";
         let url = "https://rustsec.org/advisories/".to_owned() + data.id().as_str() + ".html";
@@ -492,14 +1052,14 @@ fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
             rendered_description,
             rendered_title,
         };
-        let html = advisory_tmpl.render().unwrap();
+        let html = advisory_tmpl.render(templates);
         let content = ContentBuilder::default()
             .content_type(Some("html".to_owned()))
             .lang("en".to_owned())
             .value(Some(html))
             .build();
 
-        let mut summary = Text::plain(escaped_title);
+        let mut summary = Text::plain(escaped_excerpt);
         summary.lang = Some("en".to_owned());
 
         let item = EntryBuilder::default()
@@ -516,7 +1076,6 @@ fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
         entries.push(item);
     }
 
-    let self_url = "https://rustsec.org/feed.xml";
     let alternate_link = LinkBuilder::default()
         .href("https://rustsec.org/")
         .rel("alternate")
@@ -528,12 +1087,12 @@ fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
         .mime_type(Some("application/atom+xml".to_owned()))
         .build();
 
-    let mut subtitle = Text::plain("Security advisories filed against Rust crates".to_owned());
+    let mut subtitle = Text::plain(subtitle.to_owned());
     subtitle.lang = Some("en".to_owned());
 
     let feed = FeedBuilder::default()
         .id(self_url)
-        .title("RustSec Advisories")
+        .title(title)
         .subtitle(Some(subtitle))
         .links(vec![self_link, alternate_link])
         .icon("https://rustsec.org/favicon.ico".to_owned())
@@ -546,11 +1105,39 @@ fn render_feed(output_path: &Path, advisories: &[AdvisoryData]) {
     feed.write_to(file).unwrap();
 }
 
+/// Excerpt of the first paragraph of a Markdown description, as plain text
+fn excerpt(description: &str) -> String {
+    /// Maximum number of characters of an excerpt
+    const MAX_LEN: usize = 280;
+
+    let paragraph = description
+        .split("\n\n")
+        .map(str::trim)
+        .find(|paragraph| !paragraph.is_empty())
+        .unwrap_or_default();
+    let text = paragraph
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(['`', '*'], "");
+
+    if text.chars().count() <= MAX_LEN {
+        return text;
+    }
+
+    let mut excerpt = text.chars().take(MAX_LEN).collect::<String>();
+    if let Some(end) = excerpt.rfind(' ') {
+        excerpt.truncate(end);
+    }
+    excerpt.push('…');
+    excerpt
+}
+
 #[derive(RustEmbed)]
 #[folder = "src/web/static/"]
 struct StaticAsset;
 
-fn copy_static_assets(output_folder: &Path) {
+fn copy_static_assets(output_folder: &Path, theme: Option<&Path>) {
     for file in StaticAsset::iter() {
         let asset_path = PathBuf::from(file.as_ref());
 
@@ -562,34 +1149,44 @@ fn copy_static_assets(output_folder: &Path) {
         let asset = StaticAsset::get(file.as_ref()).unwrap();
         fs::write(output_folder.join(file.as_ref()), asset.data).unwrap();
     }
+
+    // Copy the static assets of the theme over the default ones
+    if let Some(theme_static) = theme.map(|theme| theme.join("static"))
+        && theme_static.is_dir()
+    {
+        copy_folder(&theme_static, output_folder);
+    }
 }
 
-mod filters {
-    use std::borrow::Borrow;
+/// Recursively copy the contents of a folder into another one
+fn copy_folder(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
 
-    use askama::filter_fn;
-    use chrono::NaiveDate;
-    use rustsec::advisory;
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let destination = to.join(entry.file_name());
 
-    #[filter_fn]
-    pub fn friendly_date<T: Borrow<advisory::Date>>(
-        date: T,
-        _: &dyn askama::Values,
-    ) -> ::askama::Result<String> {
-        let date = date.borrow();
+        if entry.file_type().unwrap().is_dir() {
+            copy_folder(&entry.path(), &destination);
+        } else {
+            fs::copy(entry.path(), destination).unwrap();
+        }
+    }
+}
 
-        // TODO(tarcieri): fix deprecation of `NaiveDate::from_ymd`
-        #[allow(deprecated)]
-        let date = NaiveDate::from_ymd(date.year().try_into().unwrap(), date.month(), date.day())
-            .format("%B %e, %Y")
-            .to_string();
+mod filters {
+    use chrono::NaiveDate;
 
-        Ok(date)
+    /// Format a `YYYY-MM-DD` date, e.g. as "January 1, 2024"
+    pub fn friendly_date(date: &str) -> String {
+        match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            Ok(date) => date.format("%B %e, %Y").to_string(),
+            Err(_) => date.to_owned(),
+        }
     }
 
-    #[filter_fn]
-    pub fn safe_keyword(s: &str, _: &dyn askama::Values) -> ::askama::Result<String> {
-        Ok(s.chars()
+    pub fn safe_keyword(s: &str) -> String {
+        s.chars()
             .map(|c| {
                 if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
                     c
@@ -597,7 +1194,7 @@ mod filters {
                     '-'
                 }
             })
-            .collect())
+            .collect()
     }
 }
 
@@ -631,6 +1228,34 @@ impl AdvisoryData {
     }
 }
 
+impl AdvisoryData {
+    /// Current status of the advisory, i.e. which versions are patched
+    fn status(&self) -> String {
+        if self.withdrawn() {
+            return "Withdrawn".to_owned();
+        }
+
+        match self.versions.patched() {
+            [] => "No patched versions".to_owned(),
+            patched => format!(
+                "Patched: {}",
+                patched
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Advisories are passed to templates as an [`AdvisoryView`]
+impl Serialize for AdvisoryData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AdvisoryView::new(self).serialize(serializer)
+    }
+}
+
 impl Deref for AdvisoryData {
     type Target = rustsec::Advisory;
 
@@ -638,3 +1263,142 @@ impl Deref for AdvisoryData {
         &self.advisory
     }
 }
+
+/// Data of an advisory available to templates
+#[derive(Serialize)]
+struct AdvisoryView<'a> {
+    id: &'a str,
+    package: &'a str,
+    collection: Option<String>,
+    title: &'a str,
+    title_type: String,
+    status: String,
+    withdrawn: bool,
+    expect_deleted: bool,
+    /// Date the advisory was reported
+    date: &'a str,
+    /// Date the advisory was added to the database
+    created: &'a str,
+    /// Date the advisory was last modified in the database
+    modified: &'a str,
+    informational: Option<String>,
+    categories: Vec<String>,
+    keywords: Vec<&'a str>,
+    aliases: Vec<IdView<'a>>,
+    related: Vec<IdView<'a>>,
+    url: Option<&'a str>,
+    references: Vec<&'a str>,
+    severity: Option<String>,
+    cvss: Option<CvssView>,
+    patched: Vec<String>,
+    unaffected: Vec<String>,
+    affected_arch: Vec<String>,
+    affected_os: Vec<String>,
+    /// `Vec<(function path, affected versions)>`
+    affected_functions: Vec<(String, Vec<String>)>,
+    license: &'a str,
+    /// Does the license of the advisory require attribution?
+    attribution_required: bool,
+}
+
+/// ID of another advisory, with the URL of its page if it's known
+#[derive(Serialize)]
+struct IdView<'a> {
+    id: &'a str,
+    url: Option<String>,
+}
+
+/// CVSS vector of an advisory
+#[derive(Serialize)]
+struct CvssView {
+    score: String,
+    vector: String,
+    /// URL of the vector in the FIRST CVSS calculator, if any
+    calculator: Option<String>,
+    /// `Vec<(metric description, value)>`
+    metrics: Vec<(&'static str, String)>,
+}
+
+impl<'a> AdvisoryView<'a> {
+    fn new(data: &'a AdvisoryData) -> Self {
+        fn to_strings<T: ToString>(items: &[T]) -> Vec<String> {
+            items.iter().map(ToString::to_string).collect()
+        }
+
+        let metadata = &data.metadata;
+        let ids = |ids: &'a [Id]| {
+            ids.iter()
+                .map(|id| IdView {
+                    id: id.as_str(),
+                    url: id.url(),
+                })
+                .collect()
+        };
+        let affected = data.affected.as_ref();
+
+        Self {
+            id: data.id().as_str(),
+            package: metadata.package.as_str(),
+            collection: metadata.collection.map(|collection| collection.to_string()),
+            title: data.title(),
+            title_type: title_type(data),
+            status: data.status(),
+            withdrawn: data.withdrawn(),
+            expect_deleted: metadata.expect_deleted,
+            date: data.date().as_str(),
+            created: data.created.as_str(),
+            modified: data.modified.as_str(),
+            informational: metadata.informational.as_ref().map(ToString::to_string),
+            categories: metadata
+                .categories
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            keywords: metadata.keywords.iter().map(|k| k.as_str()).collect(),
+            aliases: ids(&metadata.aliases),
+            related: ids(&metadata.related),
+            url: metadata.url.as_ref().map(|url| url.as_str()),
+            references: metadata.references.iter().map(|url| url.as_str()).collect(),
+            severity: data.severity().map(|severity| severity.to_string()),
+            cvss: metadata.cvss.as_ref().map(CvssView::new),
+            patched: to_strings(data.versions.patched()),
+            unaffected: to_strings(data.versions.unaffected()),
+            affected_arch: affected.map(|a| to_strings(&a.arch)).unwrap_or_default(),
+            affected_os: affected.map(|a| to_strings(&a.os)).unwrap_or_default(),
+            affected_functions: affected
+                .map(|a| {
+                    a.functions
+                        .iter()
+                        .map(|(path, versions)| (path.to_string(), to_strings(versions)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            license: metadata.license.spdx(),
+            attribution_required: metadata.license == advisory::License::CcBy40,
+        }
+    }
+}
+
+impl CvssView {
+    fn new(cvss: &cvss::Cvss) -> Self {
+        let calculator = match cvss {
+            cvss::Cvss::CvssV40(_) | cvss::Cvss::CvssV31(_) => {
+                Some(format!("https://www.first.org/cvss/calculator/3.1#{cvss}"))
+            }
+            cvss::Cvss::CvssV30(_) => {
+                Some(format!("https://www.first.org/cvss/calculator/3.0#{cvss}"))
+            }
+            _ => None,
+        };
+
+        Self {
+            score: cvss.score().to_string(),
+            vector: cvss.to_string(),
+            calculator,
+            metrics: cvss
+                .metrics()
+                .map(|(metric, value)| (metric.description(), format!("{value:?}")))
+                .collect(),
+        }
+    }
+}
@@ -4,7 +4,7 @@ function search(term, redirect) {
 
     // Try to open destination directly, only display search page if multiple results
     if (packages.includes(term)) {
-        window.open('/packages/'+encodeURIComponent(term)+'.html','_self');
+        window.open('/packages/'+encodeURIComponent(term)+'/','_self');
         return false;
     }
     if (term in ids && ids[term].length == 1) {
@@ -28,14 +28,27 @@ function search(term, redirect) {
     // use document.createTextNode for escaping
     document.getElementById('searched-term').innerHTML = ""
     document.getElementById('searched-term').appendChild(document.createTextNode("Search results for '"+original_term+"'"));
-    if (term in ids) {
+    var results = term in ids ? ids[term].slice() : [];
+    fullTextSearch(term).forEach(function (item) {
+        if (!results.includes(item)) {
+            results.push(item);
+        }
+    });
+    if (results.length > 0) {
+        var titles = {};
+        documents.forEach(function (doc) {
+            titles[doc[0]] = doc[2];
+        });
         var ul = document.createElement('ul');
-        ids[term].forEach(function (item, index) {
+        results.forEach(function (item, index) {
             var li = document.createElement('li');
             var a = document.createElement('a');
             a.setAttribute('href', "/advisories/"+encodeURIComponent(item)+".html");
             a.appendChild(document.createTextNode(item));
             li.appendChild(a)
+            if (item in titles) {
+                li.appendChild(document.createTextNode(": "+titles[item]));
+            }
             ul.appendChild(li)
         });
         document.getElementById('search-result').innerHTML = ul.outerHTML;
@@ -44,6 +57,39 @@ function search(term, redirect) {
     }
 }
 
+// Find the advisories matching all the words of the term, each word matching
+// the beginning of an ID, crate, title word or keyword
+function fullTextSearch(term) {
+    var words = term.split(/[^\p{L}\p{N}_-]+/u).filter(function (word) {
+        return word.length > 0;
+    });
+    if (words.length == 0) {
+        return [];
+    }
+
+    var matches = null;
+    words.forEach(function (word) {
+        var found = new Set();
+        Object.keys(terms).forEach(function (key) {
+            if (key.startsWith(word)) {
+                terms[key].forEach(function (index) {
+                    found.add(index);
+                });
+            }
+        });
+        matches = matches == null ? found : new Set([...matches].filter(function (index) {
+            return found.has(index);
+        }));
+    });
+
+    // Documents are ordered from the newest to the oldest advisory
+    return [...matches].sort(function (a, b) {
+        return a - b;
+    }).map(function (index) {
+        return documents[index][0];
+    });
+}
+
 function searchform() {
     var term = document.getElementById('search-term').value
     search(term, true)
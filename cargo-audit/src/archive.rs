@@ -0,0 +1,730 @@
+//! Finding executables inside archives
+//!
+//! Release artifacts are usually shipped as `.tar`, `.tar.gz` or `.zip`
+//! archives, or as `.deb` and `.rpm` packages. Rather than requiring them to
+//! be extracted first, the executables they contain (including those in nested
+//! archives, up to a configurable depth) are read directly so that they can be
+//! audited.
+
+use bzip2::read::MultiBzDecoder;
+use flate2::read::{DeflateDecoder, GzDecoder};
+use lzma_rust2::XzReader;
+use rustsec::{Error, ErrorKind};
+use ruzstd::decoding::StreamingDecoder;
+use std::{
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+/// Magic numbers of the executable (and static library) formats supported by binary scanning
+const EXECUTABLE_MAGIC: &[&[u8]] = &[
+    b"\x7fELF",          // ELF
+    b"MZ",               // PE
+    b"\xfe\xed\xfa\xce", // Mach-O 32-bit
+    b"\xce\xfa\xed\xfe", // Mach-O 32-bit (little endian)
+    b"\xfe\xed\xfa\xcf", // Mach-O 64-bit
+    b"\xcf\xfa\xed\xfe", // Mach-O 64-bit (little endian)
+    b"\xca\xfe\xba\xbe", // Mach-O universal binary
+    b"\0asm",            // WebAssembly
+    b"!<arch>\n",        // Static library or rlib
+];
+
+/// Number of bytes needed to identify an archive
+pub(crate) const MAGIC_LEN: usize = 262;
+
+/// Separator between the path of an archive and the path of a file inside it
+const SEPARATOR: char = '!';
+
+/// Signature of the zip end of central directory record
+const ZIP_END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+
+/// Signature of zip central directory file headers
+const ZIP_CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+
+/// Signature of zip local file headers
+const ZIP_LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+/// Maximum memory the xz decoder may use for its dictionary, in KiB
+/// (`xz -9` uses 64MiB)
+const XZ_MEMORY_LIMIT_KIB: u32 = 256 * 1024;
+
+/// Kinds of supported archives
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ArchiveKind {
+    /// Uncompressed tarball
+    Tar,
+
+    /// gzip-compressed tarball
+    TarGz,
+
+    /// Zip archive
+    Zip,
+
+    /// Debian package
+    Deb,
+
+    /// RPM package
+    Rpm,
+}
+
+impl ArchiveKind {
+    /// Identify the kind of archive from the first [`MAGIC_LEN`] bytes of a file
+    pub(crate) fn detect(magic: &[u8]) -> Option<Self> {
+        if magic.starts_with(b"\x1f\x8b") {
+            Some(Self::TarGz)
+        } else if magic.starts_with(b"PK\x03\x04") || magic.starts_with(b"PK\x05\x06") {
+            Some(Self::Zip)
+        } else if magic.starts_with(b"!<arch>\ndebian-binary") {
+            Some(Self::Deb)
+        } else if magic.starts_with(b"\xed\xab\xee\xdb") {
+            Some(Self::Rpm)
+        } else if magic.get(257..262) == Some(b"ustar") {
+            Some(Self::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Does the file start with the magic number of a supported executable format?
+pub(crate) fn is_executable(magic: &[u8]) -> bool {
+    EXECUTABLE_MAGIC
+        .iter()
+        .any(|expected| magic.starts_with(expected))
+}
+
+/// Read the first [`MAGIC_LEN`] bytes of a file
+pub(crate) fn read_magic(path: &Path) -> io::Result<Vec<u8>> {
+    let mut magic = Vec::with_capacity(MAGIC_LEN);
+    std::fs::File::open(path)?
+        .take(MAGIC_LEN as u64)
+        .read_to_end(&mut magic)?;
+    Ok(magic)
+}
+
+/// Walks archives, passing each executable found in them to a callback
+pub(crate) struct Walker<F> {
+    /// How many levels of nested archives to look inside of
+    max_depth: usize,
+
+    /// Maximum size of a file to read from an archive
+    size_limit: u64,
+
+    /// Called with the path and contents of each executable
+    callback: F,
+}
+
+impl<F> Walker<F>
+where
+    F: FnMut(PathBuf, rustsec::Result<Vec<u8>>),
+{
+    /// Create a new archive walker
+    pub(crate) fn new(max_depth: usize, size_limit: u64, callback: F) -> Self {
+        Self {
+            max_depth,
+            size_limit,
+            callback,
+        }
+    }
+
+    /// Walk the archive at the given path
+    pub(crate) fn walk_file(&mut self, path: &Path, kind: ArchiveKind) -> rustsec::Result<()> {
+        let file = std::fs::File::open(path)?;
+        self.walk(path, kind, file, 1)
+    }
+
+    /// Walk an archive read from `reader`
+    fn walk<R: Read + Seek>(
+        &mut self,
+        path: &Path,
+        kind: ArchiveKind,
+        reader: R,
+        depth: usize,
+    ) -> rustsec::Result<()> {
+        match kind {
+            ArchiveKind::Tar => self.walk_tar(path, reader, depth),
+            ArchiveKind::TarGz => self.walk_tar(path, GzDecoder::new(reader), depth),
+            ArchiveKind::Zip => self.walk_zip(path, reader, depth),
+            ArchiveKind::Deb => self.walk_deb(path, reader, depth),
+            ArchiveKind::Rpm => self.walk_rpm(path, reader, depth),
+        }
+    }
+
+    /// Walk the entries of a tarball
+    fn walk_tar(&mut self, path: &Path, reader: impl Read, depth: usize) -> rustsec::Result<()> {
+        let mut archive = tar::Archive::new(reader);
+
+        for entry in archive.entries().map_err(|e| archive_error(path, e))? {
+            let entry = entry.map_err(|e| archive_error(path, e))?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let entry_path = entry.path().map_err(|e| archive_error(path, e))?;
+            let entry_path = nested_path(path, &entry_path.to_string_lossy());
+            self.visit(entry_path, entry, depth)?;
+        }
+
+        Ok(())
+    }
+
+    /// Walk the files of a Debian package, i.e. the tarball in its `data.tar*` member
+    fn walk_deb(
+        &mut self,
+        path: &Path,
+        mut reader: impl Read,
+        depth: usize,
+    ) -> rustsec::Result<()> {
+        let mut magic = [0; 8];
+        reader
+            .read_exact(&mut magic)
+            .map_err(|e| archive_error(path, e))?;
+
+        loop {
+            let mut header = [0; 60];
+            match reader.read_exact(&mut header) {
+                Ok(()) => (),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(archive_error(path, e)),
+            }
+
+            let name = String::from_utf8_lossy(&header[..16]);
+            let name = name.trim_end().trim_end_matches('/');
+            let size = String::from_utf8_lossy(&header[48..58])
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| archive_error(path, invalid_data("invalid ar member header")))?;
+
+            // Members are aligned to 2 bytes
+            let mut member = (&mut reader).take(size + size % 2);
+
+            if name.starts_with("data.tar") {
+                let data = decompress(path, &mut member)?;
+                self.walk_tar(path, data, depth)?;
+            }
+
+            io::copy(&mut member, &mut io::sink()).map_err(|e| archive_error(path, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Walk the files of an RPM package, i.e. the cpio archive in its payload
+    fn walk_rpm(
+        &mut self,
+        path: &Path,
+        mut reader: impl Read,
+        depth: usize,
+    ) -> rustsec::Result<()> {
+        let mut lead = [0; 96];
+        reader
+            .read_exact(&mut lead)
+            .map_err(|e| archive_error(path, e))?;
+
+        // The signature header is padded to 8 bytes, followed by the main header
+        let signature_len = skip_rpm_header(&mut reader).map_err(|e| archive_error(path, e))?;
+        io::copy(
+            &mut (&mut reader).take((8 - signature_len % 8) % 8),
+            &mut io::sink(),
+        )
+        .map_err(|e| archive_error(path, e))?;
+        skip_rpm_header(&mut reader).map_err(|e| archive_error(path, e))?;
+
+        let mut payload = decompress(path, reader)?;
+
+        loop {
+            let mut header = [0; 110];
+            payload
+                .read_exact(&mut header)
+                .map_err(|e| archive_error(path, e))?;
+
+            if !header.starts_with(b"07070") {
+                return Err(archive_error(path, invalid_data("invalid cpio header")));
+            }
+
+            let field = |index: usize| {
+                std::str::from_utf8(&header[6 + 8 * index..14 + 8 * index])
+                    .ok()
+                    .and_then(|field| u64::from_str_radix(field, 16).ok())
+                    .ok_or_else(|| archive_error(path, invalid_data("invalid cpio header")))
+            };
+            let (mode, size, name_len) = (field(1)?, field(6)?, field(11)?);
+
+            // The header and name, and the file contents are each aligned to 4 bytes
+            let mut name = vec![0; (name_len + (4 - (110 + name_len) % 4) % 4) as usize];
+            payload
+                .read_exact(&mut name)
+                .map_err(|e| archive_error(path, e))?;
+            let name = String::from_utf8_lossy(&name[..name_len.saturating_sub(1) as usize]);
+
+            if name == "TRAILER!!!" {
+                break;
+            }
+
+            let padding = (4 - size % 4) % 4;
+            let mut contents = (&mut payload).take(size + padding);
+            if mode & 0o170_000 == 0o100_000 {
+                self.visit(nested_path(path, &name), (&mut contents).take(size), depth)?;
+            }
+
+            io::copy(&mut contents, &mut io::sink()).map_err(|e| archive_error(path, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Walk the entries of a zip archive
+    fn walk_zip<R: Read + Seek>(
+        &mut self,
+        path: &Path,
+        mut reader: R,
+        depth: usize,
+    ) -> rustsec::Result<()> {
+        let entries = zip_entries(&mut reader).map_err(|e| archive_error(path, e))?;
+
+        for entry in entries {
+            let entry_path = nested_path(path, &entry.name);
+
+            if entry.encrypted || entry.name.ends_with('/') {
+                continue;
+            }
+
+            // Compressed data is never larger than the file it decompresses to
+            if entry.compressed_size > self.size_limit {
+                self.too_large(entry_path);
+                continue;
+            }
+
+            let data = zip_entry_data(&mut reader, &entry).map_err(|e| archive_error(path, e))?;
+
+            match entry.method {
+                0 => self.visit(entry_path, data.as_slice(), depth)?,
+                8 => self.visit(entry_path, DeflateDecoder::new(data.as_slice()), depth)?,
+                // Other compression methods are rarely used
+                _ => continue,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Report a file which exceeds the size limit
+    fn too_large(&mut self, path: PathBuf) {
+        let error = Error::new(
+            ErrorKind::BadParam,
+            format!(
+                "{} exceeds max size limit of {} bytes",
+                path.display(),
+                self.size_limit
+            ),
+        );
+        (self.callback)(path, Err(error));
+    }
+
+    /// Visit a file inside an archive
+    fn visit(&mut self, path: PathBuf, reader: impl Read, depth: usize) -> rustsec::Result<()> {
+        let mut magic = Vec::with_capacity(MAGIC_LEN);
+        let mut reader = reader.take(self.size_limit.saturating_add(1));
+        (&mut reader)
+            .take(MAGIC_LEN as u64)
+            .read_to_end(&mut magic)
+            .map_err(|e| archive_error(&path, e))?;
+
+        let nested = ArchiveKind::detect(&magic).filter(|_| depth < self.max_depth);
+        if nested.is_none() && !is_executable(&magic) {
+            return Ok(());
+        }
+
+        let mut contents = magic;
+        reader
+            .read_to_end(&mut contents)
+            .map_err(|e| archive_error(&path, e))?;
+
+        if contents.len() as u64 > self.size_limit {
+            self.too_large(path);
+            return Ok(());
+        }
+
+        match nested {
+            Some(kind) => self.walk(&path, kind, io::Cursor::new(contents), depth + 1),
+            None => {
+                (self.callback)(path, Ok(contents));
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An entry in the central directory of a zip archive
+struct ZipEntry {
+    /// Path of the file
+    name: String,
+
+    /// Compression method
+    method: u16,
+
+    /// Is the file encrypted?
+    encrypted: bool,
+
+    /// Size of the compressed data
+    compressed_size: u64,
+
+    /// Offset of the local file header
+    offset: u64,
+}
+
+/// Read the central directory of a zip archive
+fn zip_entries<R: Read + Seek>(reader: &mut R) -> io::Result<Vec<ZipEntry>> {
+    // The end of central directory record is followed by a comment of up to 64KiB
+    let len = reader.seek(SeekFrom::End(0))?;
+    let tail_len = len.min(22 + u64::from(u16::MAX));
+    reader.seek(SeekFrom::Start(len - tail_len))?;
+    let mut tail = vec![0; tail_len as usize];
+    reader.read_exact(&mut tail)?;
+
+    let eocd = (0..tail.len().saturating_sub(21))
+        .rev()
+        .find(|&i| u32_at(&tail, i) == ZIP_END_OF_CENTRAL_DIRECTORY)
+        .map(|i| &tail[i..])
+        .ok_or_else(|| invalid_data("missing end of central directory"))?;
+
+    let count = u16_at(eocd, 10);
+    let directory_size = u32_at(eocd, 12);
+    let directory_offset = u32_at(eocd, 16);
+    if directory_offset == u32::MAX || count == u16::MAX {
+        return Err(invalid_data("zip64 archives are not supported"));
+    }
+
+    reader.seek(SeekFrom::Start(directory_offset.into()))?;
+    let mut directory = vec![0; directory_size as usize];
+    reader.read_exact(&mut directory)?;
+
+    let mut entries = Vec::with_capacity(count.into());
+    let mut pos = 0;
+
+    for _ in 0..count {
+        let header = directory
+            .get(pos..pos + 46)
+            .filter(|header| u32_at(header, 0) == ZIP_CENTRAL_DIRECTORY_HEADER)
+            .ok_or_else(|| invalid_data("invalid central directory"))?;
+
+        let name_len = usize::from(u16_at(header, 28));
+        let extra_len = usize::from(u16_at(header, 30));
+        let comment_len = usize::from(u16_at(header, 32));
+        let name = directory
+            .get(pos + 46..pos + 46 + name_len)
+            .ok_or_else(|| invalid_data("invalid central directory"))?;
+
+        entries.push(ZipEntry {
+            name: String::from_utf8_lossy(name).into_owned(),
+            method: u16_at(header, 10),
+            encrypted: u16_at(header, 8) & 1 != 0,
+            compressed_size: u32_at(header, 20).into(),
+            offset: u32_at(header, 42).into(),
+        });
+
+        pos += 46 + name_len + extra_len + comment_len;
+    }
+
+    Ok(entries)
+}
+
+/// Read the (possibly compressed) data of a zip entry
+fn zip_entry_data<R: Read + Seek>(reader: &mut R, entry: &ZipEntry) -> io::Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(entry.offset))?;
+    let mut header = [0; 30];
+    reader.read_exact(&mut header)?;
+    if u32_at(&header, 0) != ZIP_LOCAL_FILE_HEADER {
+        return Err(invalid_data("invalid local file header"));
+    }
+
+    let skip = u64::from(u16_at(&header, 26)) + u64::from(u16_at(&header, 28));
+    reader.seek(SeekFrom::Current(skip as i64))?;
+
+    let mut data = Vec::new();
+    reader.take(entry.compressed_size).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Skip over an RPM header structure, returning its length
+fn skip_rpm_header(reader: &mut impl Read) -> io::Result<u64> {
+    let mut intro = [0; 16];
+    reader.read_exact(&mut intro)?;
+    if !intro.starts_with(b"\x8e\xad\xe8\x01") {
+        return Err(invalid_data("invalid RPM header"));
+    }
+
+    let index_len = u32::from_be_bytes(intro[8..12].try_into().unwrap());
+    let data_len = u32::from_be_bytes(intro[12..16].try_into().unwrap());
+    let len = 16 * u64::from(index_len) + u64::from(data_len);
+    if io::copy(&mut reader.take(len), &mut io::sink())? != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(16 + len)
+}
+
+/// Decompress the tarball of a Debian package or the payload of an RPM package
+fn decompress<'a>(path: &Path, reader: impl Read + 'a) -> rustsec::Result<Box<dyn Read + 'a>> {
+    let mut reader = io::BufReader::new(reader);
+    let magic = reader.fill_buf().map_err(|e| archive_error(path, e))?;
+
+    if magic.starts_with(b"\x1f\x8b") {
+        Ok(Box::new(GzDecoder::new(reader)))
+    } else if magic.starts_with(b"\xfd7zXZ\0") {
+        Ok(Box::new(XzReader::new_mem_limit(
+            reader,
+            true,
+            XZ_MEMORY_LIMIT_KIB,
+        )))
+    } else if magic.starts_with(b"\x28\xb5\x2f\xfd") {
+        let decoder = StreamingDecoder::new(reader)
+            .map_err(|e| archive_error(path, invalid_data(&e.to_string())))?;
+        Ok(Box::new(decoder))
+    } else if magic.starts_with(b"BZh") {
+        Ok(Box::new(MultiBzDecoder::new(reader)))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Path of a file inside an archive, e.g. `release.tar.gz!bin/app`
+fn nested_path(archive: &Path, entry: &str) -> PathBuf {
+    let entry = entry.trim_start_matches("./").trim_start_matches('/');
+    format!("{}{SEPARATOR}{entry}", archive.display()).into()
+}
+
+/// Error reading an archive
+fn archive_error(path: &Path, err: io::Error) -> Error {
+    Error::with_source(
+        ErrorKind::Parse,
+        format!("couldn't read archive {}", path.display()),
+        err,
+    )
+}
+
+/// Error about a malformed archive
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELF: &[u8] = b"\x7fELF\x02\x01\x01\0";
+
+    /// Build a tarball with the given files
+    fn tarball(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, contents) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *contents).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    /// Build a zip archive with the given (stored) files
+    fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut directory = Vec::new();
+
+        for (name, contents) in files {
+            let offset = out.len() as u32;
+            let crc = {
+                let mut crc = flate2::Crc::new();
+                crc.update(contents);
+                crc.sum()
+            };
+
+            let mut common = Vec::new();
+            common.extend(20u16.to_le_bytes()); // version needed
+            common.extend(0u16.to_le_bytes()); // flags
+            common.extend(0u16.to_le_bytes()); // method
+            common.extend(0u32.to_le_bytes()); // time and date
+            common.extend(crc.to_le_bytes());
+            common.extend((contents.len() as u32).to_le_bytes());
+            common.extend((contents.len() as u32).to_le_bytes());
+            common.extend((name.len() as u16).to_le_bytes());
+            common.extend(0u16.to_le_bytes()); // extra length
+
+            out.extend(ZIP_LOCAL_FILE_HEADER.to_le_bytes());
+            out.extend(&common);
+            out.extend(name.as_bytes());
+            out.extend(*contents);
+
+            directory.extend(ZIP_CENTRAL_DIRECTORY_HEADER.to_le_bytes());
+            directory.extend(20u16.to_le_bytes()); // version made by
+            directory.extend(&common);
+            directory.extend([0; 10]); // comment length, disk, attributes
+            directory.extend(offset.to_le_bytes());
+            directory.extend(name.as_bytes());
+        }
+
+        let directory_offset = out.len() as u32;
+        out.extend(&directory);
+        out.extend(ZIP_END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        out.extend([0; 4]); // disk numbers
+        out.extend((files.len() as u16).to_le_bytes());
+        out.extend((files.len() as u16).to_le_bytes());
+        out.extend((directory.len() as u32).to_le_bytes());
+        out.extend(directory_offset.to_le_bytes());
+        out.extend(0u16.to_le_bytes()); // comment length
+        out
+    }
+
+    /// Compress data in one of the formats used by packages
+    fn compress(compression: &str, data: &[u8]) -> Vec<u8> {
+        match compression {
+            "" => data.to_vec(),
+            ".gz" => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                io::Write::write_all(&mut encoder, data).unwrap();
+                encoder.finish().unwrap()
+            }
+            ".xz" => {
+                let options = lzma_rust2::XzOptions::with_preset(1);
+                let mut encoder = lzma_rust2::XzWriter::new(Vec::new(), options).unwrap();
+                io::Write::write_all(&mut encoder, data).unwrap();
+                encoder.finish().unwrap()
+            }
+            ".zst" => {
+                ruzstd::encoding::compress_to_vec(data, ruzstd::encoding::CompressionLevel::Fastest)
+            }
+            ".bz2" => {
+                let mut encoder =
+                    bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+                io::Write::write_all(&mut encoder, data).unwrap();
+                encoder.finish().unwrap()
+            }
+            _ => unreachable!("unknown compression {compression}"),
+        }
+    }
+
+    /// Build a Debian package with the given files, with its `data.tar`
+    /// compressed with the given extension (e.g. `.xz`)
+    fn deb(files: &[(&str, &[u8])], compression: &str) -> Vec<u8> {
+        let mut out = b"!<arch>\n".to_vec();
+        let members: [(String, Vec<u8>); 3] = [
+            ("debian-binary".to_owned(), b"2.0\n".to_vec()),
+            (
+                "control.tar".to_owned(),
+                tarball(&[("./control", b"Package: app")]),
+            ),
+            (
+                format!("data.tar{compression}"),
+                compress(compression, &tarball(files)),
+            ),
+        ];
+
+        for (name, contents) in members {
+            out.extend(format!("{name:<16}{:<32}{:<10}`\n", 0, contents.len()).bytes());
+            out.extend(&contents);
+            if contents.len() % 2 == 1 {
+                out.push(b'\n');
+            }
+        }
+        out
+    }
+
+    /// Build an RPM package with the given files, with its payload
+    /// compressed with the given extension (e.g. `.xz`)
+    fn rpm(files: &[(&str, &[u8])], compression: &str) -> Vec<u8> {
+        let mut cpio = Vec::new();
+        let trailer = [("TRAILER!!!", &b""[..], 0)];
+        let entries = files
+            .iter()
+            .map(|(name, contents)| (*name, *contents, 0o100_755));
+
+        for (name, contents, mode) in entries.chain(trailer) {
+            cpio.extend(b"070701");
+            for field in [
+                0,
+                mode,
+                0,
+                0,
+                1,
+                0,
+                contents.len(),
+                0,
+                0,
+                0,
+                0,
+                name.len() + 1,
+                0,
+            ] {
+                cpio.extend(format!("{field:08x}").bytes());
+            }
+            cpio.extend(name.as_bytes());
+            cpio.push(0);
+            cpio.resize(cpio.len().next_multiple_of(4), 0);
+            cpio.extend(contents);
+            cpio.resize(cpio.len().next_multiple_of(4), 0);
+        }
+
+        let mut out = b"\xed\xab\xee\xdb".to_vec();
+        out.resize(96, 0);
+        // Signature header with one (empty) index entry, which needs padding
+        out.extend(b"\x8e\xad\xe8\x01\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        out.extend([0; 20]);
+        out.resize(out.len().next_multiple_of(8), 0);
+        out.extend(b"\x8e\xad\xe8\x01\0\0\0\0\0\0\0\0\0\0\0\0");
+
+        out.extend(compress(compression, &cpio));
+        out
+    }
+
+    fn walk(archive: &[u8], max_depth: usize) -> Vec<String> {
+        let mut found = vec![];
+        let kind = ArchiveKind::detect(archive).unwrap();
+        Walker::new(max_depth, 1024, |path, contents| {
+            assert_eq!(contents.unwrap(), ELF);
+            found.push(path.display().to_string());
+        })
+        .walk(Path::new("release"), kind, io::Cursor::new(archive), 1)
+        .unwrap();
+        found
+    }
+
+    #[test]
+    fn nested_archives() {
+        let inner = zip(&[("app.exe", ELF), ("README.md", b"# App")]);
+        let outer = tarball(&[("bin/app", ELF), ("windows.zip", &inner)]);
+
+        assert_eq!(
+            walk(&outer, 2),
+            ["release!bin/app", "release!windows.zip!app.exe"]
+        );
+        assert_eq!(walk(&outer, 1), ["release!bin/app"]);
+    }
+
+    #[test]
+    fn packages() {
+        let files: &[(&str, &[u8])] = &[("./usr/bin/app", ELF), ("./usr/share/doc/app", b"doc")];
+
+        for compression in ["", ".gz", ".xz", ".zst", ".bz2"] {
+            assert_eq!(
+                walk(&deb(files, compression), 1),
+                ["release!usr/bin/app"],
+                "data.tar{compression}"
+            );
+            assert_eq!(
+                walk(&rpm(files, compression), 1),
+                ["release!usr/bin/app"],
+                "payload{compression}"
+            );
+        }
+    }
+}
@@ -1,25 +1,60 @@
 //! Core auditing functionality
 
+#[cfg(feature = "binary-scanning")]
+use crate::{
+    archive::{self, ArchiveKind},
+    drift::LockfileDrift,
+    image::ImageArchive,
+    merged::MergedReport,
+    process::RunningExecutable,
+};
 use crate::{
-    config::AuditConfig, error::display_err_with_source, prelude::*, presenter::Presenter,
+    baseline::{self, ReportDiff},
+    config::{AuditConfig, DenyOption, EpssThreshold},
+    error::display_err_with_source,
+    feeds,
+    notify::Notifier,
+    prelude::*,
+    presenter::Presenter,
 };
-use rustsec::{Error, ErrorKind, Lockfile, Warning, WarningKind, registry, report};
+use rustsec::{Error, ErrorKind, Lockfile, Version, epss, kev, registry, report};
 
 use rustsec::binary_scanning::BinaryFormat;
 
+#[cfg(feature = "binary-scanning")]
+use rustsec::{
+    Warning, WarningKind, binary_scanning::Confidence, cargo_lock::dependency::Dependency,
+    package::Package,
+};
+#[cfg(feature = "binary-scanning")]
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{
+        atomic::{self, AtomicUsize},
+        mpsc,
+    },
+    thread,
+};
 use std::{
     io::{self, Read},
-    path::Path,
+    path::{Path, PathBuf},
     process::exit,
     time::Duration,
 };
 
 // TODO: make configurable
-const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+pub(crate) const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 #[cfg(feature = "binary-scanning")]
 const DEFAULT_MAX_BINARY_SIZE: u64 = 100 * 1024 * 1024; // 100MB
 
+#[cfg(feature = "binary-scanning")]
+const DEFAULT_ARCHIVE_DEPTH: usize = 2;
+
+/// Packages in the `rust` advisory collection which are compiled into binaries
+#[cfg(feature = "binary-scanning")]
+const STD_PACKAGES: &[&str] = &["std", "core", "alloc"];
+
 /// Security vulnerability auditor
 pub struct Auditor {
     /// RustSec Advisory Database
@@ -34,6 +69,30 @@ pub struct Auditor {
     /// Audit report settings
     report_settings: report::Settings,
 
+    /// EPSS scores used to annotate vulnerabilities
+    epss_scores: Option<epss::Scores>,
+
+    /// EPSS score at which vulnerabilities are denied even if ignored
+    epss_threshold: Option<EpssThreshold>,
+
+    /// CISA Known Exploited Vulnerabilities catalog
+    kev_catalog: Option<kev::Catalog>,
+
+    /// Deny vulnerabilities listed in the KEV catalog even if ignored
+    deny_kev: bool,
+
+    /// Report from an earlier run, whose findings aren't reported again
+    baseline: Option<rustsec::Report>,
+
+    /// Webhook to notify when vulnerabilities are found
+    notifier: Option<Notifier>,
+
+    /// Audit the Rust toolchain along with lockfiles
+    audit_toolchain: bool,
+
+    /// Version of the Rust toolchain used to build the lockfile being audited
+    toolchain_version: Option<Version>,
+
     /// Binary scanning configuration (max input size)
     #[cfg(feature = "binary-scanning")]
     binary_size_limit: Option<u64>,
@@ -41,87 +100,119 @@ pub struct Auditor {
     /// Binary scanning configuration (max auditable payload size)
     #[cfg(feature = "binary-scanning")]
     audit_data_size_limit: Option<usize>,
+
+    /// Binary scanning configuration (levels of nested archives to scan)
+    #[cfg(feature = "binary-scanning")]
+    archive_depth: usize,
+
+    /// Binary scanning configuration (number of threads)
+    #[cfg(feature = "binary-scanning")]
+    binary_scan_jobs: usize,
+
+    /// Binary scanning configuration (oldest acceptable compiler version)
+    #[cfg(feature = "binary-scanning")]
+    min_rustc_version: Option<Version>,
+
+    /// Findings about the binary being audited besides its dependencies
+    #[cfg(feature = "binary-scanning")]
+    binary_findings: BinaryFindings,
+
+    /// Combined report of the binaries audited so far, if their reports are merged
+    #[cfg(feature = "binary-scanning")]
+    merged_report: Option<MergedReport>,
+
+    /// Lockfile (and its path) the dependencies of binaries are expected to match
+    #[cfg(feature = "binary-scanning")]
+    expected_lockfile: Option<(PathBuf, Lockfile)>,
 }
 
 impl Auditor {
-    /// Initialize the auditor
+    /// Initialize the auditor, exiting if it can't be set up
     pub fn new(config: &AuditConfig) -> Self {
-        let advisory_db_url = config
-            .database
-            .url
-            .as_ref()
-            .map(AsRef::as_ref)
-            .unwrap_or(rustsec::repository::git::DEFAULT_URL);
+        Self::try_new(config).unwrap_or_else(|e| {
+            status_err!("{}", display_err_with_source(&e));
+            exit(1);
+        })
+    }
 
-        let advisory_db_path = config
-            .database
-            .path
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(rustsec::repository::git::Repository::default_path);
+    /// Initialize the auditor, returning an error if e.g. the advisory
+    /// database can't be fetched
+    pub fn try_new(config: &AuditConfig) -> rustsec::Result<Self> {
+        let database = try_load_database(config)?;
+        let advisory_db_path = advisory_db_path(config);
+
+        let epss_threshold = config.output.epss_threshold();
+
+        // Scores are downloaded unless a file is configured
+        let epss_path = match &config.advisories.epss_file {
+            Some(path) => Some(path.clone()),
+            None if config.advisories.epss || epss_threshold.is_some() => Some(feeds::EPSS.fetch(
+                &feeds::Feed::default_dir(),
+                config.database.fetch,
+                config.output.is_quiet(),
+            )?),
+            None => None,
+        };
+
+        let epss_scores = epss_path.as_ref().map(|epss_path| {
+            let scores = epss::Scores::load_file(epss_path).map_err(|e| {
+                Error::with_source(e.kind(), "error loading EPSS scores".to_owned(), e)
+            })?;
 
-        let database = if config.database.fetch {
             if !config.output.is_quiet() {
-                status_ok!("Fetching", "advisory database from `{}`", advisory_db_url);
+                status_ok!(
+                    "Loaded",
+                    "{} EPSS scores (from {})",
+                    scores.len(),
+                    epss_path.display()
+                );
             }
 
-            let mut result = rustsec::repository::git::Repository::fetch(
-                advisory_db_url,
-                &advisory_db_path,
-                !config.database.stale,
-                Duration::from_secs(0),
-            );
-            // If the directory is locked, print a message and wait for it to become unlocked.
-            // If we don't print the message, `cargo audit` would just hang with no explanation.
-            if let Err(e) = &result
-                && e.kind() == ErrorKind::LockTimeout
-            {
-                status_warn!(
-                    "directory {} is locked, waiting for up to {} seconds for it to become available",
-                    advisory_db_path.display(),
-                    DEFAULT_LOCK_TIMEOUT.as_secs()
-                );
-                result = rustsec::repository::git::Repository::fetch(
-                    advisory_db_url,
-                    &advisory_db_path,
-                    !config.database.stale,
-                    DEFAULT_LOCK_TIMEOUT,
+            Ok::<_, Error>(scores)
+        });
+        let epss_scores = epss_scores.transpose()?;
+
+        let deny_kev = config.output.deny.contains(&DenyOption::Kev);
+
+        // The catalog is downloaded unless a file is configured
+        let kev_path = match &config.advisories.kev_file {
+            Some(path) => Some(path.clone()),
+            None if config.advisories.kev || deny_kev => Some(feeds::KEV.fetch(
+                &feeds::Feed::default_dir(),
+                config.database.fetch,
+                config.output.is_quiet(),
+            )?),
+            None => None,
+        };
+
+        let kev_catalog = kev_path.as_ref().map(|kev_path| {
+            let catalog = load_kev_catalog(kev_path).map_err(|e| {
+                Error::with_source(e.kind(), "error loading KEV catalog".to_owned(), e)
+            })?;
+
+            if !config.output.is_quiet() {
+                status_ok!(
+                    "Loaded",
+                    "{} known exploited vulnerabilities (from {})",
+                    catalog.vulnerabilities.len(),
+                    kev_path.display()
                 );
             }
 
-            let advisory_db_repo = result.unwrap_or_else(|e| {
-                status_err!(
-                    "couldn't fetch advisory database: {}",
-                    display_err_with_source(&e)
-                );
-                exit(1);
-            });
+            Ok::<_, Error>(catalog)
+        });
+        let kev_catalog = kev_catalog.transpose()?;
 
-            rustsec::Database::load_from_repo(&advisory_db_repo).unwrap_or_else(|e| {
-                status_err!(
-                    "error loading advisory database: {}",
-                    display_err_with_source(&e)
-                );
-                exit(1);
-            })
-        } else {
-            rustsec::Database::open(&advisory_db_path).unwrap_or_else(|e| {
-                status_err!(
-                    "error loading advisory database: {}",
-                    display_err_with_source(&e)
-                );
-                exit(1);
+        let baseline = config
+            .advisories
+            .baseline
+            .as_ref()
+            .map(|baseline_path| {
+                baseline::load_report(baseline_path).map_err(|e| {
+                    Error::with_source(e.kind(), "error loading baseline report".to_owned(), e)
+                })
             })
-        };
-
-        if !config.output.is_quiet() {
-            status_ok!(
-                "Loaded",
-                "{} security advisories (from {})",
-                database.iter().count(),
-                advisory_db_path.display()
-            );
-        }
+            .transpose()?;
 
         let registry_index = if config.yanked.enabled {
             if config.yanked.update_index && config.database.fetch {
@@ -185,16 +276,36 @@ impl Auditor {
             None
         };
 
-        Self {
+        Ok(Self {
             database,
             registry_index,
             presenter: Presenter::new(&config.output),
             report_settings: config.report_settings(),
+            epss_scores,
+            epss_threshold,
+            kev_catalog,
+            deny_kev,
+            baseline,
+            notifier: Notifier::new(&config.notify),
+            audit_toolchain: config.toolchain.enabled,
+            toolchain_version: None,
             #[cfg(feature = "binary-scanning")]
             binary_size_limit: Some(DEFAULT_MAX_BINARY_SIZE),
             #[cfg(feature = "binary-scanning")]
             audit_data_size_limit: None,
-        }
+            #[cfg(feature = "binary-scanning")]
+            archive_depth: DEFAULT_ARCHIVE_DEPTH,
+            #[cfg(feature = "binary-scanning")]
+            min_rustc_version: None,
+            #[cfg(feature = "binary-scanning")]
+            binary_findings: BinaryFindings::default(),
+            #[cfg(feature = "binary-scanning")]
+            merged_report: None,
+            #[cfg(feature = "binary-scanning")]
+            expected_lockfile: None,
+            #[cfg(feature = "binary-scanning")]
+            binary_scan_jobs: default_binary_scan_jobs(),
+        })
     }
 
     /// Perform an audit of a textual `Cargo.lock` file
@@ -212,8 +323,14 @@ impl Auditor {
 
         self.presenter.before_report(lockfile_path, &lockfile);
 
+        self.find_toolchain_version(lockfile_path);
+
         let report = self.audit(&lockfile, None, None);
 
+        if let Ok(report) = &report {
+            self.notify(lockfile_path, report);
+        }
+
         let self_advisories = self.self_advisories();
 
         self.presenter.print_self_report(self_advisories.as_slice());
@@ -223,24 +340,117 @@ impl Auditor {
 
     #[cfg(feature = "binary-scanning")]
     /// Perform an audit of multiple binary files
+    ///
+    /// Binaries are read and parsed on a pool of threads (see [`Auditor::set_binary_scan_jobs`]),
+    /// and the report for each of them is printed as soon as it's ready.
     pub fn audit_binaries<P>(&mut self, binaries: &[P]) -> MultiFileReportSummmary
     where
-        P: AsRef<Path>,
+        P: AsRef<Path> + Sync,
     {
+        let (binary_size_limit, audit_data_size_limit, archive_depth) = (
+            self.binary_size_limit,
+            self.audit_data_size_limit,
+            self.archive_depth,
+        );
+
+        self.audit_scanned_binaries(binaries.len(), &|index, found| {
+            scan_path(
+                binaries[index].as_ref(),
+                binary_size_limit,
+                audit_data_size_limit,
+                archive_depth,
+                found,
+            )
+        })
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Perform an audit of the executables of running processes
+    ///
+    /// If `skip_unrecognized` is set, executables without any Rust dependency
+    /// information (e.g. the ones not written in Rust) are skipped silently.
+    pub(crate) fn audit_processes(
+        &mut self,
+        executables: &[RunningExecutable],
+        skip_unrecognized: bool,
+    ) -> MultiFileReportSummmary {
+        use rustsec::binary_scanning::BinaryReport;
+
+        let limit = self.binary_size_limit.unwrap_or(DEFAULT_MAX_BINARY_SIZE);
+        let audit_data_size_limit = self.audit_data_size_limit;
+
+        self.audit_scanned_binaries(executables.len(), &|index, found| {
+            let executable = &executables[index];
+            let name = executable.display_name();
+            let binary = read_binary(&executable.proc_path(), &name, limit)
+                .and_then(|contents| parse_binary(contents, audit_data_size_limit));
+
+            if skip_unrecognized
+                && matches!(&binary, Ok(binary) if matches!(binary.report, BinaryReport::None))
+            {
+                return;
+            }
+
+            found(name, binary);
+        })
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Audit the binaries found by calling `scan` with the indices `0..count`
+    ///
+    /// The calls to `scan` are made on a pool of threads, while the binaries it
+    /// finds are audited (and their reports printed) as soon as they're ready.
+    fn audit_scanned_binaries(
+        &mut self,
+        count: usize,
+        scan: &(dyn Fn(usize, &mut FoundBinary<'_>) + Sync),
+    ) -> MultiFileReportSummmary {
         let mut summary = MultiFileReportSummmary::default();
-        for path in binaries {
-            let result = self.audit_binary(path.as_ref());
-            match result {
-                Ok(report) => {
-                    if self.presenter.should_exit_with_failure(&report) {
-                        summary.vulnerabilities_found = true;
+        let next = AtomicUsize::new(0);
+        let jobs = self.binary_scan_jobs.clamp(1, count.max(1));
+
+        // Bound the number of binaries held in memory while waiting to be audited
+        let (sender, receiver) = mpsc::sync_channel(jobs);
+
+        thread::scope(|scope| {
+            for _ in 0..jobs {
+                let sender = sender.clone();
+                let next = &next;
+                scope.spawn(move || {
+                    loop {
+                        let index = next.fetch_add(1, atomic::Ordering::Relaxed);
+                        if index >= count {
+                            break;
+                        }
+
+                        scan(index, &mut |path, binary| {
+                            // The receiver outlives the workers
+                            let _ = sender.send((path, binary));
+                        });
+                    }
+                });
+            }
+            drop(sender);
+
+            for (path, binary) in receiver {
+                match binary.and_then(|binary| self.audit_binary(&path, binary)) {
+                    Ok((report, drifted)) => {
+                        if self.presenter.should_exit_with_failure(&report) {
+                            summary.vulnerabilities_found = true;
+                        }
+                        summary.lockfile_drift_found |= drifted;
+                    }
+                    Err(e) => {
+                        status_err!("{}", display_err_with_source(&e));
+                        summary.errors_encountered = true;
                     }
-                }
-                Err(e) => {
-                    status_err!("{}", display_err_with_source(&e));
-                    summary.errors_encountered = true;
                 }
             }
+        });
+
+        if let Some(merged_report) = self.merged_report.take() {
+            self.presenter.print_merged_report(&merged_report);
+            self.merged_report = Some(MergedReport::default());
         }
 
         let self_advisories = self.self_advisories();
@@ -272,20 +482,89 @@ impl Auditor {
         self.audit_data_size_limit = audit_data_size_limit.or(Some(8 * 1024 * 1024));
     }
 
+    #[cfg(feature = "binary-scanning")]
+    /// Configure how many levels of nested archives to look for binaries in.
+    ///
+    /// If unset, defaults to 2 (i.e. archives inside of archives). 0 disables scanning archives.
+    pub fn set_archive_depth(&mut self, depth: Option<usize>) {
+        self.archive_depth = depth.unwrap_or(DEFAULT_ARCHIVE_DEPTH);
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Configure how many binaries are read and parsed concurrently.
+    ///
+    /// If unset, defaults to the available parallelism.
+    pub fn set_binary_scan_jobs(&mut self, jobs: Option<usize>) {
+        self.binary_scan_jobs = jobs.unwrap_or_else(default_binary_scan_jobs);
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Print a single report for all binaries instead of one per binary, which
+    /// lists each finding once along with the binaries it was found in.
+    pub fn set_merge_reports(&mut self, merge: bool) {
+        self.merged_report = merge.then(MergedReport::default);
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Compare the dependencies of each binary against the given lockfile,
+    /// e.g. the one of the commit the binaries are supposed to be built from.
+    pub fn set_expected_lockfile(&mut self, lockfile_path: Option<&Path>) -> rustsec::Result<()> {
+        self.expected_lockfile = match lockfile_path {
+            Some(path) => {
+                let lockfile = self.load_lockfile(path).map_err(|e| {
+                    Error::with_source(
+                        ErrorKind::NotFound,
+                        format!("Couldn't load {}", path.display()),
+                        e,
+                    )
+                })?;
+                Some((path.to_owned(), lockfile))
+            }
+            None => None,
+        };
+        Ok(())
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Warn about binaries built with a Rust compiler older than `version`
+    pub fn set_min_rustc_version(&mut self, version: Option<Version>) {
+        self.min_rustc_version = version;
+    }
+
     #[cfg(feature = "binary-scanning")]
     /// Perform an audit of a binary file with dependency data embedded by `cargo auditable`
-    fn audit_binary(&mut self, binary_path: &Path) -> rustsec::Result<rustsec::Report> {
+    ///
+    /// Also returns whether its dependencies differ from the expected lockfile (if any).
+    fn audit_binary(
+        &mut self,
+        binary_path: &Path,
+        binary: LoadedBinary,
+    ) -> rustsec::Result<(rustsec::Report, bool)> {
         use rustsec::binary_scanning::BinaryReport::*;
-        let file_contents = self.read_binary_with_limit(binary_path)?;
-        let (binary_type, report) = rustsec::binary_scanning::load_deps_from_binary(
-            &file_contents,
-            self.audit_data_size_limit,
-        )?;
+        let LoadedBinary {
+            file_contents,
+            binary_type,
+            report,
+            findings,
+        } = binary;
         self.presenter.binary_scan_report(&report, binary_path);
         self.presenter.set_binary_contents(file_contents);
+        let drifted = self.check_lockfile_drift(binary_path, &report);
+        self.check_rustc_version(binary_path, &findings);
+        self.binary_findings = findings;
         match report {
             Complete(lockfile) | Incomplete(lockfile) => {
-                self.audit(&lockfile, Some(binary_path), Some(binary_type))
+                let report = if self.merged_report.is_some() {
+                    let (report, _) = self.build_report(&lockfile, Some(binary_type));
+                    if let Some(merged_report) = &mut self.merged_report {
+                        merged_report.add(binary_path, &report);
+                    }
+                    report
+                } else {
+                    self.audit(&lockfile, Some(binary_path), Some(binary_type))?
+                };
+                self.notify(binary_path, &report);
+                Ok((report, drifted))
             }
             None => Err(Error::new(
                 ErrorKind::Parse,
@@ -295,23 +574,171 @@ impl Auditor {
     }
 
     #[cfg(feature = "binary-scanning")]
-    fn read_binary_with_limit(&self, binary_path: &Path) -> rustsec::Result<Vec<u8>> {
-        let file = std::fs::File::open(binary_path)?;
+    /// Compare the dependencies of a binary against the expected lockfile (if any)
+    ///
+    /// Returns whether they differ. Differences found by heuristics are only
+    /// reported, because they may be false positives.
+    fn check_lockfile_drift(
+        &self,
+        binary_path: &Path,
+        report: &rustsec::binary_scanning::BinaryReport,
+    ) -> bool {
+        use rustsec::binary_scanning::BinaryReport::*;
+
+        let Some((lockfile_path, expected)) = &self.expected_lockfile else {
+            return false;
+        };
+
+        let (lockfile, complete) = match report {
+            Complete(lockfile) => (lockfile, true),
+            Incomplete(lockfile) => (lockfile, false),
+            None => return false,
+        };
+
+        let drift = LockfileDrift::new(lockfile, expected, complete);
+        self.presenter
+            .print_lockfile_drift(binary_path, lockfile_path, &drift, complete);
+        complete && drift.is_drifted()
+    }
+
+    #[cfg(feature = "binary-scanning")]
+    /// Perform an audit of the executables in a container image tarball.
+    ///
+    /// The dependencies of all executables are combined into a single report, displayed as `name`.
+    pub fn audit_image(
+        &mut self,
+        image_path: &Path,
+        name: &Path,
+    ) -> rustsec::Result<rustsec::Report> {
+        use rustsec::binary_scanning::BinaryReport;
+
+        let mut image = ImageArchive::open(image_path)?;
         let limit = self.binary_size_limit.unwrap_or(DEFAULT_MAX_BINARY_SIZE);
-        let mut limited = file.take(limit.saturating_add(1));
-        let mut buffer = Vec::new();
-        limited.read_to_end(&mut buffer)?;
-        if buffer.len() as u64 > limit {
+        let audit_data_size_limit = self.audit_data_size_limit;
+
+        let mut packages = BTreeMap::new();
+        let mut findings = BinaryFindings::default();
+        // Format shared by all executables (if any), used to filter advisories
+        let mut binary_type: Option<Option<BinaryFormat>> = None;
+        let mut binaries = 0;
+
+        image.walk(limit, &mut |path, contents| {
+            let binary =
+                contents.and_then(|contents| parse_binary(contents, audit_data_size_limit));
+
+            let binary = match binary {
+                Ok(binary) => binary,
+                Err(e) => {
+                    status_warn!("{}: {}", path.display(), display_err_with_source(&e));
+                    return;
+                }
+            };
+
+            let path = PathBuf::from(format!("{}!{}", name.display(), path.display()));
+            self.presenter.binary_scan_report(&binary.report, &path);
+
+            self.check_rustc_version(&path, &binary.findings);
+            findings.merge(binary.findings);
+
+            if let BinaryReport::Complete(lockfile) | BinaryReport::Incomplete(lockfile) =
+                binary.report
+            {
+                binaries += 1;
+                binary_type = match binary_type {
+                    Some(Some(previous)) if previous != binary.binary_type => Some(None),
+                    Some(common) => Some(common),
+                    None => Some(Some(binary.binary_type)),
+                };
+                for package in lockfile.packages {
+                    packages
+                        .entry(Dependency::from(&package))
+                        .or_insert(package);
+                }
+            }
+        })?;
+
+        if binaries == 0 {
             return Err(Error::new(
-                ErrorKind::BadParam,
-                format!(
-                    "binary {} exceeds max size limit of {} bytes",
-                    binary_path.display(),
-                    limit
-                ),
+                ErrorKind::NotFound,
+                format!("no Rust executables found in image {}", name.display()),
             ));
         }
-        Ok(buffer)
+
+        let lockfile = Lockfile {
+            version: Default::default(),
+            packages: packages.into_values().collect(),
+            root: None,
+            metadata: Default::default(),
+            patch: Default::default(),
+        };
+
+        self.binary_findings = findings;
+        let report = self.audit(&lockfile, Some(name), binary_type.flatten())?;
+        self.notify(name, &report);
+        Ok(report)
+    }
+
+    /// Generate a report for a textual `Cargo.lock` file without displaying it
+    pub fn report_lockfile(&mut self, lockfile_path: &Path) -> rustsec::Result<rustsec::Report> {
+        let lockfile = self.load_lockfile(lockfile_path).map_err(|e| {
+            Error::with_source(
+                ErrorKind::NotFound,
+                format!("Couldn't load {}", lockfile_path.display()),
+                e,
+            )
+        })?;
+
+        self.find_toolchain_version(lockfile_path);
+
+        Ok(self.build_report(&lockfile, None).0)
+    }
+
+    /// Look up the version of the Rust toolchain the given lockfile is built
+    /// with, so that the next report includes its vulnerabilities (if enabled)
+    fn find_toolchain_version(&mut self, lockfile_path: &Path) {
+        if !self.audit_toolchain {
+            return;
+        }
+
+        let dir = match lockfile_path.parent() {
+            Some(dir) if dir != Path::new("") => dir,
+            _ => Path::new("."),
+        };
+
+        self.toolchain_version = crate::toolchain::active_version(dir);
+        if self.toolchain_version.is_none() {
+            status_warn!("couldn't determine the version of the Rust toolchain");
+        }
+    }
+
+    /// Find the advisories for the given version of the Rust toolchain which
+    /// aren't ignored
+    fn toolchain_vulnerabilities(&self, rustc_version: &Version) -> Vec<rustsec::Vulnerability> {
+        let mut vulns = self.database.toolchain_vulnerabilities(rustc_version);
+        vulns.retain(|vuln| !self.report_settings.ignore.contains(&vuln.advisory.id));
+        vulns
+    }
+
+    /// Warn if a binary was built with a Rust compiler older than the configured minimum
+    #[cfg(feature = "binary-scanning")]
+    fn check_rustc_version(&self, binary_path: &Path, findings: &BinaryFindings) {
+        let Some(min_version) = &self.min_rustc_version else {
+            return;
+        };
+
+        match findings.rustc_versions.first() {
+            Some(version) if version < min_version => status_warn!(
+                "{} was built with rustc {}, which is older than the minimum of {}",
+                binary_path.display(),
+                version,
+                min_version
+            ),
+            Some(_) => (),
+            None => status_warn!(
+                "couldn't determine the rustc version {} was built with",
+                binary_path.display()
+            ),
+        }
     }
 
     /// The part of the auditing process that is shared between auditing lockfiles and binary files
@@ -319,60 +746,179 @@ impl Auditor {
         &mut self,
         lockfile: &Lockfile,
         path: Option<&Path>,
-        #[allow(unused_variables)] // May be unused when the "binary-scanning" feature is disabled
         binary_format: Option<BinaryFormat>,
     ) -> rustsec::Result<rustsec::Report> {
-        let mut report = rustsec::Report::generate(&self.database, lockfile, &self.report_settings);
+        let (report, diff) = self.build_report(lockfile, binary_format);
 
-        #[cfg(feature = "binary-scanning")]
-        if let Some(format) = binary_format {
-            use rustsec::binary_scanning::filter_report_by_binary_type;
-            filter_report_by_binary_type(&format, &mut report);
+        self.presenter.print_report(&report, lockfile, path);
+
+        if let Some(diff) = &diff {
+            self.presenter.print_resolved(&diff.resolved);
+        }
+
+        Ok(report)
+    }
+
+    /// Post a notification about the vulnerabilities in the report (if configured)
+    fn notify(&self, path: &Path, report: &rustsec::Report) {
+        if let Some(notifier) = &self.notifier
+            && let Err(e) = notifier.notify(path, &report.vulnerabilities.list)
+        {
+            status_warn!("{}", display_err_with_source(&e));
         }
+    }
+
+    /// Build the complete report for a lockfile, along with its differences
+    /// from the baseline (if any)
+    fn build_report(
+        &mut self,
+        lockfile: &Lockfile,
+        binary_format: Option<BinaryFormat>,
+    ) -> (rustsec::Report, Option<ReportDiff>) {
+        let mut report = self.generate_report(lockfile, &self.report_settings, binary_format);
+
+        // Report vulnerabilities in the Rust toolchain the lockfile is built with
+        if let Some(version) = self.toolchain_version.take() {
+            let mut vulns = self.toolchain_vulnerabilities(&version);
+            report.vulnerabilities.list.append(&mut vulns);
+            report.vulnerabilities.count = report.vulnerabilities.list.len();
+            report.vulnerabilities.found = !report.vulnerabilities.list.is_empty();
+        }
+
+        self.enrich(lockfile, binary_format, &mut report);
 
         // Warn for yanked crates
-        let mut yanked = self.check_for_yanked_crates(lockfile);
-        if !yanked.is_empty() {
-            report
-                .warnings
-                .entry(WarningKind::Yanked)
-                .or_default()
-                .append(&mut yanked);
+        if let Some(index) = &mut self.registry_index {
+            for e in report.check_yanked(index, lockfile) {
+                status_err!(
+                    "couldn't check if the package is yanked: {}",
+                    display_err_with_source(&e)
+                );
+            }
         }
 
-        self.presenter.print_report(&report, lockfile, path);
+        #[cfg(feature = "binary-scanning")]
+        {
+            let findings = std::mem::take(&mut self.binary_findings);
 
-        Ok(report)
+            // Note how reliably the affected packages were recovered from the binary
+            for vuln in &mut report.vulnerabilities.list {
+                vuln.confidence = findings.confidence_of(&vuln.package);
+            }
+            for warning in report.warnings.values_mut().flatten() {
+                warning.confidence = findings.confidence_of(&warning.package);
+            }
+
+            // Report vulnerabilities in the standard library the binary was built with
+            for version in &findings.rustc_versions {
+                let mut vulns = self.toolchain_vulnerabilities(version);
+                vulns.retain(|vuln| STD_PACKAGES.contains(&vuln.package.name.as_str()));
+                report.vulnerabilities.list.append(&mut vulns);
+            }
+            report.vulnerabilities.count = report.vulnerabilities.list.len();
+            report.vulnerabilities.found = !report.vulnerabilities.list.is_empty();
+
+            // Warn for vulnerable C libraries linked into the binary
+            let mut bundled = findings.bundled_library_warnings;
+            bundled.retain(|warning| {
+                warning
+                    .advisory
+                    .as_ref()
+                    .is_none_or(|advisory| !self.report_settings.ignore.contains(&advisory.id))
+            });
+            if !bundled.is_empty() {
+                report
+                    .warnings
+                    .entry(WarningKind::BundledLibrary)
+                    .or_default()
+                    .append(&mut bundled);
+            }
+        }
+
+        // Only report findings which aren't already in the baseline
+        let diff = self.baseline.as_ref().map(|baseline| {
+            let diff = ReportDiff::new(baseline, &report);
+            diff.apply(&mut report);
+            diff
+        });
+
+        (report, diff)
     }
 
-    fn check_for_yanked_crates(&mut self, lockfile: &Lockfile) -> Vec<Warning> {
-        let mut result = Vec::new();
-        if let Some(index) = &mut self.registry_index {
-            let pkgs_to_check: Vec<_> = lockfile
-                .packages
-                .iter()
-                .filter(|pkg| match &pkg.source {
-                    Some(source) => source.is_default_registry(),
-                    None => false,
-                })
-                .collect();
+    /// Generate a report, filtering it by binary type if applicable
+    fn generate_report(
+        &self,
+        lockfile: &Lockfile,
+        settings: &report::Settings,
+        #[allow(unused_variables)] // May be unused when the "binary-scanning" feature is disabled
+        binary_format: Option<BinaryFormat>,
+    ) -> rustsec::Report {
+        #[allow(unused_mut)]
+        let mut report = rustsec::Report::generate(&self.database, lockfile, settings);
 
-            let yanked = index.find_yanked(pkgs_to_check);
+        #[cfg(feature = "binary-scanning")]
+        if let Some(format) = binary_format {
+            use rustsec::binary_scanning::filter_report_by_binary_type;
+            filter_report_by_binary_type(&format, &mut report);
+        }
 
-            for pkg in yanked {
-                match pkg {
-                    Ok(pkg) => {
-                        let warning = Warning::new(WarningKind::Yanked, pkg, None, None, None);
-                        result.push(warning);
-                    }
-                    Err(e) => status_err!(
-                        "couldn't check if the package is yanked: {}",
-                        display_err_with_source(&e)
-                    ),
+        report
+    }
+
+    /// Annotate vulnerabilities with EPSS scores and KEV catalog entries,
+    /// re-adding any which were ignored but are denied regardless
+    /// (i.e. via `--deny epss>=...` or `--deny kev`)
+    fn enrich(
+        &self,
+        lockfile: &Lockfile,
+        binary_format: Option<BinaryFormat>,
+        report: &mut rustsec::Report,
+    ) {
+        if self.epss_threshold.is_some() || self.deny_kev {
+            let unfiltered_settings = report::Settings {
+                ignore: vec![],
+                severity: None,
+                ..self.report_settings.clone()
+            };
+
+            let unfiltered = self.generate_report(lockfile, &unfiltered_settings, binary_format);
+            let mut list = report.vulnerabilities.list.clone();
+
+            for vuln in unfiltered.vulnerabilities.list {
+                if !list.contains(&vuln) && self.is_always_denied(&vuln) {
+                    list.push(vuln);
                 }
             }
+
+            report.vulnerabilities = report::VulnerabilityInfo::new(list);
         }
-        result
+
+        for vuln in &mut report.vulnerabilities.list {
+            if let Some(scores) = &self.epss_scores {
+                vuln.epss = scores.for_advisory(&vuln.advisory);
+            }
+
+            if let Some(catalog) = &self.kev_catalog {
+                vuln.kev = catalog.for_advisory(&vuln.advisory).cloned();
+            }
+        }
+    }
+
+    /// Is this vulnerability denied even if it's ignored?
+    fn is_always_denied(&self, vuln: &rustsec::Vulnerability) -> bool {
+        let epss_denied = match (&self.epss_scores, self.epss_threshold) {
+            (Some(scores), Some(threshold)) => scores
+                .for_advisory(&vuln.advisory)
+                .is_some_and(|score| threshold.matches(&score)),
+            _ => false,
+        };
+
+        let kev_denied = match &self.kev_catalog {
+            Some(catalog) if self.deny_kev => catalog.for_advisory(&vuln.advisory).is_some(),
+            _ => false,
+        };
+
+        epss_denied || kev_denied
     }
 
     /// Load the lockfile to be audited
@@ -419,6 +965,323 @@ impl Auditor {
     }
 }
 
+/// Fetch (if enabled) and load the advisory database, exiting on failure
+pub fn load_database(config: &AuditConfig) -> rustsec::Database {
+    try_load_database(config).unwrap_or_else(|e| {
+        status_err!("{}", display_err_with_source(&e));
+        exit(1);
+    })
+}
+
+/// Fetch (if enabled) and load the advisory database
+pub fn try_load_database(config: &AuditConfig) -> rustsec::Result<rustsec::Database> {
+    let advisory_db_url = config
+        .database
+        .url
+        .as_ref()
+        .map(AsRef::as_ref)
+        .unwrap_or(rustsec::repository::git::DEFAULT_URL);
+
+    let advisory_db_path = advisory_db_path(config);
+
+    let database = if let (Some(package), true) = (&config.database.package, config.database.fetch)
+    {
+        let registry = rustsec::repository::registry::Registry::from_config(
+            config.database.registry.as_deref(),
+            None,
+        )
+        .map_err(|e| Error::with_source(e.kind(), "couldn't locate registry".to_owned(), e))?;
+
+        if !config.output.is_quiet() {
+            status_ok!(
+                "Fetching",
+                "advisory database from package `{}` in `{}`",
+                package,
+                registry.index_url()
+            );
+        }
+
+        let mut result = rustsec::repository::registry::RegistryPackage::fetch(
+            &registry,
+            package,
+            &advisory_db_path,
+            Duration::from_secs(0),
+        );
+        // If the directory is locked, print a message and wait for it to become unlocked.
+        // If we don't print the message, `cargo audit` would just hang with no explanation.
+        if let Err(e) = &result
+            && e.kind() == ErrorKind::LockTimeout
+        {
+            status_warn!(
+                "directory {} is locked, waiting for up to {} seconds for it to become available",
+                advisory_db_path.display(),
+                DEFAULT_LOCK_TIMEOUT.as_secs()
+            );
+            result = rustsec::repository::registry::RegistryPackage::fetch(
+                &registry,
+                package,
+                &advisory_db_path,
+                DEFAULT_LOCK_TIMEOUT,
+            );
+        }
+
+        let advisory_db_package = result.map_err(|e| {
+            Error::with_source(e.kind(), "couldn't fetch advisory database".to_owned(), e)
+        })?;
+
+        rustsec::Database::open(advisory_db_package.path()).map_err(loading_database_error)?
+    } else if config.database.fetch {
+        if !config.output.is_quiet() {
+            status_ok!("Fetching", "advisory database from `{}`", advisory_db_url);
+        }
+
+        let mut result = rustsec::repository::git::Repository::fetch(
+            advisory_db_url,
+            &advisory_db_path,
+            !config.database.stale,
+            Duration::from_secs(0),
+        );
+        // If the directory is locked, print a message and wait for it to become unlocked.
+        // If we don't print the message, `cargo audit` would just hang with no explanation.
+        if let Err(e) = &result
+            && e.kind() == ErrorKind::LockTimeout
+        {
+            status_warn!(
+                "directory {} is locked, waiting for up to {} seconds for it to become available",
+                advisory_db_path.display(),
+                DEFAULT_LOCK_TIMEOUT.as_secs()
+            );
+            result = rustsec::repository::git::Repository::fetch(
+                advisory_db_url,
+                &advisory_db_path,
+                !config.database.stale,
+                DEFAULT_LOCK_TIMEOUT,
+            );
+        }
+
+        let advisory_db_repo = result.map_err(|e| {
+            Error::with_source(e.kind(), "couldn't fetch advisory database".to_owned(), e)
+        })?;
+
+        rustsec::Database::load_from_repo(&advisory_db_repo).map_err(loading_database_error)?
+    } else {
+        rustsec::Database::open(&advisory_db_path).map_err(loading_database_error)?
+    };
+
+    if !config.output.is_quiet() {
+        status_ok!(
+            "Loaded",
+            "{} security advisories (from {})",
+            database.iter().count(),
+            advisory_db_path.display()
+        );
+    }
+
+    Ok(database)
+}
+
+/// Add context to an error loading the advisory database
+fn loading_database_error(e: Error) -> Error {
+    Error::with_source(e.kind(), "error loading advisory database".to_owned(), e)
+}
+
+/// A binary file which has been read and parsed, ready to be audited
+#[cfg(feature = "binary-scanning")]
+struct LoadedBinary {
+    /// Contents of the file
+    file_contents: Vec<u8>,
+
+    /// Format of the binary
+    binary_type: BinaryFormat,
+
+    /// Dependencies recovered from the binary
+    report: rustsec::binary_scanning::BinaryReport,
+
+    /// Findings about the binary besides its dependencies
+    findings: BinaryFindings,
+}
+
+/// Callback receiving each binary found by a scan, along with its path
+#[cfg(feature = "binary-scanning")]
+type FoundBinary<'a> = dyn FnMut(PathBuf, rustsec::Result<LoadedBinary>) + 'a;
+
+/// Findings about a binary (or the binaries in an image) besides its dependencies
+#[cfg(feature = "binary-scanning")]
+#[derive(Default)]
+struct BinaryFindings {
+    /// Versions of the Rust compiler the binary was built with
+    rustc_versions: BTreeSet<Version>,
+
+    /// Warnings about vulnerable C libraries linked into the binary
+    bundled_library_warnings: Vec<Warning>,
+
+    /// How reliably each dependency was recovered from the binary
+    confidence: BTreeMap<Dependency, Confidence>,
+}
+
+#[cfg(feature = "binary-scanning")]
+impl BinaryFindings {
+    /// Find out what a binary was built with
+    fn new(file_contents: &[u8]) -> Self {
+        Self {
+            rustc_versions: rustsec::binary_scanning::rustc_version(file_contents)
+                .into_iter()
+                .collect(),
+            bundled_library_warnings: rustsec::binary_scanning::bundled_library_warnings(
+                file_contents,
+            ),
+            confidence: BTreeMap::new(),
+        }
+    }
+
+    /// How reliably a package was recovered from the binary, if it was
+    fn confidence_of(&self, package: &Package) -> Option<Confidence> {
+        self.confidence.get(&Dependency::from(package)).copied()
+    }
+
+    /// Add the findings about another binary
+    fn merge(&mut self, other: Self) {
+        self.rustc_versions.extend(other.rustc_versions);
+
+        for (dependency, confidence) in other.confidence {
+            let level = self.confidence.entry(dependency).or_insert(confidence);
+            *level = confidence.max(*level);
+        }
+
+        for warning in other.bundled_library_warnings {
+            let id = warning.advisory.as_ref().map(|advisory| &advisory.id);
+            if !self.bundled_library_warnings.iter().any(|existing| {
+                existing.package == warning.package
+                    && existing.advisory.as_ref().map(|advisory| &advisory.id) == id
+            }) {
+                self.bundled_library_warnings.push(warning);
+            }
+        }
+    }
+}
+
+/// Scan a path given to `cargo audit bin`, passing each binary found in it
+/// (i.e. the file itself, or the executables in an archive) to `found`
+#[cfg(feature = "binary-scanning")]
+fn scan_path(
+    path: &Path,
+    binary_size_limit: Option<u64>,
+    audit_data_size_limit: Option<usize>,
+    archive_depth: usize,
+    found: &mut FoundBinary<'_>,
+) {
+    let limit = binary_size_limit.unwrap_or(DEFAULT_MAX_BINARY_SIZE);
+    let archive_kind = if archive_depth > 0 {
+        archive::read_magic(path)
+            .ok()
+            .and_then(|magic| ArchiveKind::detect(&magic))
+    } else {
+        None
+    };
+
+    let Some(kind) = archive_kind else {
+        let binary = read_binary(path, path, limit)
+            .and_then(|contents| parse_binary(contents, audit_data_size_limit));
+        found(path.to_owned(), binary);
+        return;
+    };
+
+    let mut count = 0;
+    let result = archive::Walker::new(archive_depth, limit, |entry_path, contents| {
+        count += 1;
+        found(
+            entry_path,
+            contents.and_then(|contents| parse_binary(contents, audit_data_size_limit)),
+        );
+    })
+    .walk_file(path, kind);
+
+    if let Err(e) = result {
+        found(path.to_owned(), Err(e));
+    } else if count == 0 {
+        let error = Error::new(
+            ErrorKind::NotFound,
+            format!("no executables found in archive {}", path.display()),
+        );
+        found(path.to_owned(), Err(error));
+    }
+}
+
+/// Read a binary file, up to the size limit. Errors refer to it as `name`.
+#[cfg(feature = "binary-scanning")]
+fn read_binary(binary_path: &Path, name: &Path, limit: u64) -> rustsec::Result<Vec<u8>> {
+    let file = std::fs::File::open(binary_path)?;
+    let mut limited = file.take(limit.saturating_add(1));
+    let mut file_contents = Vec::new();
+    limited.read_to_end(&mut file_contents)?;
+    if file_contents.len() as u64 > limit {
+        return Err(Error::new(
+            ErrorKind::BadParam,
+            format!(
+                "binary {} exceeds max size limit of {} bytes",
+                name.display(),
+                limit
+            ),
+        ));
+    }
+
+    Ok(file_contents)
+}
+
+/// Recover the dependencies of a binary
+#[cfg(feature = "binary-scanning")]
+fn parse_binary(
+    file_contents: Vec<u8>,
+    audit_data_size_limit: Option<usize>,
+) -> rustsec::Result<LoadedBinary> {
+    let scanned = rustsec::binary_scanning::scan_binary(&file_contents, audit_data_size_limit)?;
+    let mut findings = BinaryFindings::new(&file_contents);
+    findings.confidence = scanned.confidence;
+
+    Ok(LoadedBinary {
+        file_contents,
+        binary_type: scanned.format,
+        report: scanned.report,
+        findings,
+    })
+}
+
+/// Number of binaries to scan concurrently by default
+#[cfg(feature = "binary-scanning")]
+fn default_binary_scan_jobs() -> usize {
+    thread::available_parallelism().map_or(1, usize::from)
+}
+
+/// Location of the local copy of the advisory database
+pub(crate) fn advisory_db_path(config: &AuditConfig) -> PathBuf {
+    config.database.path.as_ref().cloned().unwrap_or_else(|| {
+        if config.database.package.is_some() {
+            rustsec::repository::registry::RegistryPackage::default_path()
+        } else {
+            rustsec::repository::git::Repository::default_path()
+        }
+    })
+}
+
+/// Load the CISA KEV catalog from a JSON file
+fn load_kev_catalog(path: &Path) -> rustsec::Result<kev::Catalog> {
+    let json = std::fs::read_to_string(path).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Io,
+            format!("couldn't open {}", path.display()),
+            e,
+        )
+    })?;
+
+    serde_json::from_str(&json).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Parse,
+            format!("error parsing {}", path.display()),
+            e,
+        )
+    })
+}
+
 /// Summary of the report over multiple scanned files
 #[derive(Clone, Copy, Debug, Default)]
 pub struct MultiFileReportSummmary {
@@ -426,4 +1289,6 @@ pub struct MultiFileReportSummmary {
     pub vulnerabilities_found: bool,
     /// Whether any errors were encountered during scanning
     pub errors_encountered: bool,
+    /// Whether any binaries didn't match the expected lockfile
+    pub lockfile_drift_found: bool,
 }
@@ -0,0 +1,214 @@
+//! Comparing audit reports against a baseline
+//!
+//! This allows gating CI on "no new vulnerabilities" in projects which have
+//! a known backlog of findings, by comparing a report to one saved earlier
+//! with `cargo audit --json`.
+
+use rustsec::{Error, ErrorKind, Report, Vulnerability, Warning, WarningKind, advisory, package};
+use serde::Serialize;
+use std::{collections::BTreeSet, path::Path};
+
+/// Findings introduced and resolved since a baseline report
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReportDiff {
+    /// Findings which aren't present in the baseline
+    pub introduced: Findings,
+
+    /// Findings from the baseline which are no longer present
+    pub resolved: Findings,
+}
+
+/// Vulnerabilities and warnings found in a report
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Findings {
+    /// Vulnerabilities
+    pub vulnerabilities: Vec<Vulnerability>,
+
+    /// Warnings
+    pub warnings: Vec<Warning>,
+}
+
+impl Findings {
+    /// Are there no findings?
+    pub fn is_empty(&self) -> bool {
+        self.vulnerabilities.is_empty() && self.warnings.is_empty()
+    }
+}
+
+impl ReportDiff {
+    /// Compare a report to a baseline report
+    pub fn new(baseline: &Report, current: &Report) -> Self {
+        Self {
+            introduced: difference(current, baseline),
+            resolved: difference(baseline, current),
+        }
+    }
+
+    /// Remove the findings which were already present in the baseline from the
+    /// given report, so that only the newly introduced ones remain
+    pub fn apply(&self, report: &mut Report) {
+        report.vulnerabilities =
+            rustsec::report::VulnerabilityInfo::new(self.introduced.vulnerabilities.clone());
+
+        report.warnings.clear();
+        for warning in &self.introduced.warnings {
+            report
+                .warnings
+                .entry(warning.kind)
+                .or_default()
+                .push(warning.clone());
+        }
+    }
+}
+
+/// Load a report saved with `cargo audit --json`
+pub fn load_report(path: &Path) -> rustsec::Result<Report> {
+    let json = std::fs::read_to_string(path).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Io,
+            format!("couldn't open {}", path.display()),
+            e,
+        )
+    })?;
+
+    serde_json::from_str(&json).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Parse,
+            format!("error parsing report {}", path.display()),
+            e,
+        )
+    })
+}
+
+/// Identity of a vulnerability across reports.
+///
+/// The version is deliberately left out: updating to another vulnerable
+/// version doesn't introduce a new finding.
+type VulnerabilityKey = (advisory::Id, package::Name);
+
+/// Identity of a warning across reports.
+///
+/// Warnings without an advisory (i.e. yanked crates) are specific to a version.
+type WarningKey = (
+    WarningKind,
+    Option<advisory::Id>,
+    package::Name,
+    Option<rustsec::Version>,
+);
+
+fn vulnerability_key(vulnerability: &Vulnerability) -> VulnerabilityKey {
+    (
+        vulnerability.advisory.id.clone(),
+        vulnerability.package.name.clone(),
+    )
+}
+
+fn warning_key(warning: &Warning) -> WarningKey {
+    let advisory = warning.advisory.as_ref().map(|a| a.id.clone());
+    let version = advisory.is_none().then(|| warning.package.version.clone());
+
+    (
+        warning.kind,
+        advisory,
+        warning.package.name.clone(),
+        version,
+    )
+}
+
+/// Findings in `report` which aren't in `other`
+fn difference(report: &Report, other: &Report) -> Findings {
+    let other_vulnerabilities: BTreeSet<VulnerabilityKey> = other
+        .vulnerabilities
+        .list
+        .iter()
+        .map(vulnerability_key)
+        .collect();
+    let other_warnings: BTreeSet<WarningKey> =
+        other.warnings.values().flatten().map(warning_key).collect();
+
+    Findings {
+        vulnerabilities: report
+            .vulnerabilities
+            .list
+            .iter()
+            .filter(|v| !other_vulnerabilities.contains(&vulnerability_key(v)))
+            .cloned()
+            .collect(),
+        warnings: report
+            .warnings
+            .values()
+            .flatten()
+            .filter(|w| !other_warnings.contains(&warning_key(w)))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustsec::{Advisory, cargo_lock::Package};
+
+    fn vulnerability(id: &str, version: &str) -> Vulnerability {
+        let advisory: Advisory = format!(
+            "```toml\n[advisory]\nid = \"{id}\"\npackage = \"base\"\ndate = \"2001-02-03\"\n\n\
+             [versions]\npatched = [\">= 9.0.0\"]\n```\n\n# Title\n\nDescription\n"
+        )
+        .parse()
+        .unwrap();
+        let package = Package {
+            name: "base".parse().unwrap(),
+            version: version.parse().unwrap(),
+            source: None,
+            checksum: None,
+            dependencies: vec![],
+            replace: None,
+        };
+
+        Vulnerability::new(&advisory, &package)
+    }
+
+    fn report(vulnerabilities: Vec<Vulnerability>) -> Report {
+        let mut report: Report = serde_json::from_str(
+            r#"{
+                "database": {"advisory-count": 0, "last-commit": null, "last-updated": null},
+                "lockfile": {"dependency-count": 0},
+                "settings": {"target_arch": [], "target_os": [], "severity": null, "ignore": [], "informational_warnings": []},
+                "vulnerabilities": {"found": false, "count": 0, "list": []},
+                "warnings": {}
+            }"#,
+        )
+        .unwrap();
+        report.vulnerabilities = rustsec::report::VulnerabilityInfo::new(vulnerabilities);
+        report
+    }
+
+    #[test]
+    fn introduced_and_resolved() {
+        let baseline = report(vec![
+            vulnerability("RUSTSEC-2001-0001", "1.0.0"),
+            vulnerability("RUSTSEC-2001-0002", "1.0.0"),
+        ]);
+        // 0001 is still present (on another vulnerable version), 0002 was resolved
+        let mut current = report(vec![
+            vulnerability("RUSTSEC-2001-0001", "1.0.1"),
+            vulnerability("RUSTSEC-2001-0003", "1.0.1"),
+        ]);
+
+        let diff = ReportDiff::new(&baseline, &current);
+        assert_eq!(diff.introduced.vulnerabilities.len(), 1);
+        assert_eq!(
+            diff.introduced.vulnerabilities[0].advisory.id.as_str(),
+            "RUSTSEC-2001-0003"
+        );
+        assert_eq!(diff.resolved.vulnerabilities.len(), 1);
+        assert_eq!(
+            diff.resolved.vulnerabilities[0].advisory.id.as_str(),
+            "RUSTSEC-2001-0002"
+        );
+
+        diff.apply(&mut current);
+        assert_eq!(current.vulnerabilities.count, 1);
+        assert!(current.vulnerabilities.found);
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use cargo_lock::Package;
-use object::{File, Object, ObjectSymbol};
+use object::{File, Object, ObjectSymbol, read::archive::ArchiveFile};
 use rustc_demangle::demangle;
 use rustsec::advisory::affected::FunctionPath;
 use syn::{Ident, Type, TypePath, parse_str};
@@ -18,26 +18,18 @@ impl SymbolSet {
             .map(|c| c.name.as_str().replace('-', "_"))
             .collect::<HashSet<_>>();
 
-        let file = File::parse(contents)?;
         let mut symbols = Vec::new();
-        for symbol in file.symbols() {
-            let Ok(name) = symbol.name() else {
-                continue;
-            };
-
-            // `parse_str::<TypePath>` is expensive. The filter on `crate_names`
-            // eliminates symbols that we know would be irrelevant.
-            if !crate_names
-                .iter()
-                .any(|crate_name| name.contains(crate_name.as_str()))
-            {
-                continue;
-            }
-
-            let name = format!("{:#}", demangle(name));
-            if let Ok(type_path) = parse_str::<TypePath>(&name) {
-                symbols.push(flatten_type_path(&type_path));
+        if contents.starts_with(b"!<arch>\n") {
+            // Static libraries and rlibs contain multiple object files
+            let archive = ArchiveFile::parse(contents)?;
+            for member in archive.members() {
+                // Skip members that aren't object files, such as the metadata of rlibs
+                if let Ok(file) = File::parse(member?.data(contents)?) {
+                    collect_symbols(&file, &crate_names, &mut symbols);
+                }
             }
+        } else {
+            collect_symbols(&File::parse(contents)?, &crate_names, &mut symbols);
         }
 
         symbols.sort();
@@ -80,6 +72,29 @@ impl SymbolSet {
     }
 }
 
+/// Demangle the symbols of an object file that may belong to one of `crate_names`
+fn collect_symbols(file: &File<'_>, crate_names: &HashSet<String>, symbols: &mut Vec<Vec<Ident>>) {
+    for symbol in file.symbols() {
+        let Ok(name) = symbol.name() else {
+            continue;
+        };
+
+        // `parse_str::<TypePath>` is expensive. The filter on `crate_names`
+        // eliminates symbols that we know would be irrelevant.
+        if !crate_names
+            .iter()
+            .any(|crate_name| name.contains(crate_name.as_str()))
+        {
+            continue;
+        }
+
+        let name = format!("{:#}", demangle(name));
+        if let Ok(type_path) = parse_str::<TypePath>(&name) {
+            symbols.push(flatten_type_path(&type_path));
+        }
+    }
+}
+
 fn flatten_type_path(mut type_path: &TypePath) -> Vec<Ident> {
     let mut idents = Vec::new();
     let mut stack = Vec::new();
@@ -5,6 +5,19 @@ mod fix;
 
 #[cfg(feature = "binary-scanning")]
 mod binary_scanning;
+#[cfg(feature = "binary-scanning")]
+mod image;
+#[cfg(feature = "binary-scanning")]
+mod proc;
+
+mod completions;
+mod diff;
+mod render;
+mod search;
+mod self_update;
+mod show;
+mod watch;
+mod why;
 
 use crate::{
     auditor::Auditor,
@@ -23,13 +36,17 @@ use std::{
     io::{self, IsTerminal},
     path::PathBuf,
     process::exit,
+    time::Duration,
 };
 
-#[cfg(feature = "binary-scanning")]
-use self::binary_scanning::BinCommand;
 #[cfg(feature = "fix")]
 use self::fix::FixCommand;
-#[cfg(any(feature = "fix", feature = "binary-scanning"))]
+#[cfg(feature = "binary-scanning")]
+use self::{binary_scanning::BinCommand, image::ImageCommand, proc::ProcCommand};
+use self::{
+    completions::CompletionsCommand, diff::DiffCommand, render::RenderCommand,
+    search::SearchCommand, self_update::SelfCommand, show::ShowCommand, why::WhyCommand,
+};
 use clap::Subcommand;
 
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
@@ -68,8 +85,7 @@ impl fmt::Display for Color {
 #[derive(Command, Clone, Default, Debug, Parser)]
 #[command(version)]
 pub struct AuditCommand {
-    /// Optional subcommand (used for `cargo audit fix` and `cargo audit bin`)
-    #[cfg(any(feature = "fix", feature = "binary-scanning"))]
+    /// Optional subcommand (e.g. `cargo audit fix` or `cargo audit bin`)
     #[command(subcommand)]
     subcommand: Option<AuditSubcommand>,
 
@@ -89,14 +105,69 @@ pub struct AuditCommand {
     )]
     db: Option<PathBuf>,
 
+    /// Name of a crates.io package containing the advisory database
+    #[arg(
+        long = "db-package",
+        value_name = "CRATE",
+        help = "fetch the advisory database from this crates.io package instead of git"
+    )]
+    db_package: Option<String>,
+
+    /// Name of the registry to fetch the advisory database package from
+    #[arg(
+        long = "db-registry",
+        value_name = "REGISTRY",
+        requires = "db_package",
+        help = "fetch the advisory database package from this registry from the cargo configuration instead of crates.io"
+    )]
+    db_registry: Option<String>,
+
     /// Deny flag
     #[arg(
         short = 'D',
         long = "deny",
-        help = "exit with an error on: warnings (any), unmaintained, unsound, yanked"
+        help = "exit with an error on: warnings (any), unmaintained, unsound, yanked, epss>=<SCORE>, kev"
     )]
     deny: Vec<DenyOption>,
 
+    /// Annotate vulnerabilities with EPSS scores
+    #[arg(
+        long = "epss",
+        help = "annotate vulnerabilities with EPSS scores downloaded from FIRST"
+    )]
+    epss: bool,
+
+    /// Path to a FIRST EPSS scores CSV file
+    #[arg(
+        long = "epss-file",
+        value_name = "PATH",
+        help = "use the EPSS scores from this FIRST CSV file instead of downloading them"
+    )]
+    epss_file: Option<PathBuf>,
+
+    /// Flag vulnerabilities in the CISA Known Exploited Vulnerabilities catalog
+    #[arg(
+        long = "kev",
+        help = "flag vulnerabilities listed in the CISA KEV catalog downloaded from CISA"
+    )]
+    kev: bool,
+
+    /// Path to the CISA Known Exploited Vulnerabilities catalog
+    #[arg(
+        long = "kev-file",
+        value_name = "PATH",
+        help = "use the CISA KEV catalog from this JSON file instead of downloading it"
+    )]
+    kev_file: Option<PathBuf>,
+
+    /// Path to a baseline report
+    #[arg(
+        long = "baseline",
+        value_name = "REPORT",
+        help = "only report findings not present in this JSON report from an earlier run"
+    )]
+    baseline: Option<PathBuf>,
+
     /// Path to `Cargo.lock`
     #[arg(
         short = 'f',
@@ -117,6 +188,13 @@ pub struct AuditCommand {
     #[arg(long = "no-yanked", help = "do not check for yanked crates")]
     no_yanked: bool,
 
+    /// Also audit the active Rust toolchain
+    #[arg(
+        long = "toolchain",
+        help = "also check the version of the Rust toolchain for vulnerabilities"
+    )]
+    toolchain: bool,
+
     /// Skip fetching the advisory database git repository
     #[arg(
         short = 'n',
@@ -159,17 +237,41 @@ pub struct AuditCommand {
     #[arg(
         long = "format",
         value_name = "FORMAT",
-        help = "Output format: terminal, json, or sarif"
+        help = "Output format: terminal, json, sarif, markdown, or metrics"
     )]
     output_format: Option<OutputFormat>,
 
     /// Output reports as JSON
     #[arg(long = "json", help = "Output report in JSON format")]
     output_json: bool,
+
+    /// Also write Prometheus metrics to a file
+    #[arg(
+        long = "metrics-file",
+        value_name = "PATH",
+        help = "also write Prometheus metrics to this file (e.g. for the node_exporter textfile collector)"
+    )]
+    metrics_file: Option<PathBuf>,
+
+    /// Keep running, re-auditing whenever `Cargo.lock` or the advisory database changes
+    #[arg(
+        long = "watch",
+        help = "keep running, reporting new and resolved findings whenever Cargo.lock or the advisory database changes"
+    )]
+    watch: bool,
+
+    /// How often to fetch the advisory database in watch mode
+    #[arg(
+        long = "watch-interval",
+        value_name = "SECONDS",
+        default_value_t = 3600,
+        requires = "watch",
+        help = "how often to fetch the advisory database in watch mode (default: 3600)"
+    )]
+    watch_interval: u64,
 }
 
 /// Subcommands of `cargo audit`
-#[cfg(any(feature = "fix", feature = "binary-scanning"))]
 #[derive(Subcommand, Clone, Debug, Runnable)]
 pub enum AuditSubcommand {
     /// `cargo audit fix` subcommand
@@ -187,6 +289,55 @@ Performs a complete scan if the binary is built with 'cargo auditable'.
 If not, recovers a part of the dependency list from panic messages."
     )]
     Bin(BinCommand),
+
+    /// `cargo audit image` subcommand
+    #[cfg(feature = "binary-scanning")]
+    #[command(
+        about = "scan container images",
+        long_about = "Scan the Rust executables in container images for known vulnerabilities.
+
+Produces a single report for each image, combining the dependencies of all executables in it."
+    )]
+    Image(ImageCommand),
+
+    /// `cargo audit proc` subcommand
+    #[cfg(feature = "binary-scanning")]
+    #[command(
+        about = "scan running processes",
+        long_about = "Scan the executables of running processes for known vulnerabilities (Linux only).
+
+Executables are read through /proc/<pid>/exe, so the binary that is actually running is scanned
+even if it has been replaced or deleted on disk since the process was started."
+    )]
+    Proc(ProcCommand),
+
+    /// `cargo audit completions` subcommand
+    #[command(about = "generate shell completions")]
+    Completions(CompletionsCommand),
+
+    /// `cargo audit diff` subcommand
+    #[command(about = "compare two saved JSON reports")]
+    Diff(DiffCommand),
+
+    /// `cargo audit render` subcommand
+    #[command(about = "display a saved JSON report")]
+    Render(RenderCommand),
+
+    /// `cargo audit search` subcommand
+    #[command(about = "search the advisory database")]
+    Search(SearchCommand),
+
+    /// `cargo audit self` subcommand
+    #[command(name = "self", about = "manage this installation of cargo-audit")]
+    SelfCmd(SelfCommand),
+
+    /// `cargo audit show` subcommand
+    #[command(about = "show the details of an advisory")]
+    Show(ShowCommand),
+
+    /// `cargo audit why` subcommand
+    #[command(about = "explain why a vulnerable crate is in the dependency tree")]
+    Why(WhyCommand),
 }
 
 impl AuditCommand {
@@ -215,6 +366,14 @@ impl Override<AuditConfig> for AuditCommand {
             config.database.path = Some(db.into());
         }
 
+        if let Some(db_package) = &self.db_package {
+            config.database.package = Some(db_package.clone());
+        }
+
+        if let Some(db_registry) = &self.db_registry {
+            config.database.registry = Some(db_registry.clone());
+        }
+
         for advisory_id in &self.ignore {
             config.advisories.ignore.push(
                 advisory_id
@@ -238,9 +397,30 @@ impl Override<AuditConfig> for AuditCommand {
             config.database.url = Some(url.clone())
         }
 
+        config.advisories.epss |= self.epss;
+
+        if let Some(epss_file) = &self.epss_file {
+            config.advisories.epss_file = Some(epss_file.clone());
+        }
+
+        config.advisories.kev |= self.kev;
+
+        if let Some(kev_file) = &self.kev_file {
+            config.advisories.kev_file = Some(kev_file.clone());
+        }
+
+        if let Some(baseline) = &self.baseline {
+            config.advisories.baseline = Some(baseline.clone());
+        }
+
         for kind in &self.deny {
             if *kind == DenyOption::Warnings {
-                config.output.deny = DenyOption::all();
+                // Keep any EPSS/KEV policies, which aren't covered by `warnings`
+                config
+                    .output
+                    .deny
+                    .retain(|deny| matches!(deny, DenyOption::Epss(_) | DenyOption::Kev));
+                config.output.deny.extend(DenyOption::all());
             } else {
                 config.output.deny.push(*kind);
             }
@@ -258,10 +438,18 @@ impl Override<AuditConfig> for AuditCommand {
             config.output.format = format;
         }
 
+        if let Some(metrics_file) = &self.metrics_file {
+            config.output.metrics_file = Some(metrics_file.clone());
+        }
+
         if self.no_yanked {
             config.yanked.enabled = false;
         }
 
+        if self.toolchain {
+            config.toolchain.enabled = true;
+        }
+
         Ok(config)
     }
 }
@@ -280,6 +468,53 @@ impl Runnable for AuditCommand {
             exit(0)
         }
 
+        #[cfg(feature = "binary-scanning")]
+        if let Some(AuditSubcommand::Image(image)) = &self.subcommand {
+            image.run();
+            exit(0)
+        }
+
+        #[cfg(feature = "binary-scanning")]
+        if let Some(AuditSubcommand::Proc(proc)) = &self.subcommand {
+            proc.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Completions(completions)) = &self.subcommand {
+            completions.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Diff(diff)) = &self.subcommand {
+            diff.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Render(render)) = &self.subcommand {
+            render.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Search(search)) = &self.subcommand {
+            search.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::SelfCmd(self_cmd)) = &self.subcommand {
+            self_cmd.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Show(show)) = &self.subcommand {
+            show.run();
+            exit(0)
+        }
+
+        if let Some(AuditSubcommand::Why(why)) = &self.subcommand {
+            why.run();
+            exit(0)
+        }
+
         let maybe_path = self.file.as_deref();
         // It is important to generate the lockfile before initializing the auditor,
         // otherwise we might deadlock because both need the Cargo package lock
@@ -287,6 +522,11 @@ impl Runnable for AuditCommand {
             status_err!("{}", display_err_with_source(&e));
             exit(2);
         });
+
+        if self.watch {
+            watch::watch(&path, Duration::from_secs(self.watch_interval));
+        }
+
         let mut auditor = self.auditor();
         let report = auditor.audit_lockfile(&path);
         match report {
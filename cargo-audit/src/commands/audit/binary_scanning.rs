@@ -1,8 +1,22 @@
 //! The `cargo audit bin` subcommand
 
-use crate::{auditor::Auditor, prelude::*};
+use crate::{
+    archive::{self, ArchiveKind},
+    auditor::Auditor,
+    config::OutputFormat,
+    error::display_err_with_source,
+    metadata::cargo_metadata,
+    prelude::*,
+};
 use clap::Parser;
-use std::{path::PathBuf, process::exit};
+use rustsec::Version;
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs, io,
+    path::{Path, PathBuf},
+    process::exit,
+    time::SystemTime,
+};
 
 #[cfg(feature = "binary-scanning")]
 /// The `cargo audit` subcommand
@@ -25,21 +39,113 @@ pub struct BinCommand {
     )]
     audit_data_size_limit: Option<usize>,
 
+    /// Number of binaries to scan concurrently
+    #[arg(
+        short = 'j',
+        long = "jobs",
+        value_name = "N",
+        help = "Number of binaries to scan concurrently (default: number of CPUs)"
+    )]
+    jobs: Option<usize>,
+
+    /// Levels of nested archives to look for binaries in
+    #[arg(
+        long = "archive-depth",
+        value_name = "DEPTH",
+        help = "Levels of nested tar/zip archives to look for binaries in (default: 2; use 0 to not look inside archives)"
+    )]
+    archive_depth: Option<usize>,
+
+    /// Oldest acceptable version of the Rust compiler
+    #[arg(
+        long = "min-rustc-version",
+        value_name = "VERSION",
+        help = "Warn about binaries built with a Rust compiler older than VERSION"
+    )]
+    min_rustc_version: Option<Version>,
+
+    /// Merge the reports of all binaries
+    #[arg(
+        long = "merge",
+        help = "Print a single report for all binaries, listing the binaries each finding applies to"
+    )]
+    merge: bool,
+
+    /// Lockfile the dependencies of the binaries are expected to match
+    #[arg(
+        long = "expect-lockfile",
+        value_name = "LOCKFILE",
+        help = "Report binaries whose dependencies differ from LOCKFILE (e.g. stale or tampered builds)"
+    )]
+    expect_lockfile: Option<PathBuf>,
+
+    /// Scan the executables built from the current workspace
+    #[arg(
+        long = "workspace",
+        help = "Scan the most recently built executable of each binary target in the current workspace"
+    )]
+    workspace: bool,
+
+    /// Target directory to find the workspace's executables in
+    #[arg(
+        long = "target-dir",
+        value_name = "DIR",
+        help = "Scan the workspace's executables in DIR instead of its default target directory (implies --workspace)"
+    )]
+    target_dir: Option<PathBuf>,
+
     /// Paths to the binaries to be scanned
     #[arg(
         value_parser,
-        required = true,
-        help = "Paths to the binaries to be scanned"
+        required_unless_present_any = ["workspace", "target_dir"],
+        help = "Paths to the binaries to be scanned. Directories are searched recursively \
+                for executables, and glob patterns (e.g. 'target/release/**') are expanded"
     )]
     binary_paths: Vec<PathBuf>,
 }
 
 impl Runnable for BinCommand {
     fn run(&self) {
+        if self.merge
+            && !matches!(
+                APP.config().output.format,
+                OutputFormat::Json | OutputFormat::Terminal
+            )
+        {
+            status_err!("--merge is only supported with terminal and JSON output");
+            exit(2);
+        }
+
+        let mut binary_paths = expand_paths(&self.binary_paths).unwrap_or_else(|e| {
+            status_err!("{}", e);
+            exit(2);
+        });
+
+        if self.workspace || self.target_dir.is_some() {
+            let built = workspace_binaries(self.target_dir.as_deref()).unwrap_or_else(|e| {
+                status_err!("{}", e);
+                exit(2);
+            });
+            binary_paths.extend(built);
+        }
+
+        if binary_paths.is_empty() {
+            status_err!("no executables found to scan");
+            exit(2);
+        }
+
         let mut auditor = self.auditor();
         auditor.set_binary_scan_limits(self.max_binary_size, self.audit_data_size_limit);
-        let report = auditor.audit_binaries(&self.binary_paths);
-        if report.vulnerabilities_found {
+        auditor.set_binary_scan_jobs(self.jobs);
+        auditor.set_archive_depth(self.archive_depth);
+        auditor.set_min_rustc_version(self.min_rustc_version.clone());
+        auditor.set_merge_reports(self.merge);
+        if let Err(e) = auditor.set_expected_lockfile(self.expect_lockfile.as_deref()) {
+            status_err!("{}", display_err_with_source(&e));
+            exit(2);
+        }
+        let report = auditor.audit_binaries(&binary_paths);
+        if report.vulnerabilities_found || report.lockfile_drift_found {
             exit(1)
         } else if report.errors_encountered {
             exit(2)
@@ -55,3 +161,244 @@ impl BinCommand {
         Auditor::new(&APP.config())
     }
 }
+
+/// Find the most recently built executable of each binary target in the
+/// current workspace, using `cargo metadata`
+fn workspace_binaries(target_dir: Option<&Path>) -> Result<Vec<PathBuf>, String> {
+    let metadata = cargo_metadata(None, true).map_err(|e| display_err_with_source(&e))?;
+
+    let target_dir = match target_dir {
+        Some(dir) => dir.to_owned(),
+        None => metadata["target_directory"]
+            .as_str()
+            .map(PathBuf::from)
+            .ok_or("`cargo metadata` didn't report a target directory")?,
+    };
+
+    let names = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|package| package["targets"].as_array().into_iter().flatten())
+        .filter(|target| {
+            target["kind"]
+                .as_array()
+                .is_some_and(|kinds| kinds.iter().any(|kind| kind == "bin"))
+        })
+        .filter_map(|target| target["name"].as_str())
+        .collect::<BTreeSet<_>>();
+
+    let mut binaries = vec![];
+    for name in names {
+        match latest_build(&target_dir, name)
+            .map_err(|e| format!("couldn't read {}: {}", target_dir.display(), e))?
+        {
+            Some(path) => binaries.push(path),
+            None => status_warn!(
+                "no executable found for binary target '{}' in {}, has it been built?",
+                name,
+                target_dir.display()
+            ),
+        }
+    }
+
+    if binaries.is_empty() {
+        return Err(format!(
+            "no executables of the current workspace found in {} (build them first)",
+            target_dir.display()
+        ));
+    }
+
+    Ok(binaries)
+}
+
+/// Find the most recently built executable with the given name in a target directory.
+///
+/// Executables are placed in `<target-dir>/<profile>/`, or in
+/// `<target-dir>/<target-triple>/<profile>/` when cross-compiling.
+fn latest_build(target_dir: &Path, name: &str) -> io::Result<Option<PathBuf>> {
+    let file_names = [name.to_owned(), format!("{name}.exe")];
+    let mut latest: Option<(SystemTime, PathBuf)> = None;
+
+    for dir in subdirectories(target_dir)? {
+        let dirs = subdirectories(&dir)?;
+        for dir in std::iter::once(dir).chain(dirs) {
+            for file_name in &file_names {
+                let path = dir.join(file_name);
+                let Ok(metadata) = fs::metadata(&path) else {
+                    continue;
+                };
+
+                let modified = metadata.modified()?;
+                if metadata.is_file() && latest.as_ref().is_none_or(|(time, _)| modified > *time) {
+                    latest = Some((modified, path));
+                }
+            }
+        }
+    }
+
+    Ok(latest.map(|(_, path)| path))
+}
+
+/// List the subdirectories of a directory
+fn subdirectories(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            dirs.push(entry.path());
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Expand directories and glob patterns into the executables (and archives) they contain.
+///
+/// Files given explicitly are always scanned, while only the files which look
+/// like executables or archives are picked from directories and glob matches.
+fn expand_paths(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut result = vec![];
+
+    for path in paths {
+        if path.is_dir() {
+            find_executables(path, &mut result)
+                .map_err(|e| format!("couldn't read {}: {}", path.display(), e))?;
+        } else if path.exists() || !is_glob(path) {
+            result.push(path.clone());
+        } else {
+            let pattern = path.to_string_lossy();
+
+            // `dir/**` means everything below `dir`, but only matches the
+            // subdirectories of `dir`. Directories are searched recursively anyway.
+            let pattern = match pattern.strip_suffix("**") {
+                Some(prefix) if prefix.ends_with(['/', '\\']) => {
+                    prefix.trim_end_matches(['/', '\\'])
+                }
+                _ => &pattern,
+            };
+
+            let matches = glob::glob(pattern)
+                .map_err(|e| format!("invalid glob pattern '{pattern}': {e}"))?;
+
+            for entry in matches {
+                let entry = entry.map_err(|e| e.to_string())?;
+                if entry.is_dir() {
+                    find_executables(&entry, &mut result)
+                        .map_err(|e| format!("couldn't read {}: {}", entry.display(), e))?;
+                } else if is_candidate(&entry) {
+                    result.push(entry);
+                }
+            }
+        }
+    }
+
+    // Patterns like `dir/**` match both directories and the files in them
+    let mut seen = HashSet::new();
+    result.retain(|path| seen.insert(path.clone()));
+
+    Ok(result)
+}
+
+/// Recursively find the executables in a directory
+fn find_executables(dir: &Path, result: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+
+        if file_type.is_dir() {
+            find_executables(&path, result)?;
+        } else if file_type.is_file() && is_candidate(&path) {
+            result.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Does the path contain glob metacharacters?
+fn is_glob(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Is the file an executable, or an archive which may contain some?
+fn is_candidate(path: &Path) -> bool {
+    archive::read_magic(path)
+        .is_ok_and(|magic| archive::is_executable(&magic) || ArchiveKind::detect(&magic).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_directories_and_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin_dir = dir.path().join("release").join("deps");
+        fs::create_dir_all(&bin_dir).unwrap();
+
+        fs::write(dir.path().join("release").join("app"), b"\x7fELF\x02\x01").unwrap();
+        fs::write(bin_dir.join("tool.exe"), b"MZ\x90\x00").unwrap();
+        fs::write(bin_dir.join("tool.d"), b"tool.d: src/main.rs").unwrap();
+
+        let expected = vec![
+            dir.path().join("release").join("app"),
+            bin_dir.join("tool.exe"),
+        ];
+
+        assert_eq!(
+            expand_paths(&[dir.path().join("release")]).unwrap(),
+            expected
+        );
+        assert_eq!(
+            expand_paths(&[dir.path().join("release").join("*")]).unwrap(),
+            expected
+        );
+        assert_eq!(
+            expand_paths(&[dir.path().join("release").join("**")]).unwrap(),
+            expected
+        );
+
+        // Explicitly listed files are always scanned
+        assert_eq!(
+            expand_paths(&[bin_dir.join("tool.d")]).unwrap(),
+            vec![bin_dir.join("tool.d")]
+        );
+    }
+
+    #[test]
+    fn find_latest_builds() {
+        let dir = tempfile::tempdir().unwrap();
+        let debug = dir.path().join("debug");
+        let release = dir.path().join("x86_64-unknown-linux-gnu").join("release");
+        fs::create_dir_all(debug.join("deps")).unwrap();
+        fs::create_dir_all(&release).unwrap();
+
+        fs::write(debug.join("app"), b"\x7fELF\x02\x01").unwrap();
+        fs::write(release.join("app"), b"\x7fELF\x02\x01").unwrap();
+        fs::write(debug.join("deps").join("tool-0123456789abcdef"), b"\x7fELF").unwrap();
+
+        let now = SystemTime::now();
+        fs::File::options()
+            .write(true)
+            .open(debug.join("app"))
+            .unwrap()
+            .set_modified(now - std::time::Duration::from_secs(60))
+            .unwrap();
+        fs::File::options()
+            .write(true)
+            .open(release.join("app"))
+            .unwrap()
+            .set_modified(now)
+            .unwrap();
+
+        assert_eq!(
+            latest_build(dir.path(), "app").unwrap(),
+            Some(release.join("app"))
+        );
+        assert_eq!(latest_build(dir.path(), "tool").unwrap(), None);
+    }
+}
@@ -0,0 +1,74 @@
+//! The `cargo audit completions` subcommand
+
+use crate::{auditor, commands::CargoAuditCommand, prelude::*};
+use abscissa_core::{Command, Runnable};
+use clap::{Arg, CommandFactory, Parser, builder::PossibleValuesParser};
+use clap_complete::Shell;
+use std::io;
+
+/// Name of the binary completions are generated for
+const BIN_NAME: &str = "cargo-audit";
+
+#[derive(Command, Clone, Debug, Parser)]
+#[command(author, version, about)]
+pub struct CompletionsCommand {
+    /// Shell to generate completions for
+    #[arg(value_name = "SHELL", help = "shell to generate completions for")]
+    shell: Shell,
+}
+
+impl Runnable for CompletionsCommand {
+    fn run(&self) {
+        let mut command = CargoAuditCommand::command();
+
+        // Complete advisory IDs from the local copy of the advisory database (if any)
+        let config = APP.config();
+        if let Ok(database) = rustsec::Database::open(&auditor::advisory_db_path(&config)) {
+            let ids: Vec<String> = database
+                .iter()
+                .map(|advisory| advisory.metadata.id.to_string())
+                .collect();
+
+            command = complete_advisory_ids(command, &ids);
+        }
+
+        clap_complete::generate(self.shell, &mut command, BIN_NAME, &mut io::stdout());
+    }
+}
+
+/// Complete the arguments taking advisory IDs with the given `ids`
+fn complete_advisory_ids(command: clap::Command, ids: &[String]) -> clap::Command {
+    let advisory_ids = |arg: Arg| arg.value_parser(PossibleValuesParser::new(ids));
+
+    command.mut_subcommand("audit", |audit| {
+        audit
+            .mut_arg("ignore", advisory_ids)
+            .mut_subcommand("show", |show| show.mut_arg("id", advisory_ids))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advisory_ids_completed_for_show_and_ignore() {
+        let ids = vec!["RUSTSEC-2020-0001".to_owned()];
+        let mut command = complete_advisory_ids(CargoAuditCommand::command(), &ids);
+
+        let mut completions = vec![];
+        clap_complete::generate(Shell::Bash, &mut command, BIN_NAME, &mut completions);
+        let completions = String::from_utf8(completions).unwrap();
+
+        // once for `--ignore`, once for the positional argument of `show`
+        assert!(completions.matches("RUSTSEC-2020-0001").count() >= 2);
+        let ignore = completions.find("--ignore)").unwrap();
+        assert!(
+            completions[ignore..]
+                .lines()
+                .nth(1)
+                .unwrap()
+                .contains("RUSTSEC-2020-0001")
+        );
+    }
+}
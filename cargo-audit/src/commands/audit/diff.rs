@@ -0,0 +1,41 @@
+//! The `cargo audit diff` subcommand
+
+use crate::{
+    baseline::{self, ReportDiff},
+    error::display_err_with_source,
+    prelude::*,
+    presenter::Presenter,
+};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use std::{path::PathBuf, process::exit};
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct DiffCommand {
+    /// Baseline report
+    #[arg(value_name = "OLD", help = "JSON report from an earlier run")]
+    old: PathBuf,
+
+    /// Report to compare to the baseline
+    #[arg(value_name = "NEW", help = "JSON report to compare against it")]
+    new: PathBuf,
+}
+
+impl Runnable for DiffCommand {
+    fn run(&self) {
+        let [old, new] = [&self.old, &self.new].map(|path| {
+            baseline::load_report(path).unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(2);
+            })
+        });
+
+        let diff = ReportDiff::new(&old, &new);
+        Presenter::new(&APP.config().output).print_report_diff(&diff);
+
+        if !diff.introduced.vulnerabilities.is_empty() {
+            exit(1);
+        }
+    }
+}
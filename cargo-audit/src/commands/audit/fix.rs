@@ -1,14 +1,27 @@
 //! The `cargo audit fix` subcommand
 
-use crate::{auditor::Auditor, lockfile, prelude::*};
+use crate::{
+    auditor::{Auditor, DEFAULT_LOCK_TIMEOUT},
+    commands::CONFIG_FILE,
+    error::{Error, ErrorKind},
+    lockfile,
+    metadata::{cargo_metadata, cargo_path},
+    prelude::*,
+};
 use abscissa_core::{Command, Runnable};
 use cargo_lock::Lockfile;
 use clap::Parser;
-use rustsec::{Fixer, advisory::Id};
+use rustsec::{
+    Blocker, ErrorKind as RustsecErrorKind, Fixer, PreciseUpdate, Vulnerability, advisory::Id,
+    registry::CachedIndex,
+};
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
+    ffi::OsString,
+    fmt,
+    io::{self, BufRead, Write},
     path::{Path, PathBuf},
-    process::exit,
+    process::{self, exit},
 };
 
 #[derive(Command, Clone, Default, Debug, Parser)]
@@ -21,6 +34,37 @@ pub struct FixCommand {
     /// Perform a dry run
     #[arg(long = "dry-run", help = "perform a dry run for the fix")]
     dry_run: bool,
+
+    /// Print the changes a dry run would make as a unified diff
+    #[arg(
+        long = "diff",
+        requires = "dry_run",
+        help = "print the changes to Cargo.toml/Cargo.lock as a unified diff (requires --dry-run)"
+    )]
+    diff: bool,
+
+    /// Ask for confirmation before fixing each vulnerability
+    #[arg(
+        short = 'i',
+        long = "interactive",
+        conflicts_with = "diff",
+        help = "choose whether to apply, skip or ignore the fix for each vulnerability"
+    )]
+    interactive: bool,
+
+    /// Update to the latest patched versions
+    #[arg(
+        long = "latest",
+        help = "update to the latest patched versions rather than the lowest ones"
+    )]
+    latest: bool,
+
+    /// Apply suggested `[patch.crates-io]` entries
+    #[arg(
+        long = "patch",
+        help = "add suggested temporary [patch.crates-io] entries to Cargo.toml for fixes blocked by dependency requirements"
+    )]
+    patch: bool,
 }
 
 impl FixCommand {
@@ -33,6 +77,110 @@ impl FixCommand {
     pub fn cargo_lock_path(&self) -> Option<&Path> {
         self.file.as_deref()
     }
+
+    /// Open the crates.io index used to plan `cargo update --precise` operations
+    fn registry_index(&self) -> Option<CachedIndex> {
+        let config = APP.config();
+        if !config.database.fetch {
+            return None;
+        }
+
+        status_ok!("Updating", "crates.io index");
+        match CachedIndex::fetch(DEFAULT_LOCK_TIMEOUT) {
+            Ok(index) => Some(index),
+            Err(err) => {
+                status_warn!(
+                    "couldn't update crates.io index, falling back to plain `cargo update`: {}",
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    /// Print `[patch.crates-io]` entries replacing the packages which block fixes
+    /// with their upstream repositories, and add them to `Cargo.toml` if requested
+    fn suggest_patches(
+        &self,
+        fixer: &Fixer,
+        lockfile_path: &Path,
+        manifest_path: &Path,
+        blocked: &[(Id, Blocker)],
+        apply: bool,
+    ) -> Result<(), Error> {
+        let repositories = repositories(lockfile_path);
+        let patches: Vec<Patch<'_>> = blocked
+            .iter()
+            .map(|(id, blocker)| Patch {
+                id,
+                blocker,
+                repository: repositories
+                    .get(&(
+                        blocker.package.name.to_string(),
+                        blocker.package.version.to_string(),
+                    ))
+                    .cloned(),
+            })
+            .collect();
+
+        status_warn!(
+            "Some fixes are blocked by the requirements of other packages. Until they publish \
+             releases allowing the patched versions, the following temporary `[patch.crates-io]` \
+             entries can be used to build them from their repositories instead:"
+        );
+        println!("[patch.crates-io]");
+        for patch in &patches {
+            println!("{patch}");
+        }
+
+        if !self.patch || !apply {
+            return Ok(());
+        }
+
+        write_patches(manifest_path, &patches)?;
+        status_ok!(
+            "Patched",
+            "{} in {}",
+            patches
+                .iter()
+                .filter(|patch| patch.repository.is_some())
+                .map(|patch| patch.blocker.package.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            manifest_path.display()
+        );
+
+        for patch in patches.iter().filter(|patch| patch.repository.is_some()) {
+            let command = fixer.get_package_update_command(&patch.blocker.package, false);
+            if let Err(e) = self.run_cargo(command, lockfile_path) {
+                status_warn!(
+                    "Failed to run `cargo update` for package {}: {}",
+                    patch.blocker.package.name,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a `cargo update` command in the directory containing the given `Cargo.lock`,
+    /// so that its `.cargo/config.toml` applies
+    fn run_cargo(
+        &self,
+        mut command: process::Command,
+        lockfile_path: &Path,
+    ) -> io::Result<process::ExitStatus> {
+        // documentation on .current_dir() recommends canonicalizing the path
+        let canonical_path = lockfile_path.canonicalize()?;
+        if let Some(dir) = canonical_path.parent() {
+            command.current_dir(dir);
+        }
+        // When calling `.status()` the stdout and stderr are inherited from the parent,
+        // so any status or error messages from `cargo update` will automatically be forwarded
+        // to the user of `cargo audit fix`.
+        command.status()
+    }
 }
 
 impl Runnable for FixCommand {
@@ -60,41 +208,103 @@ impl Runnable for FixCommand {
         // This should always succeed because the auditor loaded it successfully already
         let lockfile = Lockfile::load(&path).expect("Failed to load Cargo.lock");
 
-        // TODO: allow specifying manifest path
-        let path_to_cargo: Option<PathBuf> = std::env::var_os("CARGO").map(|path| path.into());
-        let fixer = Fixer::new(lockfile, None, path_to_cargo);
-
         let dry_run = self.dry_run;
         if dry_run {
             status_warn!("Performing a dry run, the fixes will not be applied");
         }
 
+        // To produce a diff, the fixes are applied to a copy of the manifests
+        // and lockfile, leaving the workspace untouched.
+        let scratch = self.diff.then(|| {
+            Scratch::new(&path).unwrap_or_else(|e| {
+                status_err!("couldn't copy the workspace to apply the fixes to: {}", e);
+                exit(2);
+            })
+        });
+        let apply = !dry_run || scratch.is_some();
+        let (target_path, manifest_path) = match &scratch {
+            Some(scratch) => (scratch.lockfile_path(), scratch.manifest_path()),
+            None => (path.clone(), path.with_file_name("Cargo.toml")),
+        };
+
+        // TODO: allow specifying manifest path
+        let fixer = Fixer::new(
+            lockfile,
+            scratch.as_ref().map(Scratch::manifest_path),
+            Some(cargo_path()),
+        )
+        .latest(self.latest);
+
         let mut unpatchable_vulns: BTreeSet<Id> = BTreeSet::new();
+        let mut unfixable_vulns: BTreeMap<Id, String> = BTreeMap::new();
+        let mut skipped_vulns: BTreeSet<Id> = BTreeSet::new();
+        let mut ignored_vulns: BTreeMap<Id, Option<String>> = BTreeMap::new();
+        let mut blocked_vulns: Vec<(Id, Blocker)> = vec![];
         let mut failed_patches = 0;
 
-        for vulnerability in &report.vulnerabilities.list {
-            if vulnerability.versions.patched().is_empty() {
-                unpatchable_vulns.insert(vulnerability.advisory.id.clone());
-                status_warn!(
-                    "No patched versions available for {} in crate {}",
-                    vulnerability.advisory.id,
-                    vulnerability.package.name
-                );
-            } else {
-                let mut command = fixer.get_fix_command(vulnerability, dry_run);
-                // If the path to Cargo.lock has been specified explicitly,
-                // run the `cargo update` command in that directory
-                if let Some(path) = self.cargo_lock_path() {
-                    // documentation on .current_dir() recommends canonicalizing the path
-                    let canonical_path = path.canonicalize().unwrap();
-                    let dir = canonical_path.parent().unwrap();
-                    command.current_dir(dir);
+        // Plan the precise updates for every vulnerability up front: the index
+        // holds the Cargo package lock, which `cargo update` needs to acquire.
+        let mut plans = vec![];
+        {
+            let mut index = self.registry_index();
+
+            for vulnerability in &report.vulnerabilities.list {
+                if vulnerability.versions.patched().is_empty() {
+                    unpatchable_vulns.insert(vulnerability.advisory.id.clone());
+                    status_warn!(
+                        "No patched versions available for {} in crate {}",
+                        vulnerability.advisory.id,
+                        vulnerability.package.name
+                    );
+                    continue;
+                }
+
+                let plan = match index.as_mut() {
+                    Some(index) => match fixer.plan_precise_updates(vulnerability, index) {
+                        Ok(updates) => Some(updates),
+                        Err(e) => {
+                            // The fix is blocked by the requirements of other packages,
+                            // which could be lifted with a `[patch]` section
+                            if e.kind() == RustsecErrorKind::Version
+                                && let Ok(blockers) = fixer.find_blockers(vulnerability, index)
+                            {
+                                blocked_vulns.extend(
+                                    blockers
+                                        .into_iter()
+                                        .map(|b| (vulnerability.advisory.id.clone(), b)),
+                                );
+                            }
+
+                            unfixable_vulns
+                                .insert(vulnerability.advisory.id.clone(), e.to_string());
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+
+                plans.push((vulnerability, plan));
+            }
+        }
+
+        for (vulnerability, plan) in plans {
+            if self.interactive {
+                match prompt(&fixer, vulnerability, plan.as_deref()) {
+                    Choice::Apply => (),
+                    Choice::Skip => {
+                        skipped_vulns.insert(vulnerability.advisory.id.clone());
+                        continue;
+                    }
+                    Choice::Ignore(reason) => {
+                        ignored_vulns.insert(vulnerability.advisory.id.clone(), reason);
+                        continue;
+                    }
                 }
-                // When calling `.status()` the stdout and stderr are inherited from the parent,
-                // so any status or error messages from `cargo update` will automatically be forwarded
-                // to the user of `cargo audit fix`.
-                let status = command.status();
-                if let Err(e) = status {
+            }
+
+            let Some(updates) = plan else {
+                let command = fixer.get_fix_command(vulnerability, !apply);
+                if let Err(e) = self.run_cargo(command, &path) {
                     failed_patches += 1;
                     status_warn!(
                         "Failed to run `cargo update` for package {}: {}",
@@ -102,6 +312,94 @@ impl Runnable for FixCommand {
                         e
                     );
                 }
+                continue;
+            };
+
+            for update in updates {
+                if !apply {
+                    status_ok!(
+                        "Would update",
+                        "{} {} -> {} ({})",
+                        update.package.name,
+                        update.package.version,
+                        update.version,
+                        vulnerability.advisory.id
+                    );
+                    continue;
+                }
+
+                // An earlier update may have already moved this package
+                if !is_locked(&target_path, &update) {
+                    continue;
+                }
+
+                let command = fixer.get_precise_update_command(&update, false);
+                match self.run_cargo(command, &path) {
+                    Ok(status) if status.success() => (),
+                    Ok(_) => {
+                        unfixable_vulns.insert(
+                            vulnerability.advisory.id.clone(),
+                            format!(
+                                "`cargo update --precise {}` failed for {} {}",
+                                update.version, update.package.name, update.package.version
+                            ),
+                        );
+                        break;
+                    }
+                    Err(e) => {
+                        failed_patches += 1;
+                        status_warn!(
+                            "Failed to run `cargo update` for package {}: {}",
+                            update.package.name,
+                            e
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+
+        for (id, reason) in &unfixable_vulns {
+            status_warn!("Unable to fix {}: {}", id, reason);
+        }
+
+        if !blocked_vulns.is_empty()
+            && let Err(e) =
+                self.suggest_patches(&fixer, &path, &manifest_path, &blocked_vulns, apply)
+        {
+            status_err!("couldn't update {}: {}", manifest_path.display(), e);
+            exit(2);
+        }
+
+        if !ignored_vulns.is_empty() {
+            let config_path = project_config_path(&path);
+            if dry_run {
+                status_ok!(
+                    "Would ignore",
+                    "{} in {}",
+                    join_ids(ignored_vulns.keys()),
+                    config_path.display()
+                );
+            } else if let Err(e) = write_ignores(&config_path, &ignored_vulns) {
+                status_err!("couldn't update {}: {}", config_path.display(), e);
+                exit(2);
+            } else {
+                status_ok!(
+                    "Ignored",
+                    "{} in {}",
+                    join_ids(ignored_vulns.keys()),
+                    config_path.display()
+                );
+            }
+        }
+
+        if let Some(scratch) = scratch {
+            match scratch.diff(&path) {
+                Ok(diff) => print!("{diff}"),
+                Err(e) => {
+                    status_err!("couldn't compare the fixed files: {}", e);
+                    exit(2);
+                }
             }
         }
 
@@ -110,7 +408,10 @@ impl Runnable for FixCommand {
         }
         if dry_run {
             // When performing a dry run, the exit status is determined by whether we had any issues along the way
-            if !unpatchable_vulns.is_empty() {
+            if !unpatchable_vulns.is_empty()
+                || !unfixable_vulns.is_empty()
+                || !skipped_vulns.is_empty()
+            {
                 exit(1);
             } else {
                 exit(0)
@@ -131,7 +432,12 @@ impl Runnable for FixCommand {
             let vulns_after_fix = &report_after_fix.vulnerabilities.list;
             let fixable_but_unfixed: Vec<String> = vulns_after_fix
                 .iter()
-                .filter(|vuln| !unpatchable_vulns.contains(&vuln.advisory.id))
+                .filter(|vuln| {
+                    !unpatchable_vulns.contains(&vuln.advisory.id)
+                        && !unfixable_vulns.contains_key(&vuln.advisory.id)
+                        && !skipped_vulns.contains(&vuln.advisory.id)
+                        && !ignored_vulns.contains_key(&vuln.advisory.id)
+                })
                 .map(|vuln| vuln.advisory.id.to_string())
                 .collect();
             if !fixable_but_unfixed.is_empty() {
@@ -164,3 +470,641 @@ impl Runnable for FixCommand {
         }
     }
 }
+
+/// Is the package to be updated still present in `Cargo.lock` at its original version?
+fn is_locked(path: &Path, update: &PreciseUpdate) -> bool {
+    Lockfile::load(path)
+        .map(|lockfile| {
+            lockfile.packages.iter().any(|pkg| {
+                pkg.name == update.package.name
+                    && pkg.version == update.package.version
+                    && pkg.source == update.package.source
+            })
+        })
+        .unwrap_or(true)
+}
+
+/// Copy of the manifests and lockfile of a workspace, which the fixes are
+/// applied to in a dry run to print them as a diff.
+///
+/// Only the `Cargo.toml` files of the workspace members are copied, along with
+/// empty stand-ins for the source files `cargo` looks for to find their
+/// targets. Relative paths to dependencies outside of the workspace are made
+/// absolute so they keep resolving. The copy is deleted when dropped.
+struct Scratch {
+    /// Temporary directory mirroring the workspace root
+    dir: tempfile::TempDir,
+
+    /// Name of the lockfile in the workspace root
+    lockfile_name: OsString,
+
+    /// Paths made absolute in the root `Cargo.toml`, with their original values
+    rewritten: BTreeMap<String, toml_edit::Value>,
+}
+
+impl Scratch {
+    /// Copy the workspace containing the given `Cargo.lock` into a temporary directory
+    fn new(lockfile_path: &Path) -> Result<Self, Error> {
+        let lockfile_path = lockfile_path.canonicalize()?;
+        let (Some(root), Some(lockfile_name)) = (lockfile_path.parent(), lockfile_path.file_name())
+        else {
+            return Err(ErrorKind::Io
+                .context(format!(
+                    "invalid lockfile path: {}",
+                    lockfile_path.display()
+                ))
+                .into());
+        };
+
+        // Directories of the workspace members, which are mirrored in the copy
+        let metadata = cargo_metadata(Some(&lockfile_path), true)?;
+        let mut package_dirs: BTreeSet<PathBuf> = metadata["packages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|package| Path::new(package["manifest_path"].as_str()?).parent())
+            .filter(|dir| dir.starts_with(root))
+            .map(Path::to_owned)
+            .collect();
+        package_dirs.insert(root.to_owned());
+
+        let dir = tempfile::Builder::new()
+            .prefix("cargo-audit-fix")
+            .tempdir()?;
+
+        let mut rewritten = BTreeMap::new();
+        for package_dir in &package_dirs {
+            let relative = package_dir.strip_prefix(root).unwrap_or(Path::new(""));
+            let dest = dir.path().join(relative);
+            std::fs::create_dir_all(&dest)?;
+            let paths = copy_manifest(package_dir, &dest, &package_dirs)?;
+            if package_dir == root {
+                rewritten = paths;
+            }
+            stub_sources(&package_dir.join("src"), &dest.join("src"))?;
+        }
+        std::fs::copy(&lockfile_path, dir.path().join(lockfile_name))?;
+
+        Ok(Self {
+            dir,
+            lockfile_name: lockfile_name.to_owned(),
+            rewritten,
+        })
+    }
+
+    /// Path of the root `Cargo.toml` in the copy
+    fn manifest_path(&self) -> PathBuf {
+        self.dir.path().join("Cargo.toml")
+    }
+
+    /// Path of `Cargo.lock` in the copy
+    fn lockfile_path(&self) -> PathBuf {
+        self.dir.path().join(&self.lockfile_name)
+    }
+
+    /// Contents of the root `Cargo.toml` in the copy, with the original paths
+    /// of dependencies put back
+    fn manifest(&self) -> Result<String, Error> {
+        let contents = std::fs::read_to_string(self.manifest_path())?;
+        if self.rewritten.is_empty() {
+            return Ok(contents);
+        }
+
+        let mut doc: toml_edit::DocumentMut =
+            contents.parse().map_err(|e| ErrorKind::Parse.context(e))?;
+        for_each_dependency_path(&mut doc, &mut |path| {
+            if let Some(original) = path.as_str().and_then(|p| self.rewritten.get(p)) {
+                *path = original.clone();
+            }
+        });
+        Ok(doc.to_string())
+    }
+
+    /// Describe the changes made to the copies of `Cargo.toml` and `Cargo.lock`
+    /// as a unified diff against the originals next to `lockfile_path`
+    fn diff(&self, lockfile_path: &Path) -> Result<String, Error> {
+        let mut diff = String::new();
+        let files = [
+            (lockfile_path.with_file_name("Cargo.toml"), self.manifest()?),
+            (
+                lockfile_path.to_owned(),
+                std::fs::read_to_string(self.lockfile_path())?,
+            ),
+        ];
+
+        for (original_path, modified) in files {
+            let original = std::fs::read_to_string(&original_path)?;
+
+            if modified != original {
+                let name = original_path.display().to_string();
+                let patch = diffy::DiffOptions::new()
+                    .set_original_filename(name.clone())
+                    .set_modified_filename(name)
+                    .create_patch(&original, &modified);
+                diff.push_str(&patch.to_string());
+            }
+        }
+
+        Ok(diff)
+    }
+}
+
+/// Copy the `Cargo.toml` in `from` to `to`, making the paths of dependencies
+/// absolute unless they point to one of the copied `package_dirs`.
+///
+/// Returns the paths made absolute, along with their original values.
+fn copy_manifest(
+    from: &Path,
+    to: &Path,
+    package_dirs: &BTreeSet<PathBuf>,
+) -> Result<BTreeMap<String, toml_edit::Value>, Error> {
+    let contents = std::fs::read_to_string(from.join("Cargo.toml"))?;
+    let mut doc: toml_edit::DocumentMut =
+        contents.parse().map_err(|e| ErrorKind::Parse.context(e))?;
+
+    let mut rewritten = BTreeMap::new();
+    for_each_dependency_path(&mut doc, &mut |path| {
+        let Some(relative) = path.as_str().filter(|p| Path::new(p).is_relative()) else {
+            return;
+        };
+        let absolute = from.join(relative);
+        let absolute = absolute.canonicalize().unwrap_or(absolute);
+        if package_dirs.contains(&absolute) {
+            return;
+        }
+
+        let absolute = absolute.display().to_string();
+        let mut value = toml_edit::Value::from(absolute.as_str());
+        *value.decor_mut() = path.decor().clone();
+        rewritten.insert(absolute, std::mem::replace(path, value));
+    });
+
+    std::fs::write(to.join("Cargo.toml"), doc.to_string())?;
+    Ok(rewritten)
+}
+
+/// Call `f` with the `path` of every dependency, patch and replacement in a manifest
+fn for_each_dependency_path(
+    doc: &mut toml_edit::DocumentMut,
+    f: &mut impl FnMut(&mut toml_edit::Value),
+) {
+    let mut visit = |dependencies: Option<&mut toml_edit::Item>| {
+        let Some(dependencies) = dependencies.and_then(|d| d.as_table_like_mut()) else {
+            return;
+        };
+        for (_, dependency) in dependencies.iter_mut() {
+            if let Some(path) = dependency
+                .get_mut("path")
+                .and_then(toml_edit::Item::as_value_mut)
+            {
+                f(path);
+            }
+        }
+    };
+
+    for key in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        visit(doc.get_mut(key));
+        if let Some(targets) = doc.get_mut("target").and_then(|t| t.as_table_like_mut()) {
+            for (_, target) in targets.iter_mut() {
+                visit(target.get_mut(key));
+            }
+        }
+    }
+    visit(
+        doc.get_mut("workspace")
+            .and_then(|workspace| workspace.get_mut("dependencies")),
+    );
+    if let Some(patches) = doc.get_mut("patch").and_then(|p| p.as_table_like_mut()) {
+        for (_, dependencies) in patches.iter_mut() {
+            visit(Some(dependencies));
+        }
+    }
+    visit(doc.get_mut("replace"));
+}
+
+/// Create empty files in `to` for the `.rs` files in `from`, for `cargo` to
+/// find the targets of packages the same way as in the original workspace
+fn stub_sources(from: &Path, to: &Path) -> io::Result<()> {
+    let entries = match std::fs::read_dir(from) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        let dest = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            stub_sources(&path, &dest)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            std::fs::create_dir_all(to)?;
+            std::fs::File::create(dest)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A suggested `[patch.crates-io]` entry
+struct Patch<'a> {
+    /// Advisory the patch works around
+    id: &'a Id,
+
+    /// Package blocking the fix
+    blocker: &'a Blocker,
+
+    /// Upstream repository of the blocking package (if known)
+    repository: Option<String>,
+}
+
+impl Patch<'_> {
+    /// Comment explaining why the patch is there and when it can be removed
+    fn comment(&self) -> String {
+        format!(
+            "# TEMPORARY: works around {} until a release of `{}` compatible with {}\n\
+             # allows `{}` {} (it currently requires {})\n",
+            self.id,
+            self.blocker.package.name,
+            self.blocker.package.version,
+            self.blocker.dependency,
+            self.blocker.patched,
+            self.blocker.requirement
+        )
+    }
+}
+
+impl fmt::Display for Patch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.comment())?;
+        match &self.repository {
+            Some(repository) => write!(
+                f,
+                "{} = {{ git = \"{}\" }}",
+                self.blocker.package.name, repository
+            ),
+            None => write!(
+                f,
+                "# {} = {{ git = \"<repository of {}>\" }}",
+                self.blocker.package.name, self.blocker.package.name
+            ),
+        }
+    }
+}
+
+/// Look up the upstream repositories of the packages in the workspace with `cargo metadata`,
+/// keyed by package name and version
+fn repositories(lockfile_path: &Path) -> BTreeMap<(String, String), String> {
+    let Ok(metadata) = cargo_metadata(Some(lockfile_path), false) else {
+        return BTreeMap::new();
+    };
+
+    metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|package| {
+            Some((
+                (
+                    package["name"].as_str()?.to_owned(),
+                    package["version"].as_str()?.to_owned(),
+                ),
+                package["repository"].as_str()?.to_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Add `[patch.crates-io]` entries for the patches with a known repository to `Cargo.toml`
+fn write_patches(path: &Path, patches: &[Patch<'_>]) -> Result<(), Error> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut doc: toml_edit::DocumentMut =
+        contents.parse().map_err(|e| ErrorKind::Parse.context(e))?;
+
+    let patch_table = doc
+        .entry("patch")
+        .or_insert({
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        })
+        .as_table_like_mut()
+        .and_then(|patch| {
+            patch
+                .entry("crates-io")
+                .or_insert(toml_edit::table())
+                .as_table_mut()
+        })
+        .ok_or_else(|| ErrorKind::Config.context("`patch.crates-io` is not a table"))?;
+
+    for patch in patches {
+        let Some(repository) = &patch.repository else {
+            continue;
+        };
+        let name = patch.blocker.package.name.as_str();
+
+        let mut source = toml_edit::InlineTable::new();
+        source.insert("git", repository.as_str().into());
+        patch_table.insert(name, toml_edit::value(source));
+        if let Some(mut key) = patch_table.key_mut(name) {
+            key.leaf_decor_mut().set_prefix(patch.comment());
+        }
+    }
+
+    std::fs::write(path, doc.to_string())?;
+
+    Ok(())
+}
+
+/// What to do about a vulnerability in interactive mode
+enum Choice {
+    /// Apply the proposed fix
+    Apply,
+
+    /// Leave the vulnerability alone for now
+    Skip,
+
+    /// Add the advisory to the ignore list, with an optional reason
+    Ignore(Option<String>),
+}
+
+/// Describe the proposed fix for a vulnerability and ask what to do about it
+fn prompt(fixer: &Fixer, vulnerability: &Vulnerability, plan: Option<&[PreciseUpdate]>) -> Choice {
+    let package = &vulnerability.package;
+
+    eprintln!();
+    eprintln!(
+        "{} {} {}: {}",
+        vulnerability.advisory.id, package.name, package.version, vulnerability.advisory.title
+    );
+
+    match plan {
+        Some(updates) => {
+            for update in updates {
+                eprintln!(
+                    "  update {} {} -> {}",
+                    update.package.name, update.package.version, update.version
+                );
+            }
+        }
+        None => eprintln!("  run `cargo update -p {}`", package.name),
+    }
+
+    let dependents: Vec<String> = fixer
+        .dependents(package)
+        .map(|pkg| format!("{} {}", pkg.name, pkg.version))
+        .collect();
+    if !dependents.is_empty() {
+        eprintln!("  depended on by: {}", dependents.join(", "));
+    }
+
+    loop {
+        match read_line("Apply this fix? [a]pply/[s]kip/[i]gnore: ").as_deref() {
+            Some("a" | "apply" | "y" | "yes") => return Choice::Apply,
+            Some("s" | "skip" | "n" | "no") | None => return Choice::Skip,
+            Some("i" | "ignore") => {
+                let reason =
+                    read_line("Reason for ignoring (optional): ").filter(|r| !r.is_empty());
+                return Choice::Ignore(reason);
+            }
+            Some(_) => continue,
+        }
+    }
+}
+
+/// Print a prompt and read a trimmed line from stdin, returning `None` on EOF
+fn read_line(prompt: &str) -> Option<String> {
+    eprint!("{prompt}");
+    io::stderr().flush().ok()?;
+
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim().to_owned()),
+    }
+}
+
+/// Add advisories to the `advisories.ignore` list in the given `audit.toml`,
+/// recording the reasons as comments
+fn write_ignores(path: &Path, ignores: &BTreeMap<Id, Option<String>>) -> Result<(), Error> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut doc: toml_edit::DocumentMut =
+        contents.parse().map_err(|e| ErrorKind::Parse.context(e))?;
+
+    let ignore = doc
+        .entry("advisories")
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .and_then(|advisories| {
+            advisories
+                .entry("ignore")
+                .or_insert(toml_edit::value(toml_edit::Array::new()))
+                .as_array_mut()
+        })
+        .ok_or_else(|| ErrorKind::Config.context("`advisories.ignore` is not an array"))?;
+
+    for (id, reason) in ignores {
+        if ignore
+            .iter()
+            .any(|value| value.as_str() == Some(id.as_str()))
+        {
+            continue;
+        }
+
+        let mut value = toml_edit::Value::from(id.as_str());
+        let prefix = match reason {
+            Some(reason) => format!("\n    # {reason}\n    "),
+            None => "\n    ".to_owned(),
+        };
+        value.decor_mut().set_prefix(prefix);
+        ignore.push_formatted(value);
+    }
+    ignore.set_trailing("\n");
+    ignore.set_trailing_comma(true);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, doc.to_string())?;
+
+    Ok(())
+}
+
+/// Location of the project's `.cargo/audit.toml`, next to `Cargo.lock`
+fn project_config_path(lockfile_path: &Path) -> PathBuf {
+    let lockfile_path = lockfile_path
+        .canonicalize()
+        .unwrap_or_else(|_| lockfile_path.to_owned());
+
+    lockfile_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(".cargo")
+        .join(CONFIG_FILE)
+}
+
+/// Format a list of advisory IDs for display
+fn join_ids<'a>(ids: impl Iterator<Item = &'a Id>) -> String {
+    ids.map(Id::as_str).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_patches_adds_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, "[package]\nname = \"app\"\nversion = \"0.1.0\"\n").unwrap();
+
+        let blocker = Blocker {
+            package: cargo_lock::Package {
+                name: "middle".parse().unwrap(),
+                version: "0.3.0".parse().unwrap(),
+                source: Some(cargo_lock::SourceId::default()),
+                checksum: None,
+                dependencies: vec![],
+                replace: None,
+            },
+            dependency: "base".parse().unwrap(),
+            requirement: "^1.2".parse().unwrap(),
+            patched: "2.1.0".parse().unwrap(),
+        };
+        let id: Id = "RUSTSEC-2001-2101".parse().unwrap();
+        let patches = [Patch {
+            id: &id,
+            blocker: &blocker,
+            repository: Some("https://github.com/example/middle".to_owned()),
+        }];
+        write_patches(&path, &patches).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("# TEMPORARY: works around RUSTSEC-2001-2101"));
+
+        let manifest: toml::Table = contents.parse().unwrap();
+        assert_eq!(
+            manifest["patch"]["crates-io"]["middle"]["git"].as_str(),
+            Some("https://github.com/example/middle")
+        );
+    }
+
+    #[test]
+    fn scratch_leaves_workspace_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("app");
+        let write = |path: &str, contents: &str| {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+        let package = |name: &str| format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n");
+
+        let manifest = format!(
+            "{}[workspace]\n\n[dependencies]\nmember = {{ path = \"member\" }}\noutside = {{ path = \"../outside\" }}\n",
+            package("app")
+        );
+        write("app/Cargo.toml", &manifest);
+        write("app/src/main.rs", "fn main() {}\n");
+        write("app/member/Cargo.toml", &package("member"));
+        write("app/member/src/lib.rs", "pub fn f() {}\n");
+        write("outside/Cargo.toml", &package("outside"));
+        write("outside/src/lib.rs", "");
+
+        let status = process::Command::new(cargo_path())
+            .args(["generate-lockfile", "--offline", "--quiet"])
+            .current_dir(&root)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let lockfile_path = root.join("Cargo.lock");
+        let lockfile = std::fs::read_to_string(&lockfile_path).unwrap();
+
+        let scratch = Scratch::new(&lockfile_path).unwrap();
+        assert!(scratch.dir.path().join("member/Cargo.toml").is_file());
+        assert!(scratch.dir.path().join("member/src/lib.rs").is_file());
+        assert!(!scratch.dir.path().join("outside").exists());
+        let copy = std::fs::read_to_string(scratch.manifest_path()).unwrap();
+        let outside = dir.path().join("outside").canonicalize().unwrap();
+        assert!(copy.contains(&format!("path = \"{}\"", outside.display())));
+        assert_eq!(scratch.diff(&lockfile_path).unwrap(), "");
+
+        // `cargo update` works on the copy, without touching the workspace
+        let status = process::Command::new(cargo_path())
+            .args(["update", "--offline", "--quiet", "--manifest-path"])
+            .arg(scratch.manifest_path())
+            .current_dir(&root)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        std::fs::write(scratch.lockfile_path(), format!("{lockfile}# updated\n")).unwrap();
+        let diff = scratch.diff(&lockfile_path).unwrap();
+
+        let name = lockfile_path.canonicalize().unwrap();
+        let name = name.display();
+        assert!(diff.starts_with(&format!("--- {name}\n+++ {name}\n")));
+        assert!(diff.contains("+# updated\n"));
+        assert_eq!(std::fs::read_to_string(&lockfile_path).unwrap(), lockfile);
+        assert_eq!(
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn project_config_next_to_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let lockfile_path = dir.path().join("Cargo.lock");
+        std::fs::write(&lockfile_path, "version = 4\n").unwrap();
+
+        assert_eq!(
+            project_config_path(&lockfile_path),
+            dir.path()
+                .canonicalize()
+                .unwrap()
+                .join(".cargo")
+                .join(CONFIG_FILE)
+        );
+    }
+
+    #[test]
+    fn write_ignores_preserves_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".cargo").join(CONFIG_FILE);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &path,
+            "# project config\n[advisories]\nignore = [\"RUSTSEC-2017-0001\"]\n",
+        )
+        .unwrap();
+
+        let ignores = BTreeMap::from([
+            (
+                "RUSTSEC-2020-0071".parse().unwrap(),
+                Some("not reachable".to_owned()),
+            ),
+            ("RUSTSEC-2017-0001".parse().unwrap(), None),
+        ]);
+        write_ignores(&path, &ignores).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("# project config\n"));
+        assert!(contents.contains("# not reachable\n"));
+
+        let config: toml::Table = contents.parse().unwrap();
+        assert_eq!(
+            config["advisories"]["ignore"].as_array().unwrap(),
+            &vec![
+                toml::Value::from("RUSTSEC-2017-0001"),
+                toml::Value::from("RUSTSEC-2020-0071"),
+            ]
+        );
+    }
+}
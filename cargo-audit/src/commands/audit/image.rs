@@ -0,0 +1,130 @@
+//! The `cargo audit image` subcommand
+
+use crate::{auditor::Auditor, error::display_err_with_source, prelude::*};
+use clap::Parser;
+use rustsec::Version;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, exit},
+};
+
+/// Container engines which can export images, in order of preference
+const CONTAINER_ENGINES: &[&str] = &["docker", "podman"];
+
+/// The `cargo audit image` subcommand
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command()]
+pub struct ImageCommand {
+    /// Maximum binary size in bytes to read
+    #[arg(
+        long = "max-binary-size",
+        value_name = "BYTES",
+        help = "Maximum binary size in bytes to read (default: 100MB; use 0 for unlimited)"
+    )]
+    max_binary_size: Option<u64>,
+
+    /// Maximum audit data size in bytes to parse
+    #[arg(
+        long = "audit-data-size-limit",
+        value_name = "BYTES",
+        help = "Maximum audit data size in bytes to parse (default: 8MB)"
+    )]
+    audit_data_size_limit: Option<usize>,
+
+    /// Oldest acceptable version of the Rust compiler
+    #[arg(
+        long = "min-rustc-version",
+        value_name = "VERSION",
+        help = "Warn about binaries built with a Rust compiler older than VERSION"
+    )]
+    min_rustc_version: Option<Version>,
+
+    /// Images to be scanned
+    #[arg(
+        value_parser,
+        required = true,
+        help = "Image tarballs (as written by `docker save`) or references to images \
+                available to docker or podman"
+    )]
+    images: Vec<String>,
+}
+
+impl Runnable for ImageCommand {
+    fn run(&self) {
+        let mut auditor = Auditor::new(&APP.config());
+        auditor.set_binary_scan_limits(self.max_binary_size, self.audit_data_size_limit);
+        auditor.set_min_rustc_version(self.min_rustc_version.clone());
+
+        let mut vulnerabilities_found = false;
+        let mut errors_encountered = false;
+
+        for image in &self.images {
+            let name = Path::new(image);
+
+            let result = if name.is_file() {
+                auditor.audit_image(name, name)
+            } else {
+                export_image(image).and_then(|tarball| {
+                    let result = auditor.audit_image(&tarball, name);
+                    let _ = fs::remove_file(&tarball);
+                    result
+                })
+            };
+
+            match result {
+                Ok(report) => vulnerabilities_found |= auditor.should_exit_with_failure(&report),
+                Err(e) => {
+                    status_err!("{}", display_err_with_source(&e));
+                    errors_encountered = true;
+                }
+            }
+        }
+
+        if vulnerabilities_found {
+            exit(1)
+        } else if errors_encountered {
+            exit(2)
+        } else {
+            exit(0)
+        }
+    }
+}
+
+/// Export an image to a temporary tarball with the first available container engine
+fn export_image(image: &str) -> rustsec::Result<PathBuf> {
+    let tarball = std::env::temp_dir().join(format!(
+        "cargo-audit-image-{}-{}.tar",
+        process::id(),
+        image.replace(['/', ':', '@'], "_")
+    ));
+
+    for engine in CONTAINER_ENGINES {
+        let status = process::Command::new(engine)
+            .args(["image", "save", "--output"])
+            .arg(&tarball)
+            .arg(image)
+            .stdout(process::Stdio::null())
+            .status();
+
+        match status {
+            Ok(status) if status.success() => return Ok(tarball),
+            Ok(status) => {
+                let _ = fs::remove_file(&tarball);
+                return Err(rustsec::Error::new(
+                    rustsec::ErrorKind::NotFound,
+                    format!("`{engine} image save {image}` failed ({status})"),
+                ));
+            }
+            // Engine isn't installed, try the next one
+            Err(_) => continue,
+        }
+    }
+
+    Err(rustsec::Error::new(
+        rustsec::ErrorKind::NotFound,
+        format!(
+            "no image tarball at {image}, and neither docker nor podman is available to export it"
+        ),
+    ))
+}
@@ -0,0 +1,115 @@
+//! The `cargo audit proc` subcommand
+
+use crate::{
+    auditor::Auditor, config::OutputFormat, error::display_err_with_source, prelude::*, process,
+};
+use clap::Parser;
+use rustsec::Version;
+use std::process::exit;
+
+/// The `cargo audit proc` subcommand
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command()]
+pub struct ProcCommand {
+    /// Scan all running processes
+    #[arg(
+        long = "all",
+        conflicts_with = "pids",
+        help = "Scan the executables of all running processes"
+    )]
+    all: bool,
+
+    /// Maximum binary size in bytes to read
+    #[arg(
+        long = "max-binary-size",
+        value_name = "BYTES",
+        help = "Maximum binary size in bytes to read (default: 100MB; use 0 for unlimited)"
+    )]
+    max_binary_size: Option<u64>,
+
+    /// Maximum audit data size in bytes to parse
+    #[arg(
+        long = "audit-data-size-limit",
+        value_name = "BYTES",
+        help = "Maximum audit data size in bytes to parse (default: 8MB)"
+    )]
+    audit_data_size_limit: Option<usize>,
+
+    /// Number of executables to scan concurrently
+    #[arg(
+        short = 'j',
+        long = "jobs",
+        value_name = "N",
+        help = "Number of executables to scan concurrently (default: number of CPUs)"
+    )]
+    jobs: Option<usize>,
+
+    /// Oldest acceptable version of the Rust compiler
+    #[arg(
+        long = "min-rustc-version",
+        value_name = "VERSION",
+        help = "Warn about binaries built with a Rust compiler older than VERSION"
+    )]
+    min_rustc_version: Option<Version>,
+
+    /// Merge the reports of all binaries
+    #[arg(
+        long = "merge",
+        help = "Print a single report for all binaries, listing the binaries each finding applies to"
+    )]
+    merge: bool,
+
+    /// IDs of the processes to be scanned
+    #[arg(
+        value_name = "PID",
+        required_unless_present = "all",
+        help = "IDs of the processes whose executables are scanned"
+    )]
+    pids: Vec<u32>,
+}
+
+impl Runnable for ProcCommand {
+    fn run(&self) {
+        if self.merge
+            && !matches!(
+                APP.config().output.format,
+                OutputFormat::Json | OutputFormat::Terminal
+            )
+        {
+            status_err!("--merge is only supported with terminal and JSON output");
+            exit(2);
+        }
+
+        let (executables, inaccessible) =
+            process::running_executables(&self.pids).unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(2);
+            });
+
+        if inaccessible > 0 {
+            status_warn!(
+                "couldn't inspect {} processes (permission denied), try running as root",
+                inaccessible
+            );
+        }
+
+        if executables.is_empty() {
+            status_err!("no executables found to scan");
+            exit(2);
+        }
+
+        let mut auditor = Auditor::new(&APP.config());
+        auditor.set_binary_scan_limits(self.max_binary_size, self.audit_data_size_limit);
+        auditor.set_binary_scan_jobs(self.jobs);
+        auditor.set_min_rustc_version(self.min_rustc_version.clone());
+        auditor.set_merge_reports(self.merge);
+        let report = auditor.audit_processes(&executables, self.all);
+        if report.vulnerabilities_found {
+            exit(1)
+        } else if report.errors_encountered {
+            exit(2)
+        } else {
+            exit(0)
+        }
+    }
+}
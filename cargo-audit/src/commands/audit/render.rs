@@ -0,0 +1,72 @@
+//! The `cargo audit render` subcommand
+
+use crate::{
+    baseline, config::OutputFormat, error::display_err_with_source, prelude::*,
+    presenter::Presenter,
+};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use rustsec::{Lockfile, cargo_lock::ResolveVersion};
+use std::{path::PathBuf, process::exit};
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct RenderCommand {
+    /// Saved report
+    #[arg(
+        value_name = "REPORT",
+        help = "JSON report saved by `cargo audit --json`"
+    )]
+    report: PathBuf,
+
+    /// Output format
+    #[arg(
+        long = "format",
+        value_name = "FORMAT",
+        help = "Output format: terminal, json, sarif, markdown, or metrics"
+    )]
+    output_format: Option<OutputFormat>,
+
+    /// Lockfile the report was generated from
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILE",
+        help = "Cargo lockfile the report was generated from (to show dependency trees)"
+    )]
+    file: Option<PathBuf>,
+}
+
+impl Runnable for RenderCommand {
+    fn run(&self) {
+        let report = baseline::load_report(&self.report).unwrap_or_else(|e| {
+            status_err!("{}", display_err_with_source(&e));
+            exit(2);
+        });
+
+        let mut config = APP.config().output.clone();
+        if let Some(format) = self.output_format {
+            config.format = format;
+        }
+
+        let lockfile = match &self.file {
+            Some(path) => Lockfile::load(path).unwrap_or_else(|e| {
+                status_err!("couldn't load {}: {}", path.display(), e);
+                exit(2);
+            }),
+            None => {
+                // Dependency trees can't be shown without the lockfile
+                config.show_tree = false;
+                Lockfile {
+                    version: ResolveVersion::default(),
+                    packages: vec![],
+                    root: None,
+                    metadata: Default::default(),
+                    patch: Default::default(),
+                }
+            }
+        };
+
+        Presenter::new(&config).print_report(&report, &lockfile, self.file.as_deref());
+    }
+}
@@ -0,0 +1,169 @@
+//! The `cargo audit search` subcommand
+
+use crate::{auditor, prelude::*};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use rustsec::{
+    Advisory,
+    advisory::{Category, Date, Keyword, Severity},
+    database::Query,
+    package,
+};
+use std::process::exit;
+
+/// Severity of advisories without a CVSS score
+const UNKNOWN_SEVERITY: &str = "-";
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct SearchCommand {
+    /// Name of the crate to find advisories for
+    #[arg(
+        long = "crate",
+        value_name = "NAME",
+        help = "only show advisories for this crate"
+    )]
+    krate: Option<package::Name>,
+
+    /// Keywords the advisories must have one of
+    #[arg(
+        long = "keyword",
+        value_name = "KEYWORD",
+        help = "only show advisories with this keyword (may be specified multiple times)"
+    )]
+    keywords: Vec<Keyword>,
+
+    /// Categories the advisories must be in one of
+    #[arg(
+        long = "category",
+        value_name = "CATEGORY",
+        help = "only show advisories in this category, e.g. memory-corruption (may be specified multiple times)"
+    )]
+    categories: Vec<Category>,
+
+    /// Minimum severity
+    #[arg(
+        long = "min-severity",
+        value_name = "SEVERITY",
+        help = "only show advisories with at least this severity (none, low, medium, high, critical)"
+    )]
+    min_severity: Option<Severity>,
+
+    /// Maximum severity
+    #[arg(
+        long = "max-severity",
+        value_name = "SEVERITY",
+        help = "only show advisories with at most this severity (none, low, medium, high, critical)"
+    )]
+    max_severity: Option<Severity>,
+
+    /// Earliest advisory date
+    #[arg(
+        long = "since",
+        value_name = "DATE",
+        help = "only show advisories published on or after this date (YYYY-MM-DD)"
+    )]
+    since: Option<Date>,
+
+    /// Latest advisory date
+    #[arg(
+        long = "until",
+        value_name = "DATE",
+        help = "only show advisories published on or before this date (YYYY-MM-DD)"
+    )]
+    until: Option<Date>,
+
+    /// Include withdrawn advisories
+    #[arg(long = "withdrawn", help = "include withdrawn advisories")]
+    withdrawn: bool,
+}
+
+impl SearchCommand {
+    /// Database query for the filters it supports
+    fn query(&self) -> Query {
+        let mut query = Query::new()
+            .keywords(self.keywords.clone())
+            .categories(self.categories.clone());
+
+        if let Some(krate) = &self.krate {
+            query = query.package_name(krate.clone());
+        }
+        if !self.withdrawn {
+            query = query.withdrawn(false);
+        }
+
+        query
+    }
+
+    /// Does the given advisory match the filters a [`Query`] can't express?
+    ///
+    /// Unlike [`Query::severity`], advisories without a severity never match
+    /// a severity range.
+    fn matches(&self, advisory: &Advisory) -> bool {
+        let metadata = &advisory.metadata;
+
+        if self.min_severity.is_some() || self.max_severity.is_some() {
+            let Some(severity) = advisory.severity() else {
+                return false;
+            };
+
+            if self.min_severity.is_some_and(|min| severity < min)
+                || self.max_severity.is_some_and(|max| severity > max)
+            {
+                return false;
+            }
+        }
+
+        if self
+            .since
+            .as_ref()
+            .is_some_and(|since| &metadata.date < since)
+            || self
+                .until
+                .as_ref()
+                .is_some_and(|until| &metadata.date > until)
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl Runnable for SearchCommand {
+    fn run(&self) {
+        let database = auditor::load_database(&APP.config());
+
+        let mut advisories: Vec<&Advisory> = database
+            .query(&self.query())
+            .into_iter()
+            .filter(|advisory| self.matches(advisory))
+            .collect();
+        advisories.sort_by(|a, b| {
+            b.metadata
+                .date
+                .cmp(&a.metadata.date)
+                .then_with(|| a.metadata.id.cmp(&b.metadata.id))
+        });
+
+        if advisories.is_empty() {
+            status_warn!("no matching advisories found");
+            exit(1);
+        }
+
+        for advisory in &advisories {
+            let metadata = &advisory.metadata;
+            let severity = advisory
+                .severity()
+                .map(|severity| severity.to_string())
+                .unwrap_or_else(|| UNKNOWN_SEVERITY.to_owned());
+
+            println!(
+                "{:<18} {} {:<8} {:<24} {}",
+                metadata.id, metadata.date, severity, metadata.package, metadata.title
+            );
+        }
+
+        status_ok!("Found", "{} matching advisories", advisories.len());
+    }
+}
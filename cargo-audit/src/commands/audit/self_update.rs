@@ -0,0 +1,267 @@
+//! The `cargo audit self` subcommand
+
+use crate::{
+    auditor::DEFAULT_LOCK_TIMEOUT, error::display_err_with_source, metadata::cargo_path, prelude::*,
+};
+use abscissa_core::{Command, Runnable};
+use clap::{Parser, Subcommand};
+use rustsec::{Version, registry::CachedIndex};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+    process::exit,
+};
+
+/// Name of this crate on crates.io
+const CRATE_NAME: &str = "cargo-audit";
+
+#[derive(Command, Clone, Debug, Parser)]
+#[command(author, version, about)]
+pub struct SelfCommand {
+    #[command(subcommand)]
+    subcommand: SelfSubcommand,
+}
+
+impl Runnable for SelfCommand {
+    fn run(&self) {
+        self.subcommand.run()
+    }
+}
+
+/// Subcommands of `cargo audit self`
+#[derive(Subcommand, Clone, Debug, Runnable)]
+pub enum SelfSubcommand {
+    /// `cargo audit self update` subcommand
+    #[command(about = "update cargo-audit to the latest release")]
+    Update(UpdateCommand),
+}
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct UpdateCommand {
+    /// Only print the command which would perform the update
+    #[arg(
+        long = "print",
+        help = "print the command to install the latest release instead of running it"
+    )]
+    print: bool,
+}
+
+impl UpdateCommand {
+    /// Find the latest stable, unyanked release of cargo-audit on crates.io
+    fn latest_version() -> rustsec::Result<Option<Version>> {
+        let mut index = CachedIndex::fetch(DEFAULT_LOCK_TIMEOUT)?;
+        let name = CRATE_NAME.parse()?;
+
+        Ok(index
+            .published_versions(&name)?
+            .into_iter()
+            .filter(|v| !v.yanked && v.version.pre.is_empty())
+            .map(|v| v.version)
+            .max())
+    }
+
+    /// Command installing the given version with the features this binary was built with.
+    ///
+    /// Cargo verifies the downloaded package against the checksum in the crates.io index.
+    fn install_command(version: &Version) -> process::Command {
+        let mut command = process::Command::new(cargo_path());
+        command
+            .args(["install", "--locked", CRATE_NAME, "--version"])
+            .arg(version.to_string());
+
+        if !cfg!(feature = "binary-scanning") {
+            command.arg("--no-default-features");
+        }
+        if cfg!(feature = "fix") {
+            command.args(["--features", "fix"]);
+        }
+
+        command
+    }
+
+    /// Directory `cargo install` puts binaries into
+    fn cargo_bin_dir() -> Option<PathBuf> {
+        match env::var_os("CARGO_INSTALL_ROOT") {
+            Some(root) => Some(PathBuf::from(root)),
+            None => home::cargo_home().ok(),
+        }
+        .map(|root| root.join("bin"))
+    }
+
+    /// Is the executable at `exe` located directly in `bin_dir`?
+    fn is_installed_in(exe: &Path, bin_dir: &Path) -> bool {
+        let (Ok(exe), Ok(bin_dir)) = (exe.canonicalize(), bin_dir.canonicalize()) else {
+            return false;
+        };
+
+        exe.parent() == Some(bin_dir.as_path())
+    }
+
+    /// Version reported by the `--version` output of the executable at `exe`
+    fn installed_version(exe: &Path) -> Option<Version> {
+        let output = process::Command::new(exe).arg("--version").output().ok()?;
+        let stdout = String::from_utf8(output.stdout).ok()?;
+
+        stdout.trim().strip_prefix(CRATE_NAME)?.trim().parse().ok()
+    }
+}
+
+impl Runnable for UpdateCommand {
+    fn run(&self) {
+        let current: Version = crate::VERSION.parse().expect("invalid crate version");
+
+        status_ok!(
+            "Checking",
+            "crates.io for the latest release of {}",
+            CRATE_NAME
+        );
+        let latest = match Self::latest_version() {
+            Ok(Some(latest)) => latest,
+            Ok(None) => {
+                status_err!("no releases of {} found on crates.io", CRATE_NAME);
+                exit(2);
+            }
+            Err(e) => {
+                status_err!(
+                    "couldn't check for the latest release: {}",
+                    display_err_with_source(&e)
+                );
+                exit(2);
+            }
+        };
+
+        if latest <= current {
+            status_ok!(
+                "Up-to-date",
+                "{} {} is the latest release",
+                CRATE_NAME,
+                current
+            );
+            return;
+        }
+
+        let mut command = Self::install_command(&latest);
+        let command_line = format!(
+            "{} {}",
+            command.get_program().to_string_lossy(),
+            command
+                .get_args()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+
+        if self.print {
+            status_ok!(
+                "Available",
+                "{} {} (current: {})",
+                CRATE_NAME,
+                latest,
+                current
+            );
+            println!("{command_line}");
+            return;
+        }
+
+        // `cargo install` can only replace a binary which lives in its bin directory;
+        // anything else (a distro package, a manual copy) would keep running the old version
+        let exe = match env::current_exe() {
+            Ok(exe) => exe,
+            Err(e) => {
+                status_err!(
+                    "couldn't locate the running executable: {}; to update manually, run: {}",
+                    e,
+                    command_line
+                );
+                exit(2);
+            }
+        };
+        let bin_dir = Self::cargo_bin_dir();
+        if !bin_dir
+            .as_deref()
+            .is_some_and(|bin_dir| Self::is_installed_in(&exe, bin_dir))
+        {
+            status_err!(
+                "{} is not installed in cargo's bin directory ({}), so it can't be updated by cargo; \
+                 update it the way it was installed, or run: {}",
+                exe.display(),
+                bin_dir.as_deref().unwrap_or(Path::new("unknown")).display(),
+                command_line
+            );
+            exit(2);
+        }
+
+        status_ok!("Updating", "{} {} -> {}", CRATE_NAME, current, latest);
+        match command.status() {
+            Ok(status) if status.success() => match Self::installed_version(&exe) {
+                Some(installed) if installed == latest => {
+                    status_ok!("Updated", "{} to {}", CRATE_NAME, latest);
+                }
+                Some(installed) => {
+                    status_err!(
+                        "{} still reports version {} after installing {}",
+                        exe.display(),
+                        installed,
+                        latest
+                    );
+                    exit(2);
+                }
+                None => {
+                    status_err!(
+                        "couldn't confirm the update: `{} --version` failed",
+                        exe.display()
+                    );
+                    exit(2);
+                }
+            },
+            Ok(_) => {
+                status_err!("update failed; to update manually, run: {}", command_line);
+                exit(2);
+            }
+            Err(e) => {
+                status_err!(
+                    "couldn't run `{}`: {}; to update manually, run: {}",
+                    command.get_program().to_string_lossy(),
+                    e,
+                    command_line
+                );
+                exit(2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn installed_in_bin_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin_dir = dir.path().join("bin");
+        let other_dir = dir.path().join("other");
+        std::fs::create_dir_all(&bin_dir).unwrap();
+        std::fs::create_dir_all(&other_dir).unwrap();
+        std::fs::write(bin_dir.join(CRATE_NAME), "").unwrap();
+        std::fs::write(other_dir.join(CRATE_NAME), "").unwrap();
+
+        assert!(UpdateCommand::is_installed_in(
+            &bin_dir.join(CRATE_NAME),
+            &bin_dir
+        ));
+        assert!(UpdateCommand::is_installed_in(
+            &dir.path().join("bin/../bin").join(CRATE_NAME),
+            &bin_dir
+        ));
+        assert!(!UpdateCommand::is_installed_in(
+            &other_dir.join(CRATE_NAME),
+            &bin_dir
+        ));
+        assert!(!UpdateCommand::is_installed_in(
+            &bin_dir.join("missing"),
+            &bin_dir
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+//! The `cargo audit show` subcommand
+
+use crate::{auditor, prelude::*, presenter::Presenter};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use rustsec::advisory::Id;
+use std::process::exit;
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct ShowCommand {
+    /// Advisory to show
+    #[arg(
+        value_name = "ADVISORY_ID",
+        help = "ID of the advisory to show (RUSTSEC, CVE or GHSA ID)"
+    )]
+    id: String,
+}
+
+impl Runnable for ShowCommand {
+    fn run(&self) {
+        let id: Id = self.id.parse().unwrap_or_else(|e| {
+            status_err!("invalid advisory ID '{}': {}", self.id, e);
+            exit(2);
+        });
+
+        let config = APP.config();
+        let database = auditor::load_database(&config);
+
+        // Also find advisories by any of the other IDs they are known by
+        let advisory = database.get(&id).or_else(|| {
+            database
+                .iter()
+                .find(|advisory| advisory.metadata.aliases.contains(&id))
+        });
+
+        let Some(advisory) = advisory else {
+            status_err!("no advisory with ID {} in the advisory database", id);
+            exit(1);
+        };
+
+        Presenter::new(&config.output).print_advisory(advisory);
+    }
+}
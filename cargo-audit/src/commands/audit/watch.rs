@@ -0,0 +1,119 @@
+//! Watch mode (`cargo audit --watch`)
+//!
+//! Keeps re-running the audit whenever `Cargo.lock` changes, and periodically
+//! fetches the advisory database, printing only the findings which were
+//! introduced or resolved since the previous run.
+
+use crate::{
+    auditor::Auditor, baseline::ReportDiff, error::display_err_with_source, notify::Notifier,
+    prelude::*, presenter::Presenter,
+};
+use std::{
+    path::Path,
+    process::exit,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// How often `Cargo.lock` is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Audit the given lockfile whenever it or the advisory database changes.
+///
+/// The advisory database is fetched every `fetch_interval`.
+pub fn watch(lockfile_path: &Path, fetch_interval: Duration) -> ! {
+    if lockfile_path == Path::new("-") {
+        status_err!("`--watch` can't be used when reading Cargo.lock from STDIN");
+        exit(2);
+    }
+
+    let config = APP.config();
+    let presenter = Presenter::new(&config.output);
+    let notifier = Notifier::new(&config.notify);
+
+    // The auditor holds the Cargo package lock while it exists, so a new
+    // one is created for each run rather than blocking `cargo` in between.
+    let mut previous = Auditor::new(&config)
+        .audit_lockfile(lockfile_path)
+        .unwrap_or_else(|e| {
+            status_err!("{}", display_err_with_source(&e));
+            exit(2);
+        });
+
+    let mut config = (*config).clone();
+    config.output.quiet = true;
+    let fetch = config.database.fetch;
+
+    let mut last_modified = modified(lockfile_path);
+    let mut last_fetch = Instant::now();
+
+    status_ok!(
+        "Watching",
+        "{} for changes (fetching advisories every {} seconds)",
+        lockfile_path.display(),
+        fetch_interval.as_secs()
+    );
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let modified = modified(lockfile_path);
+        let lockfile_changed = modified != last_modified;
+        let fetch_due = fetch && last_fetch.elapsed() >= fetch_interval;
+
+        if !lockfile_changed && !fetch_due {
+            continue;
+        }
+
+        last_modified = modified;
+        if fetch_due {
+            last_fetch = Instant::now();
+        }
+
+        config.database.fetch = fetch_due;
+        let mut auditor = match Auditor::try_new(&config) {
+            Ok(auditor) => auditor,
+            Err(e) => {
+                // e.g. the advisory database couldn't be fetched because of a
+                // network error: try again on the next change or fetch
+                status_warn!("{}", display_err_with_source(&e));
+                continue;
+            }
+        };
+        let report = match auditor.report_lockfile(lockfile_path) {
+            Ok(report) => report,
+            Err(e) => {
+                // e.g. `Cargo.lock` is in the middle of being rewritten
+                status_warn!("{}", display_err_with_source(&e));
+                continue;
+            }
+        };
+
+        let diff = ReportDiff::new(&previous, &report);
+        if !diff.introduced.is_empty() || !diff.resolved.is_empty() {
+            status_ok!(
+                "Changed",
+                "{}",
+                if lockfile_changed {
+                    "Cargo.lock was updated"
+                } else {
+                    "the advisory database was updated"
+                }
+            );
+            presenter.print_report_diff(&diff);
+
+            if let Some(notifier) = &notifier
+                && let Err(e) = notifier.notify(lockfile_path, &diff.introduced.vulnerabilities)
+            {
+                status_warn!("{}", display_err_with_source(&e));
+            }
+        }
+
+        previous = report;
+    }
+}
+
+/// Get the modification time of the given file (if available)
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,237 @@
+//! The `cargo audit why` subcommand
+
+use crate::{
+    auditor, error::display_err_with_source, lockfile, metadata::cargo_metadata, prelude::*,
+};
+use abscissa_core::{Command, Runnable};
+use clap::Parser;
+use rustsec::{
+    Lockfile,
+    advisory::Id,
+    cargo_lock::{
+        Package,
+        dependency::{
+            Dependency,
+            graph::{EdgeDirection, Graph, NodeIndex},
+        },
+    },
+};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Maximum number of dependency paths to print for each package
+const MAX_PATHS: usize = 100;
+
+/// Version requirements on dependencies, keyed by dependent and dependency name
+type Requirements = BTreeMap<(String, String), BTreeMap<String, Vec<String>>>;
+
+#[derive(Command, Clone, Default, Debug, Parser)]
+#[command(author, version, about)]
+pub struct WhyCommand {
+    /// Advisory ID or crate name
+    #[arg(
+        value_name = "ADVISORY_ID|CRATE",
+        help = "advisory ID or name of the crate to explain"
+    )]
+    target: String,
+
+    /// Path to `Cargo.lock`
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILE",
+        help = "Cargo lockfile to inspect (or `-` for STDIN)"
+    )]
+    file: Option<PathBuf>,
+}
+
+impl Runnable for WhyCommand {
+    fn run(&self) {
+        let path = lockfile::locate_or_generate(self.file.as_deref()).unwrap_or_else(|e| {
+            status_err!("{}", display_err_with_source(&e));
+            exit(2);
+        });
+
+        let lockfile = Lockfile::load(&path).unwrap_or_else(|e| {
+            status_err!("couldn't load {}: {}", path.display(), e);
+            exit(2);
+        });
+
+        let packages = self.affected_packages(&lockfile);
+        if packages.is_empty() {
+            status_ok!(
+                "Success",
+                "{} doesn't affect any dependencies in {}",
+                self.target,
+                path.display()
+            );
+            return;
+        }
+
+        let tree = lockfile.dependency_tree().unwrap_or_else(|e| {
+            status_err!("invalid Cargo.lock dependency tree: {}", e);
+            exit(2);
+        });
+        let requirements = requirements(&path);
+        let nodes = tree.nodes();
+        let graph = tree.graph();
+
+        for package in packages {
+            let node = nodes[&Dependency::from(package)];
+            let paths = paths_to(graph, node);
+
+            status_ok!(
+                "Found",
+                "{} {} ({} dependency {})",
+                package.name,
+                package.version,
+                paths.len(),
+                if paths.len() == 1 { "path" } else { "paths" }
+            );
+
+            for path in &paths {
+                println!();
+                for (depth, hop) in path.iter().enumerate() {
+                    let package = &graph[*hop];
+
+                    if depth == 0 {
+                        println!("{} {}", package.name, package.version);
+                        continue;
+                    }
+
+                    let dependent = &graph[path[depth - 1]];
+                    let req = requirements
+                        .get(&(dependent.name.to_string(), dependent.version.to_string()))
+                        .and_then(|deps| deps.get(package.name.as_str()))
+                        .map(|reqs| format!(" ({} = \"{}\")", package.name, reqs.join("\", \"")))
+                        .unwrap_or_default();
+
+                    println!(
+                        "{}└── {} {}{}",
+                        "    ".repeat(depth - 1),
+                        package.name,
+                        package.version,
+                        req
+                    );
+                }
+            }
+
+            if paths.len() >= MAX_PATHS {
+                println!();
+                status_warn!("only showing the first {} paths", MAX_PATHS);
+            }
+
+            println!();
+        }
+    }
+}
+
+impl WhyCommand {
+    /// Find the packages in the lockfile the target refers to
+    fn affected_packages<'a>(&self, lockfile: &'a Lockfile) -> Vec<&'a Package> {
+        // Anything which isn't a known kind of advisory ID is a crate name
+        let Some(id) = self.target.parse::<Id>().ok().filter(|id| !id.is_other()) else {
+            return lockfile
+                .packages
+                .iter()
+                .filter(|package| package.name.as_str() == self.target)
+                .collect();
+        };
+
+        let database = auditor::load_database(&APP.config());
+        let advisory = database.get(&id).or_else(|| {
+            database
+                .iter()
+                .find(|advisory| advisory.metadata.aliases.contains(&id))
+        });
+
+        let Some(advisory) = advisory else {
+            status_err!("no advisory with ID {} in the advisory database", id);
+            exit(1);
+        };
+
+        lockfile
+            .packages
+            .iter()
+            .filter(|package| {
+                package.name == advisory.metadata.package
+                    && advisory.versions.is_vulnerable(&package.version)
+            })
+            .collect()
+    }
+}
+
+/// Find the dependency paths from the workspace roots to the given node
+fn paths_to(graph: &Graph, node: NodeIndex) -> Vec<Vec<NodeIndex>> {
+    let mut paths = vec![];
+    let mut path = vec![node];
+    walk(graph, &mut path, &mut paths);
+    paths
+}
+
+/// Walk the dependents of the last node in `path`, recording complete paths
+fn walk(graph: &Graph, path: &mut Vec<NodeIndex>, paths: &mut Vec<Vec<NodeIndex>>) {
+    if paths.len() >= MAX_PATHS {
+        return;
+    }
+
+    let node = *path.last().expect("path is never empty");
+    let mut dependents = graph
+        .neighbors_directed(node, EdgeDirection::Incoming)
+        .peekable();
+
+    if dependents.peek().is_none() {
+        paths.push(path.iter().rev().copied().collect());
+        return;
+    }
+
+    for dependent in dependents {
+        // Avoid cycles (e.g. through dev-dependencies)
+        if path.contains(&dependent) {
+            continue;
+        }
+
+        path.push(dependent);
+        walk(graph, path, paths);
+        path.pop();
+    }
+}
+
+/// Look up the version requirements each package has on its dependencies
+/// with `cargo metadata`, in the directory of the given lockfile
+fn requirements(lockfile_path: &Path) -> Requirements {
+    let Ok(metadata) = cargo_metadata(Some(lockfile_path), false) else {
+        return Requirements::new();
+    };
+
+    let mut requirements = Requirements::new();
+
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        let (Some(name), Some(version)) = (package["name"].as_str(), package["version"].as_str())
+        else {
+            continue;
+        };
+
+        let entry = requirements
+            .entry((name.to_owned(), version.to_owned()))
+            .or_default();
+
+        for dependency in package["dependencies"].as_array().into_iter().flatten() {
+            let (Some(dep_name), Some(req)) =
+                (dependency["name"].as_str(), dependency["req"].as_str())
+            else {
+                continue;
+            };
+
+            let reqs = entry.entry(dep_name.to_owned()).or_default();
+            if !reqs.iter().any(|r| r == req) {
+                reqs.push(req.to_owned());
+            }
+        }
+    }
+
+    requirements
+}
@@ -2,11 +2,19 @@
 
 use rustsec::{
     Error, ErrorKind, WarningKind, advisory,
+    database::SourceMatching,
+    epss,
     platforms::target::{Arch, OS},
     report,
 };
-use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, str::FromStr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    str::FromStr,
+};
 
 /// `cargo audit` configuration:
 ///
@@ -23,6 +31,10 @@ pub struct AuditConfig {
     #[serde(default)]
     pub database: DatabaseConfig,
 
+    /// Notification configuration
+    #[serde(default)]
+    pub notify: NotifyConfig,
+
     /// Output configuration
     #[serde(default)]
     pub output: OutputConfig,
@@ -31,6 +43,10 @@ pub struct AuditConfig {
     #[serde(default)]
     pub target: TargetConfig,
 
+    /// Configuration for auditing the Rust toolchain
+    #[serde(default)]
+    pub toolchain: ToolchainConfig,
+
     /// Configuration for auditing for yanked crates
     #[serde(default)]
     pub yanked: YankedConfig,
@@ -44,6 +60,7 @@ impl AuditConfig {
             severity: self.advisories.severity_threshold,
             target_arch: self.target.arch(),
             target_os: self.target.os(),
+            source_matching: self.advisories.source_matching,
             ..Default::default()
         };
 
@@ -84,7 +101,7 @@ impl AuditConfig {
                     insert_if_not_present(advisory::Informational::Unmaintained)
                 }
                 DenyOption::Unsound => insert_if_not_present(advisory::Informational::Unsound),
-                DenyOption::Yanked => continue,
+                DenyOption::Yanked | DenyOption::Epss(_) | DenyOption::Kev => continue,
             };
         }
 
@@ -108,6 +125,38 @@ pub struct AdvisoryConfig {
     /// Vulnerabilities with explicit CVSS info which have a severity below
     /// this threshold will be ignored.
     pub severity_threshold: Option<advisory::Severity>,
+
+    /// Annotate vulnerabilities with their exploitation probability from the
+    /// FIRST EPSS scores, which are downloaded daily (default: false).
+    ///
+    /// Implied by a `epss>=...` deny policy.
+    #[serde(default)]
+    pub epss: bool,
+
+    /// Path to a FIRST EPSS scores CSV file to use instead of downloading it
+    pub epss_file: Option<PathBuf>,
+
+    /// Flag vulnerabilities which are exploited in the wild according to the
+    /// CISA Known Exploited Vulnerabilities catalog, which is downloaded daily
+    /// (default: false).
+    ///
+    /// Implied by the `kev` deny policy.
+    #[serde(default)]
+    pub kev: bool,
+
+    /// Path to a CISA KEV catalog JSON file to use instead of downloading it
+    pub kev_file: Option<PathBuf>,
+
+    /// Path to a JSON report saved by an earlier run. Only findings which
+    /// aren't present in it are reported.
+    pub baseline: Option<PathBuf>,
+
+    /// How advisories are matched against dependencies from alternative
+    /// registries and git repositories: `"exact"` only matches advisories
+    /// published for their source, `"name"` also matches crates.io advisories
+    /// for crates with the same name (default: `"exact"`)
+    #[serde(default)]
+    pub source_matching: SourceMatching,
 }
 
 /// Advisory Database configuration.
@@ -123,6 +172,18 @@ pub struct DatabaseConfig {
     /// URL to the advisory database's git repo (default: <https://github.com/RustSec/advisory-db>)
     pub url: Option<String>,
 
+    /// Name of a crates.io package to fetch the advisory database from
+    /// instead of its git repo (e.g. in environments which can't reach GitHub).
+    /// Its owners on crates.io are trusted to publish genuine advisories.
+    ///
+    /// It's fetched through the source replacing crates.io in the cargo
+    /// configuration (e.g. a mirror), if any.
+    pub package: Option<String>,
+
+    /// Name of the registry from the cargo configuration (`[registries]`) to
+    /// fetch `package` from instead of crates.io
+    pub registry: Option<String>,
+
     /// Perform a `git fetch` before auditing (default: true)
     #[serde(default = "default_true")]
     pub fetch: bool,
@@ -136,12 +197,29 @@ impl Default for DatabaseConfig {
         Self {
             path: None,
             url: None,
+            package: None,
+            registry: None,
             fetch: true,
             stale: false,
         }
     }
 }
 
+/// Notification configuration
+///
+/// When a webhook is configured, a summary is posted to it whenever
+/// vulnerabilities are found.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyConfig {
+    /// URL of the webhook to post notifications to (e.g. a Slack incoming webhook)
+    pub webhook_url: Option<String>,
+
+    /// Template for the notification message. Supports the `{count}`, `{ids}`,
+    /// `{crates}` and `{path}` placeholders.
+    pub template: Option<String>,
+}
+
 /// Output configuration
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -160,12 +238,27 @@ pub struct OutputConfig {
     /// Show inverse dependency trees along with advisories (default: true)
     #[serde(default = "default_show_tree")]
     pub show_tree: bool,
+
+    /// Also write Prometheus metrics to this file (e.g. for the node_exporter
+    /// textfile collector)
+    pub metrics_file: Option<PathBuf>,
 }
 
 impl OutputConfig {
     /// Is quiet mode enabled?
     pub fn is_quiet(&self) -> bool {
-        self.quiet || self.format == OutputFormat::Json || self.format == OutputFormat::Sarif
+        self.quiet || self.format != OutputFormat::Terminal
+    }
+
+    /// Get the lowest EPSS threshold at which vulnerabilities are denied (if any)
+    pub fn epss_threshold(&self) -> Option<EpssThreshold> {
+        self.deny
+            .iter()
+            .filter_map(|deny| match deny {
+                DenyOption::Epss(threshold) => Some(*threshold),
+                _ => None,
+            })
+            .min()
     }
 }
 
@@ -174,23 +267,27 @@ fn default_show_tree() -> bool {
 }
 
 /// Warning kinds
-#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum DenyOption {
     /// Deny all warnings
-    #[serde(rename = "warnings")]
     Warnings,
 
     /// Deny unmaintained dependency warnings
-    #[serde(rename = "unmaintained")]
     Unmaintained,
 
     /// Deny unsound dependency warnings
-    #[serde(rename = "unsound")]
     Unsound,
 
     /// Deny yanked dependency warnings
-    #[serde(rename = "yanked")]
     Yanked,
+
+    /// Deny vulnerabilities whose EPSS score is at or above the given
+    /// threshold, even if they would otherwise be ignored
+    Epss(EpssThreshold),
+
+    /// Deny vulnerabilities listed in the CISA KEV catalog, even if they
+    /// would otherwise be ignored
+    Kev,
 }
 
 impl DenyOption {
@@ -210,10 +307,25 @@ impl DenyOption {
                 WarningKind::Unmaintained,
                 WarningKind::Unsound,
                 WarningKind::Yanked,
+                WarningKind::BundledLibrary,
             ],
             DenyOption::Unmaintained => &[WarningKind::Unmaintained],
             DenyOption::Unsound => &[WarningKind::Unsound],
             DenyOption::Yanked => &[WarningKind::Yanked],
+            DenyOption::Epss(_) | DenyOption::Kev => &[],
+        }
+    }
+}
+
+impl fmt::Display for DenyOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DenyOption::Warnings => f.write_str("warnings"),
+            DenyOption::Unmaintained => f.write_str("unmaintained"),
+            DenyOption::Unsound => f.write_str("unsound"),
+            DenyOption::Yanked => f.write_str("yanked"),
+            DenyOption::Epss(threshold) => write!(f, "epss>={threshold}"),
+            DenyOption::Kev => f.write_str("kev"),
         }
     }
 }
@@ -227,14 +339,95 @@ impl FromStr for DenyOption {
             "unmaintained" => Ok(DenyOption::Unmaintained),
             "unsound" => Ok(DenyOption::Unsound),
             "yanked" => Ok(DenyOption::Yanked),
-            other => Err(Error::new(
+            "kev" => Ok(DenyOption::Kev),
+            other => match other.strip_prefix("epss>=") {
+                Some(threshold) => Ok(DenyOption::Epss(threshold.parse()?)),
+                None => Err(Error::new(
+                    ErrorKind::Parse,
+                    format!("invalid deny option: {other}"),
+                )),
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DenyOption {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for DenyOption {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+/// Minimum EPSS probability (0.0 - 1.0) at which a vulnerability is denied
+#[derive(Copy, Clone, Debug)]
+pub struct EpssThreshold(f64);
+
+impl EpssThreshold {
+    /// Get the threshold as a floating point value
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Does the given EPSS score meet this threshold?
+    pub fn matches(self, score: &epss::Score) -> bool {
+        score.probability >= self.0
+    }
+}
+
+impl fmt::Display for EpssThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for EpssThreshold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.parse::<f64>() {
+            Ok(value) if (0.0..=1.0).contains(&value) => Ok(EpssThreshold(value)),
+            _ => Err(Error::new(
                 ErrorKind::Parse,
-                format!("invalid deny option: {other}"),
+                format!("invalid EPSS threshold (expected 0.0 - 1.0): {s}"),
             )),
         }
     }
 }
 
+// Thresholds are validated to be finite when parsed, so they have a total order
+impl PartialEq for EpssThreshold {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EpssThreshold {}
+
+impl PartialOrd for EpssThreshold {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EpssThreshold {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for EpssThreshold {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
+    }
+}
+
 /// Output format
 #[derive(Default, Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize, clap::ValueEnum)]
 pub enum OutputFormat {
@@ -246,6 +439,14 @@ pub enum OutputFormat {
     #[serde(rename = "sarif")]
     Sarif,
 
+    /// Display a Markdown report
+    #[serde(rename = "markdown")]
+    Markdown,
+
+    /// Display Prometheus metrics (text exposition format)
+    #[serde(rename = "metrics")]
+    Metrics,
+
     /// Display human-readable output to the terminal
     #[serde(rename = "terminal")]
     #[default]
@@ -259,6 +460,8 @@ impl FromStr for OutputFormat {
         match s {
             "json" => Ok(OutputFormat::Json),
             "sarif" => Ok(OutputFormat::Sarif),
+            "markdown" => Ok(OutputFormat::Markdown),
+            "metrics" => Ok(OutputFormat::Metrics),
             "terminal" => Ok(OutputFormat::Terminal),
             other => Err(Error::new(
                 ErrorKind::Parse,
@@ -316,6 +519,15 @@ impl TargetConfig {
     }
 }
 
+/// Configuration for auditing the Rust toolchain
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolchainConfig {
+    /// Also audit the version of the Rust toolchain used to build the project,
+    /// as reported by `rustc --version` or pinned by `rust-toolchain.toml`
+    pub enabled: bool,
+}
+
 /// Configuration for auditing for yanked crates
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
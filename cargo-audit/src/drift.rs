@@ -0,0 +1,148 @@
+//! Comparing the dependencies of a binary against a lockfile
+//!
+//! A binary deployed from a given commit should contain exactly the crate
+//! versions pinned in that commit's `Cargo.lock`. Differences point to stale
+//! deployments (built from an older lockfile) or to tampered artifacts.
+
+use rustsec::{
+    Version,
+    cargo_lock::{Lockfile, Name},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Differences between the dependencies of a binary and a lockfile
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LockfileDrift {
+    /// Crates with versions in the binary which aren't in the lockfile,
+    /// along with the versions in the binary and in the lockfile
+    pub changed: Vec<(Name, Vec<Version>, Vec<Version>)>,
+
+    /// Crates in the binary which aren't in the lockfile at all
+    pub extra: Vec<(Name, Vec<Version>)>,
+
+    /// Crates in the lockfile which aren't in the binary.
+    ///
+    /// Only known when the full dependency list is embedded in the binary, and
+    /// expected for dev-dependencies and dependencies of other targets.
+    pub missing: Vec<Name>,
+}
+
+impl LockfileDrift {
+    /// Compare the dependencies recovered from a binary against a lockfile.
+    ///
+    /// `complete` tells whether the binary has its full dependency list
+    /// embedded (by `cargo auditable`), rather than a partial one recovered
+    /// heuristically.
+    pub fn new(binary: &Lockfile, expected: &Lockfile, complete: bool) -> Self {
+        let binary_versions = versions(binary);
+        let expected_versions = versions(expected);
+        let mut drift = Self::default();
+
+        for (name, versions) in &binary_versions {
+            match expected_versions.get(name) {
+                // Not all versions in the lockfile are necessarily compiled into
+                // the binary, e.g. the ones only used by build scripts
+                Some(expected) if versions.is_subset(expected) => {}
+                Some(expected) => drift.changed.push((
+                    (*name).clone(),
+                    versions.iter().cloned().collect(),
+                    expected.iter().cloned().collect(),
+                )),
+                None => drift
+                    .extra
+                    .push(((*name).clone(), versions.iter().cloned().collect())),
+            }
+        }
+
+        if complete {
+            drift.missing = expected_versions
+                .keys()
+                .filter(|name| !binary_versions.contains_key(*name))
+                .map(|name| (*name).clone())
+                .collect();
+        }
+
+        drift
+    }
+
+    /// Does the binary contain crates (or versions) which aren't in the lockfile?
+    pub fn is_drifted(&self) -> bool {
+        !self.changed.is_empty() || !self.extra.is_empty()
+    }
+}
+
+/// Versions of each crate in a lockfile
+fn versions(lockfile: &Lockfile) -> BTreeMap<&Name, BTreeSet<Version>> {
+    let mut versions: BTreeMap<&Name, BTreeSet<Version>> = BTreeMap::new();
+    for package in &lockfile.packages {
+        versions
+            .entry(&package.name)
+            .or_default()
+            .insert(package.version.clone());
+    }
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustsec::cargo_lock::Package;
+
+    fn lockfile(packages: &[(&str, &str)]) -> Lockfile {
+        Lockfile {
+            version: Default::default(),
+            packages: packages
+                .iter()
+                .map(|(name, version)| Package {
+                    name: name.parse().unwrap(),
+                    version: version.parse().unwrap(),
+                    source: None,
+                    checksum: None,
+                    dependencies: vec![],
+                    replace: None,
+                })
+                .collect(),
+            root: None,
+            metadata: Default::default(),
+            patch: Default::default(),
+        }
+    }
+
+    #[test]
+    fn compare_dependencies() {
+        let expected = lockfile(&[
+            ("app", "0.1.0"),
+            ("serde", "1.0.196"),
+            ("syn", "1.0.109"),
+            ("syn", "2.0.48"),
+            ("tempfile", "3.10.0"),
+        ]);
+        let binary = lockfile(&[
+            ("app", "0.1.0"),
+            ("serde", "1.0.190"),
+            ("syn", "2.0.48"),
+            ("evil", "0.1.0"),
+        ]);
+
+        let drift = LockfileDrift::new(&binary, &expected, true);
+        assert!(drift.is_drifted());
+        assert_eq!(
+            drift.changed,
+            [(
+                "serde".parse().unwrap(),
+                vec!["1.0.190".parse().unwrap()],
+                vec!["1.0.196".parse().unwrap()]
+            )]
+        );
+        assert_eq!(
+            drift.extra,
+            [("evil".parse().unwrap(), vec!["0.1.0".parse().unwrap()])]
+        );
+        assert_eq!(drift.missing, ["tempfile".parse::<Name>().unwrap()]);
+
+        // Crates missing from partially recovered dependency lists aren't reported
+        let drift = LockfileDrift::new(&binary, &expected, false);
+        assert_eq!(drift.changed.len(), 1);
+        assert!(drift.missing.is_empty());
+    }
+}
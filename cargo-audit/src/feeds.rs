@@ -0,0 +1,203 @@
+//! Downloading the exploitability feeds advisories are enriched with
+//!
+//! Feeds are cached under `~/.cargo/advisory-feeds`, and downloaded again
+//! once the cached copy is a day old since they're republished daily.
+
+use crate::{error::display_err_with_source, prelude::*};
+use flate2::read::GzDecoder;
+use rustsec::{Error, ErrorKind};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tame_index::external::reqwest;
+
+/// Directory under `~/.cargo` where the feeds are cached
+const CACHE_DIRECTORY: &str = "advisory-feeds";
+
+/// How long a cached feed is used before it's downloaded again
+const MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long to wait for a feed to download
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// FIRST EPSS scores of every CVE
+pub const EPSS: Feed = Feed {
+    name: "EPSS scores",
+    url: "https://epss.cyentia.com/epss_scores-current.csv.gz",
+    file_name: "epss_scores-current.csv",
+    gzip: true,
+};
+
+/// CISA Known Exploited Vulnerabilities catalog
+pub const KEV: Feed = Feed {
+    name: "KEV catalog",
+    url: "https://www.cisa.gov/sites/default/files/feeds/known_exploited_vulnerabilities.json",
+    file_name: "known_exploited_vulnerabilities.json",
+    gzip: false,
+};
+
+/// Data feed downloaded from a fixed URL
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Feed {
+    /// Name of the feed to display
+    pub name: &'static str,
+
+    /// URL the feed is downloaded from
+    pub url: &'static str,
+
+    /// File name of the cached copy
+    pub file_name: &'static str,
+
+    /// Is the download gzip compressed?
+    gzip: bool,
+}
+
+impl Feed {
+    /// Location of the default feed cache
+    pub fn default_dir() -> PathBuf {
+        home::cargo_home()
+            .unwrap_or_else(|err| {
+                panic!("Error locating Cargo home directory: {err}");
+            })
+            .join(CACHE_DIRECTORY)
+    }
+
+    /// Get the path to the copy of the feed cached in `dir`, downloading it
+    /// first if it's missing or outdated and `fetch` is enabled.
+    ///
+    /// If the download fails, an outdated copy is used after a warning.
+    pub fn fetch(&self, dir: &Path, fetch: bool, quiet: bool) -> Result<PathBuf, Error> {
+        let path = dir.join(self.file_name);
+        let age = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(|modified| modified.elapsed().unwrap_or_default());
+
+        match age {
+            Some(age) if !fetch || age < MAX_AGE => return Ok(path),
+            None if !fetch => {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "no cached {} at {} and fetching is disabled",
+                        self.name,
+                        path.display()
+                    ),
+                ));
+            }
+            _ => (),
+        }
+
+        if !quiet {
+            status_ok!("Fetching", "{} from `{}`", self.name, self.url);
+        }
+
+        match self.download(&path) {
+            Ok(()) => Ok(path),
+            Err(e) if age.is_some() => {
+                status_warn!(
+                    "using an outdated copy of the {}: {}",
+                    self.name,
+                    display_err_with_source(&e)
+                );
+                Ok(path)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Download the feed to `path`
+    fn download(&self, path: &Path) -> Result<(), Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(TIMEOUT)
+            .build()
+            .map_err(|e| {
+                Error::with_source(ErrorKind::Io, "couldn't create HTTP client".into(), e)
+            })?;
+
+        let body = client
+            .get(self.url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .map_err(|e| {
+                Error::with_source(ErrorKind::Io, format!("failed to download {}", self.url), e)
+            })?;
+
+        let contents = self.decode(&body).map_err(|e| {
+            Error::with_source(
+                ErrorKind::Parse,
+                format!("invalid download {}", self.url),
+                e,
+            )
+        })?;
+
+        // Write a temporary file first, so that an interrupted download
+        // doesn't leave a truncated feed behind
+        let partial_path = path.with_extension("part");
+        let write = || {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&partial_path, contents)?;
+            fs::rename(&partial_path, path)
+        };
+
+        write().map_err(|e| {
+            Error::with_source(
+                ErrorKind::Io,
+                format!("couldn't write {}", path.display()),
+                e,
+            )
+        })
+    }
+
+    /// Decompress the downloaded feed (if needed)
+    fn decode(&self, body: &[u8]) -> std::io::Result<Vec<u8>> {
+        if !self.gzip {
+            return Ok(body.to_vec());
+        }
+
+        let mut contents = Vec::new();
+        GzDecoder::new(body).read_to_end(&mut contents)?;
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn fresh_copy_is_used() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(EPSS.file_name), "cve,epss,percentile\n").unwrap();
+
+        // A fresh copy is used without downloading the feed again
+        let path = EPSS.fetch(dir.path(), true, true).unwrap();
+        assert_eq!(path, dir.path().join(EPSS.file_name));
+    }
+
+    #[test]
+    fn missing_copy_without_fetch() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = EPSS.fetch(dir.path(), false, true).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn decode() {
+        let csv = b"cve,epss,percentile\nCVE-2021-44228,0.97,0.99\n";
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(csv).unwrap();
+        let body = encoder.finish().unwrap();
+
+        assert_eq!(EPSS.decode(&body).unwrap(), csv);
+        assert!(EPSS.decode(csv).is_err());
+        assert_eq!(KEV.decode(csv).unwrap(), csv);
+    }
+}
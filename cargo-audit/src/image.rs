@@ -0,0 +1,352 @@
+//! Finding executables in container images
+//!
+//! Supports image tarballs as written by `docker save` / `podman save`, in
+//! both the Docker format (`manifest.json`) and the OCI image layout
+//! (`index.json`). The layers are applied in order (including whiteouts),
+//! and the executables in the resulting filesystem are passed on to be audited.
+
+use crate::{archive, prelude::*};
+use flate2::read::GzDecoder;
+use rustsec::{Error, ErrorKind};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+/// Prefix of whiteout files, which mark files deleted from lower layers
+const WHITEOUT_PREFIX: &str = ".wh.";
+
+/// Whiteout file marking a directory whose contents in lower layers are hidden
+const OPAQUE_WHITEOUT: &str = ".wh..wh..opq";
+
+/// An image tarball
+pub(crate) struct ImageArchive {
+    /// The tarball
+    file: File,
+
+    /// Offset and size of each file in the tarball
+    entries: HashMap<String, (u64, u64)>,
+}
+
+impl ImageArchive {
+    /// Open an image tarball
+    pub(crate) fn open(path: &Path) -> rustsec::Result<Self> {
+        let mut file = File::open(path)?;
+        let mut entries = HashMap::new();
+
+        {
+            let mut archive = tar::Archive::new(&mut file);
+            for entry in archive.entries().map_err(|e| image_error(path, e))? {
+                let entry = entry.map_err(|e| image_error(path, e))?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+
+                let name = entry.path().map_err(|e| image_error(path, e))?;
+                entries.insert(
+                    normalize(&name.to_string_lossy()),
+                    (entry.raw_file_position(), entry.size()),
+                );
+            }
+        }
+
+        if entries.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Parse,
+                format!(
+                    "{} is not an image tarball (compressed tarballs must be decompressed first)",
+                    path.display()
+                ),
+            ));
+        }
+
+        Ok(Self { file, entries })
+    }
+
+    /// Get the paths of the layers of the image, from the bottom up
+    pub(crate) fn layers(&mut self) -> rustsec::Result<Vec<String>> {
+        // Docker format (also written by newer Docker versions alongside the OCI layout)
+        if self.entries.contains_key("manifest.json") {
+            let manifest = self.read_json("manifest.json")?;
+            let images = manifest.as_array().map(Vec::as_slice).unwrap_or_default();
+
+            if images.len() > 1 {
+                status_warn!("image tarball contains multiple images, only scanning the first");
+            }
+
+            return images
+                .first()
+                .and_then(|image| image["Layers"].as_array())
+                .map(|layers| {
+                    layers
+                        .iter()
+                        .filter_map(|layer| layer.as_str().map(normalize))
+                        .collect()
+                })
+                .ok_or_else(|| invalid_image("invalid manifest.json"));
+        }
+
+        // OCI image layout
+        let mut manifest = self.read_json("index.json")?;
+
+        // Follow image indexes (e.g. of multi-platform images) to a manifest
+        while let Some(manifests) = manifest["manifests"].as_array() {
+            let descriptor = manifests
+                .iter()
+                .find(|descriptor| {
+                    descriptor["platform"]["os"] == "linux"
+                        && descriptor["platform"]["architecture"] == "amd64"
+                })
+                .or_else(|| manifests.first())
+                .ok_or_else(|| invalid_image("empty image index"))?;
+
+            let blob = blob_path(descriptor)?;
+            manifest = self.read_json(&blob)?;
+        }
+
+        manifest["layers"]
+            .as_array()
+            .ok_or_else(|| invalid_image("image manifest has no layers"))?
+            .iter()
+            .map(blob_path)
+            .collect()
+    }
+
+    /// Walk the filesystem of the image, passing each executable in it to `found`
+    pub(crate) fn walk(
+        &mut self,
+        size_limit: u64,
+        found: &mut dyn FnMut(PathBuf, rustsec::Result<Vec<u8>>),
+    ) -> rustsec::Result<()> {
+        let layers = self.layers()?;
+
+        // Find out which layer the final version of each file comes from
+        let mut files = BTreeMap::new();
+        for (index, layer) in layers.iter().enumerate() {
+            self.for_each_file(layer, &mut |path, _| {
+                let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+
+                if name == OPAQUE_WHITEOUT {
+                    let prefix = format!("{dir}/");
+                    files.retain(|file: &String, _| !file.starts_with(&prefix));
+                } else if let Some(deleted) = name.strip_prefix(WHITEOUT_PREFIX) {
+                    let deleted = if dir.is_empty() {
+                        deleted.to_owned()
+                    } else {
+                        format!("{dir}/{deleted}")
+                    };
+                    let prefix = format!("{deleted}/");
+                    files.retain(|file: &String, _| file != &deleted && !file.starts_with(&prefix));
+                } else {
+                    files.insert(path.to_owned(), index);
+                }
+
+                Ok(())
+            })?;
+        }
+
+        for (index, layer) in layers.iter().enumerate() {
+            self.for_each_file(layer, &mut |path, reader| {
+                if files.get(path) != Some(&index) {
+                    return Ok(());
+                }
+
+                let mut reader = reader.take(size_limit.saturating_add(1));
+                let mut contents = Vec::with_capacity(archive::MAGIC_LEN);
+                (&mut reader)
+                    .take(archive::MAGIC_LEN as u64)
+                    .read_to_end(&mut contents)?;
+
+                if !archive::is_executable(&contents) {
+                    return Ok(());
+                }
+
+                reader.read_to_end(&mut contents)?;
+                let path = PathBuf::from(format!("/{path}"));
+
+                if contents.len() as u64 > size_limit {
+                    let error = Error::new(
+                        ErrorKind::BadParam,
+                        format!(
+                            "{} exceeds max size limit of {} bytes",
+                            path.display(),
+                            size_limit
+                        ),
+                    );
+                    found(path, Err(error));
+                } else {
+                    found(path, Ok(contents));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Call `f` with the path and contents of each regular file in a layer
+    fn for_each_file(
+        &mut self,
+        layer: &str,
+        f: &mut dyn FnMut(&str, &mut dyn Read) -> io::Result<()>,
+    ) -> rustsec::Result<()> {
+        let mut reader = io::BufReader::new(self.entry(layer)?);
+        let magic = reader.fill_buf()?;
+        let (gzip, zstd) = (
+            magic.starts_with(b"\x1f\x8b"),
+            magic.starts_with(b"\x28\xb5\x2f\xfd"),
+        );
+
+        if zstd {
+            return Err(Error::new(
+                ErrorKind::Parse,
+                format!("layer {layer} is compressed with zstd, which is not supported"),
+            ));
+        }
+
+        let reader: Box<dyn Read + '_> = if gzip {
+            Box::new(GzDecoder::new(reader))
+        } else {
+            Box::new(reader)
+        };
+
+        let layer_error = |e| {
+            Error::with_source(
+                ErrorKind::Parse,
+                format!("couldn't read image layer {layer}"),
+                e,
+            )
+        };
+
+        let mut archive = tar::Archive::new(reader);
+        for entry in archive.entries().map_err(layer_error)? {
+            let mut entry = entry.map_err(layer_error)?;
+            let path = normalize(&entry.path().map_err(layer_error)?.to_string_lossy());
+
+            // Whiteouts are regular files too
+            if entry.header().entry_type().is_file() {
+                f(&path, &mut entry).map_err(layer_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get a reader for a file in the tarball
+    fn entry(&mut self, name: &str) -> rustsec::Result<io::Take<&mut File>> {
+        let (offset, size) = *self
+            .entries
+            .get(name)
+            .ok_or_else(|| invalid_image(&format!("missing {name}")))?;
+
+        self.file.seek(SeekFrom::Start(offset))?;
+        Ok((&mut self.file).take(size))
+    }
+
+    /// Read and parse a JSON file in the tarball
+    fn read_json(&mut self, name: &str) -> rustsec::Result<Value> {
+        let mut json = Vec::new();
+        self.entry(name)?.read_to_end(&mut json)?;
+        serde_json::from_slice(&json).map_err(|e| {
+            Error::with_source(ErrorKind::Parse, format!("invalid {name} in image"), e)
+        })
+    }
+}
+
+/// Get the path of the blob referenced by a descriptor in an OCI image layout
+fn blob_path(descriptor: &Value) -> rustsec::Result<String> {
+    let digest = descriptor["digest"]
+        .as_str()
+        .ok_or_else(|| invalid_image("descriptor without digest"))?;
+    let (algorithm, hash) = digest
+        .split_once(':')
+        .ok_or_else(|| invalid_image(&format!("invalid digest: {digest}")))?;
+    Ok(format!("blobs/{algorithm}/{hash}"))
+}
+
+/// Normalize a path in a tarball (i.e. without leading `./` or trailing `/`)
+fn normalize(path: &str) -> String {
+    path.trim_start_matches("./")
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+        .to_owned()
+}
+
+/// Error reading an image tarball
+fn image_error(path: &Path, err: io::Error) -> Error {
+    Error::with_source(
+        ErrorKind::Parse,
+        format!("couldn't read image {}", path.display()),
+        err,
+    )
+}
+
+/// Error about a malformed image tarball
+fn invalid_image(msg: &str) -> Error {
+    Error::new(ErrorKind::Parse, format!("invalid image: {msg}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELF: &[u8] = b"\x7fELF\x02\x01\x01\0";
+
+    /// Build a tarball with the given files
+    fn tarball(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, contents) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *contents).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn apply_layers() {
+        let base = tarball(&[
+            ("usr/bin/app", b"old"),
+            ("usr/bin/removed", ELF),
+            ("opt/tool/bin/tool", ELF),
+            ("etc/passwd", b"root:x:0:0"),
+        ]);
+        let top = tarball(&[
+            ("./usr/bin/app", ELF),
+            ("usr/bin/.wh.removed", b""),
+            ("opt/tool/.wh..wh..opq", b""),
+            ("opt/tool/new", ELF),
+        ]);
+        let manifest =
+            br#"[{"Config": "config.json", "Layers": ["base/layer.tar", "top/layer.tar"]}]"#;
+
+        let dir = tempfile::tempdir().unwrap();
+        let image_path = dir.path().join("image.tar");
+        std::fs::write(
+            &image_path,
+            tarball(&[
+                ("manifest.json", manifest),
+                ("base/layer.tar", &base),
+                ("top/layer.tar", &top),
+            ]),
+        )
+        .unwrap();
+
+        let mut found = vec![];
+        ImageArchive::open(&image_path)
+            .unwrap()
+            .walk(1024, &mut |path, contents| {
+                assert_eq!(contents.unwrap(), ELF);
+                found.push(path.display().to_string());
+            })
+            .unwrap();
+
+        assert_eq!(found, ["/usr/bin/app", "/opt/tool/new"]);
+    }
+}
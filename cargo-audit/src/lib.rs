@@ -22,17 +22,34 @@
 
 pub mod application;
 pub mod auditor;
+pub mod baseline;
 pub mod commands;
 pub mod config;
+#[cfg(feature = "binary-scanning")]
+pub mod drift;
 pub mod error;
+pub mod feeds;
 pub mod lockfile;
+mod markdown;
+#[cfg(feature = "binary-scanning")]
+pub mod merged;
+mod metadata;
+mod metrics;
+pub mod notify;
 mod prelude;
 pub mod presenter;
 mod sarif;
+mod toolchain;
 pub use sarif::SarifLog;
 
+#[cfg(feature = "binary-scanning")]
+mod archive;
 #[cfg(feature = "binary-scanning")]
 mod binary_scanning;
+#[cfg(feature = "binary-scanning")]
+mod image;
+#[cfg(feature = "binary-scanning")]
+mod process;
 
 /// Current version of the `cargo-audit` crate
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
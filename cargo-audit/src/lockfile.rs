@@ -1,5 +1,6 @@
 //! Cargo.lock-related utilities
 
+use crate::metadata::cargo_path;
 use rustsec::{Error, ErrorKind};
 use std::{
     path::{Path, PathBuf},
@@ -26,7 +27,7 @@ pub fn locate_or_generate(maybe_lockfile_path: Option<&Path>) -> rustsec::Result
 
 /// Run `cargo generate-lockfile`
 pub fn generate() -> rustsec::Result<()> {
-    let status = Command::new("cargo")
+    let status = Command::new(cargo_path())
         .arg("update")
         .arg("--workspace")
         .status();
@@ -0,0 +1,111 @@
+//! Markdown output support
+//!
+//! Renders a report as GitHub-flavored Markdown, e.g. for pull request
+//! comments or job summaries.
+
+use std::fmt::Write as _;
+
+use rustsec::{Report, advisory};
+
+/// Render a report about the lockfile (or binary) at `path` as Markdown
+pub fn render(report: &Report, path: &str) -> String {
+    let mut out = String::new();
+    writeln!(out, "# `cargo audit` report for `{path}`\n").unwrap();
+
+    let warning_count = report.warnings.values().map(Vec::len).sum::<usize>();
+
+    if !report.vulnerabilities.found && warning_count == 0 {
+        writeln!(out, "No vulnerabilities or warnings found.").unwrap();
+        return out;
+    }
+
+    if report.vulnerabilities.found {
+        writeln!(
+            out,
+            "## Vulnerabilities ({})\n",
+            report.vulnerabilities.count
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "| Crate | Version | Advisory | Severity | Title | Solution |"
+        )
+        .unwrap();
+        writeln!(out, "|---|---|---|---|---|---|").unwrap();
+
+        for vuln in &report.vulnerabilities.list {
+            let severity = vuln
+                .advisory
+                .cvss
+                .as_ref()
+                .map(|cvss| cvss.severity())
+                .or(vuln.advisory.severity)
+                .map_or("unknown", |severity| severity.as_str());
+
+            let solution = if vuln.versions.patched().is_empty() {
+                "No fixed upgrade is available".to_owned()
+            } else {
+                format!(
+                    "Upgrade to {}",
+                    vuln.versions
+                        .patched()
+                        .iter()
+                        .map(|req| format!("`{req}`"))
+                        .collect::<Vec<_>>()
+                        .join(" OR ")
+                )
+            };
+
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} | {} | {} |",
+                vuln.package.name,
+                vuln.package.version,
+                advisory_link(&vuln.advisory),
+                severity,
+                escape(&vuln.advisory.title),
+                solution
+            )
+            .unwrap();
+        }
+
+        writeln!(out).unwrap();
+    }
+
+    if warning_count > 0 {
+        writeln!(out, "## Warnings ({warning_count})\n").unwrap();
+        writeln!(out, "| Crate | Version | Kind | Advisory | Title |").unwrap();
+        writeln!(out, "|---|---|---|---|---|").unwrap();
+
+        for warning in report.warnings.values().flatten() {
+            let (link, title) = match &warning.advisory {
+                Some(metadata) => (advisory_link(metadata), escape(&metadata.title)),
+                None => ("-".to_owned(), "-".to_owned()),
+            };
+
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} | {} |",
+                warning.package.name, warning.package.version, warning.kind, link, title
+            )
+            .unwrap();
+        }
+
+        writeln!(out).unwrap();
+    }
+
+    out
+}
+
+/// Link to an advisory (if it has a URL)
+fn advisory_link(metadata: &advisory::Metadata) -> String {
+    match metadata.id.url() {
+        Some(url) => format!("[{}]({url})", metadata.id),
+        None => metadata.id.to_string(),
+    }
+}
+
+/// Escape characters which would break a table cell
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
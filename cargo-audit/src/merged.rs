@@ -0,0 +1,221 @@
+//! Merging the reports of multiple binaries
+//!
+//! Binaries built from the same workspace tend to share most of their
+//! dependencies, so auditing them separately repeats the same findings over
+//! and over. A merged report lists each finding once, along with the binaries
+//! which contain the affected package.
+
+use rustsec::{Report, Version, Vulnerability, Warning, WarningKind, advisory, package};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Findings from auditing multiple binaries, each attributed to the binaries it was found in
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MergedReport {
+    /// Binaries which have been audited
+    pub binaries: Vec<PathBuf>,
+
+    /// Vulnerabilities found in any of the binaries
+    pub vulnerabilities: Vec<Attributed<Vulnerability>>,
+
+    /// Warnings found in any of the binaries
+    pub warnings: Vec<Attributed<Warning>>,
+}
+
+/// A finding along with the binaries it was found in
+#[derive(Clone, Debug, Serialize)]
+pub struct Attributed<T> {
+    /// The finding
+    #[serde(flatten)]
+    pub finding: T,
+
+    /// Binaries containing the affected package
+    pub binaries: Vec<PathBuf>,
+}
+
+impl MergedReport {
+    /// Add the report of a binary
+    ///
+    /// Binaries and findings are kept sorted, so the result doesn't depend on
+    /// the order in which the binaries were audited.
+    pub fn add(&mut self, binary: &Path, report: &Report) {
+        insert_sorted(&mut self.binaries, binary);
+
+        for vulnerability in &report.vulnerabilities.list {
+            let key = vulnerability_key(vulnerability);
+            attribute(
+                &mut self.vulnerabilities,
+                vulnerability,
+                binary,
+                |existing| vulnerability_key(existing) == key,
+            );
+        }
+        self.vulnerabilities
+            .sort_by_key(|attributed| vulnerability_key(&attributed.finding));
+
+        for warning in report.warnings.values().flatten() {
+            let key = warning_key(warning);
+            attribute(&mut self.warnings, warning, binary, |existing| {
+                warning_key(existing) == key
+            });
+        }
+        self.warnings
+            .sort_by_key(|attributed| warning_key(&attributed.finding));
+    }
+
+    /// Were any vulnerabilities found?
+    pub fn vulnerabilities_found(&self) -> bool {
+        !self.vulnerabilities.is_empty()
+    }
+}
+
+/// Add `binary` to the matching finding, or add the finding if there's none yet
+fn attribute<T: Clone>(
+    findings: &mut Vec<Attributed<T>>,
+    finding: &T,
+    binary: &Path,
+    matches: impl Fn(&T) -> bool,
+) {
+    match findings
+        .iter_mut()
+        .find(|attributed| matches(&attributed.finding))
+    {
+        Some(attributed) => insert_sorted(&mut attributed.binaries, binary),
+        None => findings.push(Attributed {
+            finding: finding.clone(),
+            binaries: vec![binary.to_owned()],
+        }),
+    }
+}
+
+/// Insert a path into a sorted list of paths
+fn insert_sorted(paths: &mut Vec<PathBuf>, path: &Path) {
+    if let Err(index) = paths.binary_search_by(|existing| existing.as_path().cmp(path)) {
+        paths.insert(index, path.to_owned());
+    }
+}
+
+/// Identity of a vulnerability across binaries
+type VulnerabilityKey = (advisory::Id, package::Name, Version);
+
+/// Identity of a warning across binaries
+type WarningKey = (WarningKind, Option<advisory::Id>, package::Name, Version);
+
+fn vulnerability_key(vulnerability: &Vulnerability) -> VulnerabilityKey {
+    (
+        vulnerability.advisory.id.clone(),
+        vulnerability.package.name.clone(),
+        vulnerability.package.version.clone(),
+    )
+}
+
+fn warning_key(warning: &Warning) -> WarningKey {
+    (
+        warning.kind,
+        warning.advisory.as_ref().map(|a| a.id.clone()),
+        warning.package.name.clone(),
+        warning.package.version.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustsec::{Advisory, cargo_lock::Package};
+
+    fn vulnerability(id: &str, version: &str) -> Vulnerability {
+        let advisory: Advisory = format!(
+            "```toml\n[advisory]\nid = \"{id}\"\npackage = \"base\"\ndate = \"2001-02-03\"\n\n\
+             [versions]\npatched = [\">= 9.0.0\"]\n```\n\n# Title\n\nDescription\n"
+        )
+        .parse()
+        .unwrap();
+        let package = Package {
+            name: "base".parse().unwrap(),
+            version: version.parse().unwrap(),
+            source: None,
+            checksum: None,
+            dependencies: vec![],
+            replace: None,
+        };
+
+        Vulnerability::new(&advisory, &package)
+    }
+
+    fn report(vulnerabilities: Vec<Vulnerability>) -> Report {
+        let mut report: Report = serde_json::from_str(
+            r#"{
+                "database": {"advisory-count": 0, "last-commit": null, "last-updated": null},
+                "lockfile": {"dependency-count": 0},
+                "settings": {"target_arch": [], "target_os": [], "severity": null, "ignore": [], "informational_warnings": []},
+                "vulnerabilities": {"found": false, "count": 0, "list": []},
+                "warnings": {}
+            }"#,
+        )
+        .unwrap();
+        report.vulnerabilities = rustsec::report::VulnerabilityInfo::new(vulnerabilities);
+        report
+    }
+
+    #[test]
+    fn attribute_findings() {
+        let mut merged = MergedReport::default();
+        merged.add(
+            Path::new("app"),
+            &report(vec![
+                vulnerability("RUSTSEC-2001-0001", "1.0.0"),
+                vulnerability("RUSTSEC-2001-0002", "1.0.0"),
+            ]),
+        );
+        // Another version of the same package is a separate finding
+        merged.add(
+            Path::new("tool"),
+            &report(vec![
+                vulnerability("RUSTSEC-2001-0001", "1.0.0"),
+                vulnerability("RUSTSEC-2001-0002", "1.0.1"),
+            ]),
+        );
+        merged.add(Path::new("clean"), &report(vec![]));
+
+        let findings = merged
+            .vulnerabilities
+            .iter()
+            .map(|attributed| {
+                (
+                    attributed.finding.advisory.id.as_str(),
+                    attributed.finding.package.version.to_string(),
+                    attributed.binaries.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(merged.binaries.len(), 3);
+        assert_eq!(
+            findings,
+            [
+                (
+                    "RUSTSEC-2001-0001",
+                    "1.0.0".to_owned(),
+                    vec![PathBuf::from("app"), PathBuf::from("tool")]
+                ),
+                (
+                    "RUSTSEC-2001-0002",
+                    "1.0.0".to_owned(),
+                    vec![PathBuf::from("app")]
+                ),
+                (
+                    "RUSTSEC-2001-0002",
+                    "1.0.1".to_owned(),
+                    vec![PathBuf::from("tool")]
+                ),
+            ]
+        );
+
+        let json = serde_json::to_value(&merged).unwrap();
+        assert_eq!(
+            json["vulnerabilities"][0]["advisory"]["id"],
+            "RUSTSEC-2001-0001"
+        );
+        assert_eq!(json["vulnerabilities"][0]["binaries"][1], "tool");
+    }
+}
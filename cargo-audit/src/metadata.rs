@@ -0,0 +1,55 @@
+//! `cargo metadata`-related utilities
+
+use rustsec::{Error, ErrorKind};
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// Path to `cargo`: the one running `cargo audit` as a subcommand (from
+/// `$CARGO`) if any, otherwise the one in `$PATH`
+pub fn cargo_path() -> PathBuf {
+    std::env::var_os("CARGO")
+        .map(Into::into)
+        .unwrap_or_else(|| "cargo".into())
+}
+
+/// Run `cargo metadata` in the directory of the given `Cargo.lock` (or the
+/// current directory), returning its parsed output.
+///
+/// With `no_deps`, only the members of the workspace are listed.
+pub fn cargo_metadata(
+    lockfile_path: Option<&Path>,
+    no_deps: bool,
+) -> rustsec::Result<serde_json::Value> {
+    let mut command = Command::new(cargo_path());
+    command.args(["metadata", "--format-version", "1", "--quiet"]);
+    if no_deps {
+        command.arg("--no-deps");
+    }
+    if let Some(dir) = lockfile_path
+        .and_then(|path| path.canonicalize().ok())
+        .and_then(|path| path.parent().map(Path::to_owned))
+    {
+        command.current_dir(dir);
+    }
+
+    let output = command.stderr(Stdio::inherit()).output().map_err(|e| {
+        Error::with_source(ErrorKind::Io, "couldn't run `cargo metadata`".to_owned(), e)
+    })?;
+
+    if !output.status.success() {
+        return Err(Error::new(
+            ErrorKind::Io,
+            format!("`cargo metadata` failed ({})", output.status),
+        ));
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Parse,
+            "couldn't parse the output of `cargo metadata`".to_owned(),
+            e,
+        )
+    })
+}
@@ -0,0 +1,152 @@
+//! Prometheus metrics output support
+//!
+//! Renders the counts of vulnerabilities (by severity) and warnings (by kind)
+//! in a report in the Prometheus text exposition format, which can be picked
+//! up by the node_exporter textfile collector so scheduled audits can feed
+//! monitoring dashboards and alerting rules.
+
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use rustsec::{Report, WarningKind};
+
+/// Severity label for vulnerabilities without CVSS information
+const UNKNOWN_SEVERITY: &str = "unknown";
+
+/// Severity labels, in increasing order
+const SEVERITIES: &[&str] = &[
+    "none",
+    "low",
+    "medium",
+    "high",
+    "critical",
+    UNKNOWN_SEVERITY,
+];
+
+/// Warning kinds which are always emitted (so that series don't disappear)
+const WARNING_KINDS: &[WarningKind] = &[
+    WarningKind::Notice,
+    WarningKind::Unmaintained,
+    WarningKind::Unsound,
+    WarningKind::Yanked,
+    WarningKind::BundledLibrary,
+];
+
+/// Render metrics for a report about the lockfile (or binary) at `path`
+pub fn render(report: &Report, path: &str, dependency_count: usize) -> String {
+    let path = escape(path);
+    let mut out = String::new();
+
+    let mut gauge = |name: &str, help: &str, samples: &[(Option<(&str, &str)>, usize)]| {
+        writeln!(out, "# HELP {name} {help}").unwrap();
+        writeln!(out, "# TYPE {name} gauge").unwrap();
+        for (label, value) in samples {
+            match label {
+                Some((key, label)) => {
+                    writeln!(out, "{name}{{path=\"{path}\",{key}=\"{label}\"}} {value}")
+                }
+                None => writeln!(out, "{name}{{path=\"{path}\"}} {value}"),
+            }
+            .unwrap();
+        }
+    };
+
+    let vulnerabilities = SEVERITIES
+        .iter()
+        .map(|&severity| {
+            let count = report
+                .vulnerabilities
+                .list
+                .iter()
+                .filter(|vuln| {
+                    vuln.advisory
+                        .cvss
+                        .as_ref()
+                        .map(|cvss| cvss.severity())
+                        .or(vuln.advisory.severity)
+                        .map_or(UNKNOWN_SEVERITY, |severity| severity.as_str())
+                        == severity
+                })
+                .count();
+            (Some(("severity", severity)), count)
+        })
+        .collect::<Vec<_>>();
+
+    gauge(
+        "cargo_audit_vulnerabilities",
+        "Number of vulnerabilities found, by severity",
+        &vulnerabilities,
+    );
+
+    let warnings = WARNING_KINDS
+        .iter()
+        .map(|kind| {
+            let count = report.warnings.get(kind).map(Vec::len).unwrap_or_default();
+            (Some(("kind", kind.as_str())), count)
+        })
+        .collect::<Vec<_>>();
+
+    gauge(
+        "cargo_audit_warnings",
+        "Number of warnings found, by kind",
+        &warnings,
+    );
+
+    gauge(
+        "cargo_audit_dependencies",
+        "Number of dependencies audited",
+        &[(None, dependency_count)],
+    );
+
+    gauge(
+        "cargo_audit_advisories",
+        "Number of advisories in the advisory database",
+        &[(None, report.database.advisory_count)],
+    );
+
+    out
+}
+
+/// Write metrics to a file, replacing it atomically so that collectors never
+/// read a partially written file
+pub fn write_file(path: &Path, metrics: &str) -> io::Result<()> {
+    let tmp_path = path.with_extension("prom.tmp");
+    fs::write(&tmp_path, metrics)?;
+    fs::rename(&tmp_path, path)
+}
+
+/// Escape a label value
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_empty_report() {
+        let report: Report = serde_json::from_str(
+            r#"{
+                "database": {"advisory-count": 42, "last-commit": null, "last-updated": null},
+                "lockfile": {"dependency-count": 3},
+                "settings": {"target_arch": [], "target_os": [], "severity": null, "ignore": [], "informational_warnings": []},
+                "vulnerabilities": {"found": false, "count": 0, "list": []},
+                "warnings": {}
+            }"#,
+        )
+        .unwrap();
+
+        let metrics = render(&report, "C:\\Cargo.lock", 3);
+        assert!(metrics.contains("# TYPE cargo_audit_vulnerabilities gauge\n"));
+        assert!(metrics.contains(
+            "cargo_audit_vulnerabilities{path=\"C:\\\\Cargo.lock\",severity=\"critical\"} 0\n"
+        ));
+        assert!(
+            metrics.contains("cargo_audit_warnings{path=\"C:\\\\Cargo.lock\",kind=\"yanked\"} 0\n")
+        );
+        assert!(metrics.contains("cargo_audit_advisories{path=\"C:\\\\Cargo.lock\"} 42\n"));
+    }
+}
@@ -0,0 +1,148 @@
+//! Notifications about newly found vulnerabilities
+//!
+//! Posts a summary message to a webhook (e.g. a Slack incoming webhook) so
+//! that scheduled audits can alert people directly.
+
+use crate::config::NotifyConfig;
+use rustsec::{Error, ErrorKind, Vulnerability};
+use serde_json::json;
+use std::{path::Path, time::Duration};
+use tame_index::external::reqwest;
+
+/// Message template used when none is configured
+pub const DEFAULT_TEMPLATE: &str = "cargo audit found {count} vulnerabilities in {path}: {ids}";
+
+/// How long to wait for the webhook to respond
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sends notifications about vulnerabilities to a webhook
+#[derive(Clone, Debug)]
+pub struct Notifier {
+    /// URL to post notifications to
+    webhook_url: String,
+
+    /// Template for the notification message
+    template: String,
+}
+
+impl Notifier {
+    /// Create a notifier from the configuration, if a webhook is configured
+    pub fn new(config: &NotifyConfig) -> Option<Self> {
+        let webhook_url = config.webhook_url.clone()?;
+        let template = config
+            .template
+            .clone()
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_owned());
+
+        Some(Self {
+            webhook_url,
+            template,
+        })
+    }
+
+    /// Post a notification about the given vulnerabilities found in the
+    /// lockfile at `path`. Nothing is sent if there are no vulnerabilities.
+    pub fn notify(&self, path: &Path, vulnerabilities: &[Vulnerability]) -> Result<(), Error> {
+        if vulnerabilities.is_empty() {
+            return Ok(());
+        }
+
+        // Slack, Mattermost and most chat webhooks accept a `text` field
+        let payload = json!({ "text": self.message(path, vulnerabilities) });
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(TIMEOUT)
+            .build()
+            .map_err(|e| {
+                Error::with_source(ErrorKind::Io, "couldn't create HTTP client".into(), e)
+            })?;
+
+        client
+            .post(&self.webhook_url)
+            .header("Content-Type", "application/json")
+            .body(payload.to_string())
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                Error::with_source(
+                    ErrorKind::Io,
+                    "couldn't post notification to webhook".into(),
+                    e,
+                )
+            })?;
+
+        Ok(())
+    }
+
+    /// Render the notification message from the template.
+    ///
+    /// Supported placeholders are `{count}`, `{ids}`, `{crates}` and `{path}`.
+    pub fn message(&self, path: &Path, vulnerabilities: &[Vulnerability]) -> String {
+        let ids = vulnerabilities
+            .iter()
+            .map(|vuln| vuln.advisory.id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let crates = vulnerabilities
+            .iter()
+            .map(|vuln| format!("{} {}", vuln.package.name, vuln.package.version))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        self.template
+            .replace("{count}", &vulnerabilities.len().to_string())
+            .replace("{ids}", &ids)
+            .replace("{crates}", &crates)
+            .replace("{path}", &path.display().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustsec::{Advisory, cargo_lock::Package};
+
+    fn vulnerability(id: &str) -> Vulnerability {
+        let advisory: Advisory = format!(
+            "```toml\n[advisory]\nid = \"{id}\"\npackage = \"base\"\ndate = \"2001-02-03\"\n\n\
+             [versions]\npatched = [\">= 9.0.0\"]\n```\n\n# Title\n\nDescription\n"
+        )
+        .parse()
+        .unwrap();
+        let package = Package {
+            name: "base".parse().unwrap(),
+            version: "1.0.0".parse().unwrap(),
+            source: None,
+            checksum: None,
+            dependencies: vec![],
+            replace: None,
+        };
+
+        Vulnerability::new(&advisory, &package)
+    }
+
+    #[test]
+    fn render_template() {
+        let notifier = Notifier::new(&NotifyConfig {
+            webhook_url: Some("https://example.com/hook".to_owned()),
+            template: Some("{count} in {path}: {ids} ({crates})".to_owned()),
+        })
+        .unwrap();
+
+        let vulnerabilities = [
+            vulnerability("RUSTSEC-2001-0001"),
+            vulnerability("RUSTSEC-2001-0002"),
+        ];
+
+        assert_eq!(
+            notifier.message(Path::new("Cargo.lock"), &vulnerabilities),
+            "2 in Cargo.lock: RUSTSEC-2001-0001, RUSTSEC-2001-0002 (base 1.0.0, base 1.0.0)"
+        );
+    }
+
+    #[test]
+    fn disabled_without_webhook() {
+        assert!(Notifier::new(&NotifyConfig::default()).is_none());
+    }
+}
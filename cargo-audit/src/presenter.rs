@@ -10,6 +10,7 @@ use abscissa_core::terminal::{
 use rustsec::{
     Vulnerability, Warning, WarningKind,
     advisory::License,
+    binary_scanning::Confidence,
     cargo_lock::{
         Lockfile, Package,
         dependency::{Dependency, Tree, graph::EdgeDirection},
@@ -17,13 +18,16 @@ use rustsec::{
 };
 #[cfg(feature = "binary-scanning")]
 use rustsec::{advisory::affected::FunctionPath, binary_scanning::BinaryReport};
-
 #[cfg(feature = "binary-scanning")]
-use crate::binary_scanning::SymbolSet;
+use std::path::PathBuf;
+
 use crate::{
+    baseline::{Findings, ReportDiff},
     config::{DenyOption, OutputConfig, OutputFormat},
     prelude::*,
 };
+#[cfg(feature = "binary-scanning")]
+use crate::{binary_scanning::SymbolSet, drift::LockfileDrift, merged::MergedReport};
 
 /// Vulnerability information presenter
 #[derive(Clone, Debug)]
@@ -116,6 +120,17 @@ impl Presenter {
         lockfile: &Lockfile,
         path: Option<&Path>,
     ) {
+        let cargo_lock_path = path
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "Cargo.lock".to_string());
+
+        if let Some(metrics_file) = &self.config.metrics_file {
+            let metrics = crate::metrics::render(report, &cargo_lock_path, lockfile.packages.len());
+            if let Err(e) = crate::metrics::write_file(metrics_file, &metrics) {
+                status_err!("couldn't write {}: {}", metrics_file.display(), e);
+            }
+        }
+
         match self.config.format {
             OutputFormat::Json => {
                 let mut stdout = io::stdout().lock();
@@ -125,9 +140,6 @@ impl Presenter {
                 return;
             }
             OutputFormat::Sarif => {
-                let cargo_lock_path = path
-                    .map(|p| p.to_string_lossy().into_owned())
-                    .unwrap_or_else(|| "Cargo.lock".to_string());
                 let sarif_log = crate::sarif::SarifLog::from_report(report, &cargo_lock_path);
                 let mut stdout = io::stdout().lock();
                 serde_json::to_writer(&mut stdout, &sarif_log).unwrap();
@@ -135,6 +147,17 @@ impl Presenter {
                 writeln!(&mut stdout).unwrap();
                 return;
             }
+            OutputFormat::Markdown => {
+                print!("{}", crate::markdown::render(report, &cargo_lock_path));
+                return;
+            }
+            OutputFormat::Metrics => {
+                print!(
+                    "{}",
+                    crate::metrics::render(report, &cargo_lock_path, lockfile.packages.len())
+                );
+                return;
+            }
             OutputFormat::Terminal => {
                 // Continue with terminal output below
             }
@@ -198,19 +221,11 @@ impl Presenter {
                         symbols.filter(
                             warning
                                 .affected
-                                .as_ref()
-                                .map(|affected| affected.functions.iter())
-                                .unwrap_or_default()
-                                .filter_map(|(path, version_reqs)| {
-                                    if version_reqs
-                                        .iter()
-                                        .any(|req| req.matches(&warning.package.version))
-                                    {
-                                        Some(path.clone())
-                                    } else {
-                                        None
-                                    }
-                                }),
+                                .iter()
+                                .flat_map(|affected| {
+                                    affected.functions_for(&warning.package.version)
+                                })
+                                .cloned(),
                         ),
                     );
                 }
@@ -238,6 +253,20 @@ impl Presenter {
             }
         }
 
+        let num_exploited = report
+            .vulnerabilities
+            .list
+            .iter()
+            .filter(|vuln| vuln.kev.is_some())
+            .count();
+
+        if num_exploited > 0 {
+            status_err!(
+                "{} of these vulnerabilities are known to be exploited in the wild (CISA KEV)",
+                num_exploited
+            );
+        }
+
         let (num_denied, num_not_denied) = self.count_warnings(report);
 
         if num_denied > 0 || num_not_denied > 0 {
@@ -274,6 +303,151 @@ impl Presenter {
         }
     }
 
+    /// Print the combined report of multiple binaries
+    #[cfg(feature = "binary-scanning")]
+    pub fn print_merged_report(&self, merged: &MergedReport) {
+        if let OutputFormat::Json = self.config.format {
+            let mut stdout = io::stdout().lock();
+            serde_json::to_writer(&mut stdout, merged).unwrap();
+            writeln!(&mut stdout).unwrap();
+            return;
+        }
+
+        for vulnerability in &merged.vulnerabilities {
+            self.print_vulnerability(&vulnerability.finding);
+            self.print_binaries(Red, &vulnerability.binaries);
+            println!();
+        }
+
+        let (mut num_denied, mut num_not_denied) = (0, 0);
+        for warning in &merged.warnings {
+            let deny_warning = self.deny_warning_kinds.contains(&warning.finding.kind);
+            if deny_warning {
+                num_denied += 1;
+            } else {
+                num_not_denied += 1;
+            }
+
+            let color = self.warning_color(deny_warning);
+            self.print_warning(&warning.finding, color);
+            self.print_binaries(color, &warning.binaries);
+            println!();
+        }
+
+        let affected_binaries = merged
+            .vulnerabilities
+            .iter()
+            .flat_map(|vulnerability| &vulnerability.binaries)
+            .collect::<Set<_>>();
+
+        if merged.vulnerabilities_found() {
+            status_err!(
+                "{} {} found in {} of {} binaries",
+                merged.vulnerabilities.len(),
+                if merged.vulnerabilities.len() == 1 {
+                    "vulnerability"
+                } else {
+                    "vulnerabilities"
+                },
+                affected_binaries.len(),
+                merged.binaries.len()
+            );
+        }
+
+        if num_denied > 0 {
+            status_err!(
+                "{} denied {} found",
+                num_denied,
+                self.warning_word(num_denied)
+            );
+        }
+        if num_not_denied > 0 {
+            status_warn!(
+                "{} allowed {} found",
+                num_not_denied,
+                self.warning_word(num_not_denied)
+            );
+        }
+    }
+
+    /// Report the differences between the dependencies of a binary and the expected lockfile.
+    ///
+    /// Differences are only warnings if the dependencies were recovered heuristically.
+    #[cfg(feature = "binary-scanning")]
+    pub fn print_lockfile_drift(
+        &self,
+        binary_path: &Path,
+        lockfile_path: &Path,
+        drift: &LockfileDrift,
+        complete: bool,
+    ) {
+        let (binary, lockfile) = (binary_path.display(), lockfile_path.display());
+        let join = |versions: &[rustsec::Version]| {
+            versions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let report = |msg: String| {
+            if complete {
+                status_err!("{}", msg);
+            } else {
+                status_warn!("{}", msg);
+            }
+        };
+
+        for (name, versions, expected) in &drift.changed {
+            report(format!(
+                "{binary}: {name} {} doesn't match {lockfile} ({})",
+                join(versions),
+                join(expected)
+            ));
+        }
+
+        for (name, versions) in &drift.extra {
+            report(format!(
+                "{binary}: {name} {} isn't in {lockfile}",
+                join(versions)
+            ));
+        }
+
+        if self.config.is_quiet() {
+            return;
+        }
+
+        if !drift.missing.is_empty() {
+            status_warn!(
+                "{}: {} crates in {} aren't compiled in (as expected for dev-dependencies \
+                 and dependencies of other targets): {}",
+                binary,
+                drift.missing.len(),
+                lockfile,
+                drift
+                    .missing
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        if !drift.is_drifted() {
+            status_ok!(
+                "Matched",
+                "the dependencies of {} against {}",
+                binary,
+                lockfile
+            );
+        } else if !complete {
+            status_warn!(
+                "{} was not built with 'cargo auditable', so these differences may be \
+                 false positives (e.g. crates of the standard library)",
+                binary
+            );
+        }
+    }
+
     /// Print the vulnerability report for cargo-audit
     pub fn print_self_report(&mut self, self_advisories: &[rustsec::Advisory]) {
         if self_advisories.is_empty() {
@@ -298,6 +472,160 @@ impl Presenter {
         println!();
     }
 
+    /// Note the findings from a baseline report which have been resolved
+    pub fn print_resolved(&self, resolved: &Findings) {
+        if self.config.is_quiet() {
+            return;
+        }
+
+        for vulnerability in &resolved.vulnerabilities {
+            status_ok!(
+                "Resolved",
+                "{} ({})",
+                vulnerability.advisory.id,
+                vulnerability.package.name
+            );
+        }
+
+        for warning in &resolved.warnings {
+            match &warning.advisory {
+                Some(advisory) => status_ok!(
+                    "Resolved",
+                    "{} ({}, {})",
+                    advisory.id,
+                    warning.package.name,
+                    warning.kind
+                ),
+                None => status_ok!(
+                    "Resolved",
+                    "{} {} ({})",
+                    warning.package.name,
+                    warning.package.version,
+                    warning.kind
+                ),
+            }
+        }
+    }
+
+    /// Print the differences between two reports
+    pub fn print_report_diff(&self, diff: &ReportDiff) {
+        if let OutputFormat::Json = self.config.format {
+            let mut stdout = io::stdout().lock();
+            serde_json::to_writer(&mut stdout, diff).unwrap();
+            writeln!(&mut stdout).unwrap();
+            return;
+        }
+
+        for vulnerability in &diff.introduced.vulnerabilities {
+            self.print_vulnerability(vulnerability);
+            println!();
+        }
+
+        for warning in &diff.introduced.warnings {
+            let color = self.warning_color(self.deny_warning_kinds.contains(&warning.kind));
+            self.print_warning(warning, color);
+            println!();
+        }
+
+        self.print_resolved(&diff.resolved);
+
+        if diff.introduced.is_empty() {
+            status_ok!("Success", "no new vulnerabilities or warnings");
+        } else {
+            status_err!(
+                "{} new vulnerabilities and {} new warnings",
+                diff.introduced.vulnerabilities.len(),
+                diff.introduced.warnings.len()
+            );
+        }
+    }
+
+    /// Print the full details of an advisory
+    pub fn print_advisory(&self, advisory: &rustsec::Advisory) {
+        let metadata = &advisory.metadata;
+        let color = if metadata.informational.is_some() {
+            Yellow
+        } else {
+            Red
+        };
+
+        self.print_attr(color, "Crate:    ", &metadata.package);
+        self.print_metadata(metadata, color);
+
+        if let Some(informational) = &metadata.informational {
+            self.print_attr(color, "Warning:  ", informational.as_str());
+        }
+
+        if let Some(withdrawn) = &metadata.withdrawn {
+            self.print_attr(color, "Withdrawn:", withdrawn);
+        }
+
+        if let Some(cvss) = &metadata.cvss {
+            self.print_attr(color, "CVSS:     ", cvss.to_string());
+        }
+
+        let join = |items: &mut dyn Iterator<Item = String>| items.collect::<Vec<_>>().join(", ");
+
+        if !metadata.aliases.is_empty() {
+            self.print_attr(
+                color,
+                "Aliases:  ",
+                join(&mut metadata.aliases.iter().map(ToString::to_string)),
+            );
+        }
+
+        if !metadata.related.is_empty() {
+            self.print_attr(
+                color,
+                "Related:  ",
+                join(&mut metadata.related.iter().map(ToString::to_string)),
+            );
+        }
+
+        if !metadata.categories.is_empty() {
+            self.print_attr(
+                color,
+                "Category: ",
+                join(&mut metadata.categories.iter().map(ToString::to_string)),
+            );
+        }
+
+        if !metadata.keywords.is_empty() {
+            self.print_attr(
+                color,
+                "Keywords: ",
+                join(&mut metadata.keywords.iter().map(|k| k.as_str().to_owned())),
+            );
+        }
+
+        let patched = advisory.versions.patched();
+        if patched.is_empty() {
+            self.print_attr(color, "Patched:  ", "no patched versions");
+        } else {
+            self.print_attr(
+                color,
+                "Patched:  ",
+                join(&mut patched.iter().map(ToString::to_string)),
+            );
+        }
+
+        let unaffected = advisory.versions.unaffected();
+        if !unaffected.is_empty() {
+            self.print_attr(
+                color,
+                "Unaffected:",
+                join(&mut unaffected.iter().map(ToString::to_string)),
+            );
+        }
+
+        for reference in &metadata.references {
+            self.print_attr(color, "See also: ", reference);
+        }
+
+        println!();
+        println!("{}", metadata.description.trim());
+    }
+
     /// Determines whether the process should exit with failure based on configuration
     /// such as --deny=warnings
     #[must_use]
@@ -342,8 +670,24 @@ impl Presenter {
     fn print_vulnerability(&self, vulnerability: &Vulnerability) {
         self.print_attr(Red, "Crate:    ", &vulnerability.package.name);
         self.print_attr(Red, "Version:  ", vulnerability.package.version.to_string());
+        self.print_confidence(Red, vulnerability.confidence);
         self.print_metadata(&vulnerability.advisory, Red);
 
+        if let Some(epss) = &vulnerability.epss {
+            self.print_attr(Red, "EPSS:     ", epss.to_string());
+        }
+
+        if let Some(kev) = &vulnerability.kev {
+            self.print_attr(
+                Red,
+                "KEV:      ",
+                format!(
+                    "{} is known to be exploited in the wild (CISA KEV, added {})",
+                    kev.cve_id, kev.date_added
+                ),
+            );
+        }
+
         if vulnerability.versions.patched().is_empty() {
             self.print_attr(Red, "Solution: ", "No fixed upgrade is available!");
         } else {
@@ -367,8 +711,13 @@ impl Presenter {
 
     /// Print information about a given warning
     fn print_warning(&self, warning: &Warning, color: Color) {
-        self.print_attr(color, "Crate:    ", &warning.package.name);
+        if warning.is_bundled_library() {
+            self.print_attr(color, "Library:  ", &warning.package.name);
+        } else {
+            self.print_attr(color, "Crate:    ", &warning.package.name);
+        }
         self.print_attr(color, "Version:  ", warning.package.version.to_string());
+        self.print_confidence(color, warning.confidence);
         self.print_attr(color, "Warning:  ", warning.kind.as_str());
 
         if let Some(metadata) = &warning.advisory {
@@ -376,6 +725,16 @@ impl Presenter {
         }
     }
 
+    /// Note how a package was recovered from a binary, unless it's known exactly
+    fn print_confidence(&self, color: Color, confidence: Option<Confidence>) {
+        let evidence = match confidence {
+            Some(Confidence::PanicMessage) => "panic message (the version may be inaccurate)",
+            Some(Confidence::SourcePath) => "source path (may be a false positive)",
+            Some(Confidence::Exact) | None => return,
+        };
+        self.print_attr(color, "Evidence: ", evidence);
+    }
+
     /// Get the color to use when displaying warnings
     fn warning_color(&self, deny_warning: bool) -> Color {
         if deny_warning { Red } else { Yellow }
@@ -410,6 +769,8 @@ impl Presenter {
                 "Severity: ",
                 format!("{} ({})", cvss.score(), cvss.severity()),
             );
+        } else if let Some(severity) = &metadata.severity {
+            self.print_attr(color, "Severity: ", severity.as_str());
         }
     }
 
@@ -423,6 +784,20 @@ impl Presenter {
             .unwrap();
     }
 
+    /// Display the binaries a finding applies to
+    #[cfg(feature = "binary-scanning")]
+    fn print_binaries(&self, color: Color, binaries: &[PathBuf]) {
+        self.print_attr(
+            color,
+            "Binaries: ",
+            binaries
+                .iter()
+                .map(|binary| binary.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+
     #[cfg(feature = "binary-scanning")]
     fn print_affected(&self, color: Color, funcs: impl IntoIterator<Item = FunctionPath>) {
         let mut funcs = funcs.into_iter().peekable();
@@ -450,6 +825,11 @@ impl Presenter {
             return;
         }
 
+        // Packages outside of the dependency tree, e.g. C libraries linked into a binary
+        let Some(&package_node) = tree.nodes().get(&Dependency::from(package)) else {
+            return;
+        };
+
         terminal::status::Status::new()
             .bold()
             .color(color)
@@ -457,7 +837,6 @@ impl Presenter {
             .print_stdout("")
             .unwrap();
 
-        let package_node = tree.nodes()[&Dependency::from(package)];
         tree.render(
             &mut io::stdout(),
             package_node,
@@ -0,0 +1,174 @@
+//! Finding the executables of running processes
+//!
+//! Only Linux is supported for now: the executable of each process is read
+//! through `/proc/<pid>/exe`, which still works when the file on disk has been
+//! replaced or deleted since the process was started (e.g. by an upgrade that
+//! didn't restart the service).
+
+use rustsec::{Error, ErrorKind};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Mount point of the proc filesystem
+const PROC_DIR: &str = "/proc";
+
+/// Suffix the kernel appends to the path of an executable which has been deleted
+const DELETED_SUFFIX: &str = " (deleted)";
+
+/// An executable, along with the processes running it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RunningExecutable {
+    /// Path of the executable, as started
+    pub path: PathBuf,
+
+    /// Has the executable been deleted (or replaced) since it was started?
+    pub deleted: bool,
+
+    /// IDs of the processes running it
+    pub pids: Vec<u32>,
+}
+
+impl RunningExecutable {
+    /// Path to read the executable from, which is valid even if it has been deleted
+    pub fn proc_path(&self) -> PathBuf {
+        Path::new(PROC_DIR)
+            .join(self.pids[0].to_string())
+            .join("exe")
+    }
+
+    /// Name of the executable in reports, e.g. `/usr/bin/app (pids 1234, 1240)`
+    pub fn display_name(&self) -> PathBuf {
+        let pids = self
+            .pids
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let label = if self.pids.len() == 1 { "pid" } else { "pids" };
+        let deleted = if self.deleted { ", deleted" } else { "" };
+
+        PathBuf::from(format!("{} ({label} {pids}{deleted})", self.path.display()))
+    }
+}
+
+/// Find the executables of the given processes, or of all processes if `pids` is empty.
+///
+/// When listing all processes, the ones whose executable can't be inspected
+/// (kernel threads, or processes of other users when not running as root) are
+/// skipped, and their number is returned alongside the executables.
+pub(crate) fn running_executables(
+    pids: &[u32],
+) -> rustsec::Result<(Vec<RunningExecutable>, usize)> {
+    if !cfg!(target_os = "linux") {
+        return Err(Error::new(
+            ErrorKind::BadParam,
+            "scanning running processes is only supported on Linux",
+        ));
+    }
+
+    let all = pids.is_empty();
+    let pids = if all { list_pids()? } else { pids.to_vec() };
+
+    let mut executables = BTreeMap::new();
+    let mut inaccessible = 0;
+
+    for pid in pids {
+        let exe = match fs::read_link(Path::new(PROC_DIR).join(pid.to_string()).join("exe")) {
+            Ok(exe) => exe,
+            // Kernel threads don't have an executable, and processes may exit meanwhile
+            Err(e) if all && e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) if all && e.kind() == io::ErrorKind::PermissionDenied => {
+                inaccessible += 1;
+                continue;
+            }
+            Err(e) => {
+                return Err(Error::with_source(
+                    ErrorKind::NotFound,
+                    format!("couldn't find the executable of process {pid}"),
+                    e,
+                ));
+            }
+        };
+
+        let (path, deleted) = parse_exe_link(exe);
+        executables
+            .entry((path, deleted))
+            .or_insert_with(Vec::new)
+            .push(pid);
+    }
+
+    let executables = executables
+        .into_iter()
+        .map(|((path, deleted), pids)| RunningExecutable {
+            path,
+            deleted,
+            pids,
+        })
+        .collect();
+
+    Ok((executables, inaccessible))
+}
+
+/// List the IDs of all running processes
+fn list_pids() -> rustsec::Result<Vec<u32>> {
+    let mut pids = fs::read_dir(PROC_DIR)
+        .map_err(|e| Error::with_source(ErrorKind::Io, format!("couldn't read {PROC_DIR}"), e))?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect::<Vec<u32>>();
+
+    pids.sort_unstable();
+    Ok(pids)
+}
+
+/// Split the target of a `/proc/<pid>/exe` link into the path and whether it was deleted
+fn parse_exe_link(exe: PathBuf) -> (PathBuf, bool) {
+    match exe
+        .to_str()
+        .and_then(|exe| exe.strip_suffix(DELETED_SUFFIX))
+    {
+        Some(path) => (PathBuf::from(path), true),
+        None => (exe, false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exe_links() {
+        assert_eq!(
+            parse_exe_link(PathBuf::from("/usr/bin/app (deleted)")),
+            (PathBuf::from("/usr/bin/app"), true)
+        );
+        assert_eq!(
+            parse_exe_link(PathBuf::from("/usr/bin/app")),
+            (PathBuf::from("/usr/bin/app"), false)
+        );
+
+        let executable = RunningExecutable {
+            path: PathBuf::from("/usr/bin/app"),
+            deleted: true,
+            pids: vec![1234, 1240],
+        };
+        assert_eq!(executable.proc_path(), PathBuf::from("/proc/1234/exe"));
+        assert_eq!(
+            executable.display_name(),
+            PathBuf::from("/usr/bin/app (pids 1234, 1240, deleted)")
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn current_process() {
+        let pid = std::process::id();
+        let (executables, _) = running_executables(&[pid]).unwrap();
+
+        assert_eq!(executables.len(), 1);
+        assert_eq!(executables[0].pids, [pid]);
+        assert_eq!(executables[0].path, std::env::current_exe().unwrap());
+    }
+}
@@ -143,11 +143,18 @@ struct ReportingDescriptor {
 impl ReportingDescriptor {
     /// Create a ReportingDescriptor from an advisory
     fn from_advisory(metadata: &advisory::Metadata, is_vulnerability: bool) -> Self {
-        let tags = if is_vulnerability {
-            &[Tag::Security, Tag::Vulnerability]
+        let mut tags = if is_vulnerability {
+            vec![Tag::Security, Tag::Vulnerability]
         } else {
-            &[Tag::Security, Tag::Warning]
+            vec![Tag::Security, Tag::Warning]
         };
+        // Recognized by GitHub code scanning, e.g. `external/cwe/cwe-416`
+        tags.extend(
+            metadata
+                .cwe_ids
+                .iter()
+                .map(|cwe_id| Tag::External(format!("external/cwe/cwe-{}", cwe_id.number()))),
+        );
 
         let security_severity = metadata
             .cvss
@@ -210,6 +217,10 @@ impl ReportingDescriptor {
                 "yanked",
                 "Package version has been yanked from the registry",
             ),
+            WarningKind::BundledLibrary => (
+                "bundled-library",
+                "Binary contains a statically linked C library with known vulnerabilities",
+            ),
             _ => ("unknown", "Unknown warning type"),
         };
 
@@ -226,7 +237,7 @@ impl ReportingDescriptor {
             },
             help: None,
             properties: RuleProperties {
-                tags: &[Tag::Security, Tag::Warning],
+                tags: vec![Tag::Security, Tag::Warning],
                 precision: Precision::High,
                 problem_severity: Some(ProblemSeverity::Warning),
                 security_severity: None,
@@ -240,8 +251,8 @@ impl ReportingDescriptor {
 #[serde(rename_all = "camelCase")]
 struct RuleProperties {
     /// Tags associated with the rule
-    #[serde(skip_serializing_if = "<[Tag]>::is_empty")]
-    tags: &'static [Tag],
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
     /// Precision of the rule (e.g., "very-high", "high")
     precision: Precision,
     /// Problem severity for non-security issues
@@ -444,6 +455,9 @@ enum Tag {
     Security,
     Vulnerability,
     Warning,
+    /// Tag from an external taxonomy, such as CWE
+    #[serde(untagged)]
+    External(String),
 }
 
 #[derive(Debug, Serialize)]
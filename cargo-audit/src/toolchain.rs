@@ -0,0 +1,109 @@
+//! Finding the version of the Rust toolchain a project is built with
+
+use rustsec::Version;
+use std::{env, fs, path::Path, process::Command};
+
+/// Toolchain files, in the order in which rustup looks for them
+const TOOLCHAIN_FILES: &[&str] = &["rust-toolchain", "rust-toolchain.toml"];
+
+/// Find the version of the Rust toolchain used in the given directory.
+///
+/// `rustc --version` is run in the directory, so that rustup picks the
+/// toolchain pinned there by `rust-toolchain.toml` (if any). If `rustc` can't
+/// be run, the version is read from the toolchain file instead, provided it
+/// pins a specific version rather than a channel like `stable`.
+pub(crate) fn active_version(dir: &Path) -> Option<Version> {
+    rustc_version(dir).or_else(|| pinned_version(dir))
+}
+
+/// Ask `rustc` (or the compiler set in `$RUSTC`) for its version
+fn rustc_version(dir: &Path) -> Option<Version> {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc)
+        .arg("--version")
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    parse_rustc_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the output of `rustc --version`, e.g. `rustc 1.75.0 (82e1608df 2023-12-21)`
+fn parse_rustc_version(output: &str) -> Option<Version> {
+    let mut words = output.split_whitespace();
+    match (words.next(), words.next()) {
+        (Some("rustc"), Some(version)) => version.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Read the version pinned by the toolchain file of the given directory or
+/// of its closest parent which has one
+fn pinned_version(dir: &Path) -> Option<Version> {
+    let contents = dir.ancestors().find_map(|dir| {
+        TOOLCHAIN_FILES
+            .iter()
+            .find_map(|file| fs::read_to_string(dir.join(file)).ok())
+    })?;
+
+    parse_channel(&toolchain_channel(&contents)?)
+}
+
+/// Get the channel from the contents of a toolchain file, which is either in
+/// the TOML format or (for legacy `rust-toolchain` files) just the channel
+fn toolchain_channel(contents: &str) -> Option<String> {
+    match contents.parse::<toml::Table>() {
+        Ok(table) => Some(table.get("toolchain")?.get("channel")?.as_str()?.to_owned()),
+        Err(_) => Some(contents.trim().to_owned()),
+    }
+}
+
+/// Parse a channel which pins a version, e.g. `1.75.0` or `1.75` (assumed to
+/// be the first release of that version)
+fn parse_channel(channel: &str) -> Option<Version> {
+    let mut parts = channel.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = match parts.next() {
+        Some(patch) => patch.parse().ok()?,
+        None => 0,
+    };
+
+    parts
+        .next()
+        .is_none()
+        .then(|| Version::new(major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rustc_versions() {
+        assert_eq!(
+            parse_rustc_version("rustc 1.75.0 (82e1608df 2023-12-21)\n"),
+            Some(Version::new(1, 75, 0))
+        );
+        assert_eq!(
+            parse_rustc_version("rustc 1.77.0-nightly (11f32b73e 2024-01-31)"),
+            Some("1.77.0-nightly".parse().unwrap())
+        );
+        assert_eq!(parse_rustc_version("cargo 1.75.0"), None);
+    }
+
+    #[test]
+    fn toolchain_files() {
+        let channel = |contents| toolchain_channel(contents).and_then(|c| parse_channel(&c));
+
+        assert_eq!(
+            channel("[toolchain]\nchannel = \"1.75.0\"\ncomponents = [\"clippy\"]\n"),
+            Some(Version::new(1, 75, 0))
+        );
+        assert_eq!(channel("1.70\n"), Some(Version::new(1, 70, 0)));
+        assert_eq!(channel("[toolchain]\nchannel = \"stable\"\n"), None);
+        assert_eq!(channel("nightly-2024-01-31"), None);
+        assert_eq!(channel("[toolchain]\ncomponents = [\"miri\"]\n"), None);
+    }
+}
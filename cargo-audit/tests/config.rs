@@ -2,7 +2,7 @@
 
 use std::{fs, path::Path};
 
-use cargo_audit::config::AuditConfig;
+use cargo_audit::config::{AuditConfig, DenyOption};
 use rustsec::platforms::{Arch, OS};
 
 /// Ensure `audit.toml.example` parses as a valid config file
@@ -33,3 +33,19 @@ fn parser_audit_toml_example() {
     assert_eq!(config.target.arch(), vec![Arch::X86_64]);
     assert_eq!(config.target.os(), vec![OS::Linux]);
 }
+
+/// Ensure EPSS thresholds and KEV policies round-trip through the `deny` option
+#[test]
+fn parse_deny_epss_and_kev() {
+    let config: AuditConfig =
+        toml::from_str("[output]\nquiet = false\ndeny = [\"unsound\", \"epss>=0.5\"]").unwrap();
+
+    assert_eq!(config.output.deny[0], DenyOption::Unsound);
+    assert_eq!(config.output.epss_threshold().unwrap().value(), 0.5);
+    assert_eq!(config.output.deny[1].to_string(), "epss>=0.5");
+    assert_eq!("kev".parse::<DenyOption>().unwrap(), DenyOption::Kev);
+
+    assert!(
+        toml::from_str::<AuditConfig>("[output]\nquiet = false\ndeny = [\"epss>=2\"]").is_err()
+    );
+}
@@ -21,7 +21,7 @@ use {
 pub const PREFIX: &str = "CVSS";
 
 /// A CVSS vector
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[non_exhaustive]
 pub enum Cvss {
     #[cfg(feature = "v3")]
@@ -65,6 +65,24 @@ impl Cvss {
         }
     }
 
+    /// Get the canonical form of this vector, in which metrics explicitly set
+    /// to Not Defined (`X`) are omitted.
+    ///
+    /// Two vectors carrying the same metrics are equal once canonicalized,
+    /// regardless of the order or case of the metrics in the strings they
+    /// were parsed from, and [`Display`](fmt::Display) produces the same
+    /// canonical vector string for both.
+    pub fn canonicalize(&self) -> Self {
+        match self {
+            #[cfg(feature = "v3")]
+            Self::CvssV30(base) => Self::CvssV30(base.canonicalize()),
+            #[cfg(feature = "v3")]
+            Self::CvssV31(base) => Self::CvssV31(base.canonicalize()),
+            #[cfg(feature = "v4")]
+            Self::CvssV40(vector) => Self::CvssV40(vector.canonicalize()),
+        }
+    }
+
     /// Get an iterator over all defined metrics
     pub fn metrics(&self) -> Box<dyn Iterator<Item = (MetricType, &dyn fmt::Debug)> + '_> {
         match self {
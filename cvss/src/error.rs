@@ -1,5 +1,9 @@
 //! Error types
 
+#[cfg(any(feature = "v3", feature = "v4"))]
+use crate::Fix;
+#[cfg(feature = "v2")]
+use crate::v2;
 use crate::v3;
 #[cfg(feature = "v4")]
 use crate::v4;
@@ -28,6 +32,16 @@ pub enum Error {
         value: String,
     },
 
+    #[cfg(feature = "v2")]
+    /// Invalid metric for CVSSv2.
+    InvalidMetricV2 {
+        /// The metric that was invalid.
+        metric_type: v2::MetricType,
+
+        /// The value that was provided which is invalid.
+        value: String,
+    },
+
     #[cfg(feature = "v4")]
     /// Invalid metric for CVSSv4.
     InvalidMetricV4 {
@@ -82,6 +96,13 @@ pub enum Error {
         /// Provided version string.
         version: String,
     },
+
+    #[cfg(any(feature = "v3", feature = "v4"))]
+    /// Vector string which isn't in canonical form, when parsing strictly.
+    NonCanonical {
+        /// Fix the vector string needs.
+        fix: Fix,
+    },
 }
 
 impl fmt::Display for Error {
@@ -99,6 +120,16 @@ impl fmt::Display for Error {
                     value
                 )
             }
+            #[cfg(feature = "v2")]
+            Error::InvalidMetricV2 { metric_type, value } => {
+                write!(
+                    f,
+                    "invalid CVSSv2 {} ({}) metric: `{}`",
+                    metric_type.name(),
+                    metric_type.description(),
+                    value
+                )
+            }
             #[cfg(feature = "v4")]
             Error::InvalidMetricV4 { metric_type, value } => {
                 write!(
@@ -141,6 +172,10 @@ impl fmt::Display for Error {
             Error::UnsupportedVersion { version } => {
                 write!(f, "unsupported CVSS version: {version}")
             }
+            #[cfg(any(feature = "v3", feature = "v4"))]
+            Error::NonCanonical { fix } => {
+                write!(f, "CVSS vector string isn't in canonical form: {fix}")
+            }
         }
     }
 }
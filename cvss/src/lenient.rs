@@ -0,0 +1,144 @@
+//! Lenient and strict parsing of vector strings
+
+use crate::{Cvss, Error, PREFIX, Result};
+use alloc::{borrow::ToOwned, format, string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+/// Metrics which only exist in CVSS v4.0, used to guess the version of vector
+/// strings missing their prefix.
+const V4_METRICS: &[&str] = &["AT", "VC", "VI", "VA", "SC", "SI", "SA"];
+
+/// Deviation from the canonical form of a vector string, which is fixed when
+/// parsing with [`Cvss::from_str_lenient`] and rejected by
+/// [`Cvss::from_str_strict`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Fix {
+    /// Leading or trailing whitespace was removed
+    Whitespace,
+
+    /// Parentheses around the vector string were removed
+    Parentheses,
+
+    /// Empty components (e.g. from a trailing `/`) were removed
+    EmptyComponent,
+
+    /// Metric names or values were converted to uppercase
+    Lowercase,
+
+    /// The missing `CVSS:<version>` prefix was added, with the version
+    /// guessed from the metrics present
+    MissingPrefix,
+
+    /// Metrics weren't in the order given by the specification
+    Reordered,
+}
+
+impl Fix {
+    /// Get a description of this fix
+    pub fn description(self) -> &'static str {
+        match self {
+            Fix::Whitespace => "removed surrounding whitespace",
+            Fix::Parentheses => "removed surrounding parentheses",
+            Fix::EmptyComponent => "removed empty components",
+            Fix::Lowercase => "converted metrics to uppercase",
+            Fix::MissingPrefix => "added missing CVSS prefix",
+            Fix::Reordered => "metrics were out of order",
+        }
+    }
+}
+
+impl fmt::Display for Fix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl Cvss {
+    /// Parse a vector string, fixing common deviations from its canonical
+    /// form found in third-party data (see [`Fix`]).
+    ///
+    /// Returns the vector along with the list of fixes which were needed,
+    /// which is empty if the vector string was in canonical form.
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<Fix>)> {
+        let mut fixes = Vec::new();
+        let mut fix = |fix: Fix| {
+            if !fixes.contains(&fix) {
+                fixes.push(fix);
+            }
+        };
+
+        let mut vector = s.trim();
+        if vector.len() != s.len() {
+            fix(Fix::Whitespace);
+        }
+
+        if let Some(inner) = vector
+            .strip_prefix('(')
+            .and_then(|vector| vector.strip_suffix(')'))
+        {
+            fix(Fix::Parentheses);
+            vector = inner;
+        }
+
+        let mut components = Vec::new();
+        for component in vector.split('/') {
+            let trimmed = component.trim();
+            if trimmed.len() != component.len() {
+                fix(Fix::Whitespace);
+            }
+
+            if trimmed.is_empty() {
+                fix(Fix::EmptyComponent);
+            } else if trimmed.contains(char::is_lowercase) {
+                fix(Fix::Lowercase);
+                components.push(trimmed.to_ascii_uppercase());
+            } else {
+                components.push(trimmed.to_string());
+            }
+        }
+
+        let has_prefix = components
+            .first()
+            .is_some_and(|first| first.starts_with(&format!("{PREFIX}:")));
+        if !has_prefix {
+            let version = if components.iter().any(|component| {
+                let name = component.split(':').next().unwrap_or_default();
+                V4_METRICS.contains(&name)
+            }) {
+                "4.0"
+            } else {
+                "3.1"
+            };
+            components.insert(0, format!("{PREFIX}:{version}"));
+            fix(Fix::MissingPrefix);
+        }
+
+        let vector = components.join("/");
+        let cvss = Self::from_str(&vector)?;
+
+        let metric_names = |vector: &str| {
+            vector
+                .split('/')
+                .skip(1)
+                .map(|component| component.split(':').next().unwrap_or_default().to_owned())
+                .collect::<Vec<_>>()
+        };
+        if metric_names(&vector) != metric_names(&cvss.to_string()) {
+            fix(Fix::Reordered);
+        }
+
+        Ok((cvss, fixes))
+    }
+
+    /// Parse a vector string, which must be in canonical form: unlike with
+    /// [`FromStr`], lowercase and out of order metrics are rejected.
+    pub fn from_str_strict(s: &str) -> Result<Self> {
+        let (cvss, fixes) = Self::from_str_lenient(s)?;
+
+        match fixes.first() {
+            Some(&fix) => Err(Error::NonCanonical { fix }),
+            None => Ok(cvss),
+        }
+    }
+}
@@ -8,28 +8,37 @@
 //! ## Usage
 //!
 //! The [`Cvss`] type provides a unified interface for working with CVSS
-//! vectors.
+//! vectors. Besides [`FromStr`](core::str::FromStr), vector strings from
+//! third-party data can be parsed with [`Cvss::from_str_lenient`], which fixes
+//! cosmetic deviations from the canonical form (see [`Fix`]), or with
+//! [`Cvss::from_str_strict`], which rejects them.
 //!
 //! The [`v3::Base`] type provides the main functionality currently implemented
 //! for CVSS v3, namely: support for parsing, serializing, and scoring
 //! `CVSS:3.0` and `CVSS:3.1` Base Metric Group vector strings as described in
-//! the [CVSS v3.1 Specification].
+//! the [CVSS v3.1 Specification], optionally along with Temporal and
+//! Environmental metrics.
 //!
 //! The [`v4::Vector`] type provides a fully-featured implementation of CVSS
 //! v4.0, as described in the [CVSS v4.0 Specification].
 //!
+//! The [`v2::Base`] type supports parsing, serializing, and scoring legacy
+//! CVSS v2.0 Base Metric Group vector strings, as found in the data of older
+//! CVEs and described in the [CVSS v2.0 Guide].
+//!
 //! Serde support is available through the optional `serde` Cargo feature.
 //!
 //! [CVSS v3.1 Specification]: https://www.first.org/cvss/v3.1/specification-document
 //! [CVSS v4.0 Specification]: https://www.first.org/cvss/v4.0/specification-document
-
-// TODO(tarcieri): CVSS v2.0, CVSS v3.1 Temporal and Environmental Groups
+//! [CVSS v2.0 Guide]: https://www.first.org/cvss/v2/guide
 
 extern crate alloc;
 
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "v2")]
+pub mod v2;
 // A part of the v3 API is exposed even without the feature for compatibility.
 pub mod v3;
 #[cfg(feature = "v4")]
@@ -38,13 +47,15 @@ pub mod v4;
 #[cfg(any(feature = "v3", feature = "v4"))]
 mod cvss;
 mod error;
+#[cfg(any(feature = "v3", feature = "v4"))]
+mod lenient;
 mod severity;
 
 // For compatibility
 pub use crate::v3::metric::{Metric, MetricType};
 
 #[cfg(any(feature = "v3", feature = "v4"))]
-pub use crate::cvss::Cvss;
+pub use crate::{cvss::Cvss, lenient::Fix};
 
 pub use crate::{
     error::{Error, Result},
@@ -60,7 +60,8 @@ impl FromStr for Severity {
         match s.to_ascii_lowercase().as_str() {
             "none" => Ok(Severity::None),
             "low" => Ok(Severity::Low),
-            "medium" => Ok(Severity::Medium),
+            // Name used by the GitHub Advisory Database for Medium
+            "medium" | "moderate" => Ok(Severity::Medium),
             "high" => Ok(Severity::High),
             "critical" => Ok(Severity::Critical),
             _ => Err(Error::InvalidSeverity { name: s.to_owned() }),
@@ -0,0 +1,15 @@
+//! Common Vulnerability Scoring System (v2.0)
+//!
+//! Legacy version of CVSS, still found in the data of older CVEs.
+//!
+//! <https://www.first.org/cvss/v2/guide>
+
+pub mod base;
+pub mod metric;
+mod score;
+
+pub use self::{
+    base::Base,
+    metric::{Metric, MetricType},
+    score::Score,
+};
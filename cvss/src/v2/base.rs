@@ -0,0 +1,220 @@
+//! CVSS v2.0 Base Metric Group
+
+mod a;
+mod ac;
+mod au;
+mod av;
+mod c;
+mod i;
+
+pub use self::{
+    a::Availability, ac::AccessComplexity, au::Authentication, av::AccessVector,
+    c::Confidentiality, i::Integrity,
+};
+
+use super::{Metric, MetricType, Score};
+use crate::{Error, Result};
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::{String, ToString},
+    serde::{Deserialize, Serialize, de, ser},
+};
+
+#[cfg(feature = "std")]
+use crate::Severity;
+
+/// CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1:
+/// <https://www.first.org/cvss/v2/guide#2-1-Base-Metrics>
+///
+/// > The base metric group captures the characteristics of a vulnerability
+/// > that are constant with time and across user environments. The Access
+/// > Vector, Access Complexity, and Authentication metrics capture how the
+/// > vulnerability is accessed and whether or not extra conditions are
+/// > required to exploit it. The three impact metrics measure how a
+/// > vulnerability, if exploited, will directly affect an IT asset, where the
+/// > impacts are independently defined as the degree of loss of
+/// > confidentiality, integrity, and availability.
+///
+/// Unlike later versions, CVSS v2.0 vectors have no prefix, e.g.
+/// `AV:N/AC:L/Au:N/C:P/I:P/A:P`. Vectors in parentheses, as they are
+/// sometimes written, are accepted when parsing.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Base {
+    /// Access Vector (AV)
+    pub av: Option<AccessVector>,
+
+    /// Access Complexity (AC)
+    pub ac: Option<AccessComplexity>,
+
+    /// Authentication (Au)
+    pub au: Option<Authentication>,
+
+    /// Confidentiality Impact (C)
+    pub c: Option<Confidentiality>,
+
+    /// Integrity Impact (I)
+    pub i: Option<Integrity>,
+
+    /// Availability Impact (A)
+    pub a: Option<Availability>,
+}
+
+impl Base {
+    /// Calculate Base CVSS score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+    ///
+    /// > The base equation is the foundation of CVSS scoring.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        let impact = self.impact().value();
+        let exploitability = self.exploitability().value();
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+
+        Score::new((((0.6 * impact) + (0.4 * exploitability)) - 1.5) * f_impact)
+            .round_to_1_decimal()
+    }
+
+    /// Calculate Base Exploitability sub-score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+    pub fn exploitability(&self) -> Score {
+        let av_score = self.av.map(|av| av.score()).unwrap_or(0.0);
+        let ac_score = self.ac.map(|ac| ac.score()).unwrap_or(0.0);
+        let au_score = self.au.map(|au| au.score()).unwrap_or(0.0);
+
+        (20.0 * av_score * ac_score * au_score).into()
+    }
+
+    /// Calculate Base Impact sub-score.
+    ///
+    /// Described in CVSS v2.0 Guide: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+    pub fn impact(&self) -> Score {
+        let c_score = self.c.map(|c| c.score()).unwrap_or(0.0);
+        let i_score = self.i.map(|i| i.score()).unwrap_or(0.0);
+        let a_score = self.a.map(|a| a.score()).unwrap_or(0.0);
+
+        (10.41 * (1.0 - (1.0 - c_score) * (1.0 - i_score) * (1.0 - a_score))).into()
+    }
+
+    /// Iterate over all defined Base metrics
+    pub fn metrics(&self) -> impl Iterator<Item = (MetricType, &dyn fmt::Debug)> {
+        [
+            (
+                MetricType::AV,
+                self.av.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::AC,
+                self.ac.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::Au,
+                self.au.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (MetricType::C, self.c.as_ref().map(|m| m as &dyn fmt::Debug)),
+            (MetricType::I, self.i.as_ref().map(|m| m as &dyn fmt::Debug)),
+            (MetricType::A, self.a.as_ref().map(|m| m as &dyn fmt::Debug)),
+        ]
+        .into_iter()
+        .filter_map(|(name, metric)| metric.as_ref().map(|&m| (name, m)))
+    }
+
+    /// Calculate Base CVSS `Severity` according to the rating scale of the
+    /// NVD (i.e. Low / Medium / High), see [`Score::severity`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let metrics: [Option<&dyn fmt::Display>; 6] = [
+            self.av.as_ref().map(|m| m as &dyn fmt::Display),
+            self.ac.as_ref().map(|m| m as &dyn fmt::Display),
+            self.au.as_ref().map(|m| m as &dyn fmt::Display),
+            self.c.as_ref().map(|m| m as &dyn fmt::Display),
+            self.i.as_ref().map(|m| m as &dyn fmt::Display),
+            self.a.as_ref().map(|m| m as &dyn fmt::Display),
+        ];
+        for (n, metric) in metrics.iter().flatten().enumerate() {
+            if n > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{metric}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Base {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let vector = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+
+        let components = vector
+            .split('/')
+            .map(|component| {
+                component
+                    .split_once(':')
+                    .filter(|(_, value)| !value.contains(':'))
+                    .ok_or_else(|| Error::InvalidComponent {
+                        component: component.to_owned(),
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut metrics = Self::default();
+
+        for (id, value) in components {
+            match id.parse::<MetricType>()? {
+                MetricType::AV => metrics.av = Some(value.parse()?),
+                MetricType::AC => metrics.ac = Some(value.parse()?),
+                MetricType::Au => metrics.au = Some(value.parse()?),
+                MetricType::C => metrics.c = Some(value.parse()?),
+                MetricType::I => metrics.i = Some(value.parse()?),
+                MetricType::A => metrics.a = Some(value.parse()?),
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Base {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Base {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
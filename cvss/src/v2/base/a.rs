@@ -0,0 +1,78 @@
+//! CVSS v2.0 Base Metric Group - Availability Impact (A)
+
+use crate::{
+    Error,
+    v2::{Metric, MetricType},
+};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Availability Impact (A) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.6:
+/// <https://www.first.org/cvss/v2/guide#2-1-6-Availability-Impact-A>
+///
+/// > This metric measures the impact to availability of a successfully
+/// > exploited vulnerability. Availability refers to the accessibility of
+/// > information resources. Attacks that consume network bandwidth,
+/// > processor cycles, or disk space all impact the availability of a system.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Availability {
+    /// None (N)
+    ///
+    /// > There is no impact to the availability of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > There is reduced performance or interruptions in resource availability.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total shutdown of the affected resource. The attacker can
+    /// > render the resource completely unavailable.
+    Complete,
+}
+
+impl Metric for Availability {
+    const TYPE: MetricType = MetricType::A;
+
+    fn score(self) -> f64 {
+        match self {
+            Availability::None => 0.0,
+            Availability::Partial => 0.275,
+            Availability::Complete => 0.660,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Availability::None => "N",
+            Availability::Partial => "P",
+            Availability::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for Availability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for Availability {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(Availability::None),
+            "P" => Ok(Availability::Partial),
+            "C" => Ok(Availability::Complete),
+            _ => Err(Error::InvalidMetricV2 {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! CVSS v2.0 Base Metric Group - Confidentiality Impact (C)
+
+use crate::{
+    Error,
+    v2::{Metric, MetricType},
+};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Confidentiality Impact (C) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.4:
+/// <https://www.first.org/cvss/v2/guide#2-1-4-Confidentiality-Impact-C>
+///
+/// > This metric measures the impact on confidentiality of a successfully
+/// > exploited vulnerability. Confidentiality refers to limiting information
+/// > access and disclosure to only authorized users, as well as preventing
+/// > access by, or disclosure to, unauthorized ones.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Confidentiality {
+    /// None (N)
+    ///
+    /// > There is no impact to the confidentiality of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > There is considerable informational disclosure. Access to some system
+    /// > files is possible, but the attacker does not have control over what
+    /// > is obtained, or the scope of the loss is constrained.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is total information disclosure, resulting in all system files
+    /// > being revealed.
+    Complete,
+}
+
+impl Metric for Confidentiality {
+    const TYPE: MetricType = MetricType::C;
+
+    fn score(self) -> f64 {
+        match self {
+            Confidentiality::None => 0.0,
+            Confidentiality::Partial => 0.275,
+            Confidentiality::Complete => 0.660,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Confidentiality::None => "N",
+            Confidentiality::Partial => "P",
+            Confidentiality::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for Confidentiality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for Confidentiality {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(Confidentiality::None),
+            "P" => Ok(Confidentiality::Partial),
+            "C" => Ok(Confidentiality::Complete),
+            _ => Err(Error::InvalidMetricV2 {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
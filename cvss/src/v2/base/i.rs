@@ -0,0 +1,80 @@
+//! CVSS v2.0 Base Metric Group - Integrity Impact (I)
+
+use crate::{
+    Error,
+    v2::{Metric, MetricType},
+};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Integrity Impact (I) - CVSS v2.0 Base Metric Group
+///
+/// Described in CVSS v2.0 Guide: Section 2.1.5:
+/// <https://www.first.org/cvss/v2/guide#2-1-5-Integrity-Impact-I>
+///
+/// > This metric measures the impact to integrity of a successfully exploited
+/// > vulnerability. Integrity refers to the trustworthiness and guaranteed
+/// > veracity of information.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Integrity {
+    /// None (N)
+    ///
+    /// > There is no impact to the integrity of the system.
+    None,
+
+    /// Partial (P)
+    ///
+    /// > Modification of some system files or information is possible, but the
+    /// > attacker does not have control over what can be modified, or the
+    /// > scope of what the attacker can affect is limited.
+    Partial,
+
+    /// Complete (C)
+    ///
+    /// > There is a total compromise of system integrity. There is a complete
+    /// > loss of system protection, resulting in the entire system being
+    /// > compromised.
+    Complete,
+}
+
+impl Metric for Integrity {
+    const TYPE: MetricType = MetricType::I;
+
+    fn score(self) -> f64 {
+        match self {
+            Integrity::None => 0.0,
+            Integrity::Partial => 0.275,
+            Integrity::Complete => 0.660,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Integrity::None => "N",
+            Integrity::Partial => "P",
+            Integrity::Complete => "C",
+        }
+    }
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for Integrity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "N" => Ok(Integrity::None),
+            "P" => Ok(Integrity::Partial),
+            "C" => Ok(Integrity::Complete),
+            _ => Err(Error::InvalidMetricV2 {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
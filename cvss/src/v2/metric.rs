@@ -0,0 +1,96 @@
+//! CVSS v2.0 metrics.
+
+use crate::{Error, Result};
+use alloc::borrow::ToOwned;
+use core::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+/// Trait for CVSS v2.0 metrics.
+pub trait Metric: Copy + Clone + Debug + Display + Eq + FromStr + Ord {
+    /// [`MetricType`] of this metric.
+    const TYPE: MetricType;
+
+    /// Get the name of this metric.
+    fn name() -> &'static str {
+        Self::TYPE.name()
+    }
+
+    /// Get CVSS v2.0 score for this metric.
+    fn score(self) -> f64;
+
+    /// Get `str` describing this metric's value
+    fn as_str(self) -> &'static str;
+}
+
+/// Enum over all of the available CVSS v2.0 metrics.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum MetricType {
+    /// Availability Impact (A)
+    A,
+
+    /// Access Complexity (AC)
+    AC,
+
+    /// Authentication (Au)
+    Au,
+
+    /// Access Vector (AV)
+    AV,
+
+    /// Confidentiality Impact (C)
+    C,
+
+    /// Integrity Impact (I)
+    I,
+}
+
+impl MetricType {
+    /// Get the name of this metric (i.e. acronym)
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::AC => "AC",
+            Self::Au => "Au",
+            Self::AV => "AV",
+            Self::C => "C",
+            Self::I => "I",
+        }
+    }
+
+    /// Get a description of this metric.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::A => "Availability Impact",
+            Self::AC => "Access Complexity",
+            Self::Au => "Authentication",
+            Self::AV => "Access Vector",
+            Self::C => "Confidentiality Impact",
+            Self::I => "Integrity Impact",
+        }
+    }
+}
+
+impl Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for MetricType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "A" => Ok(Self::A),
+            "AC" => Ok(Self::AC),
+            "Au" => Ok(Self::Au),
+            "AV" => Ok(Self::AV),
+            "C" => Ok(Self::C),
+            "I" => Ok(Self::I),
+            _ => Err(Error::UnknownMetric { name: s.to_owned() }),
+        }
+    }
+}
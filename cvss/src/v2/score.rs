@@ -0,0 +1,63 @@
+//! CVSS v2.0 scores
+
+use crate::severity::Severity;
+
+/// CVSS v2.0 scores.
+///
+/// Formula described in CVSS v2.0 Guide: Section 3.2.1:
+/// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Score(f64);
+
+impl Score {
+    /// Create a new score object
+    pub fn new(score: f64) -> Score {
+        Score(score)
+    }
+
+    /// Get the score as a floating point value
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Round the score to one decimal, as done by the CVSS v2.0 equations.
+    #[cfg(feature = "std")]
+    pub fn round_to_1_decimal(self) -> Score {
+        Score((self.0 * 10.0).round() / 10.0)
+    }
+
+    /// Convert the numeric score into a `Severity`.
+    ///
+    /// CVSS v2.0 doesn't define a qualitative severity rating scale, so the
+    /// one of the [NVD] is used: Low (0.0 - 3.9), Medium (4.0 - 6.9) and
+    /// High (7.0 - 10.0). `None` and `Critical` are never returned.
+    ///
+    /// [NVD]: https://nvd.nist.gov/vuln-metrics/cvss
+    pub fn severity(self) -> Severity {
+        if self.0 < 4.0 {
+            Severity::Low
+        } else if self.0 < 7.0 {
+            Severity::Medium
+        } else {
+            Severity::High
+        }
+    }
+}
+
+impl From<f64> for Score {
+    fn from(score: f64) -> Score {
+        Score(score)
+    }
+}
+
+impl From<Score> for f64 {
+    fn from(score: Score) -> f64 {
+        score.value()
+    }
+}
+
+impl From<Score> for Severity {
+    fn from(score: Score) -> Severity {
+        score.severity()
+    }
+}
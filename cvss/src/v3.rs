@@ -2,19 +2,29 @@
 //!
 //! <https://www.first.org/cvss/specification-document>
 
-// TODO(tarcieri): Environmental and Temporal Metrics
-
 #[cfg(feature = "v3")]
 pub mod base;
 
+#[cfg(all(feature = "v3", feature = "std"))]
+mod breakdown;
+
+#[cfg(feature = "v3")]
+pub mod environmental;
+
 pub mod metric;
 
 #[cfg(feature = "v3")]
 mod score;
 
+#[cfg(feature = "v3")]
+pub mod temporal;
+
 #[cfg(feature = "v3")]
 pub use self::{
     base::Base,
     metric::{Metric, MetricType},
     score::Score,
 };
+
+#[cfg(all(feature = "v3", feature = "std"))]
+pub use self::breakdown::{Breakdown, MetricWeight};
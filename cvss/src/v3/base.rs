@@ -3,6 +3,7 @@
 mod a;
 mod ac;
 mod av;
+mod builder;
 mod c;
 mod i;
 mod pr;
@@ -10,11 +11,27 @@ mod s;
 mod ui;
 
 pub use self::{
-    a::Availability, ac::AttackComplexity, av::AttackVector, c::Confidentiality, i::Integrity,
-    pr::PrivilegesRequired, s::Scope, ui::UserInteraction,
+    a::Availability,
+    ac::AttackComplexity,
+    av::AttackVector,
+    builder::{Builder, Unset},
+    c::Confidentiality,
+    i::Integrity,
+    pr::PrivilegesRequired,
+    s::Scope,
+    ui::UserInteraction,
 };
 
-use super::Score;
+use super::{
+    Score,
+    environmental::{
+        AvailabilityRequirement, ConfidentialityRequirement, IntegrityRequirement,
+        ModifiedAttackComplexity, ModifiedAttackVector, ModifiedAvailability,
+        ModifiedConfidentiality, ModifiedIntegrity, ModifiedPrivilegesRequired, ModifiedScope,
+        ModifiedUserInteraction,
+    },
+    temporal::{ExploitCodeMaturity, RemediationLevel, ReportConfidence},
+};
 use crate::{Error, Metric, MetricType, PREFIX, Result};
 use alloc::{borrow::ToOwned, vec::Vec};
 use core::{fmt, str::FromStr};
@@ -26,7 +43,10 @@ use {
 };
 
 #[cfg(feature = "std")]
-use crate::Severity;
+use {
+    super::{Breakdown, MetricWeight},
+    crate::Severity,
+};
 
 /// CVSS v3.1 Base Metric Group
 ///
@@ -52,7 +72,12 @@ use crate::Severity;
 /// > resource. This potential for measuring the impact of a vulnerability other
 /// > than the vulnerable component, was a key feature introduced with
 /// > CVSS v3.0. This property is captured by the Scope metric.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+///
+/// The optional metrics of the [Temporal Metric Group](super::temporal) and
+/// the [Environmental Metric Group](super::environmental) can also be set, and
+/// are taken into account by [`Base::temporal_score`] and
+/// [`Base::environmental_score`] respectively.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Base {
     /// Minor component of the version
     pub minor_version: usize,
@@ -80,9 +105,86 @@ pub struct Base {
 
     /// Availability Impact (A)
     pub a: Option<Availability>,
+
+    /// Exploit Code Maturity (E)
+    pub e: Option<ExploitCodeMaturity>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+
+    /// Modified Attack Vector (MAV)
+    pub mav: Option<ModifiedAttackVector>,
+
+    /// Modified Attack Complexity (MAC)
+    pub mac: Option<ModifiedAttackComplexity>,
+
+    /// Modified Privileges Required (MPR)
+    pub mpr: Option<ModifiedPrivilegesRequired>,
+
+    /// Modified User Interaction (MUI)
+    pub mui: Option<ModifiedUserInteraction>,
+
+    /// Modified Scope (MS)
+    pub ms: Option<ModifiedScope>,
+
+    /// Modified Confidentiality Impact (MC)
+    pub mc: Option<ModifiedConfidentiality>,
+
+    /// Modified Integrity Impact (MI)
+    pub mi: Option<ModifiedIntegrity>,
+
+    /// Modified Availability Impact (MA)
+    pub ma: Option<ModifiedAvailability>,
 }
 
 impl Base {
+    /// Create a [`Builder`] for constructing a vector without formatting and
+    /// parsing a string.
+    ///
+    /// All of the Base metrics must be set before [`Builder::build`] can be
+    /// called:
+    ///
+    /// ```
+    /// use cvss::v3::{
+    ///     Base,
+    ///     base::{
+    ///         AttackComplexity, AttackVector, Availability, Confidentiality, Integrity,
+    ///         PrivilegesRequired, Scope, UserInteraction,
+    ///     },
+    /// };
+    ///
+    /// let base = Base::builder()
+    ///     .av(AttackVector::Network)
+    ///     .ac(AttackComplexity::Low)
+    ///     .pr(PrivilegesRequired::None)
+    ///     .ui(UserInteraction::None)
+    ///     .s(Scope::Unchanged)
+    ///     .c(Confidentiality::High)
+    ///     .i(Integrity::High)
+    ///     .a(Availability::High)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     base.to_string(),
+    ///     "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    /// );
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
     /// Calculate Base CVSS score: overall value for determining the severity
     /// of a vulnerability, generally referred to as the "CVSS score".
     ///
@@ -101,13 +203,7 @@ impl Base {
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn score(&self) -> Score {
         let exploitability = self.exploitability().value();
-        let iss = self.impact().value();
-
-        let iss_scoped = if !self.is_scope_changed() {
-            6.42 * iss
-        } else {
-            (7.52 * (iss - 0.029)) - (3.25 * (iss - 0.02).powf(15.0))
-        };
+        let iss_scoped = self.scoped_impact(self.impact().value());
 
         let score = if iss_scoped <= 0.0 {
             0.0
@@ -120,6 +216,136 @@ impl Base {
         Score::new(score).roundup()
     }
 
+    /// Get a [`Breakdown`] of the computation of the Base and Temporal
+    /// scores, i.e. the weights of the metrics and the sub-scores derived from
+    /// them, e.g. to explain a score to users.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn breakdown(&self) -> Breakdown {
+        let scope_changed = self.is_scope_changed();
+        let impact_sub_score = self.impact();
+
+        let weights = [
+            self.av.map(MetricWeight::new),
+            self.ac.map(MetricWeight::new),
+            self.pr
+                .map(|pr| MetricWeight::with_weight(pr, pr.scoped_score(scope_changed))),
+            self.ui.map(MetricWeight::new),
+            self.c.map(MetricWeight::new),
+            self.i.map(MetricWeight::new),
+            self.a.map(MetricWeight::new),
+            self.e.map(MetricWeight::new),
+            self.rl.map(MetricWeight::new),
+            self.rc.map(MetricWeight::new),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Breakdown {
+            weights,
+            scope_changed,
+            impact_sub_score,
+            impact: self.scoped_impact(impact_sub_score.value()).into(),
+            exploitability: self.exploitability(),
+            base_score: self.score(),
+            temporal_score: self.temporal_score(),
+        }
+    }
+
+    /// Calculate Temporal CVSS score: the Base score adjusted for the current
+    /// state of exploit techniques, remediation and report confidence.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.2:
+    /// <https://www.first.org/cvss/v3.1/specification-document#7-2-Temporal-Equations>
+    ///
+    /// > Roundup (BaseScore × ExploitCodeMaturity × RemediationLevel × ReportConfidence)
+    ///
+    /// Temporal metrics which aren't defined have no impact on the score, so
+    /// this is the same as the Base score when none of them is.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn temporal_score(&self) -> Score {
+        let e_score = self.e.map(|e| e.score()).unwrap_or(1.0);
+        let rl_score = self.rl.map(|rl| rl.score()).unwrap_or(1.0);
+        let rc_score = self.rc.map(|rc| rc.score()).unwrap_or(1.0);
+
+        Score::new(self.score().value() * e_score * rl_score * rc_score).roundup()
+    }
+
+    /// Calculate Environmental CVSS score: the Temporal score recomputed with
+    /// the Modified Base metrics and Security Requirements of a particular
+    /// deployment.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/v3.1/specification-document#7-3-Environmental-Equations>
+    ///
+    /// > If ModifiedImpact ≤ 0: 0, else
+    /// >
+    /// > If Modified Scope is Unchanged: Roundup (Roundup [Minimum
+    /// > ([ModifiedImpact + ModifiedExploitability], 10)] ×
+    /// > ExploitCodeMaturity × RemediationLevel × ReportConfidence)
+    /// >
+    /// > If Modified Scope is Changed: Roundup (Roundup [Minimum
+    /// > (1.08 × [ModifiedImpact + ModifiedExploitability], 10)] ×
+    /// > ExploitCodeMaturity × RemediationLevel × ReportConfidence)
+    ///
+    /// Modified Base metrics which aren't defined take the value of the
+    /// corresponding Base metric, and undefined Security Requirements have
+    /// the same effect as Medium.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn environmental_score(&self) -> Score {
+        let av = self.mav.map_or(self.av, |mav| mav.apply(self.av));
+        let ac = self.mac.map_or(self.ac, |mac| mac.apply(self.ac));
+        let pr = self.mpr.map_or(self.pr, |mpr| mpr.apply(self.pr));
+        let ui = self.mui.map_or(self.ui, |mui| mui.apply(self.ui));
+        let s = self.ms.map_or(self.s, |ms| ms.apply(self.s));
+        let c = self.mc.map_or(self.c, |mc| mc.apply(self.c));
+        let i = self.mi.map_or(self.i, |mi| mi.apply(self.i));
+        let a = self.ma.map_or(self.a, |ma| ma.apply(self.a));
+        let scope_changed = s.map(|s| s.is_changed()).unwrap_or(false);
+
+        let cr_score = self.cr.map(|cr| cr.score()).unwrap_or(1.0);
+        let ir_score = self.ir.map(|ir| ir.score()).unwrap_or(1.0);
+        let ar_score = self.ar.map(|ar| ar.score()).unwrap_or(1.0);
+        let c_score = c.map(|c| c.score()).unwrap_or(0.0);
+        let i_score = i.map(|i| i.score()).unwrap_or(0.0);
+        let a_score = a.map(|a| a.score()).unwrap_or(0.0);
+
+        let miss = (1.0
+            - (1.0 - cr_score * c_score) * (1.0 - ir_score * i_score) * (1.0 - ar_score * a_score))
+            .min(0.915);
+
+        let modified_impact = if !scope_changed {
+            6.42 * miss
+        } else {
+            (7.52 * (miss - 0.029)) - (3.25 * (miss * 0.9731 - 0.02).powf(13.0))
+        };
+
+        let modified_exploitability = 8.22
+            * av.map(|av| av.score()).unwrap_or(0.0)
+            * ac.map(|ac| ac.score()).unwrap_or(0.0)
+            * pr.map(|pr| pr.scoped_score(scope_changed)).unwrap_or(0.0)
+            * ui.map(|ui| ui.score()).unwrap_or(0.0);
+
+        if modified_impact <= 0.0 {
+            return Score::new(0.0);
+        }
+
+        let score = if !scope_changed {
+            (modified_impact + modified_exploitability).min(10.0)
+        } else {
+            (1.08 * (modified_impact + modified_exploitability)).min(10.0)
+        };
+
+        let e_score = self.e.map(|e| e.score()).unwrap_or(1.0);
+        let rl_score = self.rl.map(|rl| rl.score()).unwrap_or(1.0);
+        let rc_score = self.rc.map(|rc| rc.score()).unwrap_or(1.0);
+
+        Score::new(Score::new(score).roundup().value() * e_score * rl_score * rc_score).roundup()
+    }
+
     /// Calculate Base Exploitability score: sub-score for measuring
     /// ease of exploitation.
     ///
@@ -161,7 +387,47 @@ impl Base {
         (1.0 - ((1.0 - c_score) * (1.0 - i_score) * (1.0 - a_score)).abs()).into()
     }
 
-    /// Iterate over all defined Base metrics
+    /// Get the canonical form of this vector, in which metrics explicitly set
+    /// to Not Defined (`X`) are omitted, as they have the same meaning as
+    /// metrics which are absent.
+    ///
+    /// Metrics are always serialized in the order given by the specification
+    /// regardless of the order they were parsed in, so vectors from different
+    /// sources which carry the same metrics compare equal (and serialize to the
+    /// same string) once canonicalized.
+    pub fn canonicalize(&self) -> Self {
+        fn defined<M: Metric>(metric: Option<M>) -> Option<M> {
+            metric.filter(|m| m.as_str() != "X")
+        }
+
+        Self {
+            minor_version: self.minor_version,
+            av: defined(self.av),
+            ac: defined(self.ac),
+            pr: defined(self.pr),
+            ui: defined(self.ui),
+            s: defined(self.s),
+            c: defined(self.c),
+            i: defined(self.i),
+            a: defined(self.a),
+            e: defined(self.e),
+            rl: defined(self.rl),
+            rc: defined(self.rc),
+            cr: defined(self.cr),
+            ir: defined(self.ir),
+            ar: defined(self.ar),
+            mav: defined(self.mav),
+            mac: defined(self.mac),
+            mpr: defined(self.mpr),
+            mui: defined(self.mui),
+            ms: defined(self.ms),
+            mc: defined(self.mc),
+            mi: defined(self.mi),
+            ma: defined(self.ma),
+        }
+    }
+
+    /// Iterate over all defined Base (and Temporal and Environmental) metrics
     pub fn metrics(&self) -> impl Iterator<Item = (MetricType, &dyn fmt::Debug)> {
         [
             (
@@ -184,6 +450,59 @@ impl Base {
             (MetricType::C, self.c.as_ref().map(|m| m as &dyn fmt::Debug)),
             (MetricType::I, self.i.as_ref().map(|m| m as &dyn fmt::Debug)),
             (MetricType::A, self.a.as_ref().map(|m| m as &dyn fmt::Debug)),
+            (MetricType::E, self.e.as_ref().map(|m| m as &dyn fmt::Debug)),
+            (
+                MetricType::RL,
+                self.rl.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::RC,
+                self.rc.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::CR,
+                self.cr.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::IR,
+                self.ir.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::AR,
+                self.ar.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MAV,
+                self.mav.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MAC,
+                self.mac.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MPR,
+                self.mpr.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MUI,
+                self.mui.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MS,
+                self.ms.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MC,
+                self.mc.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MI,
+                self.mi.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
+            (
+                MetricType::MA,
+                self.ma.as_ref().map(|m| m as &dyn fmt::Debug),
+            ),
         ]
         .into_iter()
         .filter_map(|(name, metric)| metric.as_ref().map(|&m| (name, m)))
@@ -200,6 +519,16 @@ impl Base {
         self.score().severity()
     }
 
+    /// Adjust the Impact Sub-Score (ISS) for Scope
+    #[cfg(feature = "std")]
+    fn scoped_impact(&self, iss: f64) -> f64 {
+        if !self.is_scope_changed() {
+            6.42 * iss
+        } else {
+            (7.52 * (iss - 0.029)) - (3.25 * (iss - 0.02).powf(15.0))
+        }
+    }
+
     /// Has the scope changed?
     fn is_scope_changed(&self) -> bool {
         self.s.map(|s| s.is_changed()).unwrap_or(false)
@@ -220,7 +549,9 @@ impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:3.{}", PREFIX, self.minor_version)?;
         write_metrics!(
-            f, self.av, self.ac, self.pr, self.ui, self.s, self.c, self.i, self.a
+            f, self.av, self.ac, self.pr, self.ui, self.s, self.c, self.i, self.a, self.e, self.rl,
+            self.rc, self.cr, self.ir, self.ar, self.mav, self.mac, self.mpr, self.mui, self.ms,
+            self.mc, self.mi, self.ma
         );
         Ok(())
     }
@@ -290,6 +621,20 @@ impl FromStr for Base {
                 MetricType::C => metrics.c = Some(value.parse()?),
                 MetricType::I => metrics.i = Some(value.parse()?),
                 MetricType::A => metrics.a = Some(value.parse()?),
+                MetricType::E => metrics.e = Some(value.parse()?),
+                MetricType::RL => metrics.rl = Some(value.parse()?),
+                MetricType::RC => metrics.rc = Some(value.parse()?),
+                MetricType::CR => metrics.cr = Some(value.parse()?),
+                MetricType::IR => metrics.ir = Some(value.parse()?),
+                MetricType::AR => metrics.ar = Some(value.parse()?),
+                MetricType::MAV => metrics.mav = Some(value.parse()?),
+                MetricType::MAC => metrics.mac = Some(value.parse()?),
+                MetricType::MPR => metrics.mpr = Some(value.parse()?),
+                MetricType::MUI => metrics.mui = Some(value.parse()?),
+                MetricType::MS => metrics.ms = Some(value.parse()?),
+                MetricType::MC => metrics.mc = Some(value.parse()?),
+                MetricType::MI => metrics.mi = Some(value.parse()?),
+                MetricType::MA => metrics.ma = Some(value.parse()?),
             }
         }
 
@@ -0,0 +1,324 @@
+//! Builder for CVSS v3.1 Base Metric Group vectors
+
+use super::{
+    AttackComplexity, AttackVector, Availability, Base, Confidentiality, Integrity,
+    PrivilegesRequired, Scope, UserInteraction,
+};
+use crate::v3::{
+    environmental::{
+        AvailabilityRequirement, ConfidentialityRequirement, IntegrityRequirement,
+        ModifiedAttackComplexity, ModifiedAttackVector, ModifiedAvailability,
+        ModifiedConfidentiality, ModifiedIntegrity, ModifiedPrivilegesRequired, ModifiedScope,
+        ModifiedUserInteraction,
+    },
+    temporal::{ExploitCodeMaturity, RemediationLevel, ReportConfidence},
+};
+
+/// Placeholder for a required Base metric which hasn't been set yet.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Unset;
+
+/// Builder for [`Base`], obtained with [`Base::builder`].
+///
+/// Each of the eight Base metrics is tracked in the builder's type, so that
+/// [`Builder::build`] is only available once all of them have been set and
+/// an incomplete vector is rejected at compile time rather than producing a
+/// [`Base`] which can't be scored. Temporal and Environmental metrics are
+/// optional and can be set at any point.
+///
+/// ```compile_fail
+/// use cvss::v3::{Base, base::AttackVector};
+///
+/// // Missing all of the other Base metrics
+/// let base = Base::builder().av(AttackVector::Network).build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder<
+    AV = Unset,
+    AC = Unset,
+    PR = Unset,
+    UI = Unset,
+    S = Unset,
+    C = Unset,
+    I = Unset,
+    A = Unset,
+> {
+    av: AV,
+    ac: AC,
+    pr: PR,
+    ui: UI,
+    s: S,
+    c: C,
+    i: I,
+    a: A,
+
+    /// Minor version and optional metrics
+    base: Base,
+}
+
+impl Builder {
+    /// Create a new builder for a `CVSS:3.1` vector with no metrics set.
+    pub fn new() -> Self {
+        Self {
+            av: Unset,
+            ac: Unset,
+            pr: Unset,
+            ui: Unset,
+            s: Unset,
+            c: Unset,
+            i: Unset,
+            a: Unset,
+            base: Base {
+                minor_version: 1,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<AV, AC, PR, UI, S, C, I, A> Builder<AV, AC, PR, UI, S, C, I, A> {
+    /// Set Attack Vector (AV)
+    pub fn av(self, av: AttackVector) -> Builder<AttackVector, AC, PR, UI, S, C, I, A> {
+        Builder {
+            av,
+            ac: self.ac,
+            pr: self.pr,
+            ui: self.ui,
+            s: self.s,
+            c: self.c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Attack Complexity (AC)
+    pub fn ac(self, ac: AttackComplexity) -> Builder<AV, AttackComplexity, PR, UI, S, C, I, A> {
+        Builder {
+            av: self.av,
+            ac,
+            pr: self.pr,
+            ui: self.ui,
+            s: self.s,
+            c: self.c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Privileges Required (PR)
+    pub fn pr(self, pr: PrivilegesRequired) -> Builder<AV, AC, PrivilegesRequired, UI, S, C, I, A> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr,
+            ui: self.ui,
+            s: self.s,
+            c: self.c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set User Interaction (UI)
+    pub fn ui(self, ui: UserInteraction) -> Builder<AV, AC, PR, UserInteraction, S, C, I, A> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr: self.pr,
+            ui,
+            s: self.s,
+            c: self.c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Scope (S)
+    pub fn s(self, s: Scope) -> Builder<AV, AC, PR, UI, Scope, C, I, A> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr: self.pr,
+            ui: self.ui,
+            s,
+            c: self.c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Confidentiality Impact (C)
+    pub fn c(self, c: Confidentiality) -> Builder<AV, AC, PR, UI, S, Confidentiality, I, A> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr: self.pr,
+            ui: self.ui,
+            s: self.s,
+            c,
+            i: self.i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Integrity Impact (I)
+    pub fn i(self, i: Integrity) -> Builder<AV, AC, PR, UI, S, C, Integrity, A> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr: self.pr,
+            ui: self.ui,
+            s: self.s,
+            c: self.c,
+            i,
+            a: self.a,
+            base: self.base,
+        }
+    }
+
+    /// Set Availability Impact (A)
+    pub fn a(self, a: Availability) -> Builder<AV, AC, PR, UI, S, C, I, Availability> {
+        Builder {
+            av: self.av,
+            ac: self.ac,
+            pr: self.pr,
+            ui: self.ui,
+            s: self.s,
+            c: self.c,
+            i: self.i,
+            a,
+            base: self.base,
+        }
+    }
+
+    /// Set the minor component of the version, i.e. `0` for `CVSS:3.0`
+    /// (default: `1`, i.e. `CVSS:3.1`)
+    pub fn minor_version(mut self, minor_version: usize) -> Self {
+        self.base.minor_version = minor_version;
+        self
+    }
+
+    /// Set Exploit Code Maturity (E)
+    pub fn e(mut self, e: ExploitCodeMaturity) -> Self {
+        self.base.e = Some(e);
+        self
+    }
+
+    /// Set Remediation Level (RL)
+    pub fn rl(mut self, rl: RemediationLevel) -> Self {
+        self.base.rl = Some(rl);
+        self
+    }
+
+    /// Set Report Confidence (RC)
+    pub fn rc(mut self, rc: ReportConfidence) -> Self {
+        self.base.rc = Some(rc);
+        self
+    }
+
+    /// Set Confidentiality Requirement (CR)
+    pub fn cr(mut self, cr: ConfidentialityRequirement) -> Self {
+        self.base.cr = Some(cr);
+        self
+    }
+
+    /// Set Integrity Requirement (IR)
+    pub fn ir(mut self, ir: IntegrityRequirement) -> Self {
+        self.base.ir = Some(ir);
+        self
+    }
+
+    /// Set Availability Requirement (AR)
+    pub fn ar(mut self, ar: AvailabilityRequirement) -> Self {
+        self.base.ar = Some(ar);
+        self
+    }
+
+    /// Set Modified Attack Vector (MAV)
+    pub fn mav(mut self, mav: ModifiedAttackVector) -> Self {
+        self.base.mav = Some(mav);
+        self
+    }
+
+    /// Set Modified Attack Complexity (MAC)
+    pub fn mac(mut self, mac: ModifiedAttackComplexity) -> Self {
+        self.base.mac = Some(mac);
+        self
+    }
+
+    /// Set Modified Privileges Required (MPR)
+    pub fn mpr(mut self, mpr: ModifiedPrivilegesRequired) -> Self {
+        self.base.mpr = Some(mpr);
+        self
+    }
+
+    /// Set Modified User Interaction (MUI)
+    pub fn mui(mut self, mui: ModifiedUserInteraction) -> Self {
+        self.base.mui = Some(mui);
+        self
+    }
+
+    /// Set Modified Scope (MS)
+    pub fn ms(mut self, ms: ModifiedScope) -> Self {
+        self.base.ms = Some(ms);
+        self
+    }
+
+    /// Set Modified Confidentiality Impact (MC)
+    pub fn mc(mut self, mc: ModifiedConfidentiality) -> Self {
+        self.base.mc = Some(mc);
+        self
+    }
+
+    /// Set Modified Integrity Impact (MI)
+    pub fn mi(mut self, mi: ModifiedIntegrity) -> Self {
+        self.base.mi = Some(mi);
+        self
+    }
+
+    /// Set Modified Availability Impact (MA)
+    pub fn ma(mut self, ma: ModifiedAvailability) -> Self {
+        self.base.ma = Some(ma);
+        self
+    }
+}
+
+impl
+    Builder<
+        AttackVector,
+        AttackComplexity,
+        PrivilegesRequired,
+        UserInteraction,
+        Scope,
+        Confidentiality,
+        Integrity,
+        Availability,
+    >
+{
+    /// Build the [`Base`] vector.
+    pub fn build(self) -> Base {
+        Base {
+            av: Some(self.av),
+            ac: Some(self.ac),
+            pr: Some(self.pr),
+            ui: Some(self.ui),
+            s: Some(self.s),
+            c: Some(self.c),
+            i: Some(self.i),
+            a: Some(self.a),
+            ..self.base
+        }
+    }
+}
@@ -13,7 +13,7 @@ use core::{fmt, str::FromStr};
 /// > vulnerability. Integrity refers to the trustworthiness and veracity of
 /// > information. The Base Score is greatest when the consequence to the
 /// > impacted component is highest.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum Integrity {
     /// None (N)
     ///
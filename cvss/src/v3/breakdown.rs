@@ -0,0 +1,68 @@
+//! Breakdown of CVSS v3.1 scores into their intermediate values
+
+use super::{Metric, MetricType, Score};
+use alloc::vec::Vec;
+
+/// Intermediate values of the computation of a CVSS v3.1 Base (and
+/// Temporal) score, as returned by [`Base::breakdown`](super::Base::breakdown).
+///
+/// This allows explaining why a vector has the score it has, e.g. in a
+/// verbose report, without duplicating the equations of the specification.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakdown {
+    /// Numeric weight of each defined Base and Temporal metric, in the order
+    /// they appear in the vector string.
+    ///
+    /// Scope (S) has no weight of its own: it selects which equations are
+    /// used, as reflected by [`Breakdown::scope_changed`].
+    pub weights: Vec<MetricWeight>,
+
+    /// Whether the Scope (S) is Changed
+    pub scope_changed: bool,
+
+    /// Impact Sub-Score (ISS), computed from the Confidentiality, Integrity
+    /// and Availability weights
+    pub impact_sub_score: Score,
+
+    /// Impact sub-score, i.e. the ISS adjusted for Scope
+    pub impact: Score,
+
+    /// Exploitability sub-score
+    pub exploitability: Score,
+
+    /// Base score
+    pub base_score: Score,
+
+    /// Temporal score, which is the Base score when no Temporal metric is set
+    pub temporal_score: Score,
+}
+
+/// Numeric weight given to a metric value by the CVSS v3.1 equations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MetricWeight {
+    /// Type of the metric
+    pub metric_type: MetricType,
+
+    /// Value of the metric, as it appears in the vector string (e.g. `N`)
+    pub value: &'static str,
+
+    /// Numeric weight of this value
+    pub weight: f64,
+}
+
+impl MetricWeight {
+    /// Get the weight of the given metric value.
+    pub(crate) fn new<M: Metric>(metric: M) -> Self {
+        Self::with_weight(metric, metric.score())
+    }
+
+    /// Create a [`MetricWeight`] for a metric whose weight depends on other
+    /// metrics (i.e. Privileges Required, which depends on Scope).
+    pub(crate) fn with_weight<M: Metric>(metric: M, weight: f64) -> Self {
+        Self {
+            metric_type: M::TYPE,
+            value: metric.as_str(),
+            weight,
+        }
+    }
+}
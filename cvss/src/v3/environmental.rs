@@ -0,0 +1,30 @@
+//! CVSS v3.1 Environmental Metric Group
+//!
+//! Described in CVSS v3.1 Specification: Section 4:
+//! <https://www.first.org/cvss/v3.1/specification-document#4-Environmental-Metrics>
+//!
+//! > These metrics enable the analyst to customize the CVSS score depending on
+//! > the importance of the affected IT asset to a user’s organization,
+//! > measured in terms of complementary/alternative security controls in
+//! > place, Confidentiality, Integrity, and Availability. The metrics are the
+//! > modified equivalent of base metrics and are assigned metric values based
+//! > on the component placement in organization infrastructure.
+
+mod ar;
+mod cr;
+mod ir;
+mod ma;
+mod mac;
+mod mav;
+mod mc;
+mod mi;
+mod mpr;
+mod ms;
+mod mui;
+
+pub use self::{
+    ar::AvailabilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement,
+    ma::ModifiedAvailability, mac::ModifiedAttackComplexity, mav::ModifiedAttackVector,
+    mc::ModifiedConfidentiality, mi::ModifiedIntegrity, mpr::ModifiedPrivilegesRequired,
+    ms::ModifiedScope, mui::ModifiedUserInteraction,
+};
@@ -0,0 +1,89 @@
+//! Availability Requirement (AR)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Availability Requirement (AR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.1:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-1-Security-Requirements-CR-IR-AR>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization,
+/// > measured in terms of Confidentiality, Integrity, and Availability.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AvailabilityRequirement {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to
+    /// > choose one of the other values. This value has the same effect as
+    /// > assigning Medium.
+    NotDefined,
+
+    /// Low (L)
+    ///
+    /// > Loss of Availability is likely to have only a limited adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Availability is likely to have a serious adverse effect on the
+    /// > organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of Availability is likely to have a catastrophic adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    High,
+}
+
+impl Metric for AvailabilityRequirement {
+    const TYPE: MetricType = MetricType::AR;
+
+    fn score(self) -> f64 {
+        match self {
+            AvailabilityRequirement::NotDefined => 1.0,
+            AvailabilityRequirement::Low => 0.5,
+            AvailabilityRequirement::Medium => 1.0,
+            AvailabilityRequirement::High => 1.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AvailabilityRequirement::NotDefined => "X",
+            AvailabilityRequirement::Low => "L",
+            AvailabilityRequirement::Medium => "M",
+            AvailabilityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for AvailabilityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for AvailabilityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(AvailabilityRequirement::NotDefined),
+            "L" => Ok(AvailabilityRequirement::Low),
+            "M" => Ok(AvailabilityRequirement::Medium),
+            "H" => Ok(AvailabilityRequirement::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
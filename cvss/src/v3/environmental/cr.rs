@@ -0,0 +1,89 @@
+//! Confidentiality Requirement (CR)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Confidentiality Requirement (CR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.1:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-1-Security-Requirements-CR-IR-AR>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending on
+/// > the importance of the affected IT asset to a user’s organization,
+/// > measured in terms of Confidentiality, Integrity, and Availability.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ConfidentialityRequirement {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to
+    /// > choose one of the other values. This value has the same effect as
+    /// > assigning Medium.
+    NotDefined,
+
+    /// Low (L)
+    ///
+    /// > Loss of Confidentiality is likely to have only a limited adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Low,
+
+    /// Medium (M)
+    ///
+    /// > Loss of Confidentiality is likely to have a serious adverse effect on the
+    /// > organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    Medium,
+
+    /// High (H)
+    ///
+    /// > Loss of Confidentiality is likely to have a catastrophic adverse effect on
+    /// > the organization or individuals associated with the organization
+    /// > (e.g., employees, customers).
+    High,
+}
+
+impl Metric for ConfidentialityRequirement {
+    const TYPE: MetricType = MetricType::CR;
+
+    fn score(self) -> f64 {
+        match self {
+            ConfidentialityRequirement::NotDefined => 1.0,
+            ConfidentialityRequirement::Low => 0.5,
+            ConfidentialityRequirement::Medium => 1.0,
+            ConfidentialityRequirement::High => 1.5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ConfidentialityRequirement::NotDefined => "X",
+            ConfidentialityRequirement::Low => "L",
+            ConfidentialityRequirement::Medium => "M",
+            ConfidentialityRequirement::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ConfidentialityRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ConfidentialityRequirement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ConfidentialityRequirement::NotDefined),
+            "L" => Ok(ConfidentialityRequirement::Low),
+            "M" => Ok(ConfidentialityRequirement::Medium),
+            "H" => Ok(ConfidentialityRequirement::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Modified Availability (MA)
+
+use crate::{Error, Metric, MetricType, v3::base::Availability};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Availability (MA) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Availability Impact (A)](Availability).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAvailability {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// None (N)
+    ///
+    /// Same as [`Availability::None`].
+    None,
+
+    /// Low (L)
+    ///
+    /// Same as [`Availability::Low`].
+    Low,
+
+    /// High (H)
+    ///
+    /// Same as [`Availability::High`].
+    High,
+}
+
+impl ModifiedAvailability {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedAvailability::NotDefined`].
+    pub fn apply(self, base: Option<Availability>) -> Option<Availability> {
+        match self {
+            ModifiedAvailability::NotDefined => base,
+            ModifiedAvailability::None => Some(Availability::None),
+            ModifiedAvailability::Low => Some(Availability::Low),
+            ModifiedAvailability::High => Some(Availability::High),
+        }
+    }
+}
+
+impl Metric for ModifiedAvailability {
+    const TYPE: MetricType = MetricType::MA;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|a| a.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAvailability::NotDefined => "X",
+            ModifiedAvailability::None => "N",
+            ModifiedAvailability::Low => "L",
+            ModifiedAvailability::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAvailability {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedAvailability::NotDefined),
+            "N" => Ok(ModifiedAvailability::None),
+            "L" => Ok(ModifiedAvailability::Low),
+            "H" => Ok(ModifiedAvailability::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Modified Attack Complexity (MAC)
+
+use crate::{Error, Metric, MetricType, v3::base::AttackComplexity};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Attack Complexity (MAC) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Attack Complexity (AC)](AttackComplexity).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAttackComplexity {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// Same as [`AttackComplexity::High`].
+    High,
+
+    /// Low (L)
+    ///
+    /// Same as [`AttackComplexity::Low`].
+    Low,
+}
+
+impl ModifiedAttackComplexity {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedAttackComplexity::NotDefined`].
+    pub fn apply(self, base: Option<AttackComplexity>) -> Option<AttackComplexity> {
+        match self {
+            ModifiedAttackComplexity::NotDefined => base,
+            ModifiedAttackComplexity::High => Some(AttackComplexity::High),
+            ModifiedAttackComplexity::Low => Some(AttackComplexity::Low),
+        }
+    }
+}
+
+impl Metric for ModifiedAttackComplexity {
+    const TYPE: MetricType = MetricType::MAC;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|ac| ac.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAttackComplexity::NotDefined => "X",
+            ModifiedAttackComplexity::High => "H",
+            ModifiedAttackComplexity::Low => "L",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAttackComplexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAttackComplexity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedAttackComplexity::NotDefined),
+            "H" => Ok(ModifiedAttackComplexity::High),
+            "L" => Ok(ModifiedAttackComplexity::Low),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Modified Attack Vector (MAV)
+
+use crate::{Error, Metric, MetricType, v3::base::AttackVector};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Attack Vector (MAV) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Attack Vector (AV)](AttackVector).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedAttackVector {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// Physical (P)
+    ///
+    /// Same as [`AttackVector::Physical`].
+    Physical,
+
+    /// Local (L)
+    ///
+    /// Same as [`AttackVector::Local`].
+    Local,
+
+    /// Adjacent (A)
+    ///
+    /// Same as [`AttackVector::Adjacent`].
+    Adjacent,
+
+    /// Network (N)
+    ///
+    /// Same as [`AttackVector::Network`].
+    Network,
+}
+
+impl ModifiedAttackVector {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedAttackVector::NotDefined`].
+    pub fn apply(self, base: Option<AttackVector>) -> Option<AttackVector> {
+        match self {
+            ModifiedAttackVector::NotDefined => base,
+            ModifiedAttackVector::Physical => Some(AttackVector::Physical),
+            ModifiedAttackVector::Local => Some(AttackVector::Local),
+            ModifiedAttackVector::Adjacent => Some(AttackVector::Adjacent),
+            ModifiedAttackVector::Network => Some(AttackVector::Network),
+        }
+    }
+}
+
+impl Metric for ModifiedAttackVector {
+    const TYPE: MetricType = MetricType::MAV;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|av| av.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedAttackVector::NotDefined => "X",
+            ModifiedAttackVector::Physical => "P",
+            ModifiedAttackVector::Local => "L",
+            ModifiedAttackVector::Adjacent => "A",
+            ModifiedAttackVector::Network => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedAttackVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedAttackVector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedAttackVector::NotDefined),
+            "P" => Ok(ModifiedAttackVector::Physical),
+            "L" => Ok(ModifiedAttackVector::Local),
+            "A" => Ok(ModifiedAttackVector::Adjacent),
+            "N" => Ok(ModifiedAttackVector::Network),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
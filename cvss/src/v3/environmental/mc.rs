@@ -0,0 +1,91 @@
+//! Modified Confidentiality (MC)
+
+use crate::{Error, Metric, MetricType, v3::base::Confidentiality};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Confidentiality (MC) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Confidentiality Impact (C)](Confidentiality).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedConfidentiality {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// None (N)
+    ///
+    /// Same as [`Confidentiality::None`].
+    None,
+
+    /// Low (L)
+    ///
+    /// Same as [`Confidentiality::Low`].
+    Low,
+
+    /// High (H)
+    ///
+    /// Same as [`Confidentiality::High`].
+    High,
+}
+
+impl ModifiedConfidentiality {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedConfidentiality::NotDefined`].
+    pub fn apply(self, base: Option<Confidentiality>) -> Option<Confidentiality> {
+        match self {
+            ModifiedConfidentiality::NotDefined => base,
+            ModifiedConfidentiality::None => Some(Confidentiality::None),
+            ModifiedConfidentiality::Low => Some(Confidentiality::Low),
+            ModifiedConfidentiality::High => Some(Confidentiality::High),
+        }
+    }
+}
+
+impl Metric for ModifiedConfidentiality {
+    const TYPE: MetricType = MetricType::MC;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|c| c.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedConfidentiality::NotDefined => "X",
+            ModifiedConfidentiality::None => "N",
+            ModifiedConfidentiality::Low => "L",
+            ModifiedConfidentiality::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedConfidentiality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedConfidentiality {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedConfidentiality::NotDefined),
+            "N" => Ok(ModifiedConfidentiality::None),
+            "L" => Ok(ModifiedConfidentiality::Low),
+            "H" => Ok(ModifiedConfidentiality::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Modified Integrity (MI)
+
+use crate::{Error, Metric, MetricType, v3::base::Integrity};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Integrity (MI) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Integrity Impact (I)](Integrity).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedIntegrity {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// None (N)
+    ///
+    /// Same as [`Integrity::None`].
+    None,
+
+    /// Low (L)
+    ///
+    /// Same as [`Integrity::Low`].
+    Low,
+
+    /// High (H)
+    ///
+    /// Same as [`Integrity::High`].
+    High,
+}
+
+impl ModifiedIntegrity {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedIntegrity::NotDefined`].
+    pub fn apply(self, base: Option<Integrity>) -> Option<Integrity> {
+        match self {
+            ModifiedIntegrity::NotDefined => base,
+            ModifiedIntegrity::None => Some(Integrity::None),
+            ModifiedIntegrity::Low => Some(Integrity::Low),
+            ModifiedIntegrity::High => Some(Integrity::High),
+        }
+    }
+}
+
+impl Metric for ModifiedIntegrity {
+    const TYPE: MetricType = MetricType::MI;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|i| i.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedIntegrity::NotDefined => "X",
+            ModifiedIntegrity::None => "N",
+            ModifiedIntegrity::Low => "L",
+            ModifiedIntegrity::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedIntegrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedIntegrity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedIntegrity::NotDefined),
+            "N" => Ok(ModifiedIntegrity::None),
+            "L" => Ok(ModifiedIntegrity::Low),
+            "H" => Ok(ModifiedIntegrity::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
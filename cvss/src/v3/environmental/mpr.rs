@@ -0,0 +1,91 @@
+//! Modified Privileges Required (MPR)
+
+use crate::{Error, Metric, MetricType, v3::base::PrivilegesRequired};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Privileges Required (MPR) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Privileges Required (PR)](PrivilegesRequired).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedPrivilegesRequired {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// High (H)
+    ///
+    /// Same as [`PrivilegesRequired::High`].
+    High,
+
+    /// Low (L)
+    ///
+    /// Same as [`PrivilegesRequired::Low`].
+    Low,
+
+    /// None (N)
+    ///
+    /// Same as [`PrivilegesRequired::None`].
+    None,
+}
+
+impl ModifiedPrivilegesRequired {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedPrivilegesRequired::NotDefined`].
+    pub fn apply(self, base: Option<PrivilegesRequired>) -> Option<PrivilegesRequired> {
+        match self {
+            ModifiedPrivilegesRequired::NotDefined => base,
+            ModifiedPrivilegesRequired::High => Some(PrivilegesRequired::High),
+            ModifiedPrivilegesRequired::Low => Some(PrivilegesRequired::Low),
+            ModifiedPrivilegesRequired::None => Some(PrivilegesRequired::None),
+        }
+    }
+}
+
+impl Metric for ModifiedPrivilegesRequired {
+    const TYPE: MetricType = MetricType::MPR;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|pr| pr.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedPrivilegesRequired::NotDefined => "X",
+            ModifiedPrivilegesRequired::High => "H",
+            ModifiedPrivilegesRequired::Low => "L",
+            ModifiedPrivilegesRequired::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedPrivilegesRequired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedPrivilegesRequired {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedPrivilegesRequired::NotDefined),
+            "H" => Ok(ModifiedPrivilegesRequired::High),
+            "L" => Ok(ModifiedPrivilegesRequired::Low),
+            "N" => Ok(ModifiedPrivilegesRequired::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Modified Scope (MS)
+
+use crate::{Error, Metric, MetricType, v3::base::Scope};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified Scope (MS) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [Scope (S)](Scope).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedScope {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead.
+    NotDefined,
+
+    /// Unchanged (U)
+    ///
+    /// Same as [`Scope::Unchanged`].
+    Unchanged,
+
+    /// Changed (C)
+    ///
+    /// Same as [`Scope::Changed`].
+    Changed,
+}
+
+impl ModifiedScope {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedScope::NotDefined`].
+    pub fn apply(self, base: Option<Scope>) -> Option<Scope> {
+        match self {
+            ModifiedScope::NotDefined => base,
+            ModifiedScope::Unchanged => Some(Scope::Unchanged),
+            ModifiedScope::Changed => Some(Scope::Changed),
+        }
+    }
+}
+
+impl Metric for ModifiedScope {
+    const TYPE: MetricType = MetricType::MS;
+
+    fn score(self) -> f64 {
+        unimplemented!()
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedScope::NotDefined => "X",
+            ModifiedScope::Unchanged => "U",
+            ModifiedScope::Changed => "C",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedScope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedScope::NotDefined),
+            "U" => Ok(ModifiedScope::Unchanged),
+            "C" => Ok(ModifiedScope::Changed),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Modified User Interaction (MUI)
+
+use crate::{Error, Metric, MetricType, v3::base::UserInteraction};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Modified User Interaction (MUI) - CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4.2:
+/// <https://www.first.org/cvss/v3.1/specification-document#4-2-Modified-Base-Metrics>
+///
+/// > These metrics enable the analyst to override individual Base metrics
+/// > based on specific characteristics of a user’s environment.
+///
+/// Overrides the Base [User Interaction (UI)](UserInteraction).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ModifiedUserInteraction {
+    /// Not Defined (X)
+    ///
+    /// The value of the Base metric is used instead. It has no
+    /// score of its own, so the [`Metric::score`] of this value is 1.0.
+    NotDefined,
+
+    /// Required (R)
+    ///
+    /// Same as [`UserInteraction::Required`].
+    Required,
+
+    /// None (N)
+    ///
+    /// Same as [`UserInteraction::None`].
+    None,
+}
+
+impl ModifiedUserInteraction {
+    /// Apply this override to the value of the Base metric, which is kept
+    /// as-is if this metric is [`ModifiedUserInteraction::NotDefined`].
+    pub fn apply(self, base: Option<UserInteraction>) -> Option<UserInteraction> {
+        match self {
+            ModifiedUserInteraction::NotDefined => base,
+            ModifiedUserInteraction::Required => Some(UserInteraction::Required),
+            ModifiedUserInteraction::None => Some(UserInteraction::None),
+        }
+    }
+}
+
+impl Metric for ModifiedUserInteraction {
+    const TYPE: MetricType = MetricType::MUI;
+
+    fn score(self) -> f64 {
+        self.apply(None).map(|ui| ui.score()).unwrap_or(1.0)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ModifiedUserInteraction::NotDefined => "X",
+            ModifiedUserInteraction::Required => "R",
+            ModifiedUserInteraction::None => "N",
+        }
+    }
+}
+
+impl fmt::Display for ModifiedUserInteraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ModifiedUserInteraction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ModifiedUserInteraction::NotDefined),
+            "R" => Ok(ModifiedUserInteraction::Required),
+            "N" => Ok(ModifiedUserInteraction::None),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -25,7 +25,7 @@ pub trait Metric: Copy + Clone + Debug + Display + Eq + FromStr + Ord {
 }
 
 /// Enum over all of the available CVSSv3 metrics.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum MetricType {
     /// Availability Impact (A)
@@ -34,18 +34,60 @@ pub enum MetricType {
     /// Attack Complexity (AC)
     AC,
 
+    /// Availability Requirement (AR)
+    AR,
+
     /// Attack Vector (AV)
     AV,
 
     /// Confidentiality Impact (C)
     C,
 
+    /// Confidentiality Requirement (CR)
+    CR,
+
+    /// Exploit Code Maturity (E)
+    E,
+
     /// Integrity Impact (I)
     I,
 
+    /// Integrity Requirement (IR)
+    IR,
+
+    /// Modified Availability Impact (MA)
+    MA,
+
+    /// Modified Attack Complexity (MAC)
+    MAC,
+
+    /// Modified Attack Vector (MAV)
+    MAV,
+
+    /// Modified Confidentiality Impact (MC)
+    MC,
+
+    /// Modified Integrity Impact (MI)
+    MI,
+
+    /// Modified Privileges Required (MPR)
+    MPR,
+
+    /// Modified Scope (MS)
+    MS,
+
+    /// Modified User Interaction (MUI)
+    MUI,
+
     /// Privileges Required (PR)
     PR,
 
+    /// Report Confidence (RC)
+    RC,
+
+    /// Remediation Level (RL)
+    RL,
+
     /// Scope (S)
     S,
 
@@ -59,10 +101,24 @@ impl MetricType {
         match self {
             Self::A => "A",
             Self::AC => "AC",
+            Self::AR => "AR",
             Self::AV => "AV",
             Self::C => "C",
+            Self::CR => "CR",
+            Self::E => "E",
             Self::I => "I",
+            Self::IR => "IR",
+            Self::MA => "MA",
+            Self::MAC => "MAC",
+            Self::MAV => "MAV",
+            Self::MC => "MC",
+            Self::MI => "MI",
+            Self::MPR => "MPR",
+            Self::MS => "MS",
+            Self::MUI => "MUI",
             Self::PR => "PR",
+            Self::RC => "RC",
+            Self::RL => "RL",
             Self::S => "S",
             Self::UI => "UI",
         }
@@ -73,10 +129,24 @@ impl MetricType {
         match self {
             Self::A => "Availability Impact",
             Self::AC => "Attack Complexity",
+            Self::AR => "Availability Requirement",
             Self::AV => "Attack Vector",
             Self::C => "Confidentiality Impact",
+            Self::CR => "Confidentiality Requirement",
+            Self::E => "Exploit Code Maturity",
             Self::I => "Integrity Impact",
+            Self::IR => "Integrity Requirement",
+            Self::MA => "Modified Availability Impact",
+            Self::MAC => "Modified Attack Complexity",
+            Self::MAV => "Modified Attack Vector",
+            Self::MC => "Modified Confidentiality Impact",
+            Self::MI => "Modified Integrity Impact",
+            Self::MPR => "Modified Privileges Required",
+            Self::MS => "Modified Scope",
+            Self::MUI => "Modified User Interaction",
             Self::PR => "Privileges Required",
+            Self::RC => "Report Confidence",
+            Self::RL => "Remediation Level",
             Self::S => "Scope",
             Self::UI => "User Interaction",
         }
@@ -96,10 +166,24 @@ impl FromStr for MetricType {
         match s {
             "A" => Ok(Self::A),
             "AC" => Ok(Self::AC),
+            "AR" => Ok(Self::AR),
             "AV" => Ok(Self::AV),
             "C" => Ok(Self::C),
+            "CR" => Ok(Self::CR),
+            "E" => Ok(Self::E),
             "I" => Ok(Self::I),
+            "IR" => Ok(Self::IR),
+            "MA" => Ok(Self::MA),
+            "MAC" => Ok(Self::MAC),
+            "MAV" => Ok(Self::MAV),
+            "MC" => Ok(Self::MC),
+            "MI" => Ok(Self::MI),
+            "MPR" => Ok(Self::MPR),
+            "MS" => Ok(Self::MS),
+            "MUI" => Ok(Self::MUI),
             "PR" => Ok(Self::PR),
+            "RC" => Ok(Self::RC),
+            "RL" => Ok(Self::RL),
             "S" => Ok(Self::S),
             "UI" => Ok(Self::UI),
             _ => Err(Error::UnknownMetric { name: s.to_owned() }),
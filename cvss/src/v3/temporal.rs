@@ -0,0 +1,14 @@
+//! CVSS v3.1 Temporal Metric Group
+//!
+//! Described in CVSS v3.1 Specification: Section 3:
+//! <https://www.first.org/cvss/v3.1/specification-document#3-Temporal-Metrics>
+//!
+//! > The Temporal metrics measure the current state of exploit techniques or
+//! > code availability, the existence of any patches or workarounds, or the
+//! > confidence in the description of a vulnerability.
+
+mod e;
+mod rc;
+mod rl;
+
+pub use self::{e::ExploitCodeMaturity, rc::ReportConfidence, rl::RemediationLevel};
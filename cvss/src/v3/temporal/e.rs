@@ -0,0 +1,95 @@
+//! Exploit Code Maturity (E)
+
+use crate::{Error, Metric, MetricType};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Exploit Code Maturity (E) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.1:
+/// <https://www.first.org/cvss/v3.1/specification-document#3-1-Exploit-Code-Maturity-E>
+///
+/// > This metric measures the likelihood of the vulnerability being attacked,
+/// > and is typically based on the current state of exploit techniques,
+/// > exploit code availability, or active, "in-the-wild" exploitation.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ExploitCodeMaturity {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to
+    /// > choose one of the other values, and has no impact on the overall
+    /// > Temporal Score, i.e., it has the same effect on scoring as assigning
+    /// > High.
+    NotDefined,
+
+    /// Unproven (U)
+    ///
+    /// > No exploit code is available, or an exploit is theoretical.
+    Unproven,
+
+    /// Proof-of-Concept (P)
+    ///
+    /// > Proof-of-concept exploit code is available, or an attack
+    /// > demonstration is not practical for most systems.
+    ProofOfConcept,
+
+    /// Functional (F)
+    ///
+    /// > Functional exploit code is available. The code works in most
+    /// > situations where the vulnerability exists.
+    Functional,
+
+    /// High (H)
+    ///
+    /// > Functional autonomous code exists, or no exploit is required (manual
+    /// > trigger) and details are widely available.
+    High,
+}
+
+impl Metric for ExploitCodeMaturity {
+    const TYPE: MetricType = MetricType::E;
+
+    fn score(self) -> f64 {
+        match self {
+            ExploitCodeMaturity::NotDefined => 1.0,
+            ExploitCodeMaturity::Unproven => 0.91,
+            ExploitCodeMaturity::ProofOfConcept => 0.94,
+            ExploitCodeMaturity::Functional => 0.97,
+            ExploitCodeMaturity::High => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ExploitCodeMaturity::NotDefined => "X",
+            ExploitCodeMaturity::Unproven => "U",
+            ExploitCodeMaturity::ProofOfConcept => "P",
+            ExploitCodeMaturity::Functional => "F",
+            ExploitCodeMaturity::High => "H",
+        }
+    }
+}
+
+impl fmt::Display for ExploitCodeMaturity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ExploitCodeMaturity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "X" => Ok(ExploitCodeMaturity::NotDefined),
+            "U" => Ok(ExploitCodeMaturity::Unproven),
+            "P" => Ok(ExploitCodeMaturity::ProofOfConcept),
+            "F" => Ok(ExploitCodeMaturity::Functional),
+            "H" => Ok(ExploitCodeMaturity::High),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
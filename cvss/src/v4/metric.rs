@@ -34,7 +34,7 @@ pub(crate) trait MetricLevel {
 }
 
 /// Enum over all of the available metrics.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum MetricType {
     /// Attack Complexity (AC)
@@ -26,7 +26,7 @@ use core::{fmt, str::FromStr};
 /// > The evasion or satisfaction of authentication mechanisms or requisites is
 /// > included in the Privileges Required assessment and is *not* considered
 /// > here as a factor of relevance for Attack Complexity.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum AttackComplexity {
     /// High (H)
     ///
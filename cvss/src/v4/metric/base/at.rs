@@ -19,7 +19,7 @@ use core::{fmt, str::FromStr};
 /// > of the deployment and execution of the vulnerable system. If the attacker
 /// > does not take action to overcome these conditions, the attack may succeed
 /// > only occasionally or not succeed at all.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum AttackRequirements {
     /// Present (P)
     ///
@@ -17,7 +17,7 @@ use core::{fmt, str::FromStr};
 /// > solely at the will of the attacker, or whether a separate user (or
 /// > user-initiated process) must participate in some manner. The resulting
 /// > score is greatest when no user interaction is required.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum UserInteraction {
     /// Active (A)
     ///
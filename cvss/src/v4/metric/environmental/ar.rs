@@ -25,7 +25,7 @@ use core::{fmt, str::FromStr};
 /// > assuming “reasonable worst case”, in absence of explicit values, these
 /// > metrics are set to the default value of Not Defined (X), which is
 /// > equivalent to the metric value of High (H).
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum AvailabilityRequirements {
     /// Not Defined (X)
     ///
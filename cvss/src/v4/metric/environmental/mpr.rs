@@ -18,7 +18,7 @@ use core::{fmt, str::FromStr};
 /// > self-service provisioned accounts do not constitute a privilege
 /// > requirement if the attacker can grant themselves privileges as part of the
 /// > attack.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum ModifiedPrivilegesRequired {
     /// Not Defined (X)
     ///
@@ -16,7 +16,7 @@ use core::{fmt, str::FromStr};
 /// > multiple targets?” based on steps 1-4 of the kill chain [Hutchins et al.,
 /// > 2011]. These steps are reconnaissance, weaponization, delivery, and
 /// > exploitation.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum Automatable {
     /// Not Defined (X)
     ///
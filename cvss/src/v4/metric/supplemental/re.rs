@@ -10,7 +10,7 @@ use core::{fmt, str::FromStr};
 /// Vulnerability Response Effort (RE) - CVSS v4.0 Supplemental Metric Group
 ///
 /// Described in CVSS v4.0 Specification: Section 5.6
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum VulnerabilityResponseEffort {
     /// Not Defined (X)
     ///
@@ -19,7 +19,7 @@ use core::{fmt, str::FromStr};
 /// > may have Safety impact which can be represented in the Supplemental
 /// > Metrics group. Lack of a Safety metric value being supplied does NOT mean
 /// > that there may not be any Safety-related impacts.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum Safety {
     /// Not Defined (X)
     ///
@@ -27,7 +27,7 @@ use core::{fmt, str::FromStr};
 /// >
 /// > The Penultimate Product Provider (PPP) is best positioned to provide a
 /// > direct assessment of Provider Urgency.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum ProviderUrgency {
     /// Not Defined (X)
     ///
@@ -10,7 +10,7 @@ use core::{fmt, str::FromStr};
 /// Value Density (V) - CVSS v4.0 Supplemental Metric Group
 ///
 /// Described in CVSS v4.0 Specification: Section 5.5
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum ValueDensity {
     /// Not Defined (X)
     ///
@@ -37,7 +37,7 @@ use core::{fmt, str::FromStr};
 /// > multiple sources of threat intelligence as many are not comprehensive.
 /// > This information should be updated as frequently as possible and its
 /// > application to CVSS assessment should be automated.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum ExploitMaturity {
     /// Not Defined (X)
     ///
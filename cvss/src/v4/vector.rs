@@ -5,6 +5,7 @@ use crate::{
     v4::{
         MetricType,
         metric::{
+            Metric,
             base::{
                 AttackComplexity, AttackRequirements, AttackVector,
                 AvailabilityImpactToTheSubsequentSystem, AvailabilityImpactToTheVulnerableSystem,
@@ -44,7 +45,7 @@ use crate::v4::Score;
 use crate::v4::score::Nomenclature;
 
 /// A CVSS 4.0 vector
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Vector {
     /// Minor component of the version
     pub minor_version: usize,
@@ -129,6 +130,56 @@ impl Vector {
         Nomenclature::from(self)
     }
 
+    /// Get the canonical form of this vector, in which metrics explicitly set
+    /// to Not Defined (`X`) are omitted, as they have the same meaning as
+    /// metrics which are absent.
+    ///
+    /// Metrics are always serialized in the order given by the specification
+    /// regardless of the order they were parsed in, so vectors from different
+    /// sources which carry the same metrics compare equal (and serialize to the
+    /// same string) once canonicalized.
+    pub fn canonicalize(&self) -> Self {
+        fn defined<M: Metric>(metric: Option<M>) -> Option<M> {
+            metric.filter(|m| m.as_str() != "X")
+        }
+
+        Self {
+            minor_version: self.minor_version,
+            ac: defined(self.ac),
+            at: defined(self.at),
+            av: defined(self.av),
+            pr: defined(self.pr),
+            sa: defined(self.sa),
+            sc: defined(self.sc),
+            si: defined(self.si),
+            ui: defined(self.ui),
+            va: defined(self.va),
+            vc: defined(self.vc),
+            vi: defined(self.vi),
+            e: defined(self.e),
+            ar: defined(self.ar),
+            cr: defined(self.cr),
+            ir: defined(self.ir),
+            mac: defined(self.mac),
+            mat: defined(self.mat),
+            mav: defined(self.mav),
+            mpr: defined(self.mpr),
+            msa: defined(self.msa),
+            msc: defined(self.msc),
+            msi: defined(self.msi),
+            mui: defined(self.mui),
+            mva: defined(self.mva),
+            mvc: defined(self.mvc),
+            mvi: defined(self.mvi),
+            au: defined(self.au),
+            r: defined(self.r),
+            re: defined(self.re),
+            s: defined(self.s),
+            u: defined(self.u),
+            v: defined(self.v),
+        }
+    }
+
     /// Iterate over all defined vector metrics
     pub fn metrics(&self) -> impl Iterator<Item = (MetricType, &dyn fmt::Debug)> {
         [
@@ -0,0 +1,62 @@
+#![cfg(all(feature = "v3", feature = "std"))]
+/// Score breakdown tests
+use core::str::FromStr;
+
+use cvss::v3::{Base, MetricType, MetricWeight};
+
+fn assert_approx(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 0.001,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn scope_changed() {
+    let base = Base::from_str("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H/E:P").unwrap();
+    let breakdown = base.breakdown();
+
+    assert!(breakdown.scope_changed);
+    assert_eq!(
+        breakdown
+            .weights
+            .iter()
+            .map(|w| (w.metric_type, w.value))
+            .collect::<Vec<_>>(),
+        [
+            (MetricType::AV, "N"),
+            (MetricType::AC, "L"),
+            (MetricType::PR, "L"),
+            (MetricType::UI, "N"),
+            (MetricType::C, "H"),
+            (MetricType::I, "H"),
+            (MetricType::A, "H"),
+            (MetricType::E, "P"),
+        ]
+    );
+
+    // Privileges Required is weighted according to Scope
+    let pr: &MetricWeight = &breakdown.weights[2];
+    assert_eq!(pr.weight, 0.68);
+
+    assert_approx(breakdown.impact_sub_score.value(), 0.9148);
+    assert_approx(breakdown.impact.value(), 6.0477);
+    assert_approx(breakdown.exploitability.value(), 3.1096);
+    assert_eq!(breakdown.base_score, base.score());
+    assert_eq!(breakdown.base_score.value(), 9.9);
+    assert_eq!(breakdown.temporal_score.value(), 9.4);
+}
+
+#[test]
+fn scope_unchanged() {
+    let breakdown = Base::from_str("CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N")
+        .unwrap()
+        .breakdown();
+
+    assert!(!breakdown.scope_changed);
+    assert_eq!(breakdown.weights[2].weight, 0.27);
+    assert_approx(breakdown.impact_sub_score.value(), 0.22);
+    assert_approx(breakdown.impact.value(), 1.4124);
+    assert_eq!(breakdown.base_score.value(), 1.8);
+    assert_eq!(breakdown.temporal_score, breakdown.base_score);
+}
@@ -0,0 +1,58 @@
+#![cfg(all(feature = "v3", feature = "std"))]
+/// Builder tests
+use core::str::FromStr;
+
+use cvss::v3::{
+    Base,
+    base::{
+        AttackComplexity, AttackVector, Availability, Confidentiality, Integrity,
+        PrivilegesRequired, Scope, UserInteraction,
+    },
+    environmental::{ConfidentialityRequirement, ModifiedAttackVector},
+    temporal::{ExploitCodeMaturity, RemediationLevel, ReportConfidence},
+};
+
+#[test]
+fn base_metrics() {
+    let base = Base::builder()
+        .av(AttackVector::Network)
+        .ac(AttackComplexity::Low)
+        .pr(PrivilegesRequired::None)
+        .ui(UserInteraction::None)
+        .s(Scope::Unchanged)
+        .c(Confidentiality::High)
+        .i(Integrity::High)
+        .a(Availability::High)
+        .build();
+
+    assert_eq!(
+        base,
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap()
+    );
+    assert_eq!(base.score().value(), 9.8);
+}
+
+#[test]
+fn any_order_with_optional_metrics() {
+    let base = Base::builder()
+        .minor_version(0)
+        .e(ExploitCodeMaturity::ProofOfConcept)
+        .a(Availability::None)
+        .i(Integrity::Low)
+        .c(Confidentiality::Low)
+        .s(Scope::Changed)
+        .ui(UserInteraction::Required)
+        .pr(PrivilegesRequired::None)
+        .ac(AttackComplexity::Low)
+        .av(AttackVector::Network)
+        .rl(RemediationLevel::OfficialFix)
+        .rc(ReportConfidence::Confirmed)
+        .cr(ConfidentialityRequirement::High)
+        .mav(ModifiedAttackVector::Local)
+        .build();
+
+    assert_eq!(
+        base.to_string(),
+        "CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N/E:P/RL:O/RC:C/CR:H/MAV:L"
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(all(feature = "v3", feature = "v4"))]
+/// Canonicalization and comparison tests
+use std::collections::BTreeSet;
+
+use cvss::Cvss;
+
+#[test]
+fn v3_reordered_and_lowercase() {
+    let nvd: Cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+        .parse()
+        .unwrap();
+    let ghsa: Cvss = "CVSS:3.1/C:H/I:H/A:H/av:n/ac:l/pr:n/ui:n/s:u"
+        .parse()
+        .unwrap();
+    assert_eq!(nvd, ghsa);
+    assert_eq!(nvd.to_string(), ghsa.to_string());
+}
+
+#[test]
+fn v3_not_defined() {
+    let vector: Cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/RL:X/RC:C/CR:X/MAV:X"
+        .parse()
+        .unwrap();
+    let canonical = vector.canonicalize();
+    assert_ne!(vector, canonical);
+    assert_eq!(
+        canonical.to_string(),
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/RC:C"
+    );
+    assert_eq!(canonical, canonical.canonicalize());
+}
+
+#[test]
+fn v4_not_defined() {
+    let vector: Cvss = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        vector.canonicalize().to_string(),
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+    );
+}
+
+#[test]
+fn deduplication() {
+    let vectors = [
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:3.1/S:U/AV:N/AC:L/PR:N/UI:N/C:H/I:H/A:H/E:X",
+        "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    ];
+    let set: BTreeSet<Cvss> = vectors
+        .iter()
+        .map(|v| v.parse::<Cvss>().unwrap().canonicalize())
+        .collect();
+    assert_eq!(set.len(), 3);
+}
@@ -0,0 +1,76 @@
+#![cfg(all(feature = "v3", feature = "std"))]
+/// Environmental Metrics tests
+use core::str::FromStr;
+
+use cvss::v3::{
+    Base,
+    base::AttackVector,
+    environmental::{ConfidentialityRequirement, ModifiedAttackVector},
+};
+
+#[test]
+fn high_security_requirements() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:H/IR:H/AR:H";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.cr, Some(ConfidentialityRequirement::High));
+    assert_eq!(base.score().value(), 9.8);
+    assert_eq!(base.environmental_score().value(), 9.8);
+}
+
+#[test]
+fn low_security_requirements() {
+    let base =
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:L/IR:L/AR:L").unwrap();
+    assert_eq!(base.environmental_score().value(), 8.0);
+}
+
+#[test]
+fn modified_attack_vector() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:P";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.mav, Some(ModifiedAttackVector::Physical));
+    assert_eq!(
+        ModifiedAttackVector::Physical.apply(base.av),
+        Some(AttackVector::Physical)
+    );
+    assert_eq!(base.environmental_score().value(), 6.8);
+}
+
+#[test]
+fn modified_scope() {
+    let base = Base::from_str("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:H/I:H/A:H/MS:C").unwrap();
+    assert_eq!(base.score().value(), 8.8);
+    assert_eq!(base.environmental_score().value(), 10.0);
+}
+
+#[test]
+fn no_modified_impact() {
+    let base =
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MC:N/MI:N/MA:N").unwrap();
+    assert_eq!(base.environmental_score().value(), 0.0);
+}
+
+#[test]
+fn with_temporal() {
+    let base =
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:H/IR:H/AR:H")
+            .unwrap();
+    assert_eq!(base.temporal_score().value(), 8.8);
+    assert_eq!(base.environmental_score().value(), 8.8);
+}
+
+#[test]
+fn not_defined() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:L/A:N/CR:X/IR:X/AR:X/MAV:X/MAC:X/MPR:X/MUI:X/MS:X/MC:X/MI:X/MA:X";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.environmental_score(), base.score());
+}
+
+#[test]
+fn invalid_environmental_metric() {
+    assert!(Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:Q").is_err());
+    assert!(Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:N").is_err());
+}
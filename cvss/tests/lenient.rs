@@ -0,0 +1,59 @@
+#![cfg(all(feature = "v3", feature = "v4"))]
+/// Lenient and strict parsing tests
+use cvss::{Cvss, Error, Fix};
+
+const V3: &str = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+const V4: &str = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+
+#[test]
+fn canonical() {
+    for vector in [V3, V4] {
+        let (cvss, fixes) = Cvss::from_str_lenient(vector).unwrap();
+        assert_eq!(cvss.to_string(), vector);
+        assert!(fixes.is_empty());
+        assert_eq!(Cvss::from_str_strict(vector).unwrap(), cvss);
+    }
+}
+
+#[test]
+fn lowercase_and_reordered() {
+    let vector = "cvss:3.1/c:h/i:h/a:h/AV:N/AC:L/PR:N/UI:N/S:U";
+    let (cvss, fixes) = Cvss::from_str_lenient(vector).unwrap();
+    assert_eq!(cvss.to_string(), V3);
+    assert_eq!(fixes, [Fix::Lowercase, Fix::Reordered]);
+    assert_eq!(
+        Cvss::from_str_strict(vector),
+        Err(Error::NonCanonical {
+            fix: Fix::Lowercase
+        })
+    );
+}
+
+#[test]
+fn missing_prefix() {
+    let (cvss, fixes) = Cvss::from_str_lenient("AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert_eq!(cvss.to_string(), V3);
+    assert_eq!(fixes, [Fix::MissingPrefix]);
+
+    let (cvss, fixes) =
+        Cvss::from_str_lenient("AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N").unwrap();
+    assert_eq!(cvss.to_string(), V4);
+    assert_eq!(fixes, [Fix::MissingPrefix]);
+}
+
+#[test]
+fn whitespace_parentheses_and_empty_components() {
+    let (cvss, fixes) =
+        Cvss::from_str_lenient(" (CVSS:3.1/AV:N/AC:L/PR:N/UI:N/ S:U/C:H/I:H/A:H/) ").unwrap();
+    assert_eq!(cvss.to_string(), V3);
+    assert_eq!(
+        fixes,
+        [Fix::Whitespace, Fix::Parentheses, Fix::EmptyComponent]
+    );
+}
+
+#[test]
+fn invalid() {
+    assert!(Cvss::from_str_lenient("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:Q").is_err());
+    assert!(Cvss::from_str_lenient("").is_err());
+}
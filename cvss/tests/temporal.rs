@@ -0,0 +1,51 @@
+#![cfg(all(feature = "v3", feature = "std"))]
+/// Temporal Metrics tests
+use core::str::FromStr;
+
+use cvss::v3::{
+    Base,
+    temporal::{ExploitCodeMaturity, RemediationLevel, ReportConfidence},
+};
+
+#[test]
+fn proof_of_concept_official_fix() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.e, Some(ExploitCodeMaturity::ProofOfConcept));
+    assert_eq!(base.rl, Some(RemediationLevel::OfficialFix));
+    assert_eq!(base.rc, Some(ReportConfidence::Confirmed));
+    assert_eq!(base.score().value(), 9.8);
+    assert_eq!(base.temporal_score().value(), 8.8);
+}
+
+#[test]
+fn unproven_unknown() {
+    let base =
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:U/RL:O/RC:U").unwrap();
+    assert_eq!(base.temporal_score().value(), 7.8);
+}
+
+#[test]
+fn scope_changed() {
+    let base =
+        Base::from_str("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:L/I:L/A:N/E:F/RL:W/RC:R").unwrap();
+    assert_eq!(base.score().value(), 6.4);
+    assert_eq!(base.temporal_score().value(), 5.8);
+}
+
+#[test]
+fn not_defined() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/RL:X/RC:X";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.temporal_score().value(), 9.8);
+
+    let base = Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert_eq!(base.temporal_score().value(), base.score().value());
+}
+
+#[test]
+fn invalid() {
+    assert!(Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:Z").is_err());
+}
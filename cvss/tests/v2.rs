@@ -0,0 +1,66 @@
+#![cfg(all(feature = "v2", feature = "std"))]
+/// CVSS v2.0 Base Metrics tests
+use core::str::FromStr;
+
+use cvss::{Severity, v2::Base};
+
+/// CVE-2002-0392
+#[test]
+fn cve_2002_0392() {
+    let vector = "AV:N/AC:L/Au:N/C:N/I:N/A:C";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.score().value(), 7.8);
+    assert_eq!(base.severity(), Severity::High);
+}
+
+/// CVE-2003-0818
+#[test]
+fn cve_2003_0818() {
+    let vector = "AV:N/AC:L/Au:N/C:C/I:C/A:C";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.score().value(), 10.0);
+}
+
+/// CVE-2003-0062
+#[test]
+fn cve_2003_0062() {
+    let vector = "AV:L/AC:H/Au:N/C:C/I:C/A:C";
+    let base = Base::from_str(vector).unwrap();
+    assert_eq!(&base.to_string(), vector);
+    assert_eq!(base.score().value(), 6.2);
+    assert_eq!(base.severity(), Severity::Medium);
+}
+
+#[test]
+fn partial_impacts() {
+    let base = Base::from_str("AV:N/AC:M/Au:N/C:N/I:P/A:N").unwrap();
+    assert_eq!(base.score().value(), 4.3);
+
+    let base = Base::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P").unwrap();
+    assert_eq!(base.score().value(), 7.5);
+}
+
+#[test]
+fn no_impact() {
+    let base = Base::from_str("AV:N/AC:L/Au:N/C:N/I:N/A:N").unwrap();
+    assert_eq!(base.score().value(), 0.0);
+    assert_eq!(base.severity(), Severity::Low);
+}
+
+/// NVD sometimes wraps vectors in parentheses
+#[test]
+fn parenthesized() {
+    let base = Base::from_str("(AV:N/AC:L/Au:S/C:P/I:N/A:N)").unwrap();
+    assert_eq!(&base.to_string(), "AV:N/AC:L/Au:S/C:P/I:N/A:N");
+    assert_eq!(base.score().value(), 4.0);
+}
+
+#[test]
+fn invalid() {
+    assert!(Base::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").is_err());
+    assert!(Base::from_str("AV:X/AC:L/Au:N/C:P/I:P/A:P").is_err());
+    assert!(Base::from_str("AV:N/AC:L/AU:N/C:P/I:P/A:P").is_err());
+    assert!(Base::from_str("AV:N:L/AC:L").is_err());
+}
@@ -48,4 +48,15 @@ pub(crate) const COMMENTS: &[(&str, &str)] = &[
 ("musl", "Clean, efficient, standards-conformant libc implementation."),
 ("sgx", "Intel Software Guard Extensions (SGX) Enclave"),
 ("uclibc", "C library for developing embedded Linux systems"),
+// Abi
+("abi64", "MIPS N64 ABI"),
+("eabi", "ARM Embedded ABI with software floating point calling convention"),
+("eabihf", "ARM Embedded ABI with hardware floating point calling convention"),
+("elfv1", "PowerPC 64-bit ELF ABI version 1"),
+("elfv2", "PowerPC 64-bit ELF ABI version 2"),
+("ilp32", "32-bit integers, longs and pointers on a 64-bit architecture"),
+("softfloat", "Floating point operations implemented in software"),
+("spe", "PowerPC Signal Processing Engine"),
+("uwp", "Universal Windows Platform"),
+("x32", "32-bit pointers on x86_64"),
 ];
@@ -0,0 +1,181 @@
+//! Compares the targets known to two versions of `rustc`,
+//! to help writing the changelog when regenerating the data.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::doc_target_info::DocTargetInfo;
+
+/// Targets known to a `rustc` version, with their tier if documented
+pub(crate) type Targets = BTreeMap<String, Option<u8>>;
+
+#[must_use]
+pub(crate) fn targets(
+    triples: &[String],
+    doc_info: Option<&HashMap<String, DocTargetInfo>>,
+) -> Targets {
+    triples
+        .iter()
+        .map(|triple| {
+            let tier = doc_info
+                .and_then(|doc_info| doc_info.get(triple))
+                .map(|info| info.tier);
+            (triple.clone(), tier)
+        })
+        .collect()
+}
+
+/// Differences between the targets of two `rustc` versions
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct TargetsDiff {
+    pub added: Vec<(String, Option<u8>)>,
+    pub removed: Vec<(String, Option<u8>)>,
+    /// Targets present in both versions whose tier changed, with the old and new tiers
+    pub retiered: Vec<(String, u8, u8)>,
+}
+
+impl TargetsDiff {
+    #[must_use]
+    pub fn new(old: &Targets, new: &Targets) -> Self {
+        let mut diff = TargetsDiff::default();
+
+        for (triple, &tier) in new {
+            match old.get(triple) {
+                None => diff.added.push((triple.clone(), tier)),
+                Some(&old_tier) => {
+                    if let (Some(old_tier), Some(new_tier)) = (old_tier, tier) {
+                        if old_tier != new_tier {
+                            diff.retiered.push((triple.clone(), old_tier, new_tier));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (triple, &tier) in old {
+            if !new.contains_key(triple) {
+                diff.removed.push((triple.clone(), tier));
+            }
+        }
+
+        diff
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retiered.is_empty()
+    }
+}
+
+/// Formats the differences as changelog sections
+impl fmt::Display for TargetsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes to targets");
+        }
+
+        if !self.added.is_empty() {
+            writeln!(f, "### Added")?;
+            for (triple, tier) in &self.added {
+                writeln!(f, "- `{triple}`{}", describe_tier(*tier))?;
+            }
+        }
+
+        if !self.retiered.is_empty() {
+            if !self.added.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "### Changed")?;
+            for (triple, old_tier, new_tier) in &self.retiered {
+                writeln!(
+                    f,
+                    "- `{triple}` moved from tier {old_tier} to tier {new_tier}"
+                )?;
+            }
+        }
+
+        if !self.removed.is_empty() {
+            if !self.added.is_empty() || !self.retiered.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "### Removed")?;
+            for (triple, tier) in &self.removed {
+                writeln!(f, "- `{triple}`{}", describe_tier(*tier))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn describe_tier(tier: Option<u8>) -> String {
+    match tier {
+        Some(tier) => format!(" (tier {tier})"),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets(list: &[(&str, Option<u8>)]) -> Targets {
+        list.iter()
+            .map(|(triple, tier)| (triple.to_string(), *tier))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = targets(&[
+            ("aarch64-apple-darwin", Some(2)),
+            ("i686-apple-darwin", Some(3)),
+            ("x86_64-unknown-linux-gnu", Some(1)),
+        ]);
+        let new = targets(&[
+            ("aarch64-apple-darwin", Some(1)),
+            ("aarch64-unknown-linux-gnu", Some(1)),
+            ("x86_64-unknown-linux-gnu", Some(1)),
+        ]);
+
+        let diff = TargetsDiff::new(&old, &new);
+        assert_eq!(
+            diff,
+            TargetsDiff {
+                added: vec![("aarch64-unknown-linux-gnu".to_owned(), Some(1))],
+                removed: vec![("i686-apple-darwin".to_owned(), Some(3))],
+                retiered: vec![("aarch64-apple-darwin".to_owned(), 2, 1)],
+            }
+        );
+        assert_eq!(
+            diff.to_string(),
+            "\
+### Added
+- `aarch64-unknown-linux-gnu` (tier 1)
+
+### Changed
+- `aarch64-apple-darwin` moved from tier 2 to tier 1
+
+### Removed
+- `i686-apple-darwin` (tier 3)
+"
+        );
+    }
+
+    #[test]
+    fn test_diff_without_tiers() {
+        let old = targets(&[("aarch64-apple-darwin", None)]);
+        let new = targets(&[
+            ("aarch64-apple-darwin", Some(1)),
+            ("x86_64-apple-darwin", None),
+        ]);
+
+        let diff = TargetsDiff::new(&old, &new);
+        assert!(diff.retiered.is_empty());
+        assert_eq!(diff.to_string(), "### Added\n- `x86_64-apple-darwin`\n");
+        assert_eq!(
+            TargetsDiff::new(&old, &old).to_string(),
+            "No changes to targets\n"
+        );
+    }
+}
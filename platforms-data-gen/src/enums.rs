@@ -18,6 +18,7 @@ pub(crate) fn to_enum_name(key: &str) -> &'static str {
         "target_arch" => "Arch",
         "target_os" => "OS",
         "target_env" => "Env",
+        "target_abi" => "Abi",
         "tier" => "Tier",
         "target_endian" => "Endian",
         "target_pointer_width" => "PointerWidth",
@@ -29,7 +30,7 @@ pub(crate) fn to_enum_name(key: &str) -> &'static str {
 pub(crate) fn to_enum_variant_name(value: &str) -> String {
     let name = value.to_ascii_lowercase();
     match name.as_str() {
-        "" => "None".to_owned(), // This is used for `Env` which is set to empty string by default
+        "" => "None".to_owned(), // This is used for `Env` and `Abi` which are set to empty string by default
         // Numbers cannot be used as enum discriminants, so for `PointerWidth` enum we need this hack
         "16" => "U16".to_owned(),
         "32" => "U32".to_owned(),
@@ -66,14 +67,14 @@ pub(crate) fn to_enum_variant_name(value: &str) -> String {
 }
 
 fn make_ascii_titlecase(s: &str) -> String {
-    // uppercase a lowercase letter at the start of the string or after a `_`, removing the `_`.
+    // uppercase a lowercase letter at the start of the string or after a `_` or `-`, removing it.
     // this transforms `foo_bar` into `FooBar` but `x86_64` into `X86_64`
     let mut out = String::with_capacity(s.len());
     let mut at_boundary = true;
 
     for c in s.chars() {
         at_boundary = match (c, at_boundary) {
-            ('_', _) => true,
+            ('_', _) | ('-', _) => true,
             (c, true) if c.is_ascii_digit() => {
                 out.push('_');
                 out.push(c);
@@ -110,5 +111,6 @@ mod tests {
         assert_eq!(&to_enum_variant_name("PoWeRpC"), "PowerPc");
         assert_eq!(&to_enum_variant_name("x86_64"), "X86_64");
         assert_eq!(&to_enum_variant_name("solid_asp3"), "SolidAsp3");
+        assert_eq!(&to_enum_variant_name("vec-extabi"), "VecExtabi");
     }
 }
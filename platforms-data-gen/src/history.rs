@@ -0,0 +1,103 @@
+//! Computes the tier history of targets from the platform support documentation
+//! of successive Rust releases.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::doc_target_info::DocTargetInfo;
+
+/// Rust release version as `(major, minor, patch)`
+pub(crate) type Version = (u16, u16, u16);
+
+/// Parses a version like `1.70.0`, or `1.70` for the first release of a minor version
+#[must_use]
+pub(crate) fn parse_version(input: &str) -> Option<Version> {
+    let mut parts = input.split('.').map(|part| part.parse().ok());
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    match parts.next() {
+        Some(_) => None,
+        None => Some((major, minor, patch)),
+    }
+}
+
+/// Returns the releases in which each target was added or changed tiers.
+///
+/// Only targets documented in the latest release are included,
+/// since the older ones are no longer part of the `platforms` crate.
+#[must_use]
+pub(crate) fn tier_history(
+    releases: &BTreeMap<Version, HashMap<String, DocTargetInfo>>,
+) -> BTreeMap<String, Vec<(Version, u8)>> {
+    let mut history = BTreeMap::<String, Vec<(Version, u8)>>::new();
+
+    for (&version, doc_info) in releases {
+        for (triple, info) in doc_info {
+            let changes = history.entry(triple.clone()).or_default();
+            if changes.last().map(|&(_, tier)| tier) != Some(info.tier) {
+                changes.push((version, info.tier));
+            }
+        }
+    }
+
+    if let Some(latest) = releases.values().next_back() {
+        history.retain(|triple, _| latest.contains_key(triple));
+    }
+
+    history
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(targets: &[(&str, u8)]) -> HashMap<String, DocTargetInfo> {
+        targets
+            .iter()
+            .map(|&(triple, tier)| {
+                let info = DocTargetInfo {
+                    tier,
+                    notes: String::new(),
+                };
+                (triple.to_owned(), info)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.70.0"), Some((1, 70, 0)));
+        assert_eq!(parse_version("1.70"), Some((1, 70, 0)));
+        assert_eq!(parse_version("1"), None);
+        assert_eq!(parse_version("1.70.0.1"), None);
+        assert_eq!(parse_version("nightly"), None);
+    }
+
+    #[test]
+    fn test_tier_history() {
+        let mut releases = BTreeMap::new();
+        releases.insert(
+            (1, 49, 0),
+            release(&[("aarch64-apple-darwin", 2), ("i686-apple-darwin", 3)]),
+        );
+        releases.insert(
+            (1, 50, 0),
+            release(&[("aarch64-apple-darwin", 2), ("i686-apple-darwin", 3)]),
+        );
+        releases.insert(
+            (1, 70, 0),
+            release(&[("aarch64-apple-darwin", 1), ("x86_64-unknown-none", 3)]),
+        );
+
+        let history = tier_history(&releases);
+        assert_eq!(
+            history.keys().collect::<Vec<_>>(),
+            ["aarch64-apple-darwin", "x86_64-unknown-none"]
+        );
+        assert_eq!(
+            history["aarch64-apple-darwin"],
+            [((1, 49, 0), 2), ((1, 70, 0), 1)]
+        );
+        assert_eq!(history["x86_64-unknown-none"], [((1, 70, 0), 3)]);
+    }
+}
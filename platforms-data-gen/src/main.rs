@@ -1,35 +1,115 @@
 mod comments;
 mod data;
+mod diff;
 mod doc_target_info;
 mod enums;
+mod history;
 mod rustc_target_info;
 mod templates;
 mod write;
 
 use std::{
-    collections::{HashMap, HashSet},
-    env::args_os,
+    collections::{BTreeMap, HashMap, HashSet},
+    ffi::OsString,
     fs::File,
+    path::PathBuf,
 };
 
-use write::{write_enum_file, write_targets_file, FIELDS_WITH_ENUMS};
+use clap::{Parser, Subcommand};
+use write::{write_enum_file, write_history_file, write_targets_file, FIELDS_WITH_ENUMS};
 
-use crate::doc_target_info::DocTargetInfo;
+use crate::{doc_target_info::DocTargetInfo, rustc_target_info::Rustc};
+
+/// Generates the source of the `platforms` crate from the targets known to `rustc`
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// `rustup` toolchain to generate the data for, e.g. `nightly` or `1.80.0`
+    #[arg(long, value_name = "TOOLCHAIN")]
+    toolchain: Option<String>,
+
+    /// Path to the `rustc` binary to generate the data for (default: `rustc` in `$PATH`)
+    #[arg(long, value_name = "PATH")]
+    rustc_path: Option<OsString>,
+
+    /// Local copy of
+    /// https://github.com/rust-lang/rust/blob/master/src/doc/rustc/src/platform-support.md
+    #[arg(value_name = "PLATFORM_SUPPORT_MD", required = true)]
+    file: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the targets added, removed and re-tiered between two toolchains,
+    /// formatted as changelog entries
+    Diff {
+        /// `rustup` toolchain to compare from
+        #[arg(long, value_name = "TOOLCHAIN")]
+        old_toolchain: Option<String>,
+
+        /// Path to the `rustc` binary to compare from
+        #[arg(long, value_name = "PATH")]
+        old_rustc_path: Option<OsString>,
+
+        /// platform-support.md of the old toolchain, to compare tiers
+        #[arg(long, value_name = "PLATFORM_SUPPORT_MD", requires = "new_docs")]
+        old_docs: Option<PathBuf>,
+
+        /// `rustup` toolchain to compare to
+        #[arg(long, value_name = "TOOLCHAIN")]
+        new_toolchain: Option<String>,
+
+        /// Path to the `rustc` binary to compare to
+        #[arg(long, value_name = "PATH")]
+        new_rustc_path: Option<OsString>,
+
+        /// platform-support.md of the new toolchain, to compare tiers
+        #[arg(long, value_name = "PLATFORM_SUPPORT_MD", requires = "old_docs")]
+        new_docs: Option<PathBuf>,
+    },
+
+    /// Generate the tier history of targets from the platform-support.md
+    /// of past Rust releases
+    History {
+        /// Rust release and its platform-support.md, e.g. `1.70.0=platform-support-1.70.0.md`
+        #[arg(value_name = "VERSION=PLATFORM_SUPPORT_MD", required = true)]
+        releases: Vec<String>,
+    },
+}
 
 fn main() -> std::io::Result<()> {
-    let file = args_os().nth(1).expect(
-        "No path to .md file specified!\n
-Please download a local copy of
-https://github.com/rust-lang/rust/blob/master/src/doc/rustc/src/platform-support.md
-and pass it as an argument to this program.",
-    );
-    let doc_content = std::fs::read_to_string(file)?;
+    let args = Args::parse();
+
+    match args.command {
+        Some(Command::Diff {
+            old_toolchain,
+            old_rustc_path,
+            old_docs,
+            new_toolchain,
+            new_rustc_path,
+            new_docs,
+        }) => {
+            let old = Rustc::new(old_rustc_path, old_toolchain);
+            let new = Rustc::new(new_rustc_path, new_toolchain);
+            return print_diff(&old, old_docs, &new, new_docs);
+        }
+        Some(Command::History { releases }) => return generate_history(&releases),
+        None => (),
+    }
+
+    let rustc = Rustc::new(args.rustc_path, args.toolchain);
+
+    let doc_content = std::fs::read_to_string(args.file.unwrap())?;
     let doc_info = doc_target_info::parse_file(&doc_content);
-    let triples = rustc_target_info::target_triples();
+    let triples = rustc.target_triples();
 
     ensure_rustc_and_docs_agree(&triples, &doc_info);
 
-    let rustc_info = rustc_target_info::targets_info(&triples);
+    let rustc_version = rustc.version();
+    let rustc_info = rustc.targets_info(&triples);
 
     for key in FIELDS_WITH_ENUMS.iter() {
         let filename = format!("src/target/{}.rs", enums::to_enum_name(key).to_lowercase());
@@ -38,10 +118,53 @@ and pass it as an argument to this program.",
     }
 
     let mut file = File::create("src/platform/platforms.rs")?;
-    write_targets_file(&triples, &rustc_info, &doc_info, &mut file)?;
+    write_targets_file(&rustc_version, &triples, &rustc_info, &doc_info, &mut file)?;
+    Ok(())
+}
+
+fn print_diff(
+    old: &Rustc,
+    old_docs: Option<PathBuf>,
+    new: &Rustc,
+    new_docs: Option<PathBuf>,
+) -> std::io::Result<()> {
+    let targets = |rustc: &Rustc, docs: Option<PathBuf>| -> std::io::Result<diff::Targets> {
+        let doc_info = match docs {
+            Some(path) => Some(doc_target_info::parse_file(&std::fs::read_to_string(path)?)),
+            None => None,
+        };
+        Ok(diff::targets(&rustc.target_triples(), doc_info.as_ref()))
+    };
+
+    let old_targets = targets(old, old_docs)?;
+    let new_targets = targets(new, new_docs)?;
+
+    println!("Comparing {} to {}\n", old.version(), new.version());
+    print!("{}", diff::TargetsDiff::new(&old_targets, &new_targets));
     Ok(())
 }
 
+fn generate_history(releases: &[String]) -> std::io::Result<()> {
+    let mut doc_infos = BTreeMap::new();
+    for release in releases {
+        let (version, path) = release
+            .split_once('=')
+            .and_then(|(version, path)| Some((history::parse_version(version)?, path)))
+            .unwrap_or_else(|| {
+                eprintln!("Error: expected `VERSION=PLATFORM_SUPPORT_MD`, got '{release}'");
+                std::process::exit(1);
+            });
+        let doc_content = std::fs::read_to_string(path)?;
+        doc_infos.insert(version, doc_target_info::parse_file(&doc_content));
+    }
+
+    let earliest = doc_infos.keys().next().copied();
+    let history = history::tier_history(&doc_infos);
+
+    let mut file = File::create("src/platform/history/data.rs")?;
+    write_history_file(earliest, &history, &mut file)
+}
+
 fn ensure_rustc_and_docs_agree(
     rustc_triples: &[String],
     doc_triples: &HashMap<String, DocTargetInfo>,
@@ -1,68 +1,138 @@
 //! Invokes `rustc --print=cfg` to get info about a given target and parses its output.
 
-use std::{io::BufRead, process::Child};
+use std::{
+    ffi::OsString,
+    io::BufRead,
+    process::{Child, Command},
+};
 
-/// Returns a list of all known target triples.
-/// Obtains it by invoking `rustc --print=target-list`
-pub(crate) fn target_triples() -> Vec<String> {
-    std::process::Command::new("rustc")
-        .arg("--print=target-list")
-        .output()
-        .expect("Failed to invoke rustc; make sure it's in $PATH")
-        .stdout
-        .lines()
-        .map(|line| line.unwrap())
-        .collect()
-}
+/// Keys which can be present multiple times, whose values are collected into
+/// a comma-separated list, along with the name of the corresponding `Platform` field
+pub(crate) const MULTI_VALUED_KEYS: [(&str, &str); 2] = [
+    ("target_family", "target_families"),
+    ("target_feature", "target_features"),
+];
+
+/// The `rustc` to query for target information
+#[derive(Clone, Debug)]
+pub(crate) struct Rustc {
+    /// Path to the `rustc` binary, or its name to look up in `$PATH`
+    path: OsString,
 
-/// Returns detailed information about all targets.
-/// Since it invokes `rustc` for every target, it may take a while.
-pub(crate) fn targets_info(triples: &[String]) -> Vec<std::collections::HashMap<String, String>> {
-    // Spawn all queries at once to make use of all available cores.
-    // No it's not premature optimization, it lets me iterate faster okay?
-    let child_processes: Vec<Child> = triples
-        .iter()
-        .map(|t| spawn_rustc_target_info_query(t))
-        .collect();
-    child_processes
-        .into_iter()
-        .map(|c| {
-            let output = c.wait_with_output().unwrap();
-            assert_eq!(output.status.code(), Some(0));
-            parse_rustc_target_info(&output.stdout)
-        })
-        .collect()
+    /// `rustup` toolchain to select, e.g. `nightly` or `1.80.0`
+    toolchain: Option<String>,
 }
 
-fn spawn_rustc_target_info_query(target_triple: &str) -> Child {
-    std::process::Command::new("rustc")
-        .arg("--print=cfg")
-        .arg(format!("--target={}", target_triple)) //not being parsed by the shell, so not a vulnerability
-        .stdout(std::process::Stdio::piped())
-        .spawn()
-        .expect("Failed to invoke rustc; make sure it's in $PATH")
+impl Rustc {
+    pub fn new(path: Option<OsString>, toolchain: Option<String>) -> Self {
+        Rustc {
+            path: path.unwrap_or_else(|| "rustc".into()),
+            toolchain,
+        }
+    }
+
+    fn command(&self) -> Command {
+        let mut command = Command::new(&self.path);
+        if let Some(toolchain) = &self.toolchain {
+            // handled by the `rustup` proxy installed as `rustc`
+            command.arg(format!("+{toolchain}"));
+        }
+        command
+    }
+
+    /// Returns the version string of this `rustc`, e.g. `rustc 1.80.0 (051478957 2024-07-21)`.
+    /// Obtains it by invoking `rustc --version`
+    pub fn version(&self) -> String {
+        let output = self
+            .command()
+            .arg("--version")
+            .output()
+            .expect("Failed to invoke rustc; make sure it's in $PATH");
+        assert!(output.status.success(), "`rustc --version` failed");
+        String::from_utf8(output.stdout)
+            .expect("rustc version is not valid UTF-8")
+            .trim()
+            .to_owned()
+    }
+
+    /// Returns a list of all known target triples.
+    /// Obtains it by invoking `rustc --print=target-list`
+    pub fn target_triples(&self) -> Vec<String> {
+        self.command()
+            .arg("--print=target-list")
+            .output()
+            .expect("Failed to invoke rustc; make sure it's in $PATH")
+            .stdout
+            .lines()
+            .map(|line| line.unwrap())
+            .collect()
+    }
+
+    /// Returns detailed information about all targets.
+    /// Since it invokes `rustc` for every target, it may take a while.
+    pub fn targets_info(
+        &self,
+        triples: &[String],
+    ) -> Vec<std::collections::HashMap<String, String>> {
+        // Spawn all queries at once to make use of all available cores.
+        // No it's not premature optimization, it lets me iterate faster okay?
+        let child_processes: Vec<Child> = triples
+            .iter()
+            .map(|t| self.spawn_target_info_query(t))
+            .collect();
+        child_processes
+            .into_iter()
+            .map(|c| {
+                let output = c.wait_with_output().unwrap();
+                assert_eq!(output.status.code(), Some(0));
+                parse_rustc_target_info(&output.stdout)
+            })
+            .collect()
+    }
+
+    fn spawn_target_info_query(&self, target_triple: &str) -> Child {
+        self.command()
+            .arg("--print=cfg")
+            .arg(format!("--target={}", target_triple)) //not being parsed by the shell, so not a vulnerability
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+            .expect("Failed to invoke rustc; make sure it's in $PATH")
+    }
 }
 
 fn parse_rustc_target_info(rustc_output: &[u8]) -> std::collections::HashMap<String, String> {
     // Decoupled from `rustc_target_info` to allow unit testing
-    rustc_output
-        .lines()
-        .filter_map(|line| {
-            let line = line.unwrap();
-            // rustc outputs some free-standing values as well as key-value pairs
-            // we're only interested in the pairs, which are separated by '=' and the value is quoted
-            if line.contains("=") {
-                let key = line.split("=").next().unwrap();
-                let mut value: String = line.split("=").skip(1).collect();
-                // strip first and last chars of the quoted value. Verify that they're quotes
-                assert!(value.pop().unwrap() == '"');
-                assert!(value.remove(0) == '"');
-                Some((key.to_owned(), value))
+    let mut result = std::collections::HashMap::new();
+    // always record the keys that can be present multiple times,
+    // so that targets without any values for them get an empty list
+    for (key, _) in MULTI_VALUED_KEYS.iter() {
+        result.insert(key.to_string(), String::new());
+    }
+    for line in rustc_output.lines() {
+        let line = line.unwrap();
+        // rustc outputs some free-standing values as well as key-value pairs
+        // we're only interested in the pairs, which are separated by '=' and the value is quoted
+        if line.contains("=") {
+            let key = line.split("=").next().unwrap();
+            let mut value: String = line.split("=").skip(1).collect();
+            // strip first and last chars of the quoted value. Verify that they're quotes
+            assert!(value.pop().unwrap() == '"');
+            assert!(value.remove(0) == '"');
+            if MULTI_VALUED_KEYS.iter().any(|(k, _)| *k == key) {
+                // join all the values into a comma-separated list
+                let values = result.get_mut(key).unwrap();
+                if !values.is_empty() {
+                    values.push(',');
+                }
+                values.push_str(&value);
             } else {
-                None
+                result.insert(key.to_owned(), value);
             }
-        })
-        .collect()
+        }
+    }
+    // older versions of rustc don't print `target_abi`, which is then the same as unset
+    result.entry("target_abi".to_owned()).or_default();
+    result
 }
 
 #[cfg(test)]
@@ -89,5 +159,20 @@ unix
         assert_eq!(result.get("target_endian").unwrap(), "little");
         assert_eq!(result.get("target_pointer_width").unwrap(), "64");
         assert_eq!(result.get("target_vendor").unwrap(), "unknown");
+        assert_eq!(result.get("target_feature").unwrap(), "fxsr,sse,sse2");
+        assert_eq!(result.get("target_family").unwrap(), "unix");
+    }
+
+    #[test]
+    fn test_rustc_parser_no_features() {
+        let rustc_output = br#"target_arch="riscv32"
+target_endian="little"
+target_env=""
+target_os="none"
+target_pointer_width="32"
+"#;
+        let result = parse_rustc_target_info(rustc_output);
+        assert_eq!(result.get("target_feature").unwrap(), "");
+        assert_eq!(result.get("target_family").unwrap(), "");
     }
 }
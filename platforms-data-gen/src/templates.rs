@@ -5,6 +5,7 @@ pub const HEADERS: &[(&str, &[u8])] = &[
     ("target_arch", include_bytes!("../templates/arch_header.rs")),
     ("target_os", include_bytes!("../templates/os_header.rs")),
     ("target_env", include_bytes!("../templates/env_header.rs")),
+    ("target_abi", include_bytes!("../templates/abi_header.rs")),
     (
         "target_endian",
         include_bytes!("../templates/endian_header.rs"),
@@ -19,6 +20,7 @@ pub const FOOTERS: &[(&str, &[u8])] = &[
     ("target_arch", include_bytes!("../templates/arch_footer.rs")),
     ("target_os", include_bytes!("../templates/os_footer.rs")),
     ("target_env", include_bytes!("../templates/env_footer.rs")),
+    ("target_abi", include_bytes!("../templates/abi_footer.rs")),
     (
         "target_endian",
         include_bytes!("../templates/endian_footer.rs"),
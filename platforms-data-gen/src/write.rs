@@ -1,23 +1,27 @@
 //! Generates and writes contents of the auto-generated files
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Result;
 use std::io::Write;
 
 use crate::comments::Comments;
 use crate::doc_target_info::DocTargetInfo;
 use crate::enums::*;
+use crate::history::Version;
+use crate::rustc_target_info::MULTI_VALUED_KEYS;
 use crate::templates::Templates;
 
-pub(crate) const FIELDS_WITH_ENUMS: [&str; 5] = [
+pub(crate) const FIELDS_WITH_ENUMS: [&str; 6] = [
     "target_arch",
     "target_os",
     "target_env",
+    "target_abi",
     "target_endian",
     "target_pointer_width",
 ];
 
 pub(crate) fn write_targets_file<W: Write>(
+    rustc_version: &str,
     triples: &[String],
     rustc_info: &Vec<HashMap<String, String>>,
     doc_info: &HashMap<String, DocTargetInfo>,
@@ -31,6 +35,7 @@ pub(crate) fn write_targets_file<W: Write>(
 
 // Note: this file is auto-generated. Do not edit it manually!
 // If you need to referesh it, re-run the generator included in the source tree.
+// Generated from: {rustc_version}
 
 // Comments on targets are sourced from
 // https://doc.rust-lang.org/nightly/rustc/platform-support.html
@@ -92,6 +97,25 @@ pub(crate) const {}: Platform = Platform {{
         writeln!(out, "    {}: {},", key, value)?;
     }
     writeln!(out, "    tier: {},", tier_to_enum_variant(doc_info.tier))?;
+    for (key, field) in MULTI_VALUED_KEYS.iter() {
+        write!(out, "    {field}: &[")?;
+        for (i, value) in rustc_info[*key]
+            .split(',')
+            .filter(|value| !value.is_empty())
+            .enumerate()
+        {
+            if i > 0 {
+                write!(out, ", ")?;
+            }
+            write!(out, "\"{value}\"")?;
+        }
+        writeln!(out, "],")?;
+    }
+    writeln!(
+        out,
+        "    target_vendor: \"{}\",",
+        rustc_info["target_vendor"]
+    )?;
     writeln!(out, "}};")?;
     Ok(())
 }
@@ -196,6 +220,64 @@ impl FromStr for {enum_name} {{
     Ok(())
 }
 
+/// Writes the tier history of targets, as computed by `history::tier_history`
+pub(crate) fn write_history_file<W: Write>(
+    earliest: Option<Version>,
+    history: &BTreeMap<String, Vec<(Version, u8)>>,
+    out: &mut W,
+) -> Result<()> {
+    write!(
+        out,
+        "\
+//! Tier history of targets.
+
+// Note: this file is auto-generated. Do not edit it manually!
+// If you need to referesh it, re-run the generator included in the source tree
+// with the `history` subcommand.
+
+#![allow(missing_docs)]
+
+#[allow(unused_imports)]
+use crate::platform::{{
+    history::{{RustVersion, TierChange}},
+    Tier,
+}};
+
+/// Earliest Rust release the history was generated from
+pub(crate) const EARLIEST: Option<RustVersion> = "
+    )?;
+    match earliest {
+        Some(version) => writeln!(out, "Some({});", rust_version(version))?,
+        None => writeln!(out, "None;")?,
+    }
+
+    writeln!(
+        out,
+        "
+/// Tier history of every target, sorted by target triple
+pub(crate) const HISTORY: &[(&str, &[TierChange])] = &["
+    )?;
+    for (triple, changes) in history {
+        writeln!(out, "    (\"{triple}\", &[")?;
+        for &(version, tier) in changes {
+            writeln!(
+                out,
+                "        TierChange {{ version: {}, tier: {} }},",
+                rust_version(version),
+                tier_to_enum_variant(tier)
+            )?;
+        }
+        writeln!(out, "    ]),")?;
+    }
+    writeln!(out, "];")?;
+    Ok(())
+}
+
+#[must_use]
+fn rust_version((major, minor, patch): Version) -> String {
+    format!("RustVersion::new({major}, {minor}, {patch})")
+}
+
 #[must_use]
 fn to_const_variable_name(input: &str) -> String {
     input
@@ -0,0 +1,21 @@
+
+impl fmt::Display for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Abi {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = std::string::String::deserialize(deserializer)?;
+        string.parse().map_err(|_| D::Error::custom(std::format!("Unrecognized value '{}' for target_abi", string)))
+    }
+}
@@ -0,0 +1,15 @@
+//! Rust target ABIs
+
+use crate::error::Error;
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, de::Error as DeError, Deserialize, Serialize};
+
+/// `target_abi`: ABI component of the target triple which isn't part of `target_env`.
+///
+/// Target triples like `armv7-unknown-linux-gnueabihf` or `aarch64-unknown-none-softfloat`
+/// encode the ABI as a suffix, which rustc exposes separately from the environment: here
+/// `target_env` is `"gnu"` (resp. `""`) and `target_abi` is `"eabihf"` (resp. `"softfloat"`).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
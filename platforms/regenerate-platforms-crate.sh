@@ -6,7 +6,20 @@ set -e
 
 curl -o ./platform-support.md https://raw.githubusercontent.com/rust-lang/rust/master/src/doc/rustc/src/platform-support.md
 
-cargo +nightly run --manifest-path ../Cargo.toml --bin platforms-data-gen --release -- platform-support.md
+cargo run --manifest-path ../Cargo.toml --bin platforms-data-gen --release -- --toolchain nightly platform-support.md
+
+# Regenerate the tier history from the documentation of past releases
+
+latest_minor=$(rustc +stable --version | cut -d. -f2)
+mkdir -p history
+releases=()
+for minor in $(seq 54 "$latest_minor"); do
+    version="1.$minor.0"
+    curl -sf -o "history/$version.md" "https://raw.githubusercontent.com/rust-lang/rust/$version/src/doc/rustc/src/platform-support.md"
+    releases+=("$version=history/$version.md")
+done
+
+cargo run --manifest-path ../Cargo.toml --bin platforms-data-gen --release -- history "${releases[@]}"
 
 cargo fmt
 
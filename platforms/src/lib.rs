@@ -26,9 +26,12 @@ pub mod target;
 
 pub use crate::{
     error::Error,
-    platform::{Platform, Tier},
-    target::{Arch, Endian, Env, PointerWidth, OS},
+    platform::{History, Platform, RustVersion, Tier, TierChange},
+    target::{Abi, Arch, Endian, Env, PointerWidth, OS},
 };
 
 #[cfg(feature = "std")]
 pub use crate::platform::PlatformReq;
+
+#[cfg(feature = "custom-target")]
+pub use crate::platform::CustomPlatform;
@@ -1,12 +1,24 @@
 //! Rust platforms
 
+mod cfg;
 mod platforms;
 
+#[cfg(feature = "std")]
+mod current;
+#[cfg(feature = "custom-target")]
+mod custom;
+mod history;
 #[cfg(feature = "std")]
 mod req;
 mod tier;
 
-pub use self::tier::Tier;
+pub use self::{
+    history::{History, RustVersion, TierChange},
+    tier::Tier,
+};
+
+#[cfg(feature = "custom-target")]
+pub use self::custom::CustomPlatform;
 
 #[cfg(feature = "std")]
 pub use self::req::PlatformReq;
@@ -40,6 +52,11 @@ pub struct Platform {
     /// this value will be `None`.
     pub target_env: Env,
 
+    /// Target ABI `cfg` attribute (i.e. `cfg(target_abi)`).
+    /// Distinguishes e.g. `armv7-unknown-linux-gnueabihf` from
+    /// `armv7-unknown-linux-gnueabi`, which have the same `target_env`.
+    pub target_abi: Abi,
+
     /// Target pointer width `cfg` attribute, in bits (i.e. `cfg(target_pointer_width)`).
     /// Typically 64 on modern platforms, 32 on older platforms, 16 on some microcontrollers.
     pub target_pointer_width: PointerWidth,
@@ -48,12 +65,28 @@ pub struct Platform {
     /// Set to "little" on the vast majority of modern platforms.
     pub target_endian: Endian,
 
+    /// Target vendor `cfg` attribute (i.e. `cfg(target_vendor)`),
+    /// e.g. `apple`, `pc` or `unknown`.
+    pub target_vendor: &'static str,
+
+    /// Target family `cfg` attributes (i.e. `cfg(target_family)`), e.g. `unix` or `windows`.
+    /// Usually a single family, but there can be several (`wasm32-unknown-emscripten` is
+    /// both `unix` and `wasm`) or none (e.g. on bare metal).
+    pub target_families: &'static [&'static str],
+
     /// Tier of this platform:
     ///
     /// - `Tier::One`: guaranteed to work
     /// - `Tier::Two`: guaranteed to build
     /// - `Tier::Three`: unofficially supported with no guarantees
     pub tier: Tier,
+
+    /// Target features enabled by default `cfg` attributes (i.e. `cfg(target_feature)`),
+    /// e.g. `sse2` on `x86_64` or `neon` on `aarch64`.
+    ///
+    /// These are the baseline CPU features of the target, which can be
+    /// extended with `-C target-feature` or `-C target-cpu`.
+    pub target_features: &'static [&'static str],
 }
 
 impl Platform {
@@ -68,6 +101,74 @@ impl Platform {
             .iter()
             .find(|platform| platform.target_triple == target_triple)
     }
+
+    /// Find all Rust platforms with the given architecture, OS and environment,
+    /// e.g. all `aarch64` Linux targets using musl:
+    ///
+    /// ```
+    /// use platforms::{Arch, Env, Platform, OS};
+    ///
+    /// let triples: Vec<_> = Platform::find_by_components(Arch::AArch64, OS::Linux, Env::Musl)
+    ///     .map(|platform| platform.target_triple)
+    ///     .collect();
+    ///
+    /// assert!(triples.contains(&"aarch64-unknown-linux-musl"));
+    /// ```
+    pub fn find_by_components(
+        arch: Arch,
+        os: OS,
+        env: Env,
+    ) -> impl Iterator<Item = &'static Platform> {
+        Self::ALL
+            .iter()
+            .filter(Self::by_arch(arch))
+            .filter(Self::by_os(os))
+            .filter(Self::by_env(env))
+    }
+
+    /// Is the given target feature enabled by default on this platform?
+    pub fn has_target_feature(&self, feature: &str) -> bool {
+        self.target_features.contains(&feature)
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given
+    /// architecture, e.g. `Platform::ALL.iter().filter(Platform::by_arch(Arch::X86_64))`
+    pub fn by_arch(arch: Arch) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_arch == arch
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given OS
+    pub fn by_os(os: OS) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_os == os
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given
+    /// environment
+    pub fn by_env(env: Env) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_env == env
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given ABI
+    pub fn by_abi(abi: Abi) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_abi == abi
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given
+    /// pointer width
+    pub fn by_pointer_width(pointer_width: PointerWidth) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_pointer_width == pointer_width
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms with the given
+    /// endianness
+    pub fn by_endian(endian: Endian) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.target_endian == endian
+    }
+
+    /// Predicate for `Iterator::filter` matching platforms of the given tier
+    pub fn by_tier(tier: Tier) -> impl Fn(&&Platform) -> bool {
+        move |platform| platform.tier == tier
+    }
 }
 
 impl fmt::Display for Platform {
@@ -79,7 +180,7 @@ impl fmt::Display for Platform {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::Platform;
-    use std::collections::HashSet;
+    use std::{collections::HashSet, vec::Vec};
 
     /// Ensure there are no duplicate target triples in the platforms list
     #[test]
@@ -99,6 +200,76 @@ mod tests {
 
     use super::*;
 
+    /// The detected host platform must be the OS this test runs on
+    #[test]
+    fn current_test() {
+        let platform = Platform::current().expect("host platform not detected");
+        assert_eq!(platform.target_os.as_str(), std::env::consts::OS);
+    }
+
+    /// Lookup by components must only return matching platforms
+    #[test]
+    fn find_by_components_test() {
+        let platforms: Vec<_> =
+            Platform::find_by_components(Arch::AArch64, OS::Linux, Env::Musl).collect();
+
+        assert!(!platforms.is_empty());
+        for platform in &platforms {
+            assert_eq!(platform.target_arch, Arch::AArch64);
+            assert_eq!(platform.target_os, OS::Linux);
+            assert_eq!(platform.target_env, Env::Musl);
+        }
+        assert!(platforms.contains(&Platform::find("aarch64-unknown-linux-musl").unwrap()));
+    }
+
+    /// Filter predicates can be combined
+    #[test]
+    fn filter_test() {
+        let tier1_linux = Platform::ALL
+            .iter()
+            .filter(Platform::by_os(OS::Linux))
+            .filter(Platform::by_tier(Tier::One))
+            .map(|platform| platform.target_triple)
+            .collect::<HashSet<_>>();
+
+        assert!(tier1_linux.contains("x86_64-unknown-linux-gnu"));
+        assert!(!tier1_linux.contains("x86_64-pc-windows-msvc"));
+        assert!(!tier1_linux.contains("x86_64-unknown-linux-musl"));
+    }
+
+    /// The ABI is distinct from the environment
+    #[test]
+    fn abi_test() {
+        let gnueabihf = Platform::find("armv7-unknown-linux-gnueabihf").unwrap();
+        assert_eq!(gnueabihf.target_env, Env::Gnu);
+        assert_eq!(gnueabihf.target_abi, Abi::Eabihf);
+
+        let gnueabi = Platform::find("armv7-unknown-linux-gnueabi").unwrap();
+        assert_eq!(gnueabi.target_env, Env::Gnu);
+        assert_eq!(gnueabi.target_abi, Abi::Eabi);
+
+        let softfloat = Platform::find("aarch64-unknown-none-softfloat").unwrap();
+        assert_eq!(softfloat.target_env, Env::None);
+        assert_eq!(softfloat.target_abi, Abi::Softfloat);
+
+        let hard_float_linux = Platform::find_by_components(Arch::Arm, OS::Linux, Env::Gnu)
+            .filter(Platform::by_abi(Abi::Eabihf))
+            .count();
+        assert!(hard_float_linux > 0);
+    }
+
+    /// Baseline target features must match the ones enabled by rustc
+    #[test]
+    fn target_features_test() {
+        let x86_64 = Platform::find("x86_64-unknown-linux-gnu").unwrap();
+        assert!(x86_64.has_target_feature("sse2"));
+        assert!(!x86_64.has_target_feature("avx2"));
+
+        let aarch64 = Platform::find("aarch64-unknown-linux-gnu").unwrap();
+        assert!(aarch64.has_target_feature("neon"));
+        assert!(!aarch64.has_target_feature("sse2"));
+    }
+
     /// `platforms` v2.0 used to provide various constants passed as `cfg` values,
     /// and attempted to detect the target triple based on that.
     /// This test is meant to check whether such detection can be accurate.
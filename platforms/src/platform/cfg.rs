@@ -0,0 +1,273 @@
+//! Evaluation of `cfg(...)` expressions against platforms
+
+use crate::{error::Error, platform::Platform};
+
+impl Platform {
+    /// Does the given target specification match this platform?
+    ///
+    /// This accepts the keys of Cargo's `[target]` tables: either a
+    /// `cfg(...)` expression, e.g. `cfg(all(unix, target_arch = "x86_64"))`,
+    /// or a target triple.
+    ///
+    /// The `target_arch`, `target_os`, `target_env`, `target_abi`,
+    /// `target_endian`, `target_pointer_width`, `target_vendor`,
+    /// `target_family` and `target_feature` keys, as well as the `unix` and
+    /// `windows` names, are supported. Other names and keys
+    /// (e.g. `feature = "..."` or `debug_assertions`) don't describe the
+    /// platform, and are never set.
+    ///
+    /// Returns an error if the `cfg(...)` expression is malformed.
+    pub fn matches_cfg(&self, spec: &str) -> Result<bool, Error> {
+        let spec = spec.trim();
+        if !spec.starts_with("cfg(") {
+            return Ok(spec == self.target_triple);
+        }
+
+        let mut parser = Parser {
+            input: &spec["cfg".len()..],
+            platform: self,
+        };
+        parser.expect('(')?;
+        let result = parser.predicate()?;
+        parser.expect(')')?;
+
+        if !parser.input.trim_start().is_empty() {
+            return Err(Error);
+        }
+
+        Ok(result)
+    }
+
+    /// Is the given `cfg` name set for this platform, e.g. `unix`?
+    fn cfg_name(&self, name: &str) -> bool {
+        match name {
+            // shorthands for `target_family = "unix"` and `target_family = "windows"`
+            "unix" | "windows" => self.target_families.contains(&name),
+            _ => false,
+        }
+    }
+
+    /// Is the given `cfg` key set to the given value for this platform,
+    /// e.g. `target_os = "linux"`?
+    fn cfg_key_value(&self, key: &str, value: &str) -> bool {
+        match key {
+            "target_arch" => self.target_arch.as_str() == value,
+            "target_os" => self.target_os.as_str() == value,
+            "target_env" => self.target_env.as_str() == value,
+            "target_abi" => self.target_abi.as_str() == value,
+            "target_endian" => self.target_endian.as_str() == value,
+            "target_pointer_width" => self.target_pointer_width.as_str() == value,
+            "target_vendor" => self.target_vendor == value,
+            "target_family" => self.target_families.contains(&value),
+            "target_feature" => self.target_features.contains(&value),
+            _ => false,
+        }
+    }
+}
+
+/// Recursive descent parser for `cfg` predicates, which evaluates them as it
+/// goes to avoid allocating a syntax tree
+struct Parser<'a> {
+    /// Remaining input
+    input: &'a str,
+
+    /// Platform to evaluate the predicates against
+    platform: &'a Platform,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse and evaluate a configuration predicate
+    fn predicate(&mut self) -> Result<bool, Error> {
+        let name = self.identifier()?;
+
+        if self.peek() == Some('(') {
+            return match name {
+                "all" => self.list(true, |a, b| a && b),
+                "any" => self.list(false, |a, b| a || b),
+                "not" => {
+                    self.expect('(')?;
+                    let result = self.predicate()?;
+                    self.expect(')')?;
+                    Ok(!result)
+                }
+                _ => Err(Error),
+            };
+        }
+
+        if self.peek() == Some('=') {
+            self.expect('=')?;
+            let value = self.string()?;
+            return Ok(self.platform.cfg_key_value(name, value));
+        }
+
+        Ok(self.platform.cfg_name(name))
+    }
+
+    /// Parse and evaluate a parenthesized, comma-separated list of predicates
+    fn list(&mut self, init: bool, combine: fn(bool, bool) -> bool) -> Result<bool, Error> {
+        self.expect('(')?;
+
+        let mut result = init;
+        while self.peek() != Some(')') {
+            // all predicates are parsed to report errors, even if the result is known
+            let predicate = self.predicate()?;
+            result = combine(result, predicate);
+
+            if self.peek() == Some(',') {
+                self.expect(',')?;
+            } else {
+                break;
+            }
+        }
+
+        self.expect(')')?;
+        Ok(result)
+    }
+
+    /// Parse an identifier, e.g. `target_os`
+    fn identifier(&mut self) -> Result<&'a str, Error> {
+        self.input = self.input.trim_start();
+
+        let len = self
+            .input
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(self.input.len());
+        let identifier = &self.input[..len];
+
+        match identifier.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                self.input = &self.input[len..];
+                Ok(identifier)
+            }
+            _ => Err(Error),
+        }
+    }
+
+    /// Parse a string literal, e.g. `"linux"`
+    fn string(&mut self) -> Result<&'a str, Error> {
+        self.expect('"')?;
+
+        let len = self.input.find('"').ok_or(Error)?;
+        let string = &self.input[..len];
+        if string.contains('\\') {
+            // escapes never occur in the values of target `cfg` keys
+            return Err(Error);
+        }
+
+        self.input = &self.input[len + 1..];
+        Ok(string)
+    }
+
+    /// Get the next non-whitespace character, without consuming it
+    fn peek(&mut self) -> Option<char> {
+        self.input = self.input.trim_start();
+        self.input.chars().next()
+    }
+
+    /// Consume the given character, which must be next
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.peek() != Some(c) {
+            return Err(Error);
+        }
+
+        self.input = &self.input[c.len_utf8()..];
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::platform::Platform;
+
+    fn platform(target_triple: &str) -> &'static Platform {
+        Platform::find(target_triple).unwrap()
+    }
+
+    #[test]
+    fn name_test() {
+        let linux = platform("x86_64-unknown-linux-gnu");
+        assert_eq!(linux.matches_cfg("cfg(unix)"), Ok(true));
+        assert_eq!(linux.matches_cfg("cfg(windows)"), Ok(false));
+        assert_eq!(linux.matches_cfg("cfg(debug_assertions)"), Ok(false));
+
+        let emscripten = platform("wasm32-unknown-emscripten");
+        assert_eq!(emscripten.matches_cfg("cfg(unix)"), Ok(true));
+        assert_eq!(emscripten.matches_cfg("cfg(wasm)"), Ok(false));
+        assert_eq!(
+            emscripten.matches_cfg(r#"cfg(target_family = "wasm")"#),
+            Ok(true)
+        );
+
+        let windows = platform("x86_64-pc-windows-msvc");
+        assert_eq!(windows.matches_cfg("cfg(windows)"), Ok(true));
+        assert_eq!(windows.matches_cfg("cfg(unix)"), Ok(false));
+    }
+
+    #[test]
+    fn key_value_test() {
+        let platform = platform("armv7-unknown-linux-gnueabihf");
+        for cfg in &[
+            r#"cfg(target_arch = "arm")"#,
+            r#"cfg(target_os = "linux")"#,
+            r#"cfg(target_env = "gnu")"#,
+            r#"cfg(target_abi = "eabihf")"#,
+            r#"cfg(target_endian = "little")"#,
+            r#"cfg(target_pointer_width = "32")"#,
+            r#"cfg(target_vendor = "unknown")"#,
+            r#"cfg(target_family = "unix")"#,
+            r#"cfg(target_feature = "v7")"#,
+        ] {
+            assert_eq!(platform.matches_cfg(cfg), Ok(true), "{}", cfg);
+        }
+
+        assert_eq!(
+            platform.matches_cfg(r#"cfg(target_os = "macos")"#),
+            Ok(false)
+        );
+        assert_eq!(platform.matches_cfg(r#"cfg(feature = "std")"#), Ok(false));
+    }
+
+    #[test]
+    fn combinator_test() {
+        let platform = platform("aarch64-apple-darwin");
+        let matches = |cfg| platform.matches_cfg(cfg).unwrap();
+
+        assert!(matches(r#"cfg(all(unix, target_arch = "aarch64"))"#));
+        assert!(!matches(r#"cfg(all(unix, target_arch = "x86_64"))"#));
+        assert!(matches(r#"cfg(any(windows, target_vendor = "apple"))"#));
+        assert!(!matches(r#"cfg(any(windows, target_os = "linux"))"#));
+        assert!(matches(r#"cfg(not(windows))"#));
+        assert!(matches(r#"cfg(all())"#));
+        assert!(!matches(r#"cfg(any())"#));
+        assert!(matches(
+            r#"cfg( all ( not(target_env = "msvc") , any(target_os = "macos", target_os = "ios",), ) )"#
+        ));
+    }
+
+    #[test]
+    fn target_triple_test() {
+        let platform = platform("x86_64-unknown-linux-musl");
+        assert_eq!(platform.matches_cfg("x86_64-unknown-linux-musl"), Ok(true));
+        assert_eq!(platform.matches_cfg("x86_64-unknown-linux-gnu"), Ok(false));
+    }
+
+    #[test]
+    fn invalid_test() {
+        let platform = platform("x86_64-unknown-linux-gnu");
+        for cfg in &[
+            "cfg()",
+            "cfg(unix",
+            "cfg(unix))",
+            "cfg(unix, windows)",
+            "cfg(not(unix, windows))",
+            "cfg(foo(unix))",
+            "cfg(target_os =)",
+            "cfg(target_os = linux)",
+            r#"cfg(target_os = "linux)"#,
+            "cfg(all(unix windows))",
+            "cfg(1)",
+        ] {
+            assert!(platform.matches_cfg(cfg).is_err(), "{}", cfg);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Runtime detection of the host platform
+
+use crate::{
+    platform::Platform,
+    target::{Arch, Endian, Env, OS},
+};
+use core::str::FromStr;
+use std::env;
+#[cfg(unix)]
+use std::string::String;
+
+impl Platform {
+    /// Detect the platform of the host this program is running on.
+    ///
+    /// Unlike `cfg` attributes, which describe the target this program was
+    /// compiled for, the architecture is queried from the operating system at
+    /// runtime, so e.g. an `x86_64` binary running on an `aarch64` Mac under
+    /// Rosetta, or a 32-bit binary running on a 64-bit OS, reports the actual
+    /// host architecture.
+    ///
+    /// The target triple isn't fully determined by the architecture and OS,
+    /// so when several platforms match, the one with the same `target_env` as
+    /// this program is preferred, and then the one with the highest tier.
+    ///
+    /// Returns `None` if no known platform matches the detected host.
+    pub fn current() -> Option<&'static Platform> {
+        let os = OS::from_str(env::consts::OS).ok()?;
+        let compiled_arch = Arch::from_str(env::consts::ARCH).ok()?;
+        let (arch, endian) = match host_arch() {
+            Some((arch, endian)) => (arch, endian),
+            None => (compiled_arch, None),
+        };
+        let endian = endian.unwrap_or_else(compiled_endian);
+        let env = compiled_env();
+
+        Self::ALL
+            .iter()
+            .filter(|platform| platform.target_arch == arch && platform.target_os == os)
+            .min_by_key(|platform| {
+                (
+                    platform.target_env != env,
+                    platform.target_endian != endian,
+                    platform.tier,
+                )
+            })
+    }
+}
+
+/// Detect the architecture (and endianness, when the OS reports it) of the
+/// host at runtime
+#[cfg(unix)]
+fn host_arch() -> Option<(Arch, Option<Endian>)> {
+    let machine = command_output("uname", &["-m"])?;
+
+    let result = match machine.as_str() {
+        "x86_64" | "amd64" => (Arch::X86_64, None),
+        "i386" | "i486" | "i586" | "i686" => (Arch::X86, None),
+        "aarch64" | "arm64" => (Arch::AArch64, Some(Endian::Little)),
+        "aarch64_be" => (Arch::AArch64, Some(Endian::Big)),
+        "ppc64le" => (Arch::PowerPc64, Some(Endian::Little)),
+        "ppc64" => (Arch::PowerPc64, Some(Endian::Big)),
+        "ppc" | "powerpc" => (Arch::PowerPc, None),
+        "riscv64" => (Arch::Riscv64, None),
+        "s390x" => (Arch::S390X, None),
+        "loongarch64" => (Arch::Loongarch64, None),
+        "mips64" => (Arch::Mips64, None),
+        "mips" => (Arch::Mips, None),
+        "sparc64" => (Arch::Sparc64, None),
+        "m68k" => (Arch::M68k, None),
+        other if other.starts_with("armv") || other == "arm" => (Arch::Arm, None),
+        _ => return None,
+    };
+
+    // Processes translated by Rosetta see the emulated architecture
+    if cfg!(target_os = "macos") && result.0 == Arch::X86_64 {
+        let translated = command_output("sysctl", &["-in", "sysctl.proc_translated"]);
+        if translated.as_deref() == Some("1") {
+            return Some((Arch::AArch64, Some(Endian::Little)));
+        }
+    }
+
+    Some(result)
+}
+
+/// Detect the architecture of the host at runtime
+#[cfg(windows)]
+fn host_arch() -> Option<(Arch, Option<Endian>)> {
+    // Set instead of `PROCESSOR_ARCHITECTURE` for 32-bit processes on 64-bit Windows
+    let arch = env::var("PROCESSOR_ARCHITEW6432")
+        .or_else(|_| env::var("PROCESSOR_ARCHITECTURE"))
+        .ok()?;
+
+    match arch.as_str() {
+        "AMD64" => Some((Arch::X86_64, None)),
+        "ARM64" => Some((Arch::AArch64, None)),
+        "x86" => Some((Arch::X86, None)),
+        _ => None,
+    }
+}
+
+/// Detect the architecture of the host at runtime
+#[cfg(not(any(unix, windows)))]
+fn host_arch() -> Option<(Arch, Option<Endian>)> {
+    None
+}
+
+/// Run a command, returning its trimmed output if it succeeded
+#[cfg(unix)]
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|stdout| stdout.trim().into())
+}
+
+/// Endianness this program was compiled for
+fn compiled_endian() -> Endian {
+    if cfg!(target_endian = "big") {
+        Endian::Big
+    } else {
+        Endian::Little
+    }
+}
+
+/// Environment this program was compiled for
+fn compiled_env() -> Env {
+    if cfg!(target_env = "gnu") {
+        Env::Gnu
+    } else if cfg!(target_env = "musl") {
+        Env::Musl
+    } else if cfg!(target_env = "msvc") {
+        Env::Msvc
+    } else if cfg!(target_env = "uclibc") {
+        Env::UClibc
+    } else if cfg!(target_env = "ohos") {
+        Env::OhOS
+    } else if cfg!(target_env = "sgx") {
+        Env::Sgx
+    } else if cfg!(target_env = "newlib") {
+        Env::Newlib
+    } else {
+        Env::None
+    }
+}
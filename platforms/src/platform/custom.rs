@@ -0,0 +1,199 @@
+//! Custom target specifications
+
+use crate::{
+    error::Error,
+    platform::Platform,
+    target::{Abi, Arch, Endian, Env, PointerWidth, OS},
+};
+use core::str::FromStr;
+use serde_json::{Map, Value};
+use std::{fs, path::Path, string::String};
+
+/// Platform described by a rustc [custom target specification] JSON file,
+/// for out-of-tree targets such as the ones used in embedded and OS
+/// development.
+///
+/// It has the same `cfg` attributes as [`Platform`], but as it isn't
+/// supported by mainline rustc, it doesn't have a tier.
+///
+/// [custom target specification]: https://doc.rust-lang.org/rustc/targets/custom.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct CustomPlatform {
+    /// Name of the target, which rustc takes from the name of the JSON file
+    /// (without the `.json` extension)
+    pub target_triple: String,
+
+    /// Target architecture `cfg` attribute (i.e. `cfg(target_arch)`)
+    pub target_arch: Arch,
+
+    /// Target OS `cfg` attribute (i.e. `cfg(target_os)`).
+    pub target_os: OS,
+
+    /// Target environment `cfg` attribute (i.e. `cfg(target_env)`).
+    pub target_env: Env,
+
+    /// Target ABI `cfg` attribute (i.e. `cfg(target_abi)`).
+    pub target_abi: Abi,
+
+    /// Target pointer width `cfg` attribute, in bits (i.e. `cfg(target_pointer_width)`).
+    pub target_pointer_width: PointerWidth,
+
+    /// Target [endianness](https://en.wikipedia.org/wiki/Endianness) `cfg` attribute (i.e. `cfg(target_endian)`).
+    pub target_endian: Endian,
+}
+
+impl CustomPlatform {
+    /// Parse the custom target specification JSON for the target with the
+    /// given name.
+    ///
+    /// Fields which are optional in the specification default to the same
+    /// values as in rustc: `os` to `none`, `env` and `abi` to the empty
+    /// string and `target-endian` to `little`.
+    pub fn from_json(target_triple: &str, json: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(json).map_err(|_| Error)?;
+        let spec = value.as_object().ok_or(Error)?;
+
+        Ok(CustomPlatform {
+            target_triple: target_triple.into(),
+            target_arch: Arch::from_str(field(spec, "arch")?.ok_or(Error)?)?,
+            target_os: OS::from_str(field(spec, "os")?.unwrap_or("none"))?,
+            target_env: Env::from_str(field(spec, "env")?.unwrap_or(""))?,
+            target_abi: Abi::from_str(field(spec, "abi")?.unwrap_or(""))?,
+            target_pointer_width: pointer_width(spec)?,
+            target_endian: Endian::from_str(field(spec, "target-endian")?.unwrap_or("little"))?,
+        })
+    }
+
+    /// Read a custom target specification JSON file, naming the target
+    /// after the file like rustc does
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let target_triple = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or(Error)?;
+        let json = fs::read_to_string(path).map_err(|_| Error)?;
+        Self::from_json(target_triple, &json)
+    }
+
+    /// Find the mainline Rust platforms with the same `cfg` attributes as
+    /// this one, e.g. to reuse logic written for them
+    pub fn similar_platforms(&self) -> impl Iterator<Item = &'static Platform> {
+        Platform::find_by_components(self.target_arch, self.target_os, self.target_env)
+            .filter(Platform::by_abi(self.target_abi))
+            .filter(Platform::by_pointer_width(self.target_pointer_width))
+            .filter(Platform::by_endian(self.target_endian))
+    }
+}
+
+impl From<&Platform> for CustomPlatform {
+    fn from(platform: &Platform) -> Self {
+        CustomPlatform {
+            target_triple: platform.target_triple.into(),
+            target_arch: platform.target_arch,
+            target_os: platform.target_os,
+            target_env: platform.target_env,
+            target_abi: platform.target_abi,
+            target_pointer_width: platform.target_pointer_width,
+            target_endian: platform.target_endian,
+        }
+    }
+}
+
+/// Get an optional string field of a target specification
+fn field<'a>(spec: &'a Map<String, Value>, name: &str) -> Result<Option<&'a str>, Error> {
+    match spec.get(name) {
+        Some(value) => value.as_str().map(Some).ok_or(Error),
+        None => Ok(None),
+    }
+}
+
+/// Get the pointer width of a target specification, which older versions of
+/// rustc expect as a string and newer ones as an integer
+fn pointer_width(spec: &Map<String, Value>) -> Result<PointerWidth, Error> {
+    match spec.get("target-pointer-width").ok_or(Error)? {
+        Value::String(width) => PointerWidth::from_str(width),
+        Value::Number(width) => match width.as_u64() {
+            Some(16) => Ok(PointerWidth::U16),
+            Some(32) => Ok(PointerWidth::U32),
+            Some(64) => Ok(PointerWidth::U64),
+            _ => Err(Error),
+        },
+        _ => Err(Error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    const X86_64_BLOG_OS: &str = r#"{
+        "llvm-target": "x86_64-unknown-none",
+        "data-layout": "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128",
+        "arch": "x86_64",
+        "target-endian": "little",
+        "target-pointer-width": "64",
+        "target-c-int-width": "32",
+        "os": "none",
+        "executables": true,
+        "linker-flavor": "ld.lld",
+        "linker": "rust-lld",
+        "panic-strategy": "abort",
+        "disable-redzone": true,
+        "features": "-mmx,-sse,+soft-float"
+    }"#;
+
+    #[test]
+    fn from_json_test() {
+        let platform = CustomPlatform::from_json("x86_64-blog_os", X86_64_BLOG_OS).unwrap();
+
+        assert_eq!(platform.target_triple, "x86_64-blog_os");
+        assert_eq!(platform.target_arch, Arch::X86_64);
+        assert_eq!(platform.target_os, OS::None);
+        assert_eq!(platform.target_env, Env::None);
+        assert_eq!(platform.target_abi, Abi::None);
+        assert_eq!(platform.target_pointer_width, PointerWidth::U64);
+        assert_eq!(platform.target_endian, Endian::Little);
+    }
+
+    #[test]
+    fn defaults_test() {
+        let json = r#"{ "arch": "riscv32", "target-pointer-width": 32 }"#;
+        let platform = CustomPlatform::from_json("riscv32-custom", json).unwrap();
+
+        assert_eq!(platform.target_arch, Arch::Riscv32);
+        assert_eq!(platform.target_os, OS::None);
+        assert_eq!(platform.target_env, Env::None);
+        assert_eq!(platform.target_pointer_width, PointerWidth::U32);
+        assert_eq!(platform.target_endian, Endian::Little);
+    }
+
+    #[test]
+    fn invalid_test() {
+        // missing `arch`
+        assert!(
+            CustomPlatform::from_json("custom", r#"{ "target-pointer-width": "64" }"#).is_err()
+        );
+        // unsupported pointer width
+        assert!(CustomPlatform::from_json(
+            "custom",
+            r#"{ "arch": "x86_64", "target-pointer-width": "128" }"#
+        )
+        .is_err());
+        // not an object
+        assert!(CustomPlatform::from_json("custom", "[]").is_err());
+    }
+
+    #[test]
+    fn similar_platforms_test() {
+        let platform = CustomPlatform::from_json("x86_64-blog_os", X86_64_BLOG_OS).unwrap();
+        let similar = platform
+            .similar_platforms()
+            .map(|platform| platform.target_triple)
+            .collect::<Vec<_>>();
+
+        assert!(similar.contains(&"x86_64-unknown-none"));
+    }
+}
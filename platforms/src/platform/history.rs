@@ -0,0 +1,219 @@
+//! Tier history of Rust platforms
+
+mod data;
+
+use crate::{
+    error::Error,
+    platform::{Platform, Tier},
+};
+use core::{fmt, str::FromStr};
+
+/// Rust release version, e.g. `1.70.0`
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RustVersion {
+    /// Major version
+    pub major: u16,
+
+    /// Minor version
+    pub minor: u16,
+
+    /// Patch version
+    pub patch: u16,
+}
+
+impl RustVersion {
+    /// Create a new `RustVersion`
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        RustVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for RustVersion {
+    type Err = Error;
+
+    /// Parse a version like `1.70.0`, or `1.70` for the first release of a
+    /// minor version
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s
+            .split('.')
+            .map(|part| part.parse::<u16>().map_err(|_| Error));
+        let major = parts.next().ok_or(Error)??;
+        let minor = parts.next().ok_or(Error)??;
+        let patch = parts.next().unwrap_or(Ok(0))?;
+
+        if parts.next().is_some() {
+            return Err(Error);
+        }
+
+        Ok(RustVersion::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for RustVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Tier a platform was in as of a given Rust release
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct TierChange {
+    /// Rust release in which the platform was added or moved to `tier`
+    pub version: RustVersion,
+
+    /// Tier of the platform from this release on
+    pub tier: Tier,
+}
+
+/// History of a Rust platform: the release it was added in, and the releases
+/// in which it moved between tiers.
+///
+/// This is sourced from the platform support documentation of past Rust
+/// releases, starting with the [`History::EARLIEST`] one.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct History {
+    changes: &'static [TierChange],
+}
+
+impl History {
+    /// Earliest Rust release the history was sourced from, if any
+    pub const EARLIEST: Option<RustVersion> = data::EARLIEST;
+
+    /// Tier changes of the platform, in release order. The first one is the
+    /// release the platform was added in (or [`History::EARLIEST`] if it
+    /// already existed then).
+    ///
+    /// Empty if there is no recorded history for the platform.
+    pub fn changes(&self) -> &'static [TierChange] {
+        self.changes
+    }
+
+    /// Rust release in which the platform was added.
+    ///
+    /// Returns `None` if it is unknown, because the platform already existed
+    /// in the [`History::EARLIEST`] release or there is no recorded history.
+    pub fn added_in(&self) -> Option<RustVersion> {
+        self.changes
+            .first()
+            .map(|change| change.version)
+            .filter(|&version| Some(version) != Self::EARLIEST)
+    }
+
+    /// Tier of the platform in the given Rust release.
+    ///
+    /// Returns `None` if the platform wasn't supported in that release yet, or
+    /// if the release predates the recorded history.
+    pub fn tier_at(&self, version: RustVersion) -> Option<Tier> {
+        self.changes
+            .iter()
+            .take_while(|change| change.version <= version)
+            .last()
+            .map(|change| change.tier)
+    }
+}
+
+impl Platform {
+    /// Get the tier history of this platform, e.g. to find out whether it
+    /// was tier 2 before Rust 1.70:
+    ///
+    /// ```
+    /// use platforms::{Platform, RustVersion, Tier};
+    ///
+    /// let platform = Platform::find("aarch64-apple-darwin").unwrap();
+    /// let was_tier2 = platform.history().tier_at(RustVersion::new(1, 69, 0)) == Some(Tier::Two);
+    /// ```
+    pub fn history(&self) -> History {
+        let changes = data::HISTORY
+            .binary_search_by_key(&self.target_triple, |&(triple, _)| triple)
+            .map(|index| data::HISTORY[index].1)
+            .unwrap_or(&[]);
+
+        History { changes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGES: &[TierChange] = &[
+        TierChange {
+            version: RustVersion::new(1, 49, 0),
+            tier: Tier::Three,
+        },
+        TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        },
+        TierChange {
+            version: RustVersion::new(1, 70, 0),
+            tier: Tier::One,
+        },
+    ];
+
+    #[test]
+    fn version_test() {
+        assert_eq!("1.70.0".parse(), Ok(RustVersion::new(1, 70, 0)));
+        assert_eq!("1.70".parse(), Ok(RustVersion::new(1, 70, 0)));
+        assert_eq!("1.70.1".parse(), Ok(RustVersion::new(1, 70, 1)));
+        assert!("1".parse::<RustVersion>().is_err());
+        assert!("1.70.0.0".parse::<RustVersion>().is_err());
+        assert!("1.70.0-nightly".parse::<RustVersion>().is_err());
+        assert!(RustVersion::new(1, 9, 0) < RustVersion::new(1, 10, 0));
+    }
+
+    #[test]
+    fn tier_at_test() {
+        let history = History { changes: CHANGES };
+
+        assert_eq!(history.tier_at(RustVersion::new(1, 48, 0)), None);
+        assert_eq!(
+            history.tier_at(RustVersion::new(1, 49, 0)),
+            Some(Tier::Three)
+        );
+        assert_eq!(history.tier_at(RustVersion::new(1, 69, 0)), Some(Tier::Two));
+        assert_eq!(history.tier_at(RustVersion::new(1, 70, 0)), Some(Tier::One));
+        assert_eq!(history.tier_at(RustVersion::new(1, 80, 0)), Some(Tier::One));
+    }
+
+    #[test]
+    fn platform_history_test() {
+        let darwin = Platform::find("aarch64-apple-darwin").unwrap().history();
+        assert_eq!(darwin.added_in(), None);
+        assert_eq!(darwin.tier_at(RustVersion::new(1, 81, 0)), Some(Tier::Two));
+        assert_eq!(darwin.tier_at(RustVersion::new(1, 82, 0)), Some(Tier::One));
+
+        let none = Platform::find("x86_64-unknown-linux-none")
+            .unwrap()
+            .history();
+        assert_eq!(none.added_in(), Some(RustVersion::new(1, 80, 0)));
+        assert_eq!(none.tier_at(RustVersion::new(1, 79, 0)), None);
+        assert_eq!(none.tier_at(RustVersion::new(1, 80, 0)), Some(Tier::Three));
+    }
+
+    #[test]
+    fn history_sorted_test() {
+        assert!(data::HISTORY.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        for (triple, changes) in data::HISTORY {
+            assert!(
+                changes
+                    .windows(2)
+                    .all(|pair| pair[0].version < pair[1].version),
+                "unsorted history for {}",
+                triple
+            );
+        }
+    }
+
+    #[test]
+    fn missing_history_test() {
+        let history = History { changes: &[] };
+        assert_eq!(history.added_in(), None);
+        assert_eq!(history.tier_at(RustVersion::new(1, 70, 0)), None);
+    }
+}
@@ -0,0 +1,2554 @@
+//! Tier history of targets.
+
+// Note: this file is auto-generated. Do not edit it manually!
+// If you need to referesh it, re-run the generator included in the source tree
+// with the `history` subcommand.
+
+#![allow(missing_docs)]
+
+#[allow(unused_imports)]
+use crate::platform::{
+    history::{RustVersion, TierChange},
+    Tier,
+};
+
+/// Earliest Rust release the history was generated from
+pub(crate) const EARLIEST: Option<RustVersion> = Some(RustVersion::new(1, 54, 0));
+
+/// Tier history of every target, sorted by target triple
+pub(crate) const HISTORY: &[(&str, &[TierChange])] = &[
+    (
+        "aarch64-apple-darwin",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 82, 0),
+                tier: Tier::One,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-ios",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-apple-ios-macabi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 82, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-ios-sim",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 56, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-tvos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-tvos-sim",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-visionos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 79, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-visionos-sim",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 79, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-watchos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 76, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-apple-watchos-sim",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 63, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-kmc-solid_asp3",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-linux-android",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-nintendo-switch-freestanding",
+        &[TierChange {
+            version: RustVersion::new(1, 64, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-pc-windows-gnullvm",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 62, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 79, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-pc-windows-msvc",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 91, 0),
+                tier: Tier::One,
+            },
+        ],
+    ),
+    (
+        "aarch64-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-fuchsia",
+        &[TierChange {
+            version: RustVersion::new(1, 68, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-unknown-helenos",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-hermit",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-illumos",
+        &[TierChange {
+            version: RustVersion::new(1, 77, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "aarch64-unknown-linux-gnu_ilp32",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-unknown-linux-ohos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 70, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-unknown-managarm-mlibc",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-unknown-none-softfloat",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "aarch64-unknown-nto-qnx700",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-nto-qnx710",
+        &[TierChange {
+            version: RustVersion::new(1, 67, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-nto-qnx710_iosock",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-nto-qnx800",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-nuttx",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-redox",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-teeos",
+        &[TierChange {
+            version: RustVersion::new(1, 73, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-trusty",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-unknown-uefi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 56, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 67, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "aarch64-uwp-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-hermit",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-linux-gnu_ilp32",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 72, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64_be-unknown-none-softfloat",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64v8r-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "aarch64v8r-unknown-none-softfloat",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "amdgcn-amd-amdhsa",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "arm-linux-androideabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "arm-unknown-linux-gnueabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "arm-unknown-linux-gnueabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "arm-unknown-linux-musleabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "arm-unknown-linux-musleabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "arm64_32-apple-watchos",
+        &[TierChange {
+            version: RustVersion::new(1, 63, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "arm64e-apple-darwin",
+        &[TierChange {
+            version: RustVersion::new(1, 76, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "arm64e-apple-ios",
+        &[TierChange {
+            version: RustVersion::new(1, 76, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "arm64e-apple-tvos",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "arm64ec-pc-windows-msvc",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 81, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "armeb-unknown-linux-gnueabi",
+        &[TierChange {
+            version: RustVersion::new(1, 65, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armebv7r-none-eabi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 92, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "armebv7r-none-eabihf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 92, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "armv4t-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 65, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv4t-unknown-linux-gnueabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv5te-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 66, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv5te-unknown-linux-gnueabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv5te-unknown-linux-musleabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv5te-unknown-linux-uclibceabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv6-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv6-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv6-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv6-unknown-netbsd-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv6k-nintendo-3ds",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-linux-androideabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7-rtems-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-sony-vita-newlibeabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 68, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-gnueabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-gnueabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-musleabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-musleabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-ohos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 70, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "armv7-unknown-linux-uclibceabi",
+        &[TierChange {
+            version: RustVersion::new(1, 60, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-unknown-linux-uclibceabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-unknown-netbsd-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-unknown-trusty",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7-wrs-vxworks-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7a-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7a-none-eabihf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 92, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "armv7a-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7a-nuttx-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7a-vex-v5",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7k-apple-watchos",
+        &[TierChange {
+            version: RustVersion::new(1, 63, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv7r-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7r-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "armv7s-apple-ios",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "armv8r-none-eabihf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 92, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "avr-none",
+        &[TierChange {
+            version: RustVersion::new(1, 87, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "bpfeb-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "bpfel-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "csky-unknown-linux-gnuabiv2",
+        &[TierChange {
+            version: RustVersion::new(1, 73, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "csky-unknown-linux-gnuabiv2hf",
+        &[TierChange {
+            version: RustVersion::new(1, 75, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "hexagon-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "hexagon-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 77, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "hexagon-unknown-qurt",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i386-apple-ios",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i586-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "i586-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "i586-unknown-netbsd",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 77, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "i586-unknown-redox",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-apple-darwin",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-linux-android",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "i686-pc-nto-qnx700",
+        &[TierChange {
+            version: RustVersion::new(1, 87, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-pc-windows-gnu",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::One,
+            },
+            TierChange {
+                version: RustVersion::new(1, 88, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "i686-pc-windows-gnullvm",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 74, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 79, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "i686-pc-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "i686-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "i686-unknown-haiku",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-unknown-helenos",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-unknown-hurd-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 74, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "i686-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "i686-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-unknown-uefi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 67, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "i686-uwp-windows-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-uwp-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-win7-windows-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-win7-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 76, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "i686-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "loongarch32-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 89, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "loongarch32-unknown-none-softfloat",
+        &[TierChange {
+            version: RustVersion::new(1, 89, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "loongarch64-unknown-linux-gnu",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 70, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 71, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "loongarch64-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 81, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "loongarch64-unknown-linux-ohos",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "loongarch64-unknown-none",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 72, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 74, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "loongarch64-unknown-none-softfloat",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 72, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 74, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "m68k-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 57, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "m68k-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mips-mti-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mips-unknown-linux-gnu",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mips-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mips-unknown-linux-uclibc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mips64-openwrt-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 60, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mips64-unknown-linux-gnuabi64",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mips64-unknown-linux-muslabi64",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mips64el-unknown-linux-gnuabi64",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mips64el-unknown-linux-muslabi64",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mipsel-mti-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsel-sony-psp",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsel-sony-psx",
+        &[TierChange {
+            version: RustVersion::new(1, 67, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsel-unknown-linux-gnu",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mipsel-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 75, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "mipsel-unknown-linux-uclibc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsel-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 75, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsel-unknown-none",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsisa32r6-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsisa32r6el-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsisa64r6-unknown-linux-gnuabi64",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "mipsisa64r6el-unknown-linux-gnuabi64",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "msp430-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "nvptx64-nvidia-cuda",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "powerpc-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 56, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-helenos",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "powerpc-unknown-linux-gnuspe",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-linux-muslspe",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc-wrs-vxworks-spe",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64-ibm-aix",
+        &[TierChange {
+            version: RustVersion::new(1, 67, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "powerpc64-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "powerpc64-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 65, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64le-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 55, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "powerpc64le-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "powerpc64le-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 85, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "riscv32-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32e-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32em-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32emc-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32gc-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32gc-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32i-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv32im-risc0-zkvm-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 77, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32im-unknown-none-elf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 61, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 77, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "riscv32ima-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 79, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imac-esp-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 72, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imac-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv32imac-unknown-nuttx-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imac-unknown-xous-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 63, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imafc-esp-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 77, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imafc-unknown-none-elf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 76, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 77, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "riscv32imafc-unknown-nuttx-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imc-esp-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 56, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv32imc-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv32imc-unknown-nuttx-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64-linux-android",
+        &[TierChange {
+            version: RustVersion::new(1, 73, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64a23-unknown-linux-gnu",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 91, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 95, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "riscv64gc-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 59, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-fuchsia",
+        &[TierChange {
+            version: RustVersion::new(1, 70, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-hermit",
+        &[TierChange {
+            version: RustVersion::new(1, 73, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-linux-musl",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 82, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "riscv64gc-unknown-managarm-mlibc",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 72, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-nuttx-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 65, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64gc-unknown-redox",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "riscv64im-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 94, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv64imac-unknown-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "riscv64imac-unknown-nuttx-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "s390x-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "s390x-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "s390x-unknown-none-softfloat",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "sparc-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "sparc-unknown-none-elf",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 73, 0),
+                tier: Tier::Two,
+            },
+            TierChange {
+                version: RustVersion::new(1, 74, 0),
+                tier: Tier::Three,
+            },
+        ],
+    ),
+    (
+        "sparc64-unknown-helenos",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "sparc64-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "sparc64-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "sparc64-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "sparcv9-sun-solaris",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv4t-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv5te-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 66, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv6-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv6m-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv6m-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-nuttx-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-pc-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7a-uwp-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7em-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv7em-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv7em-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7em-nuttx-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7m-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv7m-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7neon-linux-androideabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv7neon-unknown-linux-gnueabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv7neon-unknown-linux-musleabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7r-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv7r-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv8m.base-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv8m.base-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv8m.main-none-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv8m.main-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "thumbv8m.main-nuttx-eabi",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv8m.main-nuttx-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 82, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "thumbv8r-none-eabihf",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "wasm32-unknown-emscripten",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "wasm32-unknown-unknown",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "wasm32-wali-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 87, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "wasm32-wasip1",
+        &[TierChange {
+            version: RustVersion::new(1, 78, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "wasm32-wasip1-threads",
+        &[TierChange {
+            version: RustVersion::new(1, 78, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "wasm32-wasip2",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 84, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "wasm32-wasip3",
+        &[TierChange {
+            version: RustVersion::new(1, 92, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "wasm32v1-none",
+        &[TierChange {
+            version: RustVersion::new(1, 84, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "wasm64-unknown-unknown",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-apple-darwin",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::One,
+            },
+            TierChange {
+                version: RustVersion::new(1, 90, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-apple-ios",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-apple-ios-macabi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 82, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-apple-tvos",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-apple-watchos-sim",
+        &[TierChange {
+            version: RustVersion::new(1, 63, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-fortanix-unknown-sgx",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-linux-android",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-lynx-lynxos178",
+        &[TierChange {
+            version: RustVersion::new(1, 88, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-pc-cygwin",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-pc-nto-qnx710",
+        &[TierChange {
+            version: RustVersion::new(1, 67, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-pc-nto-qnx710_iosock",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-pc-nto-qnx800",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-pc-solaris",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-pc-windows-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "x86_64-pc-windows-gnullvm",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 62, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 79, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-pc-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "x86_64-unikraft-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 73, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-dragonfly",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-freebsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-fuchsia",
+        &[TierChange {
+            version: RustVersion::new(1, 68, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-haiku",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-helenos",
+        &[TierChange {
+            version: RustVersion::new(1, 93, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-hermit",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-hurd-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-illumos",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-l4re-uclibc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::One,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-gnuasan",
+        &[TierChange {
+            version: RustVersion::new(1, 95, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-gnux32",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-musl",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-none",
+        &[TierChange {
+            version: RustVersion::new(1, 80, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-linux-ohos",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 73, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 78, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-unknown-managarm-mlibc",
+        &[TierChange {
+            version: RustVersion::new(1, 91, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-motor",
+        &[TierChange {
+            version: RustVersion::new(1, 92, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-netbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-none",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 58, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 62, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-unknown-openbsd",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-redox",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Two,
+        }],
+    ),
+    (
+        "x86_64-unknown-trusty",
+        &[TierChange {
+            version: RustVersion::new(1, 83, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-unknown-uefi",
+        &[
+            TierChange {
+                version: RustVersion::new(1, 54, 0),
+                tier: Tier::Three,
+            },
+            TierChange {
+                version: RustVersion::new(1, 67, 0),
+                tier: Tier::Two,
+            },
+        ],
+    ),
+    (
+        "x86_64-uwp-windows-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-uwp-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-win7-windows-gnu",
+        &[TierChange {
+            version: RustVersion::new(1, 86, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-win7-windows-msvc",
+        &[TierChange {
+            version: RustVersion::new(1, 76, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64-wrs-vxworks",
+        &[TierChange {
+            version: RustVersion::new(1, 54, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "x86_64h-apple-darwin",
+        &[TierChange {
+            version: RustVersion::new(1, 71, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32s2-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32s2-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32s3-espidf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+    (
+        "xtensa-esp32s3-none-elf",
+        &[TierChange {
+            version: RustVersion::new(1, 81, 0),
+            tier: Tier::Three,
+        }],
+    ),
+];
@@ -2,6 +2,7 @@
 
 // Note: this file is auto-generated. Do not edit it manually!
 // If you need to referesh it, re-run the generator included in the source tree.
+// Generated from: rustc 1.95.0 (59807616e 2026-04-14)
 
 // Comments on targets are sourced from
 // https://doc.rust-lang.org/nightly/rustc/platform-support.html
@@ -10,7 +11,7 @@
 
 use crate::{
     platform::{Platform, Tier},
-    target::{Arch, Endian, Env, PointerWidth, OS},
+    target::{Abi, Arch, Endian, Env, PointerWidth, OS},
 };
 
 /// The list of all targets recognized by the Rust compiler
@@ -192,7 +193,6 @@ pub(crate) const ALL: &[Platform] = &[
     POWERPC64_IBM_AIX,
     POWERPC64_UNKNOWN_FREEBSD,
     POWERPC64_UNKNOWN_LINUX_GNU,
-    POWERPC64_UNKNOWN_LINUX_GNUELFV2,
     POWERPC64_UNKNOWN_LINUX_MUSL,
     POWERPC64_UNKNOWN_OPENBSD,
     POWERPC64_WRS_VXWORKS,
@@ -312,8 +312,6 @@ pub(crate) const ALL: &[Platform] = &[
     X86_64_UNKNOWN_L4RE_UCLIBC,
     X86_64_UNKNOWN_LINUX_GNU,
     X86_64_UNKNOWN_LINUX_GNUASAN,
-    X86_64_UNKNOWN_LINUX_GNUMSAN,
-    X86_64_UNKNOWN_LINUX_GNUTSAN,
     X86_64_UNKNOWN_LINUX_GNUX32,
     X86_64_UNKNOWN_LINUX_MUSL,
     X86_64_UNKNOWN_LINUX_NONE,
@@ -346,9 +344,18 @@ pub(crate) const AARCH64_APPLE_DARWIN: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::MacOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dit", "dotprod", "dpb", "dpb2", "fcma", "fhm", "flagm", "flagm2", "fp16",
+        "frintts", "jsconv", "lor", "lse", "lse2", "neon", "paca", "pacg", "pan", "pmuv3", "ras",
+        "rcpc", "rcpc2", "rdm", "sb", "sha2", "sha3", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+        "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64 iOS
@@ -357,9 +364,13 @@ pub(crate) const AARCH64_APPLE_IOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::iOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["aes", "neon", "pmuv3", "sha2"],
+    target_vendor: "apple",
 };
 
 /// Mac Catalyst on ARM64
@@ -368,9 +379,16 @@ pub(crate) const AARCH64_APPLE_IOS_MACABI: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::iOS,
     target_env: Env::Macabi,
+    target_abi: Abi::Macabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// Apple iOS Simulator on ARM64
@@ -379,9 +397,16 @@ pub(crate) const AARCH64_APPLE_IOS_SIM: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::iOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64 tvOS
@@ -390,9 +415,13 @@ pub(crate) const AARCH64_APPLE_TVOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::TvOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["aes", "neon", "pmuv3", "sha2"],
+    target_vendor: "apple",
 };
 
 /// ARM64 tvOS Simulator
@@ -401,9 +430,16 @@ pub(crate) const AARCH64_APPLE_TVOS_SIM: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::TvOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64 Apple visionOS
@@ -412,9 +448,13 @@ pub(crate) const AARCH64_APPLE_VISIONOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::VisionOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["aes", "neon", "pmuv3", "sha2"],
+    target_vendor: "apple",
 };
 
 /// ARM64 Apple visionOS Simulator
@@ -423,9 +463,16 @@ pub(crate) const AARCH64_APPLE_VISIONOS_SIM: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::VisionOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64 Apple WatchOS
@@ -434,9 +481,13 @@ pub(crate) const AARCH64_APPLE_WATCHOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::WatchOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["aes", "neon", "pmuv3", "sha2"],
+    target_vendor: "apple",
 };
 
 /// ARM64 Apple WatchOS Simulator
@@ -445,9 +496,16 @@ pub(crate) const AARCH64_APPLE_WATCHOS_SIM: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::WatchOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64 SOLID with TOPPERS/ASP3
@@ -456,9 +514,13 @@ pub(crate) const AARCH64_KMC_SOLID_ASP3: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::SolidAsp3,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "kmc",
 };
 
 /// ARM64 Android
@@ -467,9 +529,13 @@ pub(crate) const AARCH64_LINUX_ANDROID: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Nintendo Switch, Horizon
@@ -478,9 +544,13 @@ pub(crate) const AARCH64_NINTENDO_SWITCH_FREESTANDING: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Horizon,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["aes", "crc", "neon", "sha2"],
+    target_vendor: "nintendo",
 };
 
 /// ARM64 MinGW (Windows 10+), LLVM ABI
@@ -489,9 +559,13 @@ pub(crate) const AARCH64_PC_WINDOWS_GNULLVM: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::Llvm,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["windows"],
+    target_features: &["neon"],
+    target_vendor: "pc",
 };
 
 /// ARM64 Windows MSVC
@@ -500,9 +574,13 @@ pub(crate) const AARCH64_PC_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["windows"],
+    target_features: &["neon"],
+    target_vendor: "pc",
 };
 
 /// ARM64 FreeBSD
@@ -511,9 +589,13 @@ pub(crate) const AARCH64_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Fuchsia
@@ -522,9 +604,13 @@ pub(crate) const AARCH64_UNKNOWN_FUCHSIA: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Fuchsia,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["aes", "crc", "neon", "sha2"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 HelenOS
@@ -533,9 +619,13 @@ pub(crate) const AARCH64_UNKNOWN_HELENOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::HelenOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Hermit
@@ -544,9 +634,13 @@ pub(crate) const AARCH64_UNKNOWN_HERMIT: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Hermit,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 illumos
@@ -555,9 +649,13 @@ pub(crate) const AARCH64_UNKNOWN_ILLUMOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::IllumOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux (kernel 4.1+, glibc 2.17+)
@@ -566,9 +664,13 @@ pub(crate) const AARCH64_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["unix"],
+    target_features: &["neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux (ILP32 ABI)
@@ -577,9 +679,13 @@ pub(crate) const AARCH64_UNKNOWN_LINUX_GNU_ILP32: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Ilp32,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux with musl 1.2.5
@@ -588,9 +694,13 @@ pub(crate) const AARCH64_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 OpenHarmony
@@ -599,9 +709,13 @@ pub(crate) const AARCH64_UNKNOWN_LINUX_OHOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::OhOS,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Managarm
@@ -610,9 +724,13 @@ pub(crate) const AARCH64_UNKNOWN_MANAGARM_MLIBC: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Managarm,
     target_env: Env::Mlibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 NetBSD
@@ -621,9 +739,13 @@ pub(crate) const AARCH64_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// Bare ARM64, hardfloat
@@ -632,9 +754,13 @@ pub(crate) const AARCH64_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// Bare ARM64, softfloat
@@ -643,9 +769,13 @@ pub(crate) const AARCH64_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// ARM64 QNX Neutrino 7.0 RTOS
@@ -654,9 +784,13 @@ pub(crate) const AARCH64_UNKNOWN_NTO_QNX700: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Nto,
     target_env: Env::Nto70,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 QNX Neutrino 7.1 RTOS with default network stack (io-pkt)
@@ -665,9 +799,13 @@ pub(crate) const AARCH64_UNKNOWN_NTO_QNX710: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Nto,
     target_env: Env::Nto71,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 QNX Neutrino 7.1 RTOS with new network stack (io-sock)
@@ -676,9 +814,13 @@ pub(crate) const AARCH64_UNKNOWN_NTO_QNX710_IOSOCK: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Nto,
     target_env: Env::Nto71Iosock,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 QNX Neutrino 8.0 RTOS
@@ -687,9 +829,13 @@ pub(crate) const AARCH64_UNKNOWN_NTO_QNX800: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Nto,
     target_env: Env::Nto80,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 with NuttX
@@ -698,9 +844,13 @@ pub(crate) const AARCH64_UNKNOWN_NUTTX: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 OpenBSD
@@ -709,9 +859,13 @@ pub(crate) const AARCH64_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Redox OS
@@ -720,9 +874,13 @@ pub(crate) const AARCH64_UNKNOWN_REDOX: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Redox,
     target_env: Env::Relibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 TEEOS
@@ -731,9 +889,13 @@ pub(crate) const AARCH64_UNKNOWN_TEEOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::TeeOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const AARCH64_UNKNOWN_TRUSTY: Platform = Platform {
@@ -741,9 +903,13 @@ pub(crate) const AARCH64_UNKNOWN_TRUSTY: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Trusty,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["crt-static", "neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 UEFI
@@ -752,9 +918,13 @@ pub(crate) const AARCH64_UNKNOWN_UEFI: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Uefi,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const AARCH64_UWP_WINDOWS_MSVC: Platform = Platform {
@@ -762,9 +932,13 @@ pub(crate) const AARCH64_UWP_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::Uwp,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["neon"],
+    target_vendor: "uwp",
 };
 
 /// ARM64 VxWorks OS
@@ -773,9 +947,13 @@ pub(crate) const AARCH64_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "neon"],
+    target_vendor: "wrs",
 };
 
 /// ARM64 Hermit (big-endian)
@@ -784,9 +962,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_HERMIT: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Hermit,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux (big-endian)
@@ -795,9 +977,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux (big-endian, ILP32 ABI)
@@ -806,9 +992,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_LINUX_GNU_ILP32: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Ilp32,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 Linux (big-endian) with musl-libc 1.2.5
@@ -817,9 +1007,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon", "outline-atomics"],
+    target_vendor: "unknown",
 };
 
 /// ARM64 NetBSD (big-endian)
@@ -828,9 +1022,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["neon"],
+    target_vendor: "unknown",
 };
 
 /// Bare big-endian ARM64, softfloat
@@ -839,9 +1037,13 @@ pub(crate) const AARCH64_BE_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv8-R in AArch64 mode, hardfloat
@@ -850,9 +1052,15 @@ pub(crate) const AARCH64V8R_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "crc", "dit", "dpb", "flagm", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc", "rcpc2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv8-R in AArch64 mode, softfloat
@@ -861,9 +1069,15 @@ pub(crate) const AARCH64V8R_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "crc", "dit", "dpb", "flagm", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rcpc2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// `-Ctarget-cpu=gfx...` to specify [the AMD GPU](https://llvm.org/docs/AMDGPUUsage.html#processors) to compile for
@@ -872,9 +1086,13 @@ pub(crate) const AMDGCN_AMD_AMDHSA: Platform = Platform {
     target_arch: Arch::Amdgpu,
     target_os: OS::Amdhsa,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "amd",
 };
 
 /// Armv6 Android
@@ -883,9 +1101,13 @@ pub(crate) const ARM_LINUX_ANDROIDEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["v5te"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 Linux (kernel 3.2+, glibc 2.17)
@@ -894,9 +1116,13 @@ pub(crate) const ARM_UNKNOWN_LINUX_GNUEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 Linux, hardfloat (kernel 3.2+, glibc 2.17)
@@ -905,9 +1131,13 @@ pub(crate) const ARM_UNKNOWN_LINUX_GNUEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fpregs", "v5te", "v6", "vfp2"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 Linux with musl 1.2.5
@@ -916,9 +1146,13 @@ pub(crate) const ARM_UNKNOWN_LINUX_MUSLEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 Linux with musl 1.2.5, hardfloat
@@ -927,9 +1161,13 @@ pub(crate) const ARM_UNKNOWN_LINUX_MUSLEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fpregs", "v5te", "v6", "vfp2"],
+    target_vendor: "unknown",
 };
 
 /// Arm Apple WatchOS 64-bit with 32-bit pointers
@@ -938,9 +1176,16 @@ pub(crate) const ARM64_32_APPLE_WATCHOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::WatchOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64e Apple Darwin
@@ -949,9 +1194,18 @@ pub(crate) const ARM64E_APPLE_DARWIN: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::MacOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dit", "dotprod", "dpb", "dpb2", "fcma", "fhm", "flagm", "flagm2", "fp16",
+        "frintts", "jsconv", "lor", "lse", "lse2", "neon", "paca", "pacg", "pan", "pmuv3", "ras",
+        "rcpc", "rcpc2", "rdm", "sb", "sha2", "sha3", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+        "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64e Apple iOS
@@ -960,9 +1214,16 @@ pub(crate) const ARM64E_APPLE_IOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::iOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// ARM64e Apple tvOS
@@ -971,9 +1232,16 @@ pub(crate) const ARM64E_APPLE_TVOS: Platform = Platform {
     target_arch: Arch::AArch64,
     target_os: OS::TvOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan",
+        "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+    ],
+    target_vendor: "apple",
 };
 
 /// Arm64EC Windows MSVC
@@ -982,9 +1250,13 @@ pub(crate) const ARM64EC_PC_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::Arm64ec,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["windows"],
+    target_features: &["neon"],
+    target_vendor: "pc",
 };
 
 /// Arm BE8 the default Arm big-endian architecture since [Armv6](https://developer.arm.com/documentation/101754/0616/armlink-Reference/armlink-Command-line-Options/--be8?lang=en).
@@ -993,9 +1265,13 @@ pub(crate) const ARMEB_UNKNOWN_LINUX_GNUEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crc", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "v8"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7-R, Big Endian
@@ -1004,9 +1280,13 @@ pub(crate) const ARMEBV7R_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["dsp", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7-R, Big Endian, hardfloat
@@ -1015,9 +1295,15 @@ pub(crate) const ARMEBV7R_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "dsp", "fpregs", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv4T
@@ -1026,9 +1312,13 @@ pub(crate) const ARMV4T_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["soft-float"],
+    target_vendor: "unknown",
 };
 
 /// Armv4T Linux
@@ -1037,9 +1327,13 @@ pub(crate) const ARMV4T_UNKNOWN_LINUX_GNUEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["soft-float"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv5TE
@@ -1048,9 +1342,13 @@ pub(crate) const ARMV5TE_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["soft-float", "v5te"],
+    target_vendor: "unknown",
 };
 
 /// Armv5TE Linux (kernel 4.4+, glibc 2.23)
@@ -1059,9 +1357,13 @@ pub(crate) const ARMV5TE_UNKNOWN_LINUX_GNUEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["soft-float", "v5te"],
+    target_vendor: "unknown",
 };
 
 /// Armv5TE Linux with musl 1.2.5
@@ -1070,9 +1372,13 @@ pub(crate) const ARMV5TE_UNKNOWN_LINUX_MUSLEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "soft-float", "v5te"],
+    target_vendor: "unknown",
 };
 
 /// Armv5TE Linux with uClibc
@@ -1081,9 +1387,13 @@ pub(crate) const ARMV5TE_UNKNOWN_LINUX_UCLIBCEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::UClibc,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["soft-float", "v5te"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv6
@@ -1092,9 +1402,13 @@ pub(crate) const ARMV6_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["dsp", "soft-float", "v5te", "v6", "v6k"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv6, hardfloat
@@ -1103,9 +1417,13 @@ pub(crate) const ARMV6_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["dsp", "fpregs", "v5te", "v6", "v6k", "vfp2"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 FreeBSD
@@ -1114,9 +1432,13 @@ pub(crate) const ARMV6_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["dsp", "fpregs", "v5te", "v6", "vfp2"],
+    target_vendor: "unknown",
 };
 
 /// Armv6 NetBSD w/hard-float
@@ -1125,9 +1447,13 @@ pub(crate) const ARMV6_UNKNOWN_NETBSD_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["dsp", "fpregs", "v5te", "v6", "vfp2"],
+    target_vendor: "unknown",
 };
 
 /// Armv6k Nintendo 3DS, Horizon (Requires devkitARM toolchain)
@@ -1136,9 +1462,13 @@ pub(crate) const ARMV6K_NINTENDO_3DS: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Horizon,
     target_env: Env::Newlib,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fpregs", "v5te", "v6", "v6k", "vfp2"],
+    target_vendor: "nintendo",
 };
 
 /// Armv7-A Android
@@ -1147,9 +1477,27 @@ pub(crate) const ARMV7_LINUX_ANDROIDEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RTEMS OS for ARM BSPs
@@ -1158,9 +1506,16 @@ pub(crate) const ARMV7_RTEMS_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Rtems,
     target_env: Env::Newlib,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+        "vfp2", "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Cortex-A9 Sony PlayStation Vita (requires VITASDK toolchain)
@@ -1169,9 +1524,29 @@ pub(crate) const ARMV7_SONY_VITA_NEWLIBEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Vita,
     target_env: Env::Newlib,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Eabihf,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "trustzone",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "sony",
 };
 
 /// Armv7-A FreeBSD
@@ -1180,9 +1555,16 @@ pub(crate) const ARMV7_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux (kernel 4.15+, glibc 2.27)
@@ -1191,9 +1573,27 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_GNUEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux, hardfloat (kernel 3.2+, glibc 2.17)
@@ -1202,9 +1602,16 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_GNUEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux with musl 1.2.5
@@ -1213,9 +1620,28 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_MUSLEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "crt-static",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux with musl 1.2.5, hardfloat
@@ -1224,9 +1650,27 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_MUSLEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "crt-static",
+        "d32",
+        "dsp",
+        "fpregs",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A OpenHarmony
@@ -1235,9 +1679,27 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_OHOS: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::OhOS,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux with uClibc, softfloat
@@ -1246,9 +1708,27 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_UCLIBCEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::UClibc,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Linux with uClibc, hardfloat
@@ -1257,9 +1737,16 @@ pub(crate) const ARMV7_UNKNOWN_LINUX_UCLIBCEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::UClibc,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A NetBSD w/hard-float
@@ -1268,9 +1755,16 @@ pub(crate) const ARMV7_UNKNOWN_NETBSD_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 pub(crate) const ARMV7_UNKNOWN_TRUSTY: Platform = Platform {
@@ -1278,9 +1772,28 @@ pub(crate) const ARMV7_UNKNOWN_TRUSTY: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Trusty,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "crt-static",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A for VxWorks
@@ -1289,9 +1802,27 @@ pub(crate) const ARMV7_WRS_VXWORKS_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "crt-static",
+        "d32",
+        "dsp",
+        "fpregs",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "wrs",
 };
 
 /// ARM SOLID with TOPPERS/ASP3
@@ -1300,9 +1831,27 @@ pub(crate) const ARMV7A_KMC_SOLID_ASP3_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::SolidAsp3,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "kmc",
 };
 
 /// ARM SOLID with TOPPERS/ASP3, hardfloat
@@ -1311,9 +1860,16 @@ pub(crate) const ARMV7A_KMC_SOLID_ASP3_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::SolidAsp3,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "kmc",
 };
 
 /// Bare Armv7-A
@@ -1322,9 +1878,27 @@ pub(crate) const ARMV7A_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7-A, hardfloat
@@ -1333,9 +1907,16 @@ pub(crate) const ARMV7A_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7-A with NuttX
@@ -1344,9 +1925,27 @@ pub(crate) const ARMV7A_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7-A with NuttX, hardfloat
@@ -1355,9 +1954,16 @@ pub(crate) const ARMV7A_NUTTX_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+        "vfp2", "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Cortex-A9 VEX V5 Brain, VEXos
@@ -1366,9 +1972,28 @@ pub(crate) const ARMV7A_VEX_V5: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::VexOS,
     target_env: Env::V5,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Eabihf,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb2",
+        "trustzone",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "vex",
 };
 
 /// Armv7-A Apple WatchOS
@@ -1377,9 +2002,29 @@ pub(crate) const ARMV7K_APPLE_WATCHOS: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::WatchOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb2",
+        "trustzone",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+        "vfp4",
+    ],
+    target_vendor: "apple",
 };
 
 /// Bare Armv7-R
@@ -1388,9 +2033,13 @@ pub(crate) const ARMV7R_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["dsp", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7-R, hardfloat
@@ -1399,9 +2048,15 @@ pub(crate) const ARMV7R_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "dsp", "fpregs", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Armv7-A Apple-A6 Apple iOS
@@ -1410,9 +2065,16 @@ pub(crate) const ARMV7S_APPLE_IOS: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::iOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+        "vfp2", "vfp3", "vfp4",
+    ],
+    target_vendor: "apple",
 };
 
 /// Bare Armv8-R, hardfloat
@@ -1421,9 +2083,26 @@ pub(crate) const ARMV8R_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "crc",
+        "dsp",
+        "fpregs",
+        "rclass",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "v8",
+        "virtualization",
+    ],
+    target_vendor: "unknown",
 };
 
 /// AVR; requires `-Zbuild-std=core` and `-Ctarget-cpu=...`
@@ -1432,9 +2111,13 @@ pub(crate) const AVR_NONE: Platform = Platform {
     target_arch: Arch::Avr,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U16,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["addsubiw", "ijmpcall", "lpm"],
+    target_vendor: "unknown",
 };
 
 /// BPF (big endian)
@@ -1443,9 +2126,13 @@ pub(crate) const BPFEB_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::Bpf,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// BPF (little endian)
@@ -1454,9 +2141,13 @@ pub(crate) const BPFEL_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::Bpf,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// C-SKY abiv2 Linux (little endian)
@@ -1465,9 +2156,31 @@ pub(crate) const CSKY_UNKNOWN_LINUX_GNUABIV2: Platform = Platform {
     target_arch: Arch::Csky,
     target_os: OS::Linux,
     target_env: Env::Gnu,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Abiv2,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "2e3",
+        "3e7",
+        "7e10",
+        "cache",
+        "dsp1e2",
+        "dspe60",
+        "e1",
+        "e2",
+        "edsp",
+        "elrw",
+        "hard-tp",
+        "high-registers",
+        "hwdiv",
+        "mp",
+        "mp1e2",
+        "nvic",
+        "trust",
+    ],
+    target_vendor: "unknown",
 };
 
 /// C-SKY abiv2 Linux, hardfloat (little endian)
@@ -1476,9 +2189,45 @@ pub(crate) const CSKY_UNKNOWN_LINUX_GNUABIV2HF: Platform = Platform {
     target_arch: Arch::Csky,
     target_os: OS::Linux,
     target_env: Env::Gnu,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Abiv2hf,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "10e60",
+        "2e3",
+        "3e3r1",
+        "3e3r2",
+        "3e3r3",
+        "3e7",
+        "7e10",
+        "cache",
+        "doloop",
+        "dsp1e2",
+        "dspe60",
+        "e1",
+        "e2",
+        "edsp",
+        "elrw",
+        "float7e60",
+        "fpuv3_df",
+        "fpuv3_hf",
+        "fpuv3_hi",
+        "fpuv3_sf",
+        "hard-float",
+        "hard-float-abi",
+        "hard-tp",
+        "high-registers",
+        "hwdiv",
+        "mp",
+        "mp1e2",
+        "nvic",
+        "trust",
+        "vdsp2e60f",
+        "vdspv2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Hexagon Linux with musl 1.2.5
@@ -1487,9 +2236,13 @@ pub(crate) const HEXAGON_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Hexagon,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["hvx", "hvx-length128b", "hvxv60"],
+    target_vendor: "unknown",
 };
 
 /// Bare Hexagon (v60+, HVX)
@@ -1498,9 +2251,13 @@ pub(crate) const HEXAGON_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Hexagon,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["hvx", "hvx-length128b", "hvxv60"],
+    target_vendor: "unknown",
 };
 
 /// Hexagon QuRT
@@ -1509,9 +2266,24 @@ pub(crate) const HEXAGON_UNKNOWN_QURT: Platform = Platform {
     target_arch: Arch::Hexagon,
     target_os: OS::Qurt,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "hvx",
+        "hvx-length128b",
+        "hvxv60",
+        "hvxv62",
+        "hvxv65",
+        "hvxv66",
+        "hvxv67",
+        "hvxv68",
+        "hvxv69",
+        "zreg",
+    ],
+    target_vendor: "unknown",
 };
 
 /// 32-bit x86 iOS (Penryn)
@@ -1520,9 +2292,23 @@ pub(crate) const I386_APPLE_IOS: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::iOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// 32-bit Linux (kernel 3.2+, glibc 2.17, original Pentium)
@@ -1531,9 +2317,13 @@ pub(crate) const I586_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit Linux (musl 1.2.5, original Pentium)
@@ -1542,9 +2332,13 @@ pub(crate) const I586_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit x86 (original Pentium)
@@ -1553,9 +2347,13 @@ pub(crate) const I586_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit x86 Redox OS (PentiumPro)
@@ -1564,9 +2362,13 @@ pub(crate) const I586_UNKNOWN_REDOX: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Redox,
     target_env: Env::Relibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit macOS (10.12+, Sierra+, Penryn)
@@ -1575,9 +2377,23 @@ pub(crate) const I686_APPLE_DARWIN: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::MacOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// 32-bit x86 Android ([Pentium 4 plus various extensions](https://developer.android.com/ndk/guides/abis.html#x86))
@@ -1586,9 +2402,13 @@ pub(crate) const I686_LINUX_ANDROID: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "sse3", "ssse3", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit x86 QNX Neutrino 7.0 RTOS (Pentium 4)
@@ -1597,9 +2417,13 @@ pub(crate) const I686_PC_NTO_QNX700: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Nto,
     target_env: Env::Nto70,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 32-bit MinGW (Windows 10+, Windows Server 2016+, Pentium 4)
@@ -1608,9 +2432,13 @@ pub(crate) const I686_PC_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 32-bit x86 MinGW (Windows 10+, Pentium 4), LLVM ABI
@@ -1619,9 +2447,13 @@ pub(crate) const I686_PC_WINDOWS_GNULLVM: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::Llvm,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 32-bit MSVC (Windows 10+, Windows Server 2016+, Pentium 4)
@@ -1630,9 +2462,13 @@ pub(crate) const I686_PC_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::One,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 32-bit x86 FreeBSD (Pentium 4)
@@ -1641,9 +2477,13 @@ pub(crate) const I686_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit Haiku (Pentium 4)
@@ -1652,9 +2492,13 @@ pub(crate) const I686_UNKNOWN_HAIKU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Haiku,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// HelenOS IA-32 (see docs for pending issues)
@@ -1663,9 +2507,13 @@ pub(crate) const I686_UNKNOWN_HELENOS: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::HelenOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit GNU/Hurd (Pentium 4)
@@ -1674,9 +2522,13 @@ pub(crate) const I686_UNKNOWN_HURD_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Hurd,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit Linux (kernel 3.2+, glibc 2.17+, Pentium 4)
@@ -1685,9 +2537,13 @@ pub(crate) const I686_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::One,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit Linux with musl 1.2.5 (Pentium 4)
@@ -1696,9 +2552,13 @@ pub(crate) const I686_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// NetBSD/i386 (Pentium 4)
@@ -1707,9 +2567,13 @@ pub(crate) const I686_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit OpenBSD (Pentium 4)
@@ -1718,9 +2582,13 @@ pub(crate) const I686_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit UEFI (Pentium 4, softfloat)
@@ -1729,9 +2597,13 @@ pub(crate) const I686_UNKNOWN_UEFI: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Uefi,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["fxsr", "x87"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const I686_UWP_WINDOWS_GNU: Platform = Platform {
@@ -1739,9 +2611,13 @@ pub(crate) const I686_UWP_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::Uwp,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "uwp",
 };
 
 pub(crate) const I686_UWP_WINDOWS_MSVC: Platform = Platform {
@@ -1749,9 +2625,13 @@ pub(crate) const I686_UWP_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::Uwp,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "uwp",
 };
 
 /// 32-bit Windows 7 support
@@ -1760,9 +2640,13 @@ pub(crate) const I686_WIN7_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "win7",
 };
 
 /// 32-bit Windows 7 support
@@ -1771,9 +2655,13 @@ pub(crate) const I686_WIN7_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "win7",
 };
 
 pub(crate) const I686_WRS_VXWORKS: Platform = Platform {
@@ -1781,9 +2669,13 @@ pub(crate) const I686_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::X86,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "wrs",
 };
 
 /// LoongArch32 Bare-metal (ILP32D ABI)
@@ -1792,9 +2684,13 @@ pub(crate) const LOONGARCH32_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::Loongarch32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["d", "f"],
+    target_vendor: "unknown",
 };
 
 /// LoongArch32 Bare-metal (ILP32S ABI)
@@ -1803,9 +2699,13 @@ pub(crate) const LOONGARCH32_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::Loongarch32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// LoongArch64 Linux, LP64D ABI (kernel 5.19+, glibc 2.36), LSX required
@@ -1814,9 +2714,13 @@ pub(crate) const LOONGARCH64_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Loongarch64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["32s", "d", "f", "lsx", "ual"],
+    target_vendor: "unknown",
 };
 
 /// LoongArch64 Linux, LP64D ABI (kernel 5.19+, musl 1.2.5), LSX required
@@ -1825,9 +2729,13 @@ pub(crate) const LOONGARCH64_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Loongarch64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["32s", "d", "f", "lsx", "ual"],
+    target_vendor: "unknown",
 };
 
 /// LoongArch64 OpenHarmony
@@ -1836,9 +2744,13 @@ pub(crate) const LOONGARCH64_UNKNOWN_LINUX_OHOS: Platform = Platform {
     target_arch: Arch::Loongarch64,
     target_os: OS::Linux,
     target_env: Env::OhOS,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["32s", "d", "f", "lsx", "ual"],
+    target_vendor: "unknown",
 };
 
 /// LoongArch64 Bare-metal (LP64D ABI)
@@ -1847,9 +2759,13 @@ pub(crate) const LOONGARCH64_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::Loongarch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["32s", "d", "f", "ual"],
+    target_vendor: "unknown",
 };
 
 /// LoongArch64 Bare-metal (LP64S ABI)
@@ -1858,9 +2774,13 @@ pub(crate) const LOONGARCH64_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::Loongarch64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["32s", "ual"],
+    target_vendor: "unknown",
 };
 
 /// Motorola 680x0 Linux
@@ -1869,9 +2789,13 @@ pub(crate) const M68K_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::M68k,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["isa-68000", "isa-68010", "isa-68020"],
+    target_vendor: "unknown",
 };
 
 /// Motorola 680x0
@@ -1880,9 +2804,13 @@ pub(crate) const M68K_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::M68k,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["isa-68000", "isa-68010"],
+    target_vendor: "unknown",
 };
 
 /// Bare MIPS32r2 (BE) softfloat
@@ -1891,9 +2819,13 @@ pub(crate) const MIPS_MTI_NONE_ELF: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "mti",
 };
 
 /// MIPS Linux (kernel 4.4, glibc 2.23)
@@ -1902,9 +2834,13 @@ pub(crate) const MIPS_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// MIPS Linux with musl 1.2.5
@@ -1913,9 +2849,13 @@ pub(crate) const MIPS_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// MIPS Linux with uClibc
@@ -1924,9 +2864,13 @@ pub(crate) const MIPS_UNKNOWN_LINUX_UCLIBC: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::UClibc,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// MIPS64 for OpenWrt Linux musl 1.2.5
@@ -1935,9 +2879,13 @@ pub(crate) const MIPS64_OPENWRT_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Mips64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "openwrt",
 };
 
 /// MIPS64 Linux, N64 ABI (kernel 4.4, glibc 2.23)
@@ -1946,9 +2894,13 @@ pub(crate) const MIPS64_UNKNOWN_LINUX_GNUABI64: Platform = Platform {
     target_arch: Arch::Mips64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// MIPS64 Linux, N64 ABI, musl 1.2.5
@@ -1957,9 +2909,13 @@ pub(crate) const MIPS64_UNKNOWN_LINUX_MUSLABI64: Platform = Platform {
     target_arch: Arch::Mips64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// MIPS64 (little endian) Linux, N64 ABI (kernel 4.4, glibc 2.23)
@@ -1968,9 +2924,13 @@ pub(crate) const MIPS64EL_UNKNOWN_LINUX_GNUABI64: Platform = Platform {
     target_arch: Arch::Mips64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// MIPS64 (little endian) Linux, N64 ABI, musl 1.2.5
@@ -1979,9 +2939,13 @@ pub(crate) const MIPS64EL_UNKNOWN_LINUX_MUSLABI64: Platform = Platform {
     target_arch: Arch::Mips64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// Bare MIPS32r2 (LE) softfloat
@@ -1990,9 +2954,13 @@ pub(crate) const MIPSEL_MTI_NONE_ELF: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "mti",
 };
 
 /// MIPS (LE) Sony PlayStation Portable (PSP)
@@ -2001,9 +2969,13 @@ pub(crate) const MIPSEL_SONY_PSP: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Psp,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "sony",
 };
 
 /// MIPS (LE) Sony PlayStation 1 (PSX)
@@ -2012,9 +2984,13 @@ pub(crate) const MIPSEL_SONY_PSX: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Psx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "sony",
 };
 
 /// MIPS (little endian) Linux (kernel 4.4, glibc 2.23)
@@ -2023,9 +2999,13 @@ pub(crate) const MIPSEL_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// MIPS (little endian) Linux with musl 1.2.5
@@ -2034,9 +3014,13 @@ pub(crate) const MIPSEL_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// MIPS (LE) Linux with uClibc
@@ -2045,9 +3029,13 @@ pub(crate) const MIPSEL_UNKNOWN_LINUX_UCLIBC: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::Linux,
     target_env: Env::UClibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// 32-bit MIPS (LE), requires mips32 cpu support
@@ -2056,9 +3044,13 @@ pub(crate) const MIPSEL_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// Bare MIPS (LE) softfloat
@@ -2067,9 +3059,13 @@ pub(crate) const MIPSEL_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::Mips,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// 32-bit MIPS Release 6 Big Endian
@@ -2078,9 +3074,13 @@ pub(crate) const MIPSISA32R6_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Mips32r6,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// 32-bit MIPS Release 6 Little Endian
@@ -2089,9 +3089,13 @@ pub(crate) const MIPSISA32R6EL_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Mips32r6,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit MIPS Release 6 Big Endian
@@ -2100,9 +3104,13 @@ pub(crate) const MIPSISA64R6_UNKNOWN_LINUX_GNUABI64: Platform = Platform {
     target_arch: Arch::Mips64r6,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit MIPS Release 6 Little Endian
@@ -2111,9 +3119,13 @@ pub(crate) const MIPSISA64R6EL_UNKNOWN_LINUX_GNUABI64: Platform = Platform {
     target_arch: Arch::Mips64r6,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Abi64,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fp64"],
+    target_vendor: "unknown",
 };
 
 /// 16-bit MSP430 microcontrollers
@@ -2122,9 +3134,13 @@ pub(crate) const MSP430_NONE_ELF: Platform = Platform {
     target_arch: Arch::Msp430,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U16,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// --emit=asm generates PTX code that [runs on NVIDIA GPUs](https://github.com/japaric-archived/nvptx#targets)
@@ -2133,9 +3149,13 @@ pub(crate) const NVPTX64_NVIDIA_CUDA: Platform = Platform {
     target_arch: Arch::Nvptx64,
     target_os: OS::Cuda,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["sm_20", "sm_21", "sm_30"],
+    target_vendor: "nvidia",
 };
 
 /// PowerPC FreeBSD
@@ -2144,9 +3164,13 @@ pub(crate) const POWERPC_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// PowerPC HelenOS
@@ -2155,9 +3179,13 @@ pub(crate) const POWERPC_UNKNOWN_HELENOS: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::HelenOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// PowerPC Linux (kernel 3.2+, glibc 2.17)
@@ -2166,9 +3194,13 @@ pub(crate) const POWERPC_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// PowerPC SPE Linux
@@ -2177,9 +3209,13 @@ pub(crate) const POWERPC_UNKNOWN_LINUX_GNUSPE: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Spe,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["msync"],
+    target_vendor: "unknown",
 };
 
 /// PowerPC Linux with musl 1.2.5
@@ -2188,9 +3224,13 @@ pub(crate) const POWERPC_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// PowerPC SPE Linux with musl 1.2.5
@@ -2199,9 +3239,13 @@ pub(crate) const POWERPC_UNKNOWN_LINUX_MUSLSPE: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Spe,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["msync"],
+    target_vendor: "unknown",
 };
 
 /// NetBSD 32-bit powerpc systems
@@ -2210,9 +3254,13 @@ pub(crate) const POWERPC_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 pub(crate) const POWERPC_UNKNOWN_OPENBSD: Platform = Platform {
@@ -2220,9 +3268,13 @@ pub(crate) const POWERPC_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 pub(crate) const POWERPC_WRS_VXWORKS: Platform = Platform {
@@ -2230,9 +3282,13 @@ pub(crate) const POWERPC_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static"],
+    target_vendor: "wrs",
 };
 
 pub(crate) const POWERPC_WRS_VXWORKS_SPE: Platform = Platform {
@@ -2240,9 +3296,13 @@ pub(crate) const POWERPC_WRS_VXWORKS_SPE: Platform = Platform {
     target_arch: Arch::PowerPc,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::Spe,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "msync"],
+    target_vendor: "wrs",
 };
 
 /// 64-bit AIX (7.2 and newer)
@@ -2251,9 +3311,13 @@ pub(crate) const POWERPC64_IBM_AIX: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::Aix,
     target_env: Env::None,
+    target_abi: Abi::VecExtabi,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["altivec", "vsx"],
+    target_vendor: "ibm",
 };
 
 /// PPC64 FreeBSD (ELFv2)
@@ -2262,9 +3326,13 @@ pub(crate) const POWERPC64_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["altivec"],
+    target_vendor: "unknown",
 };
 
 /// PPC64 Linux (kernel 3.2+, glibc 2.17)
@@ -2273,20 +3341,13 @@ pub(crate) const POWERPC64_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Elfv1,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
-};
-
-/// PPC64 Linux (ELFv2 ABI, kernel 3.2, glibc 2.17)
-pub(crate) const POWERPC64_UNKNOWN_LINUX_GNUELFV2: Platform = Platform {
-    target_triple: "powerpc64-unknown-linux-gnuelfv2",
-    target_arch: Arch::PowerPc64,
-    target_os: OS::Linux,
-    target_env: Env::Gnu,
-    target_endian: Endian::Big,
-    target_pointer_width: PointerWidth::U64,
-    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["altivec"],
+    target_vendor: "unknown",
 };
 
 /// PPC64 Linux (kernel 4.19+, musl 1.2.5)
@@ -2295,9 +3356,13 @@ pub(crate) const POWERPC64_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["altivec"],
+    target_vendor: "unknown",
 };
 
 /// OpenBSD/powerpc64
@@ -2306,9 +3371,13 @@ pub(crate) const POWERPC64_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["altivec"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const POWERPC64_WRS_VXWORKS: Platform = Platform {
@@ -2316,9 +3385,13 @@ pub(crate) const POWERPC64_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::Elfv1,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["altivec", "crt-static"],
+    target_vendor: "wrs",
 };
 
 /// PPC64LE FreeBSD
@@ -2327,9 +3400,21 @@ pub(crate) const POWERPC64LE_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "altivec",
+        "partword-atomics",
+        "power8-altivec",
+        "power8-crypto",
+        "power8-vector",
+        "quadword-atomics",
+        "vsx",
+    ],
+    target_vendor: "unknown",
 };
 
 /// PPC64LE Linux (kernel 3.10+, glibc 2.17)
@@ -2338,9 +3423,21 @@ pub(crate) const POWERPC64LE_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "altivec",
+        "partword-atomics",
+        "power8-altivec",
+        "power8-crypto",
+        "power8-vector",
+        "quadword-atomics",
+        "vsx",
+    ],
+    target_vendor: "unknown",
 };
 
 /// PPC64LE Linux (kernel 4.19+, musl 1.2.5)
@@ -2349,9 +3446,22 @@ pub(crate) const POWERPC64LE_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::PowerPc64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::Elfv2,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "altivec",
+        "crt-static",
+        "partword-atomics",
+        "power8-altivec",
+        "power8-crypto",
+        "power8-vector",
+        "quadword-atomics",
+        "vsx",
+    ],
+    target_vendor: "unknown",
 };
 
 pub(crate) const RISCV32_WRS_VXWORKS: Platform = Platform {
@@ -2359,9 +3469,25 @@ pub(crate) const RISCV32_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a",
+        "c",
+        "crt-static",
+        "d",
+        "f",
+        "m",
+        "zaamo",
+        "zalrsc",
+        "zca",
+        "zicsr",
+        "zifencei",
+    ],
+    target_vendor: "wrs",
 };
 
 /// Bare RISC-V (RV32E ISA)
@@ -2370,9 +3496,13 @@ pub(crate) const RISCV32E_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Ilp32e,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["e"],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV32EM ISA)
@@ -2381,9 +3511,13 @@ pub(crate) const RISCV32EM_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Ilp32e,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["e", "m"],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV32EMC ISA)
@@ -2392,9 +3526,13 @@ pub(crate) const RISCV32EMC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Ilp32e,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["c", "e", "m", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Linux (kernel 5.4, glibc 2.33)
@@ -2403,9 +3541,15 @@ pub(crate) const RISCV32GC_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Linux (kernel 5.4, musl 1.2.5)
@@ -2414,9 +3558,15 @@ pub(crate) const RISCV32GC_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV32I ISA)
@@ -2425,9 +3575,13 @@ pub(crate) const RISCV32I_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// RISC Zero's zero-knowledge Virtual Machine (RV32IM ISA)
@@ -2436,9 +3590,13 @@ pub(crate) const RISCV32IM_RISC0_ZKVM_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Zkvm,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["m"],
+    target_vendor: "risc0",
 };
 
 /// Bare RISC-V (RV32IM ISA)
@@ -2447,9 +3605,13 @@ pub(crate) const RISCV32IM_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["m"],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV32IMA ISA)
@@ -2458,9 +3620,13 @@ pub(crate) const RISCV32IMA_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["a", "m", "zaamo", "zalrsc"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V ESP-IDF
@@ -2469,9 +3635,13 @@ pub(crate) const RISCV32IMAC_ESP_ESPIDF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "espressif",
 };
 
 /// Bare RISC-V (RV32IMAC ISA)
@@ -2480,9 +3650,13 @@ pub(crate) const RISCV32IMAC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 32bit with NuttX
@@ -2491,9 +3665,13 @@ pub(crate) const RISCV32IMAC_UNKNOWN_NUTTX_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Xous (RV32IMAC ISA)
@@ -2502,9 +3680,13 @@ pub(crate) const RISCV32IMAC_UNKNOWN_XOUS_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Xous,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V ESP-IDF
@@ -2513,9 +3695,13 @@ pub(crate) const RISCV32IMAFC_ESP_ESPIDF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    target_vendor: "espressif",
 };
 
 /// Bare RISC-V (RV32IMAFC ISA)
@@ -2524,9 +3710,13 @@ pub(crate) const RISCV32IMAFC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 32bit with NuttX
@@ -2535,9 +3725,13 @@ pub(crate) const RISCV32IMAFC_UNKNOWN_NUTTX_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V ESP-IDF
@@ -2546,9 +3740,13 @@ pub(crate) const RISCV32IMC_ESP_ESPIDF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["c", "m", "zca"],
+    target_vendor: "espressif",
 };
 
 /// Bare RISC-V (RV32IMC ISA)
@@ -2557,9 +3755,13 @@ pub(crate) const RISCV32IMC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["c", "m", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 32bit with NuttX
@@ -2568,9 +3770,13 @@ pub(crate) const RISCV32IMC_UNKNOWN_NUTTX_ELF: Platform = Platform {
     target_arch: Arch::Riscv32,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["c", "m", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 64-bit Android
@@ -2579,9 +3785,17 @@ pub(crate) const RISCV64_LINUX_ANDROID: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "b", "c", "d", "f", "m", "v", "zaamo", "zalrsc", "zba", "zbb", "zbs", "zca", "zicsr",
+        "zifencei", "zve32f", "zve32x", "zve64d", "zve64f", "zve64x", "zvl128b", "zvl32b",
+        "zvl64b",
+    ],
+    target_vendor: "unknown",
 };
 
 pub(crate) const RISCV64_WRS_VXWORKS: Platform = Platform {
@@ -2589,9 +3803,25 @@ pub(crate) const RISCV64_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a",
+        "c",
+        "crt-static",
+        "d",
+        "f",
+        "m",
+        "zaamo",
+        "zalrsc",
+        "zca",
+        "zicsr",
+        "zifencei",
+    ],
+    target_vendor: "wrs",
 };
 
 /// RISC-V Linux (kernel 6.8.0+, glibc 2.39)
@@ -2600,9 +3830,64 @@ pub(crate) const RISCV64A23_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "a",
+        "b",
+        "c",
+        "d",
+        "f",
+        "m",
+        "rva23u64",
+        "supm",
+        "v",
+        "za128rs",
+        "za64rs",
+        "zaamo",
+        "zalrsc",
+        "zawrs",
+        "zba",
+        "zbb",
+        "zbs",
+        "zca",
+        "zcb",
+        "zcmop",
+        "zfa",
+        "zfhmin",
+        "zic64b",
+        "zicbom",
+        "zicbop",
+        "zicboz",
+        "ziccamoa",
+        "ziccif",
+        "zicclsm",
+        "ziccrse",
+        "zicntr",
+        "zicond",
+        "zicsr",
+        "zihintntl",
+        "zihintpause",
+        "zihpm",
+        "zimop",
+        "zkt",
+        "zvbb",
+        "zve32f",
+        "zve32x",
+        "zve64d",
+        "zve64f",
+        "zve64x",
+        "zvfhmin",
+        "zvkb",
+        "zvkt",
+        "zvl128b",
+        "zvl32b",
+        "zvl64b",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V FreeBSD
@@ -2611,9 +3896,15 @@ pub(crate) const RISCV64GC_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Fuchsia
@@ -2622,9 +3913,15 @@ pub(crate) const RISCV64GC_UNKNOWN_FUCHSIA: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Fuchsia,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Hermit
@@ -2633,9 +3930,15 @@ pub(crate) const RISCV64GC_UNKNOWN_HERMIT: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Hermit,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Linux (kernel 4.20+, glibc 2.29)
@@ -2644,9 +3947,15 @@ pub(crate) const RISCV64GC_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Linux (kernel 4.20+, musl 1.2.5)
@@ -2655,9 +3964,15 @@ pub(crate) const RISCV64GC_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V Managarm
@@ -2666,9 +3981,13 @@ pub(crate) const RISCV64GC_UNKNOWN_MANAGARM_MLIBC: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Managarm,
     target_env: Env::Mlibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V NetBSD
@@ -2677,9 +3996,15 @@ pub(crate) const RISCV64GC_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV64IMAFDC ISA)
@@ -2688,9 +4013,15 @@ pub(crate) const RISCV64GC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 64bit with NuttX
@@ -2699,9 +4030,15 @@ pub(crate) const RISCV64GC_UNKNOWN_NUTTX_ELF: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// OpenBSD/riscv64
@@ -2710,9 +4047,15 @@ pub(crate) const RISCV64GC_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+    ],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 64bit Redox OS
@@ -2721,9 +4064,24 @@ pub(crate) const RISCV64GC_UNKNOWN_REDOX: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Redox,
     target_env: Env::Relibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "a",
+        "c",
+        "crt-static",
+        "d",
+        "f",
+        "m",
+        "zaamo",
+        "zalrsc",
+        "zca",
+        "zicsr",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV64IM ISA)
@@ -2732,9 +4090,13 @@ pub(crate) const RISCV64IM_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["m"],
+    target_vendor: "unknown",
 };
 
 /// Bare RISC-V (RV64IMAC ISA)
@@ -2743,9 +4105,13 @@ pub(crate) const RISCV64IMAC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "unknown",
 };
 
 /// RISC-V 64bit with NuttX
@@ -2754,9 +4120,13 @@ pub(crate) const RISCV64IMAC_UNKNOWN_NUTTX_ELF: Platform = Platform {
     target_arch: Arch::Riscv64,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    target_vendor: "unknown",
 };
 
 /// S390x Linux (kernel 3.2+, glibc 2.17)
@@ -2765,9 +4135,13 @@ pub(crate) const S390X_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::S390X,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// S390x Linux (kernel 3.2, musl 1.2.5)
@@ -2776,9 +4150,13 @@ pub(crate) const S390X_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::S390X,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// Bare S390x (softfloat ABI)
@@ -2787,9 +4165,13 @@ pub(crate) const S390X_UNKNOWN_NONE_SOFTFLOAT: Platform = Platform {
     target_arch: Arch::S390X,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Softfloat,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// 32-bit SPARC Linux
@@ -2798,9 +4180,13 @@ pub(crate) const SPARC_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Sparc,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["v8plus", "v9"],
+    target_vendor: "unknown",
 };
 
 /// Bare 32-bit SPARC V7+
@@ -2809,9 +4195,13 @@ pub(crate) const SPARC_UNKNOWN_NONE_ELF: Platform = Platform {
     target_arch: Arch::Sparc,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "unknown",
 };
 
 /// sparc64 HelenOS
@@ -2820,9 +4210,13 @@ pub(crate) const SPARC64_UNKNOWN_HELENOS: Platform = Platform {
     target_arch: Arch::Sparc64,
     target_os: OS::HelenOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["v9"],
+    target_vendor: "unknown",
 };
 
 /// SPARC Linux (kernel 4.4+, glibc 2.23)
@@ -2831,9 +4225,13 @@ pub(crate) const SPARC64_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::Sparc64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["v9"],
+    target_vendor: "unknown",
 };
 
 /// NetBSD/sparc64
@@ -2842,9 +4240,13 @@ pub(crate) const SPARC64_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::Sparc64,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["v9"],
+    target_vendor: "unknown",
 };
 
 /// OpenBSD/sparc64
@@ -2853,9 +4255,13 @@ pub(crate) const SPARC64_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::Sparc64,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["v9"],
+    target_vendor: "unknown",
 };
 
 /// SPARC V9 Solaris 11.4
@@ -2864,9 +4270,13 @@ pub(crate) const SPARCV9_SUN_SOLARIS: Platform = Platform {
     target_arch: Arch::Sparc64,
     target_os: OS::Solaris,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Big,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["v9"],
+    target_vendor: "sun",
 };
 
 /// Thumb-mode Bare Armv4T
@@ -2875,9 +4285,13 @@ pub(crate) const THUMBV4T_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["soft-float", "thumb-mode"],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv5TE
@@ -2886,9 +4300,13 @@ pub(crate) const THUMBV5TE_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["soft-float", "thumb-mode", "v5te"],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv6
@@ -2897,9 +4315,13 @@ pub(crate) const THUMBV6_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["dsp", "soft-float", "thumb-mode", "v5te", "v6", "v6k"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv6-M
@@ -2908,9 +4330,13 @@ pub(crate) const THUMBV6M_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["mclass", "thumb-mode", "v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// ARMv6M with NuttX
@@ -2919,9 +4345,13 @@ pub(crate) const THUMBV6M_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["mclass", "thumb-mode", "v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv7-A
@@ -2930,9 +4360,28 @@ pub(crate) const THUMBV7A_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv7-A, hardfloat
@@ -2941,9 +4390,27 @@ pub(crate) const THUMBV7A_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7-A with NuttX
@@ -2952,9 +4419,28 @@ pub(crate) const THUMBV7A_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "soft-float",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7-A with NuttX, hardfloat
@@ -2963,9 +4449,28 @@ pub(crate) const THUMBV7A_NUTTX_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 pub(crate) const THUMBV7A_PC_WINDOWS_MSVC: Platform = Platform {
@@ -2973,9 +4478,28 @@ pub(crate) const THUMBV7A_PC_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Windows,
     target_env: Env::Msvc,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::None,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "pc",
 };
 
 pub(crate) const THUMBV7A_UWP_WINDOWS_MSVC: Platform = Platform {
@@ -2983,9 +4507,28 @@ pub(crate) const THUMBV7A_UWP_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Windows,
     target_env: Env::Msvc,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Uwp,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "uwp",
 };
 
 /// Bare Armv7E-M
@@ -2994,9 +4537,23 @@ pub(crate) const THUMBV7EM_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "dsp",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7E-M, hardfloat
@@ -3005,9 +4562,24 @@ pub(crate) const THUMBV7EM_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "dsp",
+        "fpregs",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7EM with NuttX
@@ -3016,9 +4588,23 @@ pub(crate) const THUMBV7EM_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "dsp",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7EM with NuttX, hardfloat
@@ -3027,9 +4613,24 @@ pub(crate) const THUMBV7EM_NUTTX_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "dsp",
+        "fpregs",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv7-M
@@ -3038,9 +4639,22 @@ pub(crate) const THUMBV7M_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv7M with NuttX
@@ -3049,9 +4663,22 @@ pub(crate) const THUMBV7M_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb2-mode Armv7-A Android with NEON
@@ -3060,9 +4687,28 @@ pub(crate) const THUMBV7NEON_LINUX_ANDROIDEABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb2-mode Armv7-A Linux with NEON (kernel 4.4+, glibc 2.23)
@@ -3071,9 +4717,28 @@ pub(crate) const THUMBV7NEON_UNKNOWN_LINUX_GNUEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb2-mode Armv7-A Linux with NEON, musl 1.2.5
@@ -3082,9 +4747,28 @@ pub(crate) const THUMBV7NEON_UNKNOWN_LINUX_MUSLEABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Linux,
     target_env: Env::Musl,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U32,
-    tier: Tier::Three,
+    target_abi: Abi::Eabihf,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U32,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "aclass",
+        "d32",
+        "dsp",
+        "fpregs",
+        "neon",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+        "vfp3",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv7-R
@@ -3093,9 +4777,23 @@ pub(crate) const THUMBV7R_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "dsp",
+        "rclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv7-R, hardfloat
@@ -3104,9 +4802,25 @@ pub(crate) const THUMBV7R_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "dsp",
+        "fpregs",
+        "rclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "vfp2",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv8-M Baseline
@@ -3115,9 +4829,13 @@ pub(crate) const THUMBV8M_BASE_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["mclass", "thumb-mode", "v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// ARMv8M Baseline with NuttX
@@ -3126,9 +4844,13 @@ pub(crate) const THUMBV8M_BASE_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["mclass", "thumb-mode", "v5te", "v6"],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv8-M Mainline
@@ -3137,9 +4859,22 @@ pub(crate) const THUMBV8M_MAIN_NONE_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Bare Armv8-M Mainline, hardfloat
@@ -3148,9 +4883,23 @@ pub(crate) const THUMBV8M_MAIN_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &[
+        "fpregs",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv8M Mainline with NuttX
@@ -3159,9 +4908,22 @@ pub(crate) const THUMBV8M_MAIN_NUTTX_EABI: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// ARMv8M Mainline with NuttX, hardfloat
@@ -3170,9 +4932,23 @@ pub(crate) const THUMBV8M_MAIN_NUTTX_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::Nuttx,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "fpregs",
+        "mclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+    ],
+    target_vendor: "unknown",
 };
 
 /// Thumb-mode Bare Armv8-R, hardfloat
@@ -3181,9 +4957,27 @@ pub(crate) const THUMBV8R_NONE_EABIHF: Platform = Platform {
     target_arch: Arch::Arm,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::Eabihf,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
-    tier: Tier::Two,
+    tier: Tier::Three,
+    target_families: &[],
+    target_features: &[
+        "crc",
+        "dsp",
+        "fpregs",
+        "rclass",
+        "thumb-mode",
+        "thumb2",
+        "v5te",
+        "v6",
+        "v6k",
+        "v6t2",
+        "v7",
+        "v8",
+        "virtualization",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly via Emscripten
@@ -3192,9 +4986,22 @@ pub(crate) const WASM32_UNKNOWN_EMSCRIPTEN: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Emscripten,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix", "wasm"],
+    target_features: &[
+        "bulk-memory",
+        "crt-static",
+        "exception-handling",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly
@@ -3203,9 +5010,20 @@ pub(crate) const WASM32_UNKNOWN_UNKNOWN: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Unknown,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["wasm"],
+    target_features: &[
+        "bulk-memory",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly with [WALI](https://github.com/arjunr2/WALI)
@@ -3214,9 +5032,22 @@ pub(crate) const WASM32_WALI_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix", "wasm"],
+    target_features: &[
+        "atomics",
+        "bulk-memory",
+        "crt-static",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly with WASIp1
@@ -3225,9 +5056,21 @@ pub(crate) const WASM32_WASIP1: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Wasi,
     target_env: Env::P1,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["wasm"],
+    target_features: &[
+        "bulk-memory",
+        "crt-static",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly with WASI Preview 1 and threads
@@ -3236,9 +5079,22 @@ pub(crate) const WASM32_WASIP1_THREADS: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Wasi,
     target_env: Env::P1,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["wasm"],
+    target_features: &[
+        "atomics",
+        "bulk-memory",
+        "crt-static",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly with WASIp2
@@ -3247,9 +5103,21 @@ pub(crate) const WASM32_WASIP2: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Wasi,
     target_env: Env::P2,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["wasm"],
+    target_features: &[
+        "bulk-memory",
+        "crt-static",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly with WASIp3
@@ -3258,9 +5126,21 @@ pub(crate) const WASM32_WASIP3: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::Wasi,
     target_env: Env::P3,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["wasm"],
+    target_features: &[
+        "bulk-memory",
+        "crt-static",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly limited to 1.0 features and no imports
@@ -3269,9 +5149,13 @@ pub(crate) const WASM32V1_NONE: Platform = Platform {
     target_arch: Arch::Wasm32,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["wasm"],
+    target_features: &["mutable-globals"],
+    target_vendor: "unknown",
 };
 
 /// WebAssembly
@@ -3280,9 +5164,20 @@ pub(crate) const WASM64_UNKNOWN_UNKNOWN: Platform = Platform {
     target_arch: Arch::Wasm64,
     target_os: OS::Unknown,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["wasm"],
+    target_features: &[
+        "bulk-memory",
+        "multivalue",
+        "mutable-globals",
+        "nontrapping-fptoint",
+        "reference-types",
+        "sign-ext",
+    ],
+    target_vendor: "unknown",
 };
 
 /// 64-bit macOS (10.12+, Sierra+)
@@ -3291,9 +5186,23 @@ pub(crate) const X86_64_APPLE_DARWIN: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::MacOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// 64-bit x86 iOS
@@ -3302,9 +5211,23 @@ pub(crate) const X86_64_APPLE_IOS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::iOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// Mac Catalyst on x86_64
@@ -3313,9 +5236,23 @@ pub(crate) const X86_64_APPLE_IOS_MACABI: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::iOS,
     target_env: Env::Macabi,
+    target_abi: Abi::Macabi,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// x86 64-bit tvOS
@@ -3324,9 +5261,23 @@ pub(crate) const X86_64_APPLE_TVOS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::TvOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// x86 64-bit Apple WatchOS simulator
@@ -3335,9 +5286,23 @@ pub(crate) const X86_64_APPLE_WATCHOS_SIM: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::WatchOS,
     target_env: Env::Sim,
+    target_abi: Abi::Sim,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "apple",
 };
 
 /// [Fortanix ABI](https://edp.fortanix.com/) for 64-bit Intel SGX
@@ -3346,9 +5311,13 @@ pub(crate) const X86_64_FORTANIX_UNKNOWN_SGX: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Unknown,
     target_env: Env::Sgx,
+    target_abi: Abi::Fortanix,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["fxsr", "rdrand", "rdseed", "sse", "sse2", "x87"],
+    target_vendor: "fortanix",
 };
 
 /// 64-bit x86 Android
@@ -3357,9 +5326,15 @@ pub(crate) const X86_64_LINUX_ANDROID: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Android,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "fxsr", "popcnt", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3", "x87",
+    ],
+    target_vendor: "unknown",
 };
 
 /// x86_64 LynxOS-178
@@ -3368,9 +5343,13 @@ pub(crate) const X86_64_LYNX_LYNXOS178: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Lynxos178,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit x86 Cygwin
@@ -3379,9 +5358,13 @@ pub(crate) const X86_64_PC_CYGWIN: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Cygwin,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// x86 64-bit QNX Neutrino 7.1 RTOS with default network stack (io-pkt)
@@ -3390,9 +5373,13 @@ pub(crate) const X86_64_PC_NTO_QNX710: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Nto,
     target_env: Env::Nto71,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// x86 64-bit QNX Neutrino 7.1 RTOS with new network stack (io-sock)
@@ -3401,9 +5388,13 @@ pub(crate) const X86_64_PC_NTO_QNX710_IOSOCK: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Nto,
     target_env: Env::Nto71Iosock,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// x86 64-bit QNX Neutrino 8.0 RTOS
@@ -3412,9 +5403,13 @@ pub(crate) const X86_64_PC_NTO_QNX800: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Nto,
     target_env: Env::Nto80,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 64-bit x86 Solaris 11.4
@@ -3423,9 +5418,13 @@ pub(crate) const X86_64_PC_SOLARIS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Solaris,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "pc",
 };
 
 /// 64-bit MinGW (Windows 10+, Windows Server 2016+)
@@ -3434,9 +5433,21 @@ pub(crate) const X86_64_PC_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["windows"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "x87",
+    ],
+    target_vendor: "pc",
 };
 
 /// 64-bit x86 MinGW (Windows 10+), LLVM ABI
@@ -3445,9 +5456,21 @@ pub(crate) const X86_64_PC_WINDOWS_GNULLVM: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::Llvm,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["windows"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "x87",
+    ],
+    target_vendor: "pc",
 };
 
 /// 64-bit MSVC (Windows 10+, Windows Server 2016+)
@@ -3456,9 +5479,21 @@ pub(crate) const X86_64_PC_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["windows"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "x87",
+    ],
+    target_vendor: "pc",
 };
 
 /// 64-bit Unikraft with musl 1.2.5
@@ -3467,9 +5502,13 @@ pub(crate) const X86_64_UNIKRAFT_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unikraft",
 };
 
 /// 64-bit DragonFlyBSD
@@ -3478,9 +5517,13 @@ pub(crate) const X86_64_UNKNOWN_DRAGONFLY: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Dragonfly,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit x86 FreeBSD
@@ -3489,9 +5532,13 @@ pub(crate) const X86_64_UNKNOWN_FREEBSD: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::FreeBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit x86 Fuchsia
@@ -3500,9 +5547,25 @@ pub(crate) const X86_64_UNKNOWN_FUCHSIA: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Fuchsia,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "popcnt",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "sse4.2",
+        "ssse3",
+        "x87",
+    ],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Haiku
@@ -3511,9 +5574,13 @@ pub(crate) const X86_64_UNKNOWN_HAIKU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Haiku,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// x86_64 (amd64) HelenOS
@@ -3522,9 +5589,13 @@ pub(crate) const X86_64_UNKNOWN_HELENOS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::HelenOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// x86_64 Hermit
@@ -3533,9 +5604,13 @@ pub(crate) const X86_64_UNKNOWN_HERMIT: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Hermit,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["fxsr", "rdrand", "rdseed", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit GNU/Hurd
@@ -3544,9 +5619,13 @@ pub(crate) const X86_64_UNKNOWN_HURD_GNU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Hurd,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// illumos
@@ -3555,9 +5634,13 @@ pub(crate) const X86_64_UNKNOWN_ILLUMOS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::IllumOS,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const X86_64_UNKNOWN_L4RE_UCLIBC: Platform = Platform {
@@ -3565,9 +5648,13 @@ pub(crate) const X86_64_UNKNOWN_L4RE_UCLIBC: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::L4re,
     target_env: Env::UClibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Linux (kernel 3.2+, glibc 2.17+)
@@ -3576,9 +5663,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_GNU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::One,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Linux (kernel 3.2+, glibc 2.17+) with ASAN enabled by default
@@ -3587,31 +5678,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_GNUASAN: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
-};
-
-/// 64-bit Linux (kernel 3.2+, glibc 2.17+) with MSAN enabled by default
-pub(crate) const X86_64_UNKNOWN_LINUX_GNUMSAN: Platform = Platform {
-    target_triple: "x86_64-unknown-linux-gnumsan",
-    target_arch: Arch::X86_64,
-    target_os: OS::Linux,
-    target_env: Env::Gnu,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U64,
-    tier: Tier::Two,
-};
-
-/// 64-bit Linux (kernel 3.2+, glibc 2.17+) with TSAN enabled by default
-pub(crate) const X86_64_UNKNOWN_LINUX_GNUTSAN: Platform = Platform {
-    target_triple: "x86_64-unknown-linux-gnutsan",
-    target_arch: Arch::X86_64,
-    target_os: OS::Linux,
-    target_env: Env::Gnu,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U64,
-    tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Linux (x32 ABI) (kernel 4.15+, glibc 2.27)
@@ -3620,9 +5693,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_GNUX32: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::Gnu,
+    target_abi: Abi::X32,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Linux with musl 1.2.5
@@ -3631,9 +5708,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_MUSL: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::Musl,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit Linux with no libc
@@ -3642,9 +5723,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_NONE: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// x86_64 OpenHarmony
@@ -3653,9 +5738,13 @@ pub(crate) const X86_64_UNKNOWN_LINUX_OHOS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Linux,
     target_env: Env::OhOS,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// x86_64 Managarm
@@ -3664,9 +5753,13 @@ pub(crate) const X86_64_UNKNOWN_MANAGARM_MLIBC: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Managarm,
     target_env: Env::Mlibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// x86_64 Motor OS
@@ -3675,9 +5768,13 @@ pub(crate) const X86_64_UNKNOWN_MOTOR: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Motor,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// NetBSD/amd64
@@ -3686,9 +5783,13 @@ pub(crate) const X86_64_UNKNOWN_NETBSD: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::NetBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// Freestanding/bare-metal x86_64, softfloat
@@ -3697,9 +5798,13 @@ pub(crate) const X86_64_UNKNOWN_NONE: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["fxsr", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit OpenBSD
@@ -3708,9 +5813,13 @@ pub(crate) const X86_64_UNKNOWN_OPENBSD: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::OpenBSD,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// Redox OS
@@ -3719,9 +5828,13 @@ pub(crate) const X86_64_UNKNOWN_REDOX: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Redox,
     target_env: Env::Relibc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const X86_64_UNKNOWN_TRUSTY: Platform = Platform {
@@ -3729,9 +5842,13 @@ pub(crate) const X86_64_UNKNOWN_TRUSTY: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Trusty,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &["crt-static", "sse", "sse2", "x87"],
+    target_vendor: "unknown",
 };
 
 /// 64-bit UEFI
@@ -3740,9 +5857,13 @@ pub(crate) const X86_64_UNKNOWN_UEFI: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Uefi,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Two,
+    target_families: &[],
+    target_features: &["fxsr", "x87"],
+    target_vendor: "unknown",
 };
 
 pub(crate) const X86_64_UWP_WINDOWS_GNU: Platform = Platform {
@@ -3750,9 +5871,21 @@ pub(crate) const X86_64_UWP_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::Uwp,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "x87",
+    ],
+    target_vendor: "uwp",
 };
 
 pub(crate) const X86_64_UWP_WINDOWS_MSVC: Platform = Platform {
@@ -3760,9 +5893,21 @@ pub(crate) const X86_64_UWP_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::Uwp,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &[
+        "cmpxchg16b",
+        "fxsr",
+        "lahfsahf",
+        "sse",
+        "sse2",
+        "sse3",
+        "x87",
+    ],
+    target_vendor: "uwp",
 };
 
 /// 64-bit Windows 7 support
@@ -3771,9 +5916,13 @@ pub(crate) const X86_64_WIN7_WINDOWS_GNU: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "win7",
 };
 
 /// 64-bit Windows 7 support
@@ -3782,9 +5931,13 @@ pub(crate) const X86_64_WIN7_WINDOWS_MSVC: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::Windows,
     target_env: Env::Msvc,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["windows"],
+    target_features: &["fxsr", "sse", "sse2", "x87"],
+    target_vendor: "win7",
 };
 
 pub(crate) const X86_64_WRS_VXWORKS: Platform = Platform {
@@ -3792,9 +5945,13 @@ pub(crate) const X86_64_WRS_VXWORKS: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::VxWorks,
     target_env: Env::Gnu,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U64,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    target_vendor: "wrs",
 };
 
 /// macOS with late-gen Intel (at least Haswell)
@@ -3803,9 +5960,36 @@ pub(crate) const X86_64H_APPLE_DARWIN: Platform = Platform {
     target_arch: Arch::X86_64,
     target_os: OS::MacOS,
     target_env: Env::None,
-    target_endian: Endian::Little,
-    target_pointer_width: PointerWidth::U64,
-    tier: Tier::Three,
+    target_abi: Abi::None,
+    target_endian: Endian::Little,
+    target_pointer_width: PointerWidth::U64,
+    tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[
+        "avx",
+        "avx2",
+        "bmi1",
+        "bmi2",
+        "cmpxchg16b",
+        "ermsb",
+        "f16c",
+        "fma",
+        "fxsr",
+        "lahfsahf",
+        "lzcnt",
+        "movbe",
+        "popcnt",
+        "sse",
+        "sse2",
+        "sse3",
+        "sse4.1",
+        "sse4.2",
+        "ssse3",
+        "x87",
+        "xsave",
+        "xsaveopt",
+    ],
+    target_vendor: "apple",
 };
 
 /// Xtensa ESP32
@@ -3814,9 +5998,13 @@ pub(crate) const XTENSA_ESP32_ESPIDF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "espressif",
 };
 
 /// Xtensa ESP32
@@ -3825,9 +6013,13 @@ pub(crate) const XTENSA_ESP32_NONE_ELF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "espressif",
 };
 
 /// Xtensa ESP32-S2
@@ -3836,9 +6028,13 @@ pub(crate) const XTENSA_ESP32S2_ESPIDF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "espressif",
 };
 
 /// Xtensa ESP32-S2
@@ -3847,9 +6043,13 @@ pub(crate) const XTENSA_ESP32S2_NONE_ELF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "espressif",
 };
 
 /// Xtensa ESP32-S3
@@ -3858,9 +6058,13 @@ pub(crate) const XTENSA_ESP32S3_ESPIDF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::Espidf,
     target_env: Env::Newlib,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &["unix"],
+    target_features: &[],
+    target_vendor: "espressif",
 };
 
 /// Xtensa ESP32-S3
@@ -3869,7 +6073,11 @@ pub(crate) const XTENSA_ESP32S3_NONE_ELF: Platform = Platform {
     target_arch: Arch::Xtensa,
     target_os: OS::None,
     target_env: Env::None,
+    target_abi: Abi::None,
     target_endian: Endian::Little,
     target_pointer_width: PointerWidth::U32,
     tier: Tier::Three,
+    target_families: &[],
+    target_features: &[],
+    target_vendor: "espressif",
 };
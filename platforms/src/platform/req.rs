@@ -14,17 +14,22 @@ use serde::{de, ser, Deserialize, Serialize};
 ///
 /// <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
 ///
-/// Platforms can be grouped with simple globbing rules:
+/// Platforms can be grouped with simple globbing rules, where `*` matches any
+/// sequence of characters and `?` matches a single character:
 ///
 /// - Start with wildcard: `*-gnu`
 /// - End with wildcard: `x86_64-*`
 /// - Start and end with wildcard: `*windows*`
+/// - Wildcards in the middle: `aarch64-*-linux-musl`, `i?86-*`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PlatformReq(String);
 
 /// Wildcard character used for globbing
 pub const WILDCARD: char = '*';
 
+/// Wildcard character matching a single character
+const SINGLE_WILDCARD: u8 = b'?';
+
 impl PlatformReq {
     /// Borrow this platform requirement as a string slice
     pub fn as_str(&self) -> &str {
@@ -37,36 +42,7 @@ impl PlatformReq {
     /// `x86_64-unknown-linux-gnu` and matches it against this
     /// `Platform`, using simple glob like rules.
     pub fn matches(&self, platform: &Platform) -> bool {
-        let self_len = self.as_str().len();
-
-        // Universal matcher
-        if self.0.len() == 1 && self.0.chars().next().unwrap() == WILDCARD {
-            return true;
-        }
-
-        let mut chars = self.as_str().chars();
-        let starts_with_wildcard = chars.next().unwrap() == WILDCARD;
-        let ends_with_wildcard = chars.last() == Some(WILDCARD);
-
-        if starts_with_wildcard {
-            if ends_with_wildcard {
-                // Contains expression: `*windows*`
-                platform
-                    .target_triple
-                    .contains(&self.0[1..self_len.checked_sub(1).unwrap()])
-            } else {
-                // Suffix expression: `*-gnu`
-                platform.target_triple.ends_with(&self.0[1..])
-            }
-        } else if ends_with_wildcard {
-            // Prefix expression: `x86_64-*`
-            platform
-                .target_triple
-                .starts_with(&self.0[..self_len.checked_sub(1).unwrap()])
-        } else {
-            // No wildcards: direct comparison
-            self.as_str() == platform.target_triple
-        }
+        glob_match(self.as_str(), platform.target_triple)
     }
 
     /// Expand glob expressions into a list of all known matching platforms
@@ -75,6 +51,42 @@ impl PlatformReq {
     }
 }
 
+/// Does the given glob pattern match the whole of `text`?
+///
+/// Target triples are ASCII, so this works on bytes, backtracking to the
+/// last `*` on mismatches.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let (pattern, text) = (pattern.as_bytes(), text.as_bytes());
+    let (mut p, mut t) = (0, 0);
+
+    // Positions in the pattern and the text after the last `*` seen
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(&c) if c == WILDCARD as u8 => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(&c) if c == SINGLE_WILDCARD || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` match one more character
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == WILDCARD as u8)
+}
+
 // Split into its own function for unit testing
 #[inline]
 fn matching_platforms<'a>(
@@ -90,7 +102,7 @@ impl FromStr for PlatformReq {
     type Err = Error;
 
     /// Create a new platform requirement. Platforms support glob-like
-    /// wildcards on the beginning and end, e.g. `*windows*`.
+    /// wildcards, e.g. `*windows*` or `aarch64-*-linux-musl`.
     ///
     /// Must match at least one known Rust platform "target triple"
     /// (e.g. `x86_64-unknown-linux-gnu`) to be considered valid.
@@ -209,6 +221,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn middle_glob_test() {
+        let req = PlatformReq::from_str("*-uwp-*-msvc").unwrap();
+
+        assert_eq!(
+            matching_platforms(&req, TEST_PLATFORM_LIST)
+                .map(|p| p.target_triple)
+                .collect::<Vec<_>>(),
+            [
+                "aarch64-uwp-windows-msvc",
+                "i686-uwp-windows-msvc",
+                "thumbv7a-uwp-windows-msvc"
+            ]
+        );
+
+        let req = PlatformReq::from_str("armv7-*-musleabi*").unwrap();
+
+        assert_eq!(
+            matching_platforms(&req, TEST_PLATFORM_LIST)
+                .map(|p| p.target_triple)
+                .collect::<Vec<_>>(),
+            [
+                "armv7-unknown-linux-musleabi",
+                "armv7-unknown-linux-musleabihf"
+            ]
+        );
+    }
+
+    #[test]
+    fn single_wildcard_test() {
+        let req = PlatformReq::from_str("i686-???-windows-*").unwrap();
+
+        assert_eq!(
+            matching_platforms(&req, TEST_PLATFORM_LIST)
+                .map(|p| p.target_triple)
+                .collect::<Vec<_>>(),
+            ["i686-uwp-windows-gnu", "i686-uwp-windows-msvc"]
+        );
+    }
+
+    #[test]
+    fn glob_match_test() {
+        assert!(glob_match(
+            "*-unknown-linux-musl",
+            "aarch64-unknown-linux-musl"
+        ));
+        assert!(glob_match("aarch64-*", "aarch64-unknown-linux-musl"));
+        assert!(glob_match("a*a*a", "aaa"));
+        assert!(glob_match("*-*-*-*", "x86_64-unknown-linux-gnu"));
+        assert!(!glob_match("*-*-*-*", "x86_64-apple-darwin"));
+        assert!(!glob_match("a*a*a", "aa"));
+        assert!(!glob_match("x86_64-?", "x86_64-apple-darwin"));
+    }
+
     #[test]
     fn direct_match_test() {
         let req = PlatformReq::from_str("x86_64-unknown-dragonfly").unwrap();
@@ -227,11 +293,14 @@ mod tests {
         assert_eq!(req.matching_platforms().count(), Platform::ALL.len())
     }
 
-    // How to handle this is debatable...
+    // Runs of wildcards are equivalent to a single one
     #[test]
     fn double_wildcard_test() {
         let req = PlatformReq::from_str("**").unwrap();
-        assert_eq!(req.matching_platforms().count(), Platform::ALL.len())
+        assert_eq!(req.matching_platforms().count(), Platform::ALL.len());
+
+        let req = PlatformReq::from_str("***").unwrap();
+        assert_eq!(req.matching_platforms().count(), Platform::ALL.len());
     }
 
     #[test]
@@ -239,6 +308,6 @@ mod tests {
         assert!(PlatformReq::from_str("").is_err());
         assert!(PlatformReq::from_str(" ").is_err());
         assert!(PlatformReq::from_str("derp").is_err());
-        assert!(PlatformReq::from_str("***").is_err());
+        assert!(PlatformReq::from_str("*-derp-*").is_err());
     }
 }
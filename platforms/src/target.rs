@@ -3,10 +3,13 @@
 //!
 //! <https://doc.rust-lang.org/reference/attributes.html#conditional-compilation>
 
+mod abi;
 mod arch;
 mod endian;
 mod env;
 mod os;
 mod pointerwidth;
 
-pub use self::{arch::Arch, endian::Endian, env::Env, os::OS, pointerwidth::PointerWidth};
+pub use self::{
+    abi::Abi, arch::Arch, endian::Endian, env::Env, os::OS, pointerwidth::PointerWidth,
+};
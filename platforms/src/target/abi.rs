@@ -0,0 +1,158 @@
+//! Rust target ABIs
+
+use crate::error::Error;
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, de::Error as DeError, ser, Deserialize, Serialize};
+
+/// `target_abi`: ABI component of the target triple which isn't part of `target_env`.
+///
+/// Target triples like `armv7-unknown-linux-gnueabihf` or `aarch64-unknown-none-softfloat`
+/// encode the ABI as a suffix, which rustc exposes separately from the environment: here
+/// `target_env` is `"gnu"` (resp. `""`) and `target_abi` is `"eabihf"` (resp. `"softfloat"`).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Abi {
+    /// ``: None
+    None,
+
+    /// `abi64`: MIPS N64 ABI
+    Abi64,
+
+    /// `abiv2`
+    Abiv2,
+
+    /// `abiv2hf`
+    Abiv2hf,
+
+    /// `eabi`: ARM Embedded ABI with software floating point calling convention
+    Eabi,
+
+    /// `eabihf`: ARM Embedded ABI with hardware floating point calling convention
+    Eabihf,
+
+    /// `elfv1`: PowerPC 64-bit ELF ABI version 1
+    Elfv1,
+
+    /// `elfv2`: PowerPC 64-bit ELF ABI version 2
+    Elfv2,
+
+    /// `fortanix`
+    Fortanix,
+
+    /// `ilp32`: 32-bit integers, longs and pointers on a 64-bit architecture
+    Ilp32,
+
+    /// `ilp32e`
+    Ilp32e,
+
+    /// `llvm`
+    Llvm,
+
+    /// `macabi`
+    Macabi,
+
+    /// `sim`
+    Sim,
+
+    /// `softfloat`: Floating point operations implemented in software
+    Softfloat,
+
+    /// `spe`: PowerPC Signal Processing Engine
+    Spe,
+
+    /// `uwp`: Universal Windows Platform
+    Uwp,
+
+    /// `vec-extabi`
+    VecExtabi,
+
+    /// `x32`: 32-bit pointers on x86_64
+    X32,
+}
+
+impl Abi {
+    /// String representing this `Abi` which matches `#[cfg(target_abi)]`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Abi::None => "",
+            Abi::Abi64 => "abi64",
+            Abi::Abiv2 => "abiv2",
+            Abi::Abiv2hf => "abiv2hf",
+            Abi::Eabi => "eabi",
+            Abi::Eabihf => "eabihf",
+            Abi::Elfv1 => "elfv1",
+            Abi::Elfv2 => "elfv2",
+            Abi::Fortanix => "fortanix",
+            Abi::Ilp32 => "ilp32",
+            Abi::Ilp32e => "ilp32e",
+            Abi::Llvm => "llvm",
+            Abi::Macabi => "macabi",
+            Abi::Sim => "sim",
+            Abi::Softfloat => "softfloat",
+            Abi::Spe => "spe",
+            Abi::Uwp => "uwp",
+            Abi::VecExtabi => "vec-extabi",
+            Abi::X32 => "x32",
+        }
+    }
+}
+
+impl FromStr for Abi {
+    type Err = Error;
+
+    /// Create a new `Abi` from the given string
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let result = match name {
+            "" => Abi::None,
+            "abi64" => Abi::Abi64,
+            "abiv2" => Abi::Abiv2,
+            "abiv2hf" => Abi::Abiv2hf,
+            "eabi" => Abi::Eabi,
+            "eabihf" => Abi::Eabihf,
+            "elfv1" => Abi::Elfv1,
+            "elfv2" => Abi::Elfv2,
+            "fortanix" => Abi::Fortanix,
+            "ilp32" => Abi::Ilp32,
+            "ilp32e" => Abi::Ilp32e,
+            "llvm" => Abi::Llvm,
+            "macabi" => Abi::Macabi,
+            "sim" => Abi::Sim,
+            "softfloat" => Abi::Softfloat,
+            "spe" => Abi::Spe,
+            "uwp" => Abi::Uwp,
+            "vec-extabi" => Abi::VecExtabi,
+            "x32" => Abi::X32,
+            _ => return Err(Error),
+        };
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Abi {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = std::string::String::deserialize(deserializer)?;
+        string.parse().map_err(|_| {
+            D::Error::custom(std::format!(
+                "Unrecognized value '{}' for target_abi",
+                string
+            ))
+        })
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod affected;
 mod category;
+mod cwe;
 mod date;
 mod id;
 mod informational;
@@ -11,10 +12,12 @@ pub mod linter;
 mod metadata;
 mod parts;
 pub(crate) mod versions;
+mod writer;
 
 pub use self::{
     affected::Affected,
     category::Category,
+    cwe::CweId,
     date::Date,
     id::{Id, IdKind},
     informational::Informational,
@@ -51,8 +54,17 @@ pub struct Advisory {
 impl Advisory {
     /// Load an advisory from a `RUSTSEC-20XX-NNNN.md` file
     pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let path = path.as_ref();
+        Self::load(path.as_ref(), true)
+    }
+
+    /// Load an advisory from a file without keeping its description, which is
+    /// left empty
+    pub(crate) fn load_file_without_description(path: &Path) -> Result<Self, Error> {
+        Self::load(path, false)
+    }
 
+    /// Load an advisory from a file, with or without its description
+    fn load(path: &Path, description: bool) -> Result<Self, Error> {
         let advisory_data = fs::read_to_string(path).map_err(|e| {
             Error::with_source(
                 ErrorKind::Io,
@@ -61,7 +73,7 @@ impl Advisory {
             )
         })?;
 
-        advisory_data.parse().map_err(|e| {
+        Self::parse(&advisory_data, description).map_err(|e| {
             Error::with_source(
                 ErrorKind::Parse,
                 format!("error parsing {}", path.display()),
@@ -70,6 +82,45 @@ impl Advisory {
         })
     }
 
+    /// Parse a Markdown advisory, with or without its description
+    fn parse(advisory_data: &str, description: bool) -> Result<Self, Error> {
+        let parts = Parts::parse(advisory_data)?;
+
+        // V4 advisories omit the leading `[advisory]` TOML table
+        let front_matter = if parts.front_matter.starts_with("[advisory]") {
+            parts.front_matter.to_owned()
+        } else {
+            String::from("[advisory]\n") + parts.front_matter
+        };
+
+        let mut advisory: Self = toml::from_str(&front_matter).map_err(Error::from_toml)?;
+
+        if !advisory.metadata.title.is_empty() {
+            fail!(
+                ErrorKind::Parse,
+                "invalid `title` attribute in advisory TOML"
+            );
+        }
+
+        if !advisory.metadata.description.is_empty() {
+            fail!(
+                ErrorKind::Parse,
+                "invalid `description` attribute in advisory TOML"
+            );
+        }
+
+        #[allow(clippy::assigning_clones)]
+        {
+            advisory.metadata.title = parts.title.to_owned();
+        }
+
+        if description {
+            advisory.metadata.description = parts.description.to_owned();
+        }
+
+        Ok(advisory)
+    }
+
     /// Get advisory ID
     pub fn id(&self) -> &Id {
         &self.metadata.id
@@ -90,9 +141,14 @@ impl Advisory {
         &self.metadata.date
     }
 
-    /// Get the severity of this advisory if it has a CVSS v3 associated
+    /// Get the severity of this advisory, computed from its CVSS vector if it
+    /// has one, or its qualitative severity rating otherwise
     pub fn severity(&self) -> Option<Severity> {
-        self.metadata.cvss.as_ref().map(|cvss| cvss.severity())
+        self.metadata
+            .cvss
+            .as_ref()
+            .map(|cvss| cvss.severity())
+            .or(self.metadata.severity)
     }
 
     /// Whether the advisory has been withdrawn, i.e. soft-deleted
@@ -100,6 +156,28 @@ impl Advisory {
         self.metadata.withdrawn.is_some()
     }
 
+    /// Render this advisory in the Markdown format of the advisory database,
+    /// i.e. TOML front matter followed by the title and description.
+    ///
+    /// Parsing the result gives back the same advisory. Optional fields are
+    /// omitted when they're empty.
+    pub fn to_markdown(&self) -> String {
+        writer::write(self).expect("advisory fields are valid TOML values")
+    }
+
+    /// Write this advisory to a `RUSTSEC-20XX-NNNN.md` file
+    pub fn write_file(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        fs::write(path, self.to_markdown()).map_err(|e| {
+            Error::with_source(
+                ErrorKind::Io,
+                format!("couldn't write {}", path.display()),
+                e,
+            )
+        })
+    }
+
     /// Whether the given `path` represents a draft advisory
     pub fn is_draft(path: &Path) -> bool {
         matches!(
@@ -113,37 +191,6 @@ impl FromStr for Advisory {
     type Err = Error;
 
     fn from_str(advisory_data: &str) -> Result<Self, Error> {
-        let parts = Parts::parse(advisory_data)?;
-
-        // V4 advisories omit the leading `[advisory]` TOML table
-        let front_matter = if parts.front_matter.starts_with("[advisory]") {
-            parts.front_matter.to_owned()
-        } else {
-            String::from("[advisory]\n") + parts.front_matter
-        };
-
-        let mut advisory: Self = toml::from_str(&front_matter).map_err(Error::from_toml)?;
-
-        if !advisory.metadata.title.is_empty() {
-            fail!(
-                ErrorKind::Parse,
-                "invalid `title` attribute in advisory TOML"
-            );
-        }
-
-        if !advisory.metadata.description.is_empty() {
-            fail!(
-                ErrorKind::Parse,
-                "invalid `description` attribute in advisory TOML"
-            );
-        }
-
-        #[allow(clippy::assigning_clones)]
-        {
-            advisory.metadata.title = parts.title.to_owned();
-            advisory.metadata.description = parts.description.to_owned();
-        }
-
-        Ok(advisory)
+        Self::parse(advisory_data, true)
     }
 }
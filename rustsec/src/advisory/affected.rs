@@ -6,7 +6,7 @@ use crate::{
     error::{Error, ErrorKind},
 };
 use platforms::target::{Arch, OS};
-use semver::VersionReq;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
 use std::{
     fmt::{self, Display},
@@ -36,6 +36,37 @@ pub struct Affected {
     pub functions: Map<FunctionPath, Vec<VersionReq>>,
 }
 
+impl Affected {
+    /// Iterate over the affected functions, along with the versions of the
+    /// crate in which each of them is vulnerable
+    pub fn functions(&self) -> impl Iterator<Item = (&FunctionPath, &[VersionReq])> {
+        self.functions
+            .iter()
+            .map(|(path, versions)| (path, versions.as_slice()))
+    }
+
+    /// Iterate over the functions which are vulnerable in the given version
+    /// of the crate
+    pub fn functions_for<'a>(
+        &'a self,
+        version: &'a Version,
+    ) -> impl Iterator<Item = &'a FunctionPath> {
+        self.functions()
+            .filter(|(_, versions)| versions.iter().any(|req| req.matches(version)))
+            .map(|(path, _)| path)
+    }
+
+    /// Is the given item vulnerable in the given version of the crate?
+    ///
+    /// This is the case when it's listed as affected, when it's within an
+    /// affected type or module, or when it's a type or module containing an
+    /// affected function.
+    pub fn affects_function(&self, path: &FunctionPath, version: &Version) -> bool {
+        self.functions_for(version)
+            .any(|affected| affected.overlaps(path))
+    }
+}
+
 /// Canonical Rust Paths (sans parameters) to vulnerable types and/or functions
 /// affected by a particular advisory.
 /// <https://doc.rust-lang.org/reference/paths.html#canonical-paths>
@@ -65,6 +96,30 @@ impl FunctionPath {
     pub fn segments(&self) -> &[Identifier] {
         self.0.as_slice()
     }
+
+    /// Get the last segment of this path, i.e. the name of the function or type
+    pub fn name(&self) -> &Identifier {
+        self.0.last().expect("path must have 2 or more segments")
+    }
+
+    /// Get the path of the type or module containing this item, unless it's
+    /// at the root of the crate
+    pub fn parent(&self) -> Option<FunctionPath> {
+        let segments = &self.0[..self.0.len() - 1];
+        (segments.len() >= 2).then(|| FunctionPath(segments.to_vec()))
+    }
+
+    /// Is this path equal to `prefix`, or an item within it?
+    ///
+    /// (e.g. `mycrate::Struct::func` starts with `mycrate::Struct`)
+    pub fn starts_with(&self, prefix: &FunctionPath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Is either of these paths equal to the other, or an item within it?
+    pub fn overlaps(&self, other: &FunctionPath) -> bool {
+        self.starts_with(other) || other.starts_with(self)
+    }
 }
 
 impl Display for FunctionPath {
@@ -204,6 +259,21 @@ mod tests {
         assert_eq!(path.to_string(), EXAMPLE_PATH_STR)
     }
 
+    #[test]
+    fn prefix_test() {
+        let path = FunctionPath::from_str(EXAMPLE_PATH_STR).unwrap();
+        assert_eq!(path.name().as_str(), "baz");
+
+        let parent = path.parent().unwrap();
+        assert_eq!(parent.to_string(), "foo::bar");
+        assert!(parent.parent().is_none());
+
+        assert!(path.starts_with(&parent));
+        assert!(path.starts_with(&path));
+        assert!(!parent.starts_with(&path));
+        assert!(!path.starts_with(&FunctionPath::from_str("foo::ba").unwrap()));
+    }
+
     #[test]
     fn from_str_test() {
         // Valid paths
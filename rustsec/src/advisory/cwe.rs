@@ -0,0 +1,85 @@
+//! Common Weakness Enumeration (CWE) identifiers
+
+use crate::error::{Error, ErrorKind};
+use serde::{Deserialize, Serialize, de, ser};
+use std::{fmt, str::FromStr};
+
+/// Prefix of CWE identifiers
+const PREFIX: &str = "CWE-";
+
+/// Identifier of a weakness type in MITRE's [Common Weakness Enumeration][1],
+/// e.g. `CWE-416` for use-after-free.
+///
+/// [1]: https://cwe.mitre.org/
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CweId(u32);
+
+impl CweId {
+    /// Create a CWE identifier from its number
+    pub fn new(number: u32) -> Self {
+        CweId(number)
+    }
+
+    /// Get the number of this weakness, e.g. `416` for `CWE-416`
+    pub fn number(self) -> u32 {
+        self.0
+    }
+
+    /// Get the URL of this weakness on cwe.mitre.org
+    pub fn url(self) -> String {
+        format!("https://cwe.mitre.org/data/definitions/{}.html", self.0)
+    }
+}
+
+impl fmt::Display for CweId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PREFIX}{}", self.0)
+    }
+}
+
+impl FromStr for CweId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let number = s
+            .strip_prefix(PREFIX)
+            .filter(|number| !number.starts_with(['+', '0']))
+            .and_then(|number| number.parse().ok());
+
+        match number {
+            Some(number) => Ok(CweId(number)),
+            None => fail!(ErrorKind::Parse, "malformed CWE identifier: {}", s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CweId {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for CweId {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CweId;
+
+    #[test]
+    fn parse() {
+        let cwe = "CWE-416".parse::<CweId>().unwrap();
+        assert_eq!(cwe.number(), 416);
+        assert_eq!(cwe.to_string(), "CWE-416");
+        assert_eq!(cwe.url(), "https://cwe.mitre.org/data/definitions/416.html");
+
+        for malformed in ["416", "cwe-416", "CWE-", "CWE-0416", "CWE-+416", "CWE-41a"] {
+            assert!(malformed.parse::<CweId>().is_err(), "{malformed}");
+        }
+    }
+}
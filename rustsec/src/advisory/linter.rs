@@ -216,8 +216,19 @@ impl Linter {
                             }
                         }
                     }
-                    "aliases" | "cvss" | "keywords" | "package" | "references" | "related"
-                    | "title" | "withdrawn" | "description" | "expect-deleted" => (),
+                    "severity" => {
+                        if table.contains_key("cvss") {
+                            self.errors.push(Error {
+                                kind: ErrorKind::key(key),
+                                section: Some("advisory"),
+                                message: Some(
+                                    "Field `severity` is only allowed for advisories without a `cvss` vector",
+                                ),
+                            });
+                        }
+                    }
+                    "aliases" | "cvss" | "cwe-ids" | "keywords" | "package" | "references"
+                    | "related" | "title" | "withdrawn" | "description" | "expect-deleted" => (),
                     _ => self.errors.push(Error {
                         kind: ErrorKind::key(key),
                         section: Some("advisory"),
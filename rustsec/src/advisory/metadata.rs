@@ -1,7 +1,8 @@
 //! Advisory information (i.e. the `[advisory]` section)
 
 use super::{
-    category::Category, date::Date, id::Id, informational::Informational, keyword::Keyword,
+    category::Category, cwe::CweId, date::Date, id::Id, informational::Informational,
+    keyword::Keyword,
 };
 use crate::advisory::license::License;
 use crate::{SourceId, collection::Collection, package};
@@ -53,6 +54,11 @@ pub struct Metadata {
     #[serde(default)]
     pub keywords: Vec<Keyword>,
 
+    /// Common Weakness Enumeration identifiers of the weaknesses involved
+    /// (e.g. `CWE-416` for use-after-free)
+    #[serde(rename = "cwe-ids", default)]
+    pub cwe_ids: Vec<CweId>,
+
     /// CVSS v3.1 Base Metrics vector string containing severity information.
     ///
     /// Example:
@@ -62,6 +68,13 @@ pub struct Metadata {
     /// ```
     pub cvss: Option<Cvss>,
 
+    /// Qualitative severity rating (i.e. `low`, `medium`, `high` or `critical`)
+    /// for vulnerabilities whose source doesn't provide a CVSS vector.
+    ///
+    /// Only used when `cvss` isn't set, as the severity is otherwise derived
+    /// from the CVSS score.
+    pub severity: Option<cvss::Severity>,
+
     /// Informational advisories can be used to warn users about issues
     /// affecting a particular crate without failing the build.
     pub informational: Option<Informational>,
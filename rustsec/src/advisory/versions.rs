@@ -1,6 +1,9 @@
 //! The `[versions]` subsection of an advisory.
 
-use crate::{Error, osv};
+use crate::{
+    Error,
+    osv::{self, OsvRange},
+};
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 
@@ -47,6 +50,36 @@ impl Versions {
     pub fn unaffected(&self) -> &[VersionReq] {
         self.unaffected.as_slice()
     }
+
+    /// Ranges of versions which are vulnerable, sorted and without overlaps.
+    pub fn affected_ranges(&self) -> Vec<OsvRange> {
+        osv::normalize(osv::ranges_for_advisory(self))
+    }
+
+    /// Ranges of versions which aren't vulnerable (i.e. patched or unaffected),
+    /// sorted and without overlaps.
+    ///
+    /// This is the complement of [`Versions::affected_ranges`].
+    pub fn unaffected_ranges(&self) -> Vec<OsvRange> {
+        osv::complement(&self.affected_ranges())
+    }
+
+    /// Ranges of versions which are vulnerable to both this and the `other` advisory
+    pub fn intersection(&self, other: &Versions) -> Vec<OsvRange> {
+        osv::intersection(&self.affected_ranges(), &other.affected_ranges())
+    }
+
+    /// Ranges of versions which are vulnerable to either this or the `other` advisory
+    pub fn union(&self, other: &Versions) -> Vec<OsvRange> {
+        let mut ranges = self.affected_ranges();
+        ranges.extend(other.affected_ranges());
+        osv::normalize(ranges)
+    }
+
+    /// Is any version vulnerable to both this and the `other` advisory?
+    pub fn overlaps(&self, other: &Versions) -> bool {
+        !self.intersection(other).is_empty()
+    }
 }
 
 impl TryFrom<RawVersions> for Versions {
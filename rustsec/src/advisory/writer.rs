@@ -0,0 +1,132 @@
+//! Writing advisories in the Markdown format with TOML front matter
+//! (a.k.a. "V4 advisory format")
+
+use super::{Advisory, License};
+use crate::Map;
+use semver::{Op, VersionReq};
+use serde::Serialize;
+use std::fmt::{self, Write};
+
+/// Writes the TOML front matter of an advisory, one key at a time
+struct FrontMatter<'a> {
+    /// Output being written to
+    out: &'a mut String,
+}
+
+impl FrontMatter<'_> {
+    /// Write a `key = value` line
+    fn key(&mut self, key: &str, value: &impl Serialize) -> fmt::Result {
+        let value = toml::Value::try_from(value).map_err(|_| fmt::Error)?;
+        writeln!(self.out, "{key} = {value}")
+    }
+
+    /// Write a `key = value` line, unless the value is empty
+    fn optional<T: Serialize>(&mut self, key: &str, value: &[T]) -> fmt::Result {
+        if value.is_empty() {
+            return Ok(());
+        }
+        self.key(key, &value)
+    }
+
+    /// Write a table header
+    fn table(&mut self, name: &str) -> fmt::Result {
+        writeln!(self.out, "\n[{name}]")
+    }
+}
+
+/// Write the given advisory in the format used by the advisory database
+pub(super) fn write(advisory: &Advisory) -> Result<String, fmt::Error> {
+    let metadata = &advisory.metadata;
+    let mut out = String::from("```toml\n");
+    let mut toml = FrontMatter { out: &mut out };
+
+    toml.key("id", &metadata.id.as_str())?;
+    toml.key("package", &metadata.package)?;
+    toml.key("date", &metadata.date)?;
+    if let Some(url) = &metadata.url {
+        toml.key("url", url)?;
+    }
+    toml.optional("references", &metadata.references)?;
+    if let Some(informational) = &metadata.informational {
+        toml.key("informational", &informational.as_str())?;
+    }
+    toml.optional("categories", &metadata.categories)?;
+    toml.optional("keywords", &metadata.keywords)?;
+    toml.optional("cwe-ids", &metadata.cwe_ids)?;
+    toml.optional("aliases", &metadata.aliases)?;
+    toml.optional("related", &metadata.related)?;
+    if let Some(cvss) = &metadata.cvss {
+        toml.key("cvss", &cvss.to_string())?;
+    }
+    if let Some(severity) = &metadata.severity {
+        toml.key("severity", &severity.as_str())?;
+    }
+    if let Some(source) = &metadata.source {
+        toml.key("source", source)?;
+    }
+    if let Some(withdrawn) = &metadata.withdrawn {
+        toml.key("withdrawn", withdrawn)?;
+    }
+    if metadata.license != License::default() {
+        toml.key("license", &metadata.license)?;
+    }
+    if metadata.expect_deleted {
+        toml.key("expect-deleted", &true)?;
+    }
+
+    if let Some(affected) = advisory
+        .affected
+        .as_ref()
+        .filter(|affected| **affected != Default::default())
+    {
+        toml.table("affected")?;
+        toml.optional("arch", &affected.arch)?;
+        toml.optional("os", &affected.os)?;
+        if !affected.functions.is_empty() {
+            let functions = affected
+                .functions
+                .iter()
+                .map(|(path, versions)| (path.to_string(), format_reqs(versions)))
+                .collect::<Map<_, _>>();
+            toml.key("functions", &functions)?;
+        }
+    }
+
+    toml.table("versions")?;
+    toml.key("patched", &format_reqs(advisory.versions.patched()))?;
+    toml.optional("unaffected", &format_reqs(advisory.versions.unaffected()))?;
+
+    write!(
+        out,
+        "```\n\n# {}\n\n{}\n",
+        metadata.title.trim(),
+        metadata.description.trim()
+    )?;
+
+    Ok(out)
+}
+
+/// Format version requirements the way they're written in advisories,
+/// e.g. `">= 1.2.3"` rather than `">=1.2.3"`
+fn format_reqs(reqs: &[VersionReq]) -> Vec<String> {
+    reqs.iter()
+        .map(|req| {
+            let comparators = req
+                .comparators
+                .iter()
+                .map(|comparator| {
+                    let op = match comparator.op {
+                        Op::GreaterEq => ">= ",
+                        Op::Greater => "> ",
+                        Op::LessEq => "<= ",
+                        Op::Less => "< ",
+                        _ => return comparator.to_string(),
+                    };
+                    let comparator = comparator.to_string();
+                    format!("{op}{}", comparator.trim_start_matches(['>', '<', '=']))
+                })
+                .collect::<Vec<_>>();
+            comparators.join(", ")
+        })
+        .collect()
+}
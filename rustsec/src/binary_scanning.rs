@@ -6,14 +6,26 @@
 // enabled unconditionally due to being able to name the type being useful
 // and this type alone not pulling in any additional dependencies
 mod binary_format;
+mod confidence;
 pub use binary_format::*;
+pub use confidence::*;
 
 #[cfg(feature = "binary-scanning")]
 mod binary_deps;
 #[cfg(feature = "binary-scanning")]
 mod binary_type_filter;
+#[cfg(feature = "binary-scanning")]
+mod bundled_libraries;
+#[cfg(feature = "binary-scanning")]
+mod rustc_version;
+#[cfg(feature = "binary-scanning")]
+mod source_paths;
 
 #[cfg(feature = "binary-scanning")]
 pub use binary_deps::*;
 #[cfg(feature = "binary-scanning")]
 pub use binary_type_filter::*;
+#[cfg(feature = "binary-scanning")]
+pub use bundled_libraries::*;
+#[cfg(feature = "binary-scanning")]
+pub use rustc_version::*;
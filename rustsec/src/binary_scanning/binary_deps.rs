@@ -1,57 +1,185 @@
 //! Extracts the list of dependencies from a binary file
 
-use std::str::FromStr;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
 
 use crate::{Error, ErrorKind};
 use auditable_serde::VersionInfo;
 use cargo_lock::{Dependency, Lockfile, Package};
+use object::read::archive::ArchiveFile;
 
-use crate::binary_scanning::BinaryFormat;
+use crate::binary_scanning::{BinaryFormat, Confidence, source_paths};
 
 /// The default file size limit is 8MB
 const DEFAULT_FILE_SIZE_LIMIT: usize = 8 * 1024 * 1024;
 
+/// Magic number of `ar` archives, which static libraries and `.rlib`s are
+const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+
 /// Dependencies recovered from scanning a compiled Rust executable
 pub enum BinaryReport {
     /// Full dependency list embedded by `cargo auditable`
     Complete(Lockfile),
-    /// Partially recovered dependencies from panic messages
+    /// Partially recovered dependencies from panic messages, source paths and debug info
     Incomplete(Lockfile),
     /// No data found whatsoever, probably not a Rust executable
     None,
 }
 
+/// A compiled binary along with the dependencies recovered from it
+pub struct ScannedBinary {
+    /// Format of the binary
+    pub format: BinaryFormat,
+
+    /// Dependencies recovered from the binary
+    pub report: BinaryReport,
+
+    /// How reliably each of the dependencies in the report was recovered
+    pub confidence: BTreeMap<Dependency, Confidence>,
+}
+
 /// Load the dependency tree from a compiled Rust executable.
 ///
+/// See [`scan_binary`] for how reliably each of the dependencies was recovered.
+///
 /// Recovers the precise dependency list if the binary is built with [`cargo auditable`](https://crates.io/crates/cargo-auditable).
-/// Failing that, recovers as many crates as possible from panic messages (using [quitters]).
+/// Failing that, recovers as many crates as possible from panic messages (using [quitters]),
+/// and from the source paths in symbol paths and DWARF debug info.
+///
+/// Static libraries (`.a`) and `.rlib`s are supported too, by combining what
+/// is recovered from each of the object files they contain.
 ///
 /// If `audit_data_size_limit` is set to `None`, the limit defaults to 8MB.
 pub fn load_deps_from_binary(
     file_contents: &[u8],
     audit_data_size_limit: Option<usize>,
 ) -> crate::Result<(BinaryFormat, BinaryReport)> {
-    let format = detect_format(file_contents);
+    let scanned = scan_binary(file_contents, audit_data_size_limit)?;
+    Ok((scanned.format, scanned.report))
+}
+
+/// Load the dependency tree from a compiled Rust executable, along with how
+/// reliably each dependency was recovered.
+///
+/// Dependencies listed by `cargo auditable` are [`Confidence::Exact`], while
+/// the ones recovered heuristically get the level of the most reliable
+/// evidence found for them.
+pub fn scan_binary(
+    file_contents: &[u8],
+    audit_data_size_limit: Option<usize>,
+) -> crate::Result<ScannedBinary> {
     let file_size_limit = match audit_data_size_limit {
         Some(size) => size,
         None => DEFAULT_FILE_SIZE_LIMIT,
     };
+
+    if file_contents.starts_with(ARCHIVE_MAGIC) {
+        return load_deps_from_archive(file_contents, file_size_limit);
+    }
+
+    let format = detect_format(file_contents);
+    if let Some(lockfile) = deps_from_audit_data(file_contents, file_size_limit)? {
+        Ok(ScannedBinary::complete(format, lockfile))
+    } else {
+        Ok(ScannedBinary::heuristic(format, [file_contents]))
+    }
+}
+
+impl ScannedBinary {
+    /// Binary with the dependency tree embedded by `cargo auditable`
+    fn complete(format: BinaryFormat, lockfile: Lockfile) -> Self {
+        let confidence = lockfile
+            .packages
+            .iter()
+            .map(|package| (Dependency::from(package), Confidence::Exact))
+            .collect();
+
+        Self {
+            format,
+            report: BinaryReport::Complete(lockfile),
+            confidence,
+        }
+    }
+
+    /// Binary whose dependencies are recovered from the source paths in its object files
+    fn heuristic<'a>(format: BinaryFormat, files: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        match deps_from_source_paths(files) {
+            Some((lockfile, confidence)) => Self {
+                format,
+                report: BinaryReport::Incomplete(lockfile),
+                confidence,
+            },
+            None => Self {
+                format,
+                report: BinaryReport::None,
+                confidence: BTreeMap::new(),
+            },
+        }
+    }
+}
+
+/// Load the dependency tree from the object files in a static library or `.rlib`
+fn load_deps_from_archive(
+    file_contents: &[u8],
+    file_size_limit: usize,
+) -> crate::Result<ScannedBinary> {
+    let archive = ArchiveFile::parse(file_contents).map_err(|e| {
+        Error::with_source(
+            ErrorKind::Parse,
+            "could not parse static library".to_string(),
+            e,
+        )
+    })?;
+
+    let mut format = BinaryFormat::Unknown;
+    let mut members = Vec::new();
+    let mut lockfiles = Vec::new();
+
+    for member in archive.members() {
+        let data = member
+            .and_then(|member| member.data(file_contents))
+            .map_err(|e| {
+                Error::with_source(
+                    ErrorKind::Parse,
+                    "could not read object file from static library".to_string(),
+                    e,
+                )
+            })?;
+
+        // Members that aren't object files (such as the metadata of rlibs) or that
+        // can't be parsed can still contain source paths, so they aren't skipped
+        let member_format = detect_format(data);
+        if member_format != BinaryFormat::Unknown {
+            if format == BinaryFormat::Unknown {
+                format = member_format;
+            }
+            lockfiles.extend(deps_from_audit_data(data, file_size_limit).ok().flatten());
+        }
+
+        members.push(data);
+    }
+
+    if !lockfiles.is_empty() {
+        Ok(ScannedBinary::complete(format, merge_lockfiles(lockfiles)))
+    } else {
+        Ok(ScannedBinary::heuristic(format, members))
+    }
+}
+
+/// Recover the dependency tree embedded by `cargo auditable`, if any
+fn deps_from_audit_data(
+    file_contents: &[u8],
+    file_size_limit: usize,
+) -> crate::Result<Option<Lockfile>> {
     let version_info = auditable_info::audit_info_from_slice(file_contents, file_size_limit);
 
     use auditable_info::Error::*; // otherwise rustfmt makes the matches multiline and unreadable
     match version_info {
-        Ok(json_struct) => Ok((
-            format,
-            BinaryReport::Complete(lockfile_from_version_info_json(&json_struct)?),
-        )),
+        Ok(json_struct) => Ok(Some(lockfile_from_version_info_json(&json_struct)?)),
         Err(e) => match e {
-            NoAuditData => {
-                if let Some(deps) = deps_from_panic_messages(file_contents) {
-                    Ok((format, BinaryReport::Incomplete(deps)))
-                } else {
-                    Ok((format, BinaryReport::None))
-                }
-            }
+            NoAuditData => Ok(None),
             // The error handling boilerplate is in here instead of the `rustsec` crate because as of this writing
             // the public APIs of the crates involved are still somewhat unstable,
             // and this way we don't expose the error types in any public APIs
@@ -71,6 +199,29 @@ pub fn load_deps_from_binary(
     }
 }
 
+/// Combine the dependency trees of the object files in a static library
+fn merge_lockfiles(lockfiles: Vec<Lockfile>) -> Lockfile {
+    if lockfiles.len() == 1 {
+        return lockfiles.into_iter().next().unwrap();
+    }
+
+    let mut seen = BTreeSet::new();
+    let packages = lockfiles
+        .into_iter()
+        .flat_map(|lockfile| lockfile.packages)
+        .filter(|package| seen.insert(Dependency::from(package)))
+        .collect();
+
+    Lockfile {
+        version: cargo_lock::ResolveVersion::V2,
+        packages,
+        // Each object file may have its own root package
+        root: None,
+        metadata: Default::default(),
+        patch: Default::default(),
+    }
+}
+
 fn detect_format(data: &[u8]) -> BinaryFormat {
     match binfarce::detect_format(data) {
         binfarce::Format::Unknown => {
@@ -85,31 +236,58 @@ fn detect_format(data: &[u8]) -> BinaryFormat {
     }
 }
 
-fn deps_from_panic_messages(data: &[u8]) -> Option<Lockfile> {
-    let deps = quitters::versions(data);
-    if !deps.is_empty() {
-        let packages: Vec<Package> = deps.into_iter().map(to_package).collect();
-        Some(Lockfile {
-            version: cargo_lock::ResolveVersion::V2,
-            packages,
-            root: None,
-            metadata: Default::default(),
-            patch: Default::default(),
-        })
-    } else {
-        None
+/// Recover dependencies from panic messages and source paths, along with how
+/// reliably each of them was recovered
+fn deps_from_source_paths<'a>(
+    files: impl IntoIterator<Item = &'a [u8]>,
+) -> Option<(Lockfile, BTreeMap<Dependency, Confidence>)> {
+    let mut deps = BTreeMap::new();
+    let mut add = |dep: (String, cargo_lock::Version), confidence: Confidence| {
+        let level = deps.entry(dep).or_insert(confidence);
+        *level = confidence.max(*level);
+    };
+
+    for data in files {
+        for (name, version) in quitters::versions(data) {
+            add((name.to_owned(), version), Confidence::PanicMessage);
+        }
+        for dep in source_paths::versions(data) {
+            add(dep, Confidence::SourcePath);
+        }
     }
+
+    if deps.is_empty() {
+        return None;
+    }
+
+    let mut confidence = BTreeMap::new();
+    let mut packages = Vec::with_capacity(deps.len());
+    for (dep, level) in deps {
+        let package = to_package(dep);
+        confidence.insert(Dependency::from(&package), level);
+        packages.push(package);
+    }
+
+    let lockfile = Lockfile {
+        version: cargo_lock::ResolveVersion::V2,
+        packages,
+        root: None,
+        metadata: Default::default(),
+        patch: Default::default(),
+    };
+
+    Some((lockfile, confidence))
 }
 
 // matches https://docs.rs/cargo-lock/8.0.2/src/cargo_lock/package/source.rs.html#19
 // to signal crates.io to the `cargo-lock` crate
 const CRATES_IO_INDEX: &str = "registry+https://github.com/rust-lang/crates.io-index";
 
-fn to_package(quitter: (&str, cargo_lock::Version)) -> Package {
+fn to_package((name, version): (String, cargo_lock::Version)) -> Package {
     Package {
-        // The `quitters` crate already ensures the name is valid, so we can just `.unwrap()` here
-        name: cargo_lock::Name::from_str(quitter.0).unwrap(),
-        version: quitter.1,
+        // Names are already validated when they're recovered, so we can just `.unwrap()` here
+        name: cargo_lock::Name::from_str(&name).unwrap(),
+        version,
         // we can't know the exact registry, but by default `cargo audit` will
         // only scan crates from crates.io, so assume they're from there
         source: Some(cargo_lock::package::SourceId::from_url(CRATES_IO_INDEX).unwrap()),
@@ -167,7 +345,7 @@ fn lockfile_from_version_info_json(input: &VersionInfo) -> Result<Lockfile, carg
         version: cargo_lock::ResolveVersion::V2,
         packages,
         root: root_package,
-        metadata: std::collections::BTreeMap::new(),
+        metadata: BTreeMap::new(),
         patch: cargo_lock::Patch { unused: Vec::new() },
     })
 }
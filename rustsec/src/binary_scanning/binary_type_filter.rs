@@ -62,15 +62,14 @@ fn at_least_one_os_runs_binary(binary_type: &BinaryFormat, os_list: &[OS]) -> bo
             // That way we can capture all the unix-likes as using ELF and discard everything else
         }
         Wasm => {
-            // WASM doesn't have an OS, so OS-specific vulns should not be an issue.
-            // There isn't really a way to indicate WASM-specific vulns,
-            // because in Rustc's terms WASM is not an OS.
-            // Assume vulns with `Unknown` and `None` OS still apply,
+            // WASM doesn't have an OS of its own, but it's the only format used by
+            // `wasm32-wasip1` and `wasm32-unknown-emscripten`, so advisories for those apply.
+            // Also assume vulns with `Unknown` and `None` OS still apply,
             // just to have some last-ditch way to indicate WASM is affected
             // other than including all the platforms ever.
             os_list
                 .iter()
-                .any(|os| os == &OS::Unknown || os == &OS::None)
+                .any(|os| matches!(os, OS::Wasi | OS::Emscripten | OS::Unknown | OS::None))
         }
         Unknown => true, // might be possible for detection based on panic messages?
     }
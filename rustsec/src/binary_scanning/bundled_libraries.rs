@@ -0,0 +1,373 @@
+//! Detects C libraries statically linked into binaries
+//!
+//! Crates such as `openssl-sys`, `libz-sys` and `libsqlite3-sys` can build the
+//! C library they bind to from source and link it into the binary. Its version
+//! doesn't appear in the dependency tree, so it's recovered from the version
+//! strings the library embeds instead, and checked against a (short) list of
+//! its known vulnerabilities.
+
+use std::{cmp::Ordering, str::FromStr};
+
+use cargo_lock::Package;
+
+use crate::{
+    advisory::{Date, Id, Metadata},
+    warning::{Warning, WarningKind},
+};
+
+/// Maximum distance between the version of SQLite and its source ID
+const SQLITE_VERSION_DISTANCE: usize = 512;
+
+/// A C library found in a binary
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundledLibrary {
+    /// Name of the library, e.g. `openssl`
+    pub name: &'static str,
+
+    /// Version of the library as it calls it, e.g. `1.1.1k`
+    pub version: String,
+}
+
+impl BundledLibrary {
+    /// Get the known vulnerabilities affecting this version of the library
+    fn vulnerabilities(&self) -> impl Iterator<Item = &'static KnownVulnerability> + '_ {
+        KNOWN_VULNERABILITIES.iter().filter(|vuln| {
+            vuln.library == self.name
+                && vuln.affected.iter().any(|(introduced, fixed)| {
+                    compare_versions(&self.version, introduced) != Ordering::Less
+                        && compare_versions(&self.version, fixed) == Ordering::Less
+                })
+        })
+    }
+
+    /// Represent the library as a package, so that it can be included in reports
+    ///
+    /// Versions are not necessarily semver, so any suffix (such as the `k` in
+    /// OpenSSL `1.1.1k`) is kept as build metadata.
+    fn to_package(&self) -> Package {
+        let numeric_len = self
+            .version
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(self.version.len());
+        let (numeric, suffix) = self.version.split_at(numeric_len);
+
+        let mut components = numeric.split('.').map(|c| c.parse().unwrap_or(0));
+        let mut version = semver::Version::new(
+            components.next().unwrap_or(0),
+            components.next().unwrap_or(0),
+            components.next().unwrap_or(0),
+        );
+        if !suffix.is_empty() {
+            version.build = semver::BuildMetadata::new(suffix).unwrap_or_default();
+        }
+
+        Package {
+            name: self.name.parse().unwrap(),
+            version,
+            source: None,
+            checksum: None,
+            dependencies: Vec::new(),
+            replace: None,
+        }
+    }
+}
+
+/// A vulnerability in a C library
+struct KnownVulnerability {
+    /// Name of the library
+    library: &'static str,
+
+    /// CVE ID of the vulnerability
+    id: &'static str,
+
+    /// Date of the disclosure
+    date: &'static str,
+
+    /// Description of the vulnerability
+    title: &'static str,
+
+    /// Affected version ranges, as (first affected version, fixed version) pairs
+    affected: &'static [(&'static str, &'static str)],
+}
+
+/// Known vulnerabilities in commonly bundled C libraries
+const KNOWN_VULNERABILITIES: &[KnownVulnerability] = &[
+    KnownVulnerability {
+        library: "openssl",
+        id: "CVE-2014-0160",
+        date: "2014-04-07",
+        title: "Heartbleed: TLS heartbeat read overrun",
+        affected: &[("1.0.1", "1.0.1g")],
+    },
+    KnownVulnerability {
+        library: "openssl",
+        id: "CVE-2022-3602",
+        date: "2022-11-01",
+        title: "X.509 email address 4-byte buffer overflow",
+        affected: &[("3.0.0", "3.0.7")],
+    },
+    KnownVulnerability {
+        library: "openssl",
+        id: "CVE-2023-0286",
+        date: "2023-02-07",
+        title: "X.400 address type confusion in X.509 GeneralName",
+        affected: &[
+            ("1.0.2", "1.0.2zg"),
+            ("1.1.1", "1.1.1t"),
+            ("3.0.0", "3.0.8"),
+        ],
+    },
+    KnownVulnerability {
+        library: "zlib",
+        id: "CVE-2018-25032",
+        date: "2022-03-25",
+        title: "Memory corruption when compressing if the input has many distant matches",
+        affected: &[("0", "1.2.12")],
+    },
+    KnownVulnerability {
+        library: "zlib",
+        id: "CVE-2022-37434",
+        date: "2022-08-05",
+        title: "Heap-based buffer over-read or overflow in inflate via a large gzip header extra field",
+        affected: &[("0", "1.2.13")],
+    },
+    KnownVulnerability {
+        library: "sqlite",
+        id: "CVE-2022-35737",
+        date: "2022-08-03",
+        title: "Array-bounds overflow when using billion-byte string arguments to C APIs",
+        affected: &[("1.0.12", "3.39.2")],
+    },
+];
+
+/// Find the C libraries linked into a binary from their version strings
+pub fn find_bundled_libraries(data: &[u8]) -> Vec<BundledLibrary> {
+    let mut libraries = Vec::new();
+    let mut found = |name, version: &str| {
+        let library = BundledLibrary {
+            name,
+            version: version.to_owned(),
+        };
+        if !libraries.contains(&library) {
+            libraries.push(library);
+        }
+    };
+
+    // e.g. `OpenSSL 1.1.1k  25 Mar 2021` or `OpenSSL 3.0.7 1 Nov 2022`, but not
+    // `OpenSSL 1.1.1 (compatible; BoringSSL)`
+    for pos in find_all(data, b"OpenSSL ") {
+        let rest = &data[pos + 8..];
+        if let Some(version) = parse_version(rest, true)
+            && rest[version.len()..].starts_with(b" ")
+            && rest
+                .get(version.len() + 1)
+                .is_some_and(|&byte| byte == b' ' || byte.is_ascii_digit())
+        {
+            found("openssl", version);
+        }
+    }
+
+    // e.g. ` deflate 1.2.11 Copyright 1995-2017 Jean-loup Gailly and Mark Adler `
+    for pos in find_all(data, b" deflate ") {
+        let rest = &data[pos + 9..];
+        if let Some(version) = parse_version(rest, false)
+            && rest[version.len()..].starts_with(b" Copyright ")
+        {
+            found("zlib", version);
+        }
+    }
+
+    // SQLite's version string is stored on its own, but close to its source ID,
+    // e.g. `2022-07-21 15:24:47 698edb77537b67c41adc68f9b892db56bcf9a55e00371a61420f3ddd668e6603`
+    for pos in find_all(data, b" ") {
+        if !is_sqlite_source_id(data, pos) {
+            continue;
+        }
+
+        let start = pos.saturating_sub(SQLITE_VERSION_DISTANCE);
+        let end = (pos + SQLITE_VERSION_DISTANCE).min(data.len());
+        let version = data[start..end]
+            .split(|&byte| byte == 0)
+            .filter_map(|string| std::str::from_utf8(string).ok())
+            .find(|string| {
+                string.starts_with("3.")
+                    && string.split('.').count() == 3
+                    && string.split('.').all(|c| c.parse::<u32>().is_ok())
+            });
+
+        if let Some(version) = version {
+            found("sqlite", version);
+        }
+    }
+
+    libraries
+}
+
+/// Get warnings about the vulnerable C libraries linked into a binary
+pub fn bundled_library_warnings(data: &[u8]) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+
+    for library in find_bundled_libraries(data) {
+        let package = library.to_package();
+        for vuln in library.vulnerabilities() {
+            let metadata = Metadata {
+                id: Id::from_str(vuln.id).unwrap(),
+                package: package.name.clone(),
+                title: vuln.title.to_owned(),
+                description: format!(
+                    "{} {} is statically linked into the binary, e.g. by a `-sys` crate \
+                     building it from source, and is affected by {}.",
+                    library.name, library.version, vuln.id
+                ),
+                date: Date::from_str(vuln.date).unwrap(),
+                aliases: Vec::new(),
+                related: Vec::new(),
+                collection: None,
+                categories: Vec::new(),
+                keywords: Vec::new(),
+                cwe_ids: Vec::new(),
+                cvss: None,
+                severity: None,
+                informational: None,
+                references: Vec::new(),
+                source: None,
+                url: None,
+                withdrawn: None,
+                license: Default::default(),
+                expect_deleted: false,
+            };
+
+            warnings.push(Warning::new(
+                WarningKind::BundledLibrary,
+                &package,
+                Some(metadata),
+                None,
+                None,
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Find the positions of all occurrences of `needle` in `data`
+fn find_all<'a>(data: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    data.windows(needle.len())
+        .enumerate()
+        .filter(move |(_, window)| *window == needle)
+        .map(|(pos, _)| pos)
+}
+
+/// Parse a version such as `1.2.11` (or `1.1.1k` if `suffix` is allowed) at the start of `data`
+fn parse_version(data: &[u8], suffix: bool) -> Option<&str> {
+    let numeric_len = data
+        .iter()
+        .position(|&byte| !byte.is_ascii_digit() && byte != b'.')
+        .unwrap_or(data.len());
+    let numeric = std::str::from_utf8(&data[..numeric_len]).ok()?;
+
+    if numeric.split('.').count() < 2 || numeric.split('.').any(str::is_empty) {
+        return None;
+    }
+
+    let suffix_len = if suffix {
+        data[numeric_len..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_lowercase())
+            .count()
+    } else {
+        0
+    };
+
+    std::str::from_utf8(&data[..numeric_len + suffix_len]).ok()
+}
+
+/// Is there an SQLite source ID around the space at `pos` (between its time and hash)?
+fn is_sqlite_source_id(data: &[u8], pos: usize) -> bool {
+    let Some(timestamp) = pos.checked_sub(19).map(|start| &data[start..pos]) else {
+        return false;
+    };
+    let Some(hash) = data.get(pos + 1..pos + 65) else {
+        return false;
+    };
+
+    timestamp.iter().enumerate().all(|(i, &byte)| match i {
+        4 | 7 => byte == b'-',
+        10 => byte == b' ',
+        13 | 16 => byte == b':',
+        _ => byte.is_ascii_digit(),
+    }) && hash
+        .iter()
+        .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(byte))
+}
+
+/// Compare versions of C libraries, e.g. `1.1.1k` and `1.1.1t`
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn key(version: &str) -> (Vec<u32>, usize, &str) {
+        let numeric_len = version
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(version.len());
+        let (numeric, suffix) = version.split_at(numeric_len);
+        let components = numeric.split('.').map(|c| c.parse().unwrap_or(0)).collect();
+
+        // OpenSSL letter releases go `z`, `za`, `zb`, ...
+        (components, suffix.len(), suffix)
+    }
+
+    let (a, b) = (key(a), key(b));
+    let len = a.0.len().max(b.0.len());
+    let pad = |components: &[u32]| {
+        let mut components = components.to_vec();
+        components.resize(len, 0);
+        components
+    };
+
+    pad(&a.0)
+        .cmp(&pad(&b.0))
+        .then(a.1.cmp(&b.1))
+        .then(a.2.cmp(b.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_libraries() {
+        let data = b"\0OpenSSL 1.1.1k  25 Mar 2021\0OpenSSL 1.1.1 (compatible; BoringSSL)\0\
+            \0 deflate 1.2.11 Copyright 1995-2017 Jean-loup Gailly and Mark Adler \0\
+            3.39.2\0sqlite3_open\0\
+            2022-07-21 15:24:47 698edb77537b67c41adc68f9b892db56bcf9a55e00371a61420f3ddd668e6603\0";
+
+        let libraries = find_bundled_libraries(data);
+        let versions: Vec<_> = libraries
+            .iter()
+            .map(|library| format!("{} {}", library.name, library.version))
+            .collect();
+        assert_eq!(versions, ["openssl 1.1.1k", "zlib 1.2.11", "sqlite 3.39.2"]);
+
+        let ids: Vec<_> = bundled_library_warnings(data)
+            .iter()
+            .map(|warning| warning.advisory.as_ref().unwrap().id.to_string())
+            .collect();
+        assert_eq!(ids, ["CVE-2023-0286", "CVE-2018-25032", "CVE-2022-37434"]);
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert_eq!(compare_versions("1.1.1k", "1.1.1t"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.2z", "1.0.2zg"), Ordering::Less);
+        assert_eq!(compare_versions("1.1.1", "1.1.1a"), Ordering::Less);
+        assert_eq!(compare_versions("1.3", "1.2.13"), Ordering::Greater);
+        assert_eq!(compare_versions("3.0.7", "3.0.7"), Ordering::Equal);
+    }
+
+    #[test]
+    fn package_versions() {
+        let library = BundledLibrary {
+            name: "openssl",
+            version: "1.1.1k".to_owned(),
+        };
+        assert_eq!(library.to_package().version.to_string(), "1.1.1+k");
+    }
+}
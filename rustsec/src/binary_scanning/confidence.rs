@@ -0,0 +1,39 @@
+//! How reliably a dependency was recovered from a binary
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How reliably a dependency was recovered from a compiled binary.
+///
+/// Levels are ordered from the least to the most reliable.
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Confidence {
+    /// Guessed from a source path in symbol paths or debug info, such as
+    /// `vendor/serde-1.0.196/src/lib.rs`. The directory may not actually
+    /// contain the crate of that name and version (e.g. if it's a fork).
+    SourcePath,
+
+    /// Found in a panic message referring to a crate in the Cargo registry
+    PanicMessage,
+
+    /// Listed in the dependency tree embedded by `cargo auditable`
+    Exact,
+}
+
+impl Confidence {
+    /// Get a `str` representing this confidence level
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SourcePath => "source-path",
+            Self::PanicMessage => "panic-message",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,39 @@
+//! Recovers the version of the Rust compiler a binary was built with
+
+use semver::Version;
+
+/// Prefix of the version string rustc records in the `.comment` section of ELF files
+const RUSTC_VERSION_PREFIX: &[u8] = b"rustc version ";
+
+/// Find the version of the Rust compiler a binary was built with.
+///
+/// The version is recorded in ELF binaries as e.g. `rustc version 1.75.0 (82e1608df 2023-12-21)`.
+/// Mach-O and PE binaries don't record it, so `None` is returned for them.
+/// If the binary was linked from objects built by multiple compilers, the oldest is returned.
+pub fn rustc_version(data: &[u8]) -> Option<Version> {
+    data.windows(RUSTC_VERSION_PREFIX.len())
+        .enumerate()
+        .filter(|(_, window)| *window == RUSTC_VERSION_PREFIX)
+        .filter_map(|(pos, _)| {
+            let rest = &data[pos + RUSTC_VERSION_PREFIX.len()..];
+            let len = rest
+                .iter()
+                .position(|&byte| byte == b' ' || byte == 0)
+                .unwrap_or(rest.len());
+            Version::parse(std::str::from_utf8(&rest[..len]).ok()?).ok()
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_rustc_version() {
+        let data = b"\0GCC: (GNU) 13.2.1\0rustc version 1.75.0 (82e1608df 2023-12-21)\0\
+            rustc version 1.77.0-nightly (bf8716f1c 2023-12-24)\0";
+        assert_eq!(rustc_version(data), Some(Version::parse("1.75.0").unwrap()));
+        assert_eq!(rustc_version(b"\0GCC: (GNU) 13.2.1\0"), None);
+    }
+}
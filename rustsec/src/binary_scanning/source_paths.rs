@@ -0,0 +1,120 @@
+//! Recovers crate versions from the source paths embedded in a binary
+//!
+//! Binaries built without `cargo auditable` still refer to the files they were
+//! compiled from: panic messages and symbol paths contain source paths such as
+//! `vendor/serde-1.0.196/src/de/mod.rs`, and DWARF debug info records the
+//! directory of each crate (e.g. `/build/vendor/serde-1.0.196`). Unlike the
+//! panic messages found by [quitters], these are not limited to crates from
+//! `~/.cargo/registry`, so vendored crates and paths rewritten with
+//! `--remap-path-prefix` are discovered as well.
+
+use std::collections::BTreeSet;
+
+use object::{Object, ObjectSection};
+use semver::Version;
+
+/// DWARF sections containing the directories of compilation units and line tables
+const DWARF_SECTIONS: &[&str] = &[".debug_str", ".debug_line_str", ".debug_line"];
+
+/// Longest crate directory name that is considered, e.g. `serde-1.0.196`
+const MAX_DIRECTORY_LEN: usize = 128;
+
+/// Find the crates (and their versions) referred to by source paths in a binary
+pub(crate) fn versions(data: &[u8]) -> BTreeSet<(String, Version)> {
+    let mut versions = BTreeSet::new();
+
+    // Paths to source files, e.g. `serde-1.0.196/src/lib.rs`
+    for (pos, window) in data.windows(5).enumerate() {
+        if window != b"/src/" && window != b"\\src\\" {
+            continue;
+        }
+
+        let search_start = pos.saturating_sub(MAX_DIRECTORY_LEN);
+        let start = data[search_start..pos]
+            .iter()
+            .rposition(|&byte| !is_directory_char(byte))
+            .map_or(search_start, |offset| search_start + offset + 1);
+
+        versions.extend(parse_directory(&data[start..pos]));
+    }
+
+    // Crate directories in DWARF debug info, e.g. `/build/vendor/serde-1.0.196`
+    if let Ok(file) = object::File::parse(data) {
+        for name in DWARF_SECTIONS {
+            // Compressed debug sections are not supported
+            let Some(contents) = file
+                .section_by_name(name)
+                .and_then(|section| section.uncompressed_data().ok())
+            else {
+                continue;
+            };
+
+            for string in contents.split(|&byte| byte == 0) {
+                let directory = string
+                    .rsplit(|&byte| byte == b'/' || byte == b'\\')
+                    .next()
+                    .unwrap_or_default();
+                versions.extend(parse_directory(directory));
+            }
+        }
+    }
+
+    versions
+}
+
+/// Parse a crate directory name such as `zstd-safe-5.0.2+zstd.1.5.2`
+fn parse_directory(directory: &[u8]) -> Option<(String, Version)> {
+    let directory = std::str::from_utf8(directory).ok()?;
+
+    // Crate names can contain `-` too, but never the `.` that versions must contain
+    directory
+        .match_indices('-')
+        .map(|(index, _)| (&directory[..index], &directory[index + 1..]))
+        .filter(|(name, _)| {
+            name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .find_map(|(name, version)| Some((name.to_owned(), Version::parse(version).ok()?)))
+}
+
+/// Can the byte be part of a crate directory name?
+fn is_directory_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'+')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_paths() {
+        let data = b"\0vendor/zstd-safe-5.0.2+zstd.1.5.2/src/lib.rs\0called `Option::unwrap()`\
+            C:\\build\\deps\\serde-1.0.196\\src\\de\\mod.rs\0src/main.rs\0foo-bar/src/lib.rs\0";
+
+        assert_eq!(
+            versions(data),
+            BTreeSet::from([
+                ("serde".to_owned(), Version::parse("1.0.196").unwrap()),
+                (
+                    "zstd-safe".to_owned(),
+                    Version::parse("5.0.2+zstd.1.5.2").unwrap()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn directory_names() {
+        assert_eq!(
+            parse_directory(b"rustc-demangle-0.1.21-rc.1"),
+            Some((
+                "rustc-demangle".to_owned(),
+                Version::parse("0.1.21-rc.1").unwrap()
+            ))
+        );
+        assert_eq!(parse_directory(b"x86_64-unknown-linux-gnu"), None);
+        assert_eq!(parse_directory(b"1.0.0"), None);
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    Version, VersionReq,
     error::{Error, ErrorKind},
     package::{self, Package},
 };
@@ -35,6 +36,29 @@ impl Index {
     }
 }
 
+/// A version of a crate published to the crates.io index
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublishedVersion {
+    /// Version number
+    pub version: Version,
+
+    /// Has this version been yanked?
+    pub yanked: bool,
+
+    /// Requirements this version places on its (non-dev) dependencies
+    pub dependencies: Vec<(package::Name, VersionReq)>,
+}
+
+impl PublishedVersion {
+    /// Get the requirement this version places on the given dependency (if any)
+    pub fn requirement(&self, name: &package::Name) -> Option<&VersionReq> {
+        self.dependencies
+            .iter()
+            .find(|(dep_name, _)| dep_name == name)
+            .map(|(_, req)| req)
+    }
+}
+
 /// Provides an efficient way to check if the given package has been yanked.
 ///
 /// Operations on crates.io index are rather slow.
@@ -235,6 +259,44 @@ impl CachedIndex {
         }
     }
 
+    /// Get all of the published versions of the given crate, along with the
+    /// requirements each of them places on their dependencies.
+    ///
+    /// Versions which aren't valid semver are skipped.
+    pub fn published_versions(
+        &mut self,
+        name: &package::Name,
+    ) -> Result<Vec<PublishedVersion>, Error> {
+        // Ensure the index entry has been downloaded (and written to the local cache)
+        self.populate_cache(BTreeSet::from([name]))?;
+
+        let krate = self.index.krate(name, &self.lock)?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("No such crate in crates.io index: {name}"),
+            )
+        })?;
+
+        Ok(krate
+            .versions
+            .iter()
+            .filter_map(|v| {
+                Some(PublishedVersion {
+                    version: v.version.parse().ok()?,
+                    yanked: v.is_yanked(),
+                    dependencies: v
+                        .dependencies()
+                        .iter()
+                        .filter(|dep| dep.kind() != tame_index::krate::DependencyKind::Dev)
+                        .filter_map(|dep| {
+                            Some((dep.crate_name().parse().ok()?, dep.req.parse().ok()?))
+                        })
+                        .collect(),
+                })
+            })
+            .collect())
+    }
+
     /// Iterate over the provided packages, returning a vector of the
     /// packages which have been yanked.
     ///
@@ -273,7 +335,9 @@ impl CachedIndex {
 }
 
 // We cannot expose these publicly because that would leak the `tame_index` SemVer into the public API
-fn acquire_cargo_package_lock(lock_timeout: Duration) -> Result<FileLock, tame_index::Error> {
+pub(crate) fn acquire_cargo_package_lock(
+    lock_timeout: Duration,
+) -> Result<FileLock, tame_index::Error> {
     let lock_opts = LockOptions::cargo_package_lock(None)?.exclusive(false);
     acquire_lock(lock_opts, lock_timeout)
 }
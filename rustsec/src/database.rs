@@ -1,24 +1,45 @@
 //! Database containing `RustSec` security advisories
 
+mod alias_graph;
+mod diff;
 mod entries;
 mod index;
 mod query;
+mod search;
 
-pub use self::query::Query;
+pub use self::{
+    alias_graph::{AliasConflict, AliasGraph, AliasSet},
+    diff::DatabaseDiff,
+    query::{Query, SourceMatching},
+};
 
-use self::{entries::Entries, index::Index};
+use self::{entries::Entries, index::Index, search::SearchIndex};
 use crate::{
-    Lockfile,
+    Lockfile, Map, Version,
     advisory::{self, Advisory},
     collection::Collection,
     error::Error,
     fs,
+    package::Package,
+    report::{Report, Settings},
     vulnerability::Vulnerability,
 };
-use std::path::Path;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
 
 #[cfg(feature = "git")]
-use crate::repository::git;
+use crate::{registry::CachedIndex, repository::git};
+
+#[cfg(feature = "async")]
+use crate::repository::registry;
+
+#[cfg(any(feature = "git", feature = "osv"))]
+use crate::error::ErrorKind;
+
+#[cfg(feature = "osv")]
+use crate::osv::OsvAdvisory;
 
 /// Iterator over entries in the database
 pub type Iter<'a> = std::slice::Iter<'a, Advisory>;
@@ -35,6 +56,13 @@ pub struct Database {
     /// Index of third party crates
     crate_index: Index,
 
+    /// Full-text index of advisory titles, descriptions and keywords
+    search_index: SearchIndex,
+
+    /// Files to read the descriptions of advisories from, when they aren't
+    /// kept in memory
+    description_paths: Map<advisory::Id, PathBuf>,
+
     /// Information about the last git commit to the database
     #[cfg(feature = "git")]
     latest_commit: Option<git::Commit>,
@@ -43,59 +71,136 @@ pub struct Database {
 impl Database {
     /// Open [`Database`] located at the given local path
     pub fn open(path: &Path) -> Result<Self, Error> {
-        let mut advisory_paths = vec![];
-
-        for collection in Collection::all() {
-            let collection_path = path.join(collection.as_str());
-            let collection_entry = match fs::read_dir(&collection_path) {
-                Ok(entries) => entries,
-                // The `Rust` collection is currently not useful to end users
-                Err(_) if collection == &Collection::Rust => continue,
-                Err(err) => return Err(err.into()),
-            };
+        let paths = advisory_paths(path)?;
 
-            for dir_entry in collection_entry {
-                let dir_entry = dir_entry?;
-                if !dir_entry.file_type()?.is_dir() {
-                    continue;
-                }
-                for advisory_entry in fs::read_dir(dir_entry.path())? {
-                    let advisory_path = advisory_entry?.path();
-                    let file_name = advisory_path.file_name().and_then(|f| f.to_str());
-                    // skip dotfiles like .DS_Store
-                    if file_name.is_some_and(|f| f.starts_with('.')) {
-                        continue;
-                    }
-                    advisory_paths.push(advisory_path);
+        let mut advisories = Entries::new();
+        for (advisory, path) in load_advisories(&paths, true)?.into_iter().zip(&paths) {
+            advisories.insert_file(advisory, path)?;
+        }
+
+        Ok(Self::from_entries(advisories))
+    }
+
+    /// Open [`Database`] located at the given local path, without keeping the
+    /// descriptions of advisories in memory.
+    ///
+    /// Only the front matter and title of advisories are loaded, so their
+    /// `description` (i.e. [`Advisory::description`]) is **empty**: use
+    /// [`Database::description`] instead, which reads it from disk again. This
+    /// saves memory in services which keep databases loaded but seldom display
+    /// advisories in full.
+    ///
+    /// Since descriptions aren't loaded, [`Database::search`] only covers the
+    /// titles and keywords of advisories.
+    pub fn open_lazy(path: &Path) -> Result<Self, Error> {
+        let paths = advisory_paths(path)?;
+
+        let mut advisories = Entries::new();
+        let mut description_paths = Map::new();
+        for (advisory, path) in load_advisories(&paths, false)?.into_iter().zip(paths) {
+            let id = advisory.metadata.id.clone();
+            if advisories.insert_file(advisory, &path)?.is_some() {
+                description_paths.insert(id, path);
+            }
+        }
+
+        let mut db = Self::from_entries(advisories);
+        db.description_paths = description_paths;
+        Ok(db)
+    }
+
+    /// Get the description of the given advisory, reading it from disk if the
+    /// database was opened with [`Database::open_lazy`].
+    pub fn description<'a>(&self, advisory: &'a Advisory) -> Result<Cow<'a, str>, Error> {
+        match self.description_paths.get(advisory.id()) {
+            Some(path) => Ok(Cow::Owned(Advisory::load_file(path)?.metadata.description)),
+            None => Ok(Cow::Borrowed(&advisory.metadata.description)),
+        }
+    }
+
+    /// Create a [`Database`] from the given advisories, e.g. ones converted
+    /// from another format.
+    ///
+    /// Advisories without a collection are assumed to be about crates.
+    /// Advisories for different crates may have the same ID, as happens with
+    /// advisories converted from an OSV advisory affecting several crates.
+    pub fn from_advisories(advisories: impl IntoIterator<Item = Advisory>) -> Result<Self, Error> {
+        let mut entries = Entries::new();
+
+        for mut advisory in advisories {
+            advisory
+                .metadata
+                .collection
+                .get_or_insert(Collection::Crates);
+            entries.insert(advisory, true)?;
+        }
+
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Load a [`Database`] from a directory containing advisories in the
+    /// [OSV] JSON format (e.g. a dump of the GitHub Advisory Database).
+    ///
+    /// All `.json` files in the directory and its subdirectories are loaded,
+    /// keeping the parts of the advisories about crates.io packages.
+    ///
+    /// [OSV]: https://ossf.github.io/osv-schema/
+    #[cfg(feature = "osv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "osv")))]
+    pub fn open_osv(path: &Path) -> Result<Self, Error> {
+        let mut advisories = vec![];
+        let mut dirs = vec![path.to_owned()];
+
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "json") {
+                    let osv: OsvAdvisory = serde_json::from_str(&fs::read_to_string(&path)?)
+                        .map_err(|e| {
+                            Error::with_source(
+                                ErrorKind::Parse,
+                                format!("error parsing {}", path.display()),
+                                e,
+                            )
+                        })?;
+                    advisories.extend(osv.to_advisories()?);
                 }
             }
         }
 
-        let mut advisories = Entries::new();
+        Self::from_advisories(advisories)
+    }
+
+    /// Index the given entries
+    fn from_entries(advisories: Entries) -> Self {
         let mut rust_index = Index::new();
         let mut crate_index = Index::new();
+        let mut search_index = SearchIndex::new();
 
-        for path in &advisory_paths {
-            if let Some(slot) = advisories.load_file(path)? {
-                let advisory = advisories.get(slot).unwrap();
-                match advisory.metadata.collection.unwrap() {
-                    Collection::Crates => {
-                        crate_index.insert(&advisory.metadata.package, slot);
-                    }
-                    Collection::Rust => {
-                        rust_index.insert(&advisory.metadata.package, slot);
-                    }
+        for (slot, advisory) in advisories.slots() {
+            match advisory.metadata.collection.unwrap() {
+                Collection::Crates => {
+                    crate_index.insert(&advisory.metadata.package, slot);
+                }
+                Collection::Rust => {
+                    rust_index.insert(&advisory.metadata.package, slot);
                 }
             }
+            search_index.insert(advisory, slot);
         }
 
-        Ok(Self {
+        Self {
             advisories,
             crate_index,
             rust_index,
+            search_index,
+            description_paths: Map::new(),
             #[cfg(feature = "git")]
             latest_commit: None,
-        })
+        }
     }
 
     /// Load [`Database`] from the given [`git::Repository`]
@@ -106,17 +211,125 @@ impl Database {
         Ok(db)
     }
 
+    /// Load [`Database`] as of the given revision (e.g. a commit hash, a tag
+    /// or `HEAD~1`) of the given [`git::Repository`], regardless of what is
+    /// checked out.
+    #[cfg(feature = "git")]
+    pub fn load_from_commit(repo: &git::Repository, rev: &str) -> Result<Self, Error> {
+        let collections = Collection::all()
+            .iter()
+            .map(Collection::as_str)
+            .collect::<Vec<_>>();
+        let (commit, files) = repo.markdown_files_at(rev, &collections)?;
+
+        let mut advisories = Entries::new();
+        for (path, contents) in &files {
+            // Only `<collection>/<package>/<advisory>.md`, like `Database::open`
+            if path.iter().count() != 3 {
+                continue;
+            }
+            let advisory = contents.parse().map_err(|e| {
+                Error::with_source(
+                    ErrorKind::Parse,
+                    format!("error parsing {} at '{rev}'", path.display()),
+                    e,
+                )
+            })?;
+            advisories.insert_file(advisory, path)?;
+        }
+
+        let mut db = Self::from_entries(advisories);
+        db.latest_commit = Some(commit);
+        Ok(db)
+    }
+
+    /// Compare the advisories at two revisions of the given [`git::Repository`],
+    /// e.g. to list the advisories published since the last release.
+    #[cfg(feature = "git")]
+    pub fn diff_commits(
+        repo: &git::Repository,
+        old_rev: &str,
+        new_rev: &str,
+    ) -> Result<DatabaseDiff, Error> {
+        let old = Self::load_from_commit(repo, old_rev)?;
+        let new = Self::load_from_commit(repo, new_rev)?;
+        Ok(old.diff(&new))
+    }
+
     /// Fetch the default advisory database from GitHub
     #[cfg(feature = "git")]
     pub fn fetch() -> Result<Self, Error> {
         git::Repository::fetch_default_repo().and_then(|repo| Self::load_from_repo(&repo))
     }
 
+    /// Fetch the advisory database from the package published on crates.io
+    /// (or the registry replacing it in the cargo configuration, see
+    /// [`Registry::crates_io`](registry::Registry::crates_io)) as `crate_name`,
+    /// using the given async HTTP client.
+    ///
+    /// Note that this is a different source than the git repository
+    /// [`Database::fetch`] pulls, which may lag behind it. See
+    /// [`repository::registry`](crate::repository::registry) for the trust
+    /// this places in the owner of the crate.
+    ///
+    /// Only the network I/O is async: the package is unpacked and the
+    /// advisories are parsed on the calling thread, which blocks it for a
+    /// while. To keep that off an async runtime, fetch the package with
+    /// [`RegistryPackage::fetch_async`](registry::RegistryPackage::fetch_async)
+    /// and open it with [`Database::open`] on a blocking thread instead (e.g.
+    /// with `tokio::task::spawn_blocking`).
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn fetch_package_async<C: registry::HttpClient>(
+        client: &C,
+        crate_name: &str,
+    ) -> Result<Self, Error> {
+        let package = registry::RegistryPackage::fetch_async(
+            client,
+            &registry::Registry::crates_io()?,
+            crate_name,
+            registry::RegistryPackage::default_path(),
+        )
+        .await?;
+
+        Self::open(package.path())
+    }
+
     /// Look up an advisory by an advisory ID (e.g. "RUSTSEC-YYYY-XXXX")
     pub fn get(&self, id: &advisory::Id) -> Option<&Advisory> {
         self.advisories.find_by_id(id)
     }
 
+    /// Look up the advisories for a vulnerability by one of its aliases in
+    /// other databases (e.g. "CVE-YYYY-XXXX" or "GHSA-xxxx-xxxx-xxxx").
+    ///
+    /// Several advisories can share an alias when a vulnerability affects
+    /// more than one crate.
+    pub fn find_by_alias(&self, alias: &advisory::Id) -> Vec<&Advisory> {
+        self.advisories.find_by_alias(alias).collect()
+    }
+
+    /// Search the titles, descriptions and keywords of advisories for any of
+    /// the words in `query`, ignoring case.
+    ///
+    /// The best matches come first: advisories matching more of the words,
+    /// then advisories where they appear in the title or keywords rather than
+    /// only in the description.
+    pub fn search(&self, query: &str) -> Vec<&Advisory> {
+        let mut matches = self
+            .search_index
+            .search(query)
+            .into_iter()
+            .map(|(slot, score)| (self.advisories.get(slot).unwrap(), score))
+            .collect::<Vec<_>>();
+
+        matches.sort_by(|(a, a_score), (b, b_score)| {
+            b_score.cmp(a_score).then_with(|| a.id().cmp(b.id()))
+        });
+
+        matches.into_iter().map(|(advisory, _)| advisory).collect()
+    }
+
     /// Query the database according to the given query object
     pub fn query(&self, query: &Query) -> Vec<&Advisory> {
         // Use indexes if we know a package name and collection
@@ -146,15 +359,23 @@ impl Database {
         let mut vulns = vec![];
 
         for package in &lockfile.packages {
-            if package
-                .source
-                .as_ref()
-                .is_none_or(|source| !source.is_default_registry())
-            {
+            // Path dependencies are part of the project being audited
+            let Some(source) = &package.source else {
+                continue;
+            };
+            if source.is_path() {
                 continue;
             }
 
-            let advisories = self.query(&query.clone().package(package));
+            let mut package_query = query.clone().package(package);
+
+            // Advisories for the package's own source take precedence over
+            // the ones matched by name
+            if !source.is_default_registry() && self.has_source_advisories(package) {
+                package_query = package_query.source_matching(SourceMatching::Exact);
+            }
+
+            let advisories = self.query(&package_query);
 
             vulns.extend(
                 advisories
@@ -166,11 +387,100 @@ impl Database {
         vulns
     }
 
+    /// Are there any advisories for the package with the same name from the
+    /// same source as the given one?
+    fn has_source_advisories(&self, package: &Package) -> bool {
+        let mut query = Query::new().package_name(package.name.clone());
+        if let Some(source) = &package.source {
+            query = query.package_source(source.clone());
+        }
+
+        !self.query(&query).is_empty()
+    }
+
     /// Scan for vulnerabilities in the provided `Lockfile`.
     pub fn vulnerabilities(&self, lockfile: &Lockfile) -> Vec<Vulnerability> {
         self.query_vulnerabilities(lockfile, &Query::crate_scope())
     }
 
+    /// Find vulnerabilities in the given version of the Rust toolchain
+    /// (i.e. advisories in [`Collection::Rust`], such as the ones about the
+    /// standard library, `rustc` or `cargo`) which match a given query.
+    ///
+    /// The components of a toolchain are released together, so the version
+    /// printed by `rustc --version` is used for all of them.
+    pub fn query_toolchain_vulnerabilities(
+        &self,
+        version: &Version,
+        query: &Query,
+    ) -> Vec<Vulnerability> {
+        let query = query
+            .clone()
+            .collection(Collection::Rust)
+            .package_version(version.clone());
+
+        self.query(&query)
+            .into_iter()
+            .map(|advisory| {
+                let package = Package {
+                    name: advisory.metadata.package.clone(),
+                    version: version.clone(),
+                    source: None,
+                    checksum: None,
+                    dependencies: vec![],
+                    replace: None,
+                };
+                Vulnerability::new(advisory, &package)
+            })
+            .collect()
+    }
+
+    /// Scan for vulnerabilities in the given version of the Rust toolchain.
+    pub fn toolchain_vulnerabilities(&self, version: &Version) -> Vec<Vulnerability> {
+        self.query_toolchain_vulnerabilities(version, &Query::crate_scope())
+    }
+
+    /// Audit the given `Lockfile` for vulnerabilities and warnings according
+    /// to `settings`.
+    ///
+    /// Checking for yanked packages requires the crates.io index, so it is
+    /// only done by [`Database::audit_lockfile_with_index`].
+    pub fn audit_lockfile(&self, lockfile: &Lockfile, settings: &Settings) -> Report {
+        Report::generate(self, lockfile, settings)
+    }
+
+    /// Audit the given `Lockfile` the same way `cargo audit` does: like
+    /// [`Database::audit_lockfile`], but also warning about the packages
+    /// which have been yanked from crates.io according to `index`.
+    ///
+    /// Returns the errors for packages whose yanked status couldn't be
+    /// determined alongside the report.
+    #[cfg(feature = "git")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+    pub fn audit_lockfile_with_index(
+        &self,
+        lockfile: &Lockfile,
+        settings: &Settings,
+        index: &mut CachedIndex,
+    ) -> (Report, Vec<Error>) {
+        let mut report = self.audit_lockfile(lockfile, settings);
+        let errors = report.check_yanked(index, lockfile);
+        (report, errors)
+    }
+
+    /// Load the `Cargo.lock` file at the given path and audit it, as with
+    /// [`Database::audit_lockfile`].
+    pub fn audit_lockfile_path(&self, path: &Path, settings: &Settings) -> Result<Report, Error> {
+        let lockfile = Lockfile::load(path)?;
+        Ok(self.audit_lockfile(&lockfile, settings))
+    }
+
+    /// Compare this database with a newer version of it, listing the
+    /// advisories which have been added, modified, withdrawn or removed.
+    pub fn diff(&self, newer: &Database) -> DatabaseDiff {
+        DatabaseDiff::new(self, newer)
+    }
+
     /// Iterate over all of the advisories in the database
     pub fn iter(&self) -> Iter<'_> {
         self.advisories.iter()
@@ -183,6 +493,58 @@ impl Database {
     }
 }
 
+/// Find the advisory files in the database located at the given path
+fn advisory_paths(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut advisory_paths = vec![];
+
+    for collection in Collection::all() {
+        let collection_path = path.join(collection.as_str());
+        let collection_entry = match fs::read_dir(&collection_path) {
+            Ok(entries) => entries,
+            // The `Rust` collection is currently not useful to end users
+            Err(_) if collection == &Collection::Rust => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        for dir_entry in collection_entry {
+            let dir_entry = dir_entry?;
+            if !dir_entry.file_type()?.is_dir() {
+                continue;
+            }
+            for advisory_entry in fs::read_dir(dir_entry.path())? {
+                let advisory_path = advisory_entry?.path();
+                let file_name = advisory_path.file_name().and_then(|f| f.to_str());
+                // skip dotfiles like .DS_Store
+                if file_name.is_some_and(|f| f.starts_with('.')) {
+                    continue;
+                }
+                advisory_paths.push(advisory_path);
+            }
+        }
+    }
+
+    Ok(advisory_paths)
+}
+
+/// Parse the advisories at the given paths, in parallel with the `parallel` feature
+fn load_advisories(paths: &[PathBuf], descriptions: bool) -> Result<Vec<Advisory>, Error> {
+    let load = |path: &PathBuf| match descriptions {
+        true => Advisory::load_file(path),
+        false => Advisory::load_file_without_description(path),
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        paths.par_iter().map(load).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        paths.iter().map(load).collect()
+    }
+}
+
 impl IntoIterator for Database {
     type Item = Advisory;
 
@@ -0,0 +1,124 @@
+//! Resolving advisory identifiers across identifier systems
+
+use super::Database;
+use crate::{
+    Map, Set,
+    advisory::{Advisory, Id},
+};
+
+/// Graph linking advisory IDs to their aliases, across any number of databases.
+///
+/// Each advisory is linked to the aliases it lists, so the IDs referring to
+/// the same vulnerability (e.g. a RUSTSEC ID, a CVE and a GHSA) end up in the
+/// same connected component, even if no single advisory lists all of them.
+#[derive(Clone, Debug, Default)]
+pub struct AliasGraph {
+    /// IDs linked to each ID, in both directions
+    edges: Map<Id, Set<Id>>,
+}
+
+/// IDs which are equivalent to a given ID
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AliasSet {
+    /// All IDs for the vulnerability, including the one which was resolved
+    pub ids: Set<Id>,
+
+    /// Aliases in this set which are claimed by more than one RustSec advisory
+    pub conflicts: Vec<AliasConflict>,
+}
+
+/// An alias claimed by more than one RustSec advisory.
+///
+/// This is either a mistake, or a vulnerability which affects several crates
+/// and has a separate advisory for each of them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasConflict {
+    /// The alias (e.g. a CVE or GHSA ID)
+    pub alias: Id,
+
+    /// The RustSec advisories claiming it
+    pub advisories: Set<Id>,
+}
+
+impl AliasGraph {
+    /// Create a new, empty alias graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the aliases of all advisories in the given database
+    pub fn add_database(&mut self, db: &Database) {
+        for advisory in db.iter() {
+            self.add_advisory(advisory);
+        }
+    }
+
+    /// Add the aliases of the given advisory
+    pub fn add_advisory(&mut self, advisory: &Advisory) {
+        let id = &advisory.metadata.id;
+        self.edges.entry(id.clone()).or_default();
+
+        for alias in &advisory.metadata.aliases {
+            self.link(id, alias);
+        }
+    }
+
+    /// Find all IDs which are equivalent to `id`.
+    ///
+    /// The result always includes `id` itself, even if it isn't known.
+    pub fn resolve(&self, id: &Id) -> AliasSet {
+        let mut ids = Set::new();
+        let mut pending = vec![id];
+
+        while let Some(id) = pending.pop() {
+            if ids.insert(id.clone()) {
+                pending.extend(self.edges.get(id).into_iter().flatten());
+            }
+        }
+
+        let conflicts = ids.iter().filter_map(|id| self.conflict(id)).collect();
+        AliasSet { ids, conflicts }
+    }
+
+    /// Find all aliases which are claimed by more than one RustSec advisory
+    pub fn conflicts(&self) -> Vec<AliasConflict> {
+        self.edges
+            .keys()
+            .filter_map(|id| self.conflict(id))
+            .collect()
+    }
+
+    /// Link two IDs in both directions
+    fn link(&mut self, a: &Id, b: &Id) {
+        self.edges.entry(a.clone()).or_default().insert(b.clone());
+        self.edges.entry(b.clone()).or_default().insert(a.clone());
+    }
+
+    /// Get the conflict for the given alias, if any
+    fn conflict(&self, alias: &Id) -> Option<AliasConflict> {
+        if alias.is_rustsec() {
+            return None;
+        }
+
+        let advisories = self
+            .edges
+            .get(alias)?
+            .iter()
+            .filter(|id| id.is_rustsec())
+            .cloned()
+            .collect::<Set<_>>();
+
+        (advisories.len() > 1).then(|| AliasConflict {
+            alias: alias.clone(),
+            advisories,
+        })
+    }
+}
+
+impl From<&Database> for AliasGraph {
+    fn from(db: &Database) -> Self {
+        let mut graph = Self::new();
+        graph.add_database(db);
+        graph
+    }
+}
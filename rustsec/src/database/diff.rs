@@ -0,0 +1,69 @@
+//! Differences between two versions of the advisory database
+
+use super::Database;
+use crate::advisory::Id;
+
+/// Differences between two versions of the advisory database, e.g. between
+/// two commits of the advisory repository.
+///
+/// Each list of IDs is sorted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DatabaseDiff {
+    /// Advisories which are only in the newer database
+    pub added: Vec<Id>,
+
+    /// Advisories which have changed, other than by being withdrawn
+    pub modified: Vec<Id>,
+
+    /// Advisories which have been withdrawn in the newer database
+    pub withdrawn: Vec<Id>,
+
+    /// Advisories which have been deleted from the newer database
+    pub removed: Vec<Id>,
+}
+
+impl DatabaseDiff {
+    /// Compare the `old` and `new` versions of a database
+    pub(super) fn new(old: &Database, new: &Database) -> Self {
+        let mut diff = Self::default();
+
+        for advisory in new.iter() {
+            let id = advisory.id();
+            match old.get(id) {
+                None => diff.added.push(id.clone()),
+                Some(previous) if previous == advisory => {}
+                Some(previous) if advisory.withdrawn() && !previous.withdrawn() => {
+                    diff.withdrawn.push(id.clone())
+                }
+                Some(_) => diff.modified.push(id.clone()),
+            }
+        }
+
+        diff.removed = old
+            .iter()
+            .map(|advisory| advisory.id())
+            .filter(|id| new.get(id).is_none())
+            .cloned()
+            .collect();
+
+        for ids in [
+            &mut diff.added,
+            &mut diff.modified,
+            &mut diff.withdrawn,
+            &mut diff.removed,
+        ] {
+            ids.sort();
+            ids.dedup();
+        }
+
+        diff
+    }
+
+    /// Are both versions of the database the same?
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.modified.is_empty()
+            && self.withdrawn.is_empty()
+            && self.removed.is_empty()
+    }
+}
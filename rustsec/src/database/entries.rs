@@ -2,7 +2,7 @@
 
 use super::Iter;
 use crate::{
-    Map,
+    Map, Set,
     advisory::{self, Advisory},
     collection::Collection,
     error::{Error, ErrorKind},
@@ -24,6 +24,10 @@ pub(crate) struct Entries {
     /// Index of advisory IDs to their slots
     index: Map<advisory::Id, Slot>,
 
+    /// Index of aliases (e.g. CVE or GHSA IDs) to the slots of the advisories
+    /// they refer to
+    aliases: Map<advisory::Id, Set<Slot>>,
+
     /// Advisory collection
     advisories: Vec<Advisory>,
 }
@@ -34,11 +38,14 @@ impl Entries {
         Self::default()
     }
 
-    /// Load an advisory from a file and insert it into the database entry table
+    /// Insert an advisory loaded from the given path, after checking that the
+    /// path matches its ID, package and collection
     // TODO(tarcieri): factor more of this into `advisory.rs`?
-    pub fn load_file(&mut self, path: &Path) -> Result<Option<Slot>, Error> {
-        let mut advisory = Advisory::load_file(path)?;
-
+    pub fn insert_file(
+        &mut self,
+        mut advisory: Advisory,
+        path: &Path,
+    ) -> Result<Option<Slot>, Error> {
         // TODO(tarcieri): deprecate and remove legacy TOML-based advisory format
         let expected_filename = match path.extension().and_then(|ext| ext.to_str()) {
             Some("md") => OsString::from(format!("{}.md", advisory.metadata.id)),
@@ -114,25 +121,43 @@ impl Entries {
             None => advisory.metadata.collection = Some(collection),
         }
 
+        self.insert(advisory, false)
+    }
+
+    /// Insert an advisory into the database entry table.
+    ///
+    /// When `shared_ids` is set, advisories for different packages may have
+    /// the same ID (as with OSV advisories affecting several crates), and
+    /// looking up that ID finds the first of them.
+    pub fn insert(&mut self, advisory: Advisory, shared_ids: bool) -> Result<Option<Slot>, Error> {
         // Ensure placeholder advisories load and parse correctly, but
         // don't actually insert them into the advisory database
         if advisory.metadata.id.is_placeholder() {
             return Ok(None);
         }
 
-        let id = advisory.metadata.id.clone();
         let slot = Slot(self.advisories.len());
-        self.advisories.push(advisory);
 
-        match self.index.entry(id) {
+        match self.index.entry(advisory.metadata.id.clone()) {
             map::Entry::Vacant(entry) => {
                 entry.insert(slot);
             }
+            map::Entry::Occupied(entry)
+                if shared_ids
+                    && self.advisories[entry.get().0].metadata.package
+                        != advisory.metadata.package => {}
             map::Entry::Occupied(entry) => {
                 fail!(ErrorKind::Parse, "duplicate advisory ID: {}", entry.key())
             }
         }
 
+        // The same vulnerability may affect several crates, each with its own
+        // advisory, so an alias can refer to more than one advisory
+        for alias in &advisory.metadata.aliases {
+            self.aliases.entry(alias.clone()).or_default().insert(slot);
+        }
+
+        self.advisories.push(advisory);
         Ok(Some(slot))
     }
 
@@ -141,11 +166,28 @@ impl Entries {
         self.index.get(id).and_then(|slot| self.get(*slot))
     }
 
+    /// Find the advisories which have the given `advisory::Id` as an alias
+    pub fn find_by_alias(&self, alias: &advisory::Id) -> impl Iterator<Item = &Advisory> {
+        self.aliases
+            .get(alias)
+            .into_iter()
+            .flatten()
+            .filter_map(|slot| self.get(*slot))
+    }
+
     /// Get an advisory from the database by its [`Slot`]
     pub fn get(&self, slot: Slot) -> Option<&Advisory> {
         self.advisories.get(slot.0)
     }
 
+    /// Iterate over all of the entries in the database, along with their [`Slot`]s
+    pub fn slots(&self) -> impl Iterator<Item = (Slot, &Advisory)> {
+        self.advisories
+            .iter()
+            .enumerate()
+            .map(|(i, advisory)| (Slot(i), advisory))
+    }
+
     /// Iterate over all of the entries in the database
     pub fn iter(&self) -> Iter<'_> {
         self.advisories.iter()
@@ -2,12 +2,13 @@
 //!
 use crate::{
     SourceId,
-    advisory::{Advisory, Severity},
+    advisory::{Advisory, Category, CweId, Date, Keyword, Severity, affected::FunctionPath},
     collection::Collection,
     package::{self, Package},
 };
 use platforms::target::{Arch, OS};
 use semver::Version;
+use serde::{Deserialize, Serialize};
 
 /// Queries against the RustSec database
 #[derive(Clone, Debug)]
@@ -24,6 +25,9 @@ pub struct Query {
     /// Source of the package advisories should be matched against
     package_source: Option<SourceId>,
 
+    /// How advisories are matched against packages from other sources
+    source_matching: SourceMatching,
+
     /// Severity threshold (i.e. minimum severity)
     severity: Option<Severity>,
 
@@ -36,6 +40,21 @@ pub struct Query {
     /// Year associated with the advisory ID
     year: Option<u32>,
 
+    /// Categories of vulnerability
+    categories: Vec<Category>,
+
+    /// Keywords describing the vulnerability
+    keywords: Vec<Keyword>,
+
+    /// CWE identifiers of the weaknesses involved
+    cwe_ids: Vec<CweId>,
+
+    /// Disclosure date as of which the database is queried
+    as_of: Option<Date>,
+
+    /// Function (or type, or module) which must be affected
+    function: Option<FunctionPath>,
+
     /// Query for withdrawn advisories
     /// (i.e. advisories which were soft-deleted from the database,
     /// as opposed to yanked crates)
@@ -61,10 +80,16 @@ impl Query {
             package_name: None,
             package_version: None,
             package_source: None,
+            source_matching: SourceMatching::default(),
             severity: None,
             target_arch: Default::default(),
             target_os: Default::default(),
             year: None,
+            categories: Default::default(),
+            keywords: Default::default(),
+            cwe_ids: Default::default(),
+            as_of: None,
+            function: None,
             withdrawn: None,
             informational: None,
         }
@@ -115,6 +140,13 @@ impl Query {
         self
     }
 
+    /// Set how advisories are matched against packages which aren't from
+    /// the source the advisory is for (see [`SourceMatching`])
+    pub fn source_matching(mut self, source_matching: SourceMatching) -> Self {
+        self.source_matching = source_matching;
+        self
+    }
+
     /// Set minimum severity threshold according to the CVSS
     /// Qualitative Severity Rating Scale.
     ///
@@ -143,6 +175,53 @@ impl Query {
         self
     }
 
+    /// Query for advisories in any of the given categories
+    /// (e.g. memory corruption or cryptographic failures).
+    pub fn categories(mut self, categories: Vec<Category>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Query for advisories with any of the given keywords.
+    pub fn keywords(mut self, keywords: Vec<Keyword>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Query for advisories involving any of the given CWE weaknesses.
+    pub fn cwe_ids(mut self, cwe_ids: Vec<CweId>) -> Self {
+        self.cwe_ids = cwe_ids;
+        self
+    }
+
+    /// Query for advisories affecting the given function, or the items within
+    /// the given type or module.
+    ///
+    /// When a package version is set, only the functions which are vulnerable
+    /// in that version are considered. Advisories which don't list affected
+    /// functions never match.
+    pub fn function(mut self, path: FunctionPath) -> Self {
+        self.function = Some(path);
+        self
+    }
+
+    /// Query only the advisories disclosed as of the given date.
+    ///
+    /// This filters on the `date` of advisories (i.e. when the vulnerability
+    /// was disclosed, **not** when the advisory was added to the database),
+    /// and advisories withdrawn after `date` are treated as not withdrawn.
+    /// Changes made to advisories since then (e.g. newly patched versions) are
+    /// not undone either, so this only approximates an audit performed back
+    /// then.
+    ///
+    /// To query the database exactly as it was at some point, load it from
+    /// that revision of its git repository with `Database::load_from_commit`
+    /// (requires the `git` feature) instead.
+    pub fn as_of(mut self, date: Date) -> Self {
+        self.as_of = Some(date);
+        self
+    }
+
     /// Query for withdrawn advisories.
     ///
     /// By default they will be omitted from query results.
@@ -151,6 +230,16 @@ impl Query {
         self
     }
 
+    /// Include withdrawn advisories in query results, alongside the ones
+    /// which haven't been withdrawn.
+    ///
+    /// This undoes [`Query::withdrawn`], e.g. to look up any advisory for a
+    /// package with [`Query::crate_scope`].
+    pub fn include_withdrawn(mut self) -> Self {
+        self.withdrawn = None;
+        self
+    }
+
     /// Query for informational advisories. By default they will be omitted
     /// from query results.
     pub fn informational(mut self, setting: bool) -> Self {
@@ -178,20 +267,10 @@ impl Query {
             return false;
         }
 
-        if let Some(package_source) = &self.package_source {
-            let advisory_source = advisory
-                .metadata
-                .source
-                .as_ref()
-                .cloned()
-                .unwrap_or_default();
-
-            // TODO(tarcieri): better source comparison?
-            if advisory_source.kind() != package_source.kind()
-                || advisory_source.url() != package_source.url()
-            {
-                return false;
-            }
+        if let Some(package_source) = &self.package_source
+            && !self.source_matches(advisory, package_source)
+        {
+            return false;
         }
 
         if let Some(severity_threshold) = self.severity
@@ -230,8 +309,57 @@ impl Query {
             return false;
         }
 
+        if !self.categories.is_empty()
+            && !self
+                .categories
+                .iter()
+                .any(|category| advisory.metadata.categories.contains(category))
+        {
+            return false;
+        }
+
+        if !self.keywords.is_empty()
+            && !self
+                .keywords
+                .iter()
+                .any(|keyword| advisory.metadata.keywords.contains(keyword))
+        {
+            return false;
+        }
+
+        if !self.cwe_ids.is_empty()
+            && !self
+                .cwe_ids
+                .iter()
+                .any(|cwe_id| advisory.metadata.cwe_ids.contains(cwe_id))
+        {
+            return false;
+        }
+
+        if let Some(path) = &self.function {
+            let affected = match &advisory.affected {
+                Some(affected) => affected,
+                None => return false,
+            };
+
+            let matches = match &self.package_version {
+                Some(version) => affected.affects_function(path, version),
+                None => affected.functions.keys().any(|f| f.overlaps(path)),
+            };
+
+            if !matches {
+                return false;
+            }
+        }
+
+        if let Some(as_of) = &self.as_of
+            && &advisory.metadata.date > as_of
+        {
+            return false;
+        }
+
         if let Some(withdrawn) = self.withdrawn
-            && withdrawn != advisory.metadata.withdrawn.is_some()
+            && withdrawn != self.is_withdrawn(advisory)
         {
             return false;
         }
@@ -244,6 +372,39 @@ impl Query {
 
         true
     }
+
+    /// Does the advisory apply to packages from the given source?
+    fn source_matches(&self, advisory: &Advisory, package_source: &SourceId) -> bool {
+        let advisory_source = advisory
+            .metadata
+            .source
+            .as_ref()
+            .cloned()
+            .unwrap_or_default();
+
+        if same_source(&advisory_source, package_source) {
+            return true;
+        }
+
+        // Packages from other registries or git repositories are often forks
+        // or mirrors of crates.io packages, which share their vulnerabilities
+        match self.source_matching {
+            SourceMatching::Exact => false,
+            SourceMatching::Name => {
+                advisory_source.is_default_registry()
+                    && (package_source.is_registry() || package_source.is_git())
+            }
+        }
+    }
+
+    /// Was the advisory withdrawn as of the date of this query?
+    fn is_withdrawn(&self, advisory: &Advisory) -> bool {
+        advisory
+            .metadata
+            .withdrawn
+            .as_ref()
+            .is_some_and(|withdrawn| self.as_of.as_ref().is_none_or(|as_of| withdrawn <= as_of))
+    }
 }
 
 impl Default for Query {
@@ -251,3 +412,28 @@ impl Default for Query {
         Query::crate_scope()
     }
 }
+
+/// How advisories are matched against packages from sources other than the
+/// one they are for (crates.io, unless the advisory says otherwise), such as
+/// alternative registries or git repositories.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceMatching {
+    /// Only match packages from the source the advisory is for
+    #[default]
+    Exact,
+
+    /// Also match crates.io advisories against packages with the same name
+    /// from alternative registries and git repositories.
+    ///
+    /// When auditing a lockfile, advisories for the package's own source take
+    /// precedence: if there are any, crates.io advisories aren't matched.
+    Name,
+}
+
+/// Are both sources the same? (crates.io can be accessed either through its
+/// git index or its sparse index)
+fn same_source(a: &SourceId, b: &SourceId) -> bool {
+    (a.is_default_registry() && b.is_default_registry())
+        || (a.kind() == b.kind() && a.url() == b.url())
+}
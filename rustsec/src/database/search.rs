@@ -0,0 +1,73 @@
+//! Full-text search index over advisories
+
+use super::entries::Slot;
+use crate::{Map, Set, advisory::Advisory};
+
+/// Weight of a term appearing in the title of an advisory
+const TITLE_WEIGHT: u32 = 3;
+
+/// Weight of a term appearing in the keywords of an advisory
+const KEYWORD_WEIGHT: u32 = 2;
+
+/// Weight of a term appearing in the description of an advisory
+const DESCRIPTION_WEIGHT: u32 = 1;
+
+/// Relevance of an advisory to a search query: the number of query terms it
+/// matches, then the total weight of those terms
+pub(crate) type Score = (usize, u32);
+
+/// Database index which maps terms to the advisories containing them
+#[derive(Debug, Default)]
+pub(crate) struct SearchIndex(Map<String, Map<Slot, u32>>);
+
+impl SearchIndex {
+    /// Create a new search index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index the title, description and keywords of an advisory
+    pub fn insert(&mut self, advisory: &Advisory, slot: Slot) {
+        let fields = [
+            (advisory.title(), TITLE_WEIGHT),
+            (advisory.description(), DESCRIPTION_WEIGHT),
+        ]
+        .into_iter()
+        .chain(
+            advisory
+                .metadata
+                .keywords
+                .iter()
+                .map(|keyword| (keyword.as_str(), KEYWORD_WEIGHT)),
+        );
+
+        for (text, weight) in fields {
+            for term in tokenize(text) {
+                *self.0.entry(term).or_default().entry(slot).or_default() += weight;
+            }
+        }
+    }
+
+    /// Find the advisories matching any of the terms in `query`, along with
+    /// their relevance
+    pub fn search(&self, query: &str) -> Map<Slot, Score> {
+        let mut scores = Map::<Slot, Score>::new();
+
+        for term in tokenize(query).collect::<Set<_>>() {
+            for (slot, weight) in self.0.get(&term).into_iter().flatten() {
+                let score = scores.entry(*slot).or_default();
+                score.0 += 1;
+                score.1 += weight;
+            }
+        }
+
+        scores
+    }
+}
+
+/// Split text into lowercase alphanumeric terms
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+}
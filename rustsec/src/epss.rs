@@ -0,0 +1,246 @@
+//! Exploit Prediction Scoring System (EPSS) data
+//!
+//! [EPSS] is a data-driven estimate of the probability that a CVE will be
+//! exploited in the wild over the next 30 days, published daily by FIRST.
+//!
+//! Scores are loaded from the (decompressed) CSV file distributed by FIRST at
+//! <https://epss.cyentia.com/epss_scores-current.csv.gz>, which `cargo audit`
+//! downloads with `--epss`.
+//!
+//! [EPSS]: https://www.first.org/epss/
+
+use crate::{
+    Map,
+    advisory::{self, Id},
+    error::{Error, ErrorKind},
+    fs,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::Path, str::FromStr};
+
+/// EPSS score for a single CVE
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "RawScore")]
+pub struct Score {
+    /// Probability of exploitation in the next 30 days (0.0 - 1.0)
+    pub probability: f64,
+
+    /// Percentile of this score relative to all scored CVEs (0.0 - 1.0)
+    pub percentile: f64,
+}
+
+// Scores are validated to be finite when parsed, so they have a total order
+impl Eq for Score {}
+
+impl Score {
+    /// Create a new EPSS score, ensuring both values are probabilities
+    pub fn new(probability: f64, percentile: f64) -> Result<Self, Error> {
+        for value in [probability, percentile] {
+            if !(0.0..=1.0).contains(&value) {
+                fail!(ErrorKind::Parse, "EPSS value out of range: {}", value);
+            }
+        }
+
+        Ok(Self {
+            probability,
+            percentile,
+        })
+    }
+}
+
+impl TryFrom<RawScore> for Score {
+    type Error = Error;
+
+    fn try_from(raw: RawScore) -> Result<Self, Self::Error> {
+        Self::new(raw.probability, raw.percentile)
+    }
+}
+
+/// Raw deserialized score that didn't pass validation yet
+#[derive(Deserialize)]
+struct RawScore {
+    probability: f64,
+    percentile: f64,
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.5} (percentile {:.5})",
+            self.probability, self.percentile
+        )
+    }
+}
+
+/// Collection of EPSS scores, indexed by CVE ID
+#[derive(Clone, Debug, Default)]
+pub struct Scores {
+    /// Model version the scores were generated with (if known)
+    model_version: Option<String>,
+
+    /// Date the scores were published (if known)
+    score_date: Option<String>,
+
+    /// Scores indexed by CVE ID
+    scores: Map<String, Score>,
+}
+
+impl Scores {
+    /// Load EPSS scores from a (decompressed) FIRST EPSS CSV file
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let csv = fs::read_to_string(path).map_err(|e| {
+            Error::with_source(
+                ErrorKind::Io,
+                format!("couldn't open {}", path.display()),
+                e,
+            )
+        })?;
+
+        csv.parse().map_err(|e| {
+            Error::with_source(
+                ErrorKind::Parse,
+                format!("error parsing {}", path.display()),
+                e,
+            )
+        })
+    }
+
+    /// Get the model version these scores were generated with (if known)
+    pub fn model_version(&self) -> Option<&str> {
+        self.model_version.as_deref()
+    }
+
+    /// Get the date these scores were published (if known)
+    pub fn score_date(&self) -> Option<&str> {
+        self.score_date.as_deref()
+    }
+
+    /// Look up the score for a given CVE ID
+    pub fn get(&self, id: &Id) -> Option<Score> {
+        if !id.is_cve() {
+            return None;
+        }
+
+        self.scores.get(id.as_str()).copied()
+    }
+
+    /// Find the highest score for any of the CVE IDs an advisory is known by
+    pub fn for_advisory(&self, metadata: &advisory::Metadata) -> Option<Score> {
+        std::iter::once(&metadata.id)
+            .chain(metadata.aliases.iter())
+            .filter_map(|id| self.get(id))
+            .max_by(|a, b| a.probability.total_cmp(&b.probability))
+    }
+
+    /// Number of CVEs with a score
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Are there no scores in this collection?
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+impl FromStr for Scores {
+    type Err = Error;
+
+    fn from_str(csv: &str) -> Result<Self, Error> {
+        let mut result = Self::default();
+
+        for (line_no, line) in csv.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            // The first line carries metadata, e.g.:
+            // `#model_version:v2023.03.01,score_date:2023-03-07T00:00:00+0000`
+            if let Some(comment) = line.strip_prefix('#') {
+                for field in comment.split(',') {
+                    match field.split_once(':') {
+                        Some(("model_version", value)) => {
+                            result.model_version = Some(value.to_owned())
+                        }
+                        Some(("score_date", value)) => result.score_date = Some(value.to_owned()),
+                        _ => (),
+                    }
+                }
+                continue;
+            }
+
+            let mut columns = line.split(',').map(str::trim);
+            let (Some(cve), Some(epss), Some(percentile)) =
+                (columns.next(), columns.next(), columns.next())
+            else {
+                fail!(ErrorKind::Parse, "malformed EPSS line {}", line_no + 1);
+            };
+
+            // Column header
+            if cve == "cve" {
+                continue;
+            }
+
+            let parse = |value: &str| {
+                value.parse::<f64>().map_err(|e| {
+                    Error::with_source(
+                        ErrorKind::Parse,
+                        format!("invalid EPSS value on line {}", line_no + 1),
+                        e,
+                    )
+                })
+            };
+
+            let score = Score::new(parse(epss)?, parse(percentile)?)?;
+            result.scores.insert(cve.to_owned(), score);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_CSV: &str = "\
+#model_version:v2023.03.01,score_date:2023-03-07T00:00:00+0000
+cve,epss,percentile
+CVE-2021-44228,0.97565,0.99996
+CVE-2022-24713,0.00217,0.59133
+";
+
+    #[test]
+    fn parse_csv() {
+        let scores: Scores = EXAMPLE_CSV.parse().unwrap();
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores.model_version(), Some("v2023.03.01"));
+
+        let score = scores.get(&"CVE-2021-44228".parse().unwrap()).unwrap();
+        assert_eq!(score.probability, 0.97565);
+        assert_eq!(score.percentile, 0.99996);
+
+        assert!(scores.get(&"CVE-2000-0001".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        assert!("CVE-2021-44228,1.5,0.9".parse::<Scores>().is_err());
+        assert!("CVE-2021-44228,NaN,0.9".parse::<Scores>().is_err());
+    }
+
+    #[test]
+    fn deserialize_validates_range() {
+        let score: Score =
+            serde_json::from_str(r#"{"probability":0.5,"percentile":0.75}"#).unwrap();
+        assert_eq!(score, Score::new(0.5, 0.75).unwrap());
+
+        assert!(serde_json::from_str::<Score>(r#"{"probability":1.5,"percentile":0.75}"#).is_err());
+        assert!(serde_json::from_str::<Score>(r#"{"probability":0.5,"percentile":-1}"#).is_err());
+    }
+}
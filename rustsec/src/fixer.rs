@@ -2,17 +2,36 @@
 //!
 //! This module is **experimental**, and its behavior may change in the future.
 
-use crate::vulnerability::Vulnerability;
+use crate::{Version, vulnerability::Vulnerability};
 use cargo_lock::{Lockfile, Package};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+#[cfg(feature = "git")]
+use crate::{
+    VersionReq,
+    error::{Error, ErrorKind},
+    package::Name,
+    registry::{CachedIndex, PublishedVersion},
+};
+
+/// A `cargo update --precise` operation planned by the [`Fixer`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreciseUpdate {
+    /// Package in `Cargo.lock` to be updated
+    pub package: Package,
+
+    /// Version the package should be updated to
+    pub version: Version,
+}
+
 /// Auto-fixer for vulnerable dependencies
 #[cfg_attr(docsrs, doc(cfg(feature = "fix")))]
 pub struct Fixer {
     lockfile: Lockfile,
     manifest_path: Option<PathBuf>,
     path_to_cargo: Option<PathBuf>,
+    latest: bool,
 }
 
 impl Fixer {
@@ -32,9 +51,20 @@ impl Fixer {
             lockfile: cargo_lock,
             manifest_path: cargo_toml,
             path_to_cargo,
+            latest: false,
         }
     }
 
+    /// Plan updates to the latest patched releases instead of the lowest ones.
+    ///
+    /// By default, [`Fixer::plan_precise_updates`] picks the lowest patched
+    /// release (and the lowest bumps of the packages depending on it) that
+    /// satisfy the existing requirements, to minimize churn.
+    pub fn latest(mut self, latest: bool) -> Self {
+        self.latest = latest;
+        self
+    }
+
     /// Returns a command that calls `cargo update` with the right arguments
     /// to attempt to fix this vulnerability.
     ///
@@ -62,6 +92,335 @@ impl Fixer {
 
         command
     }
+
+    /// Iterate over the packages in `Cargo.lock` which depend on the given package
+    pub fn dependents<'a>(&'a self, package: &'a Package) -> impl Iterator<Item = &'a Package> {
+        self.lockfile
+            .packages
+            .iter()
+            .filter(|pkg| pkg.dependencies.iter().any(|dep| dep.matches(package)))
+    }
+
+    /// Returns a command that calls `cargo update` for a single package in
+    /// `Cargo.lock`, e.g. to pick up a `[patch]` section replacing it.
+    pub fn get_package_update_command(&self, package: &Package, dry_run: bool) -> Command {
+        let cargo_path: &Path = self.path_to_cargo.as_deref().unwrap_or(Path::new("cargo"));
+        let mut command = Command::new(cargo_path);
+        command.arg("update");
+        if let Some(path) = self.manifest_path.as_ref() {
+            command.arg("--manifest-path").arg(path);
+        }
+        if dry_run {
+            command.arg("--dry-run");
+        }
+        command.arg(pkgid(package));
+
+        command
+    }
+
+    /// Returns a command that calls `cargo update --precise` to perform
+    /// the given planned update.
+    pub fn get_precise_update_command(&self, update: &PreciseUpdate, dry_run: bool) -> Command {
+        let mut command = self.get_package_update_command(&update.package, dry_run);
+        command.arg("--precise").arg(update.version.to_string());
+
+        command
+    }
+
+    /// Plan the `cargo update --precise` operations which move the vulnerable
+    /// package onto a patched version, including bumps of the packages that
+    /// depend on it when their requirements would otherwise rule out every
+    /// patched release.
+    ///
+    /// This works for transitive dependencies as well as direct ones.
+    /// Dependents are only ever bumped to semver-compatible releases,
+    /// and only one level up the dependency tree.
+    ///
+    /// The lowest suitable versions are chosen unless [`Fixer::latest`] is set.
+    ///
+    /// Returns an error describing why the vulnerability can't be fixed
+    /// if no such plan exists.
+    #[cfg(feature = "git")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+    pub fn plan_precise_updates(
+        &self,
+        vulnerability: &Vulnerability,
+        index: &mut CachedIndex,
+    ) -> Result<Vec<PreciseUpdate>, Error> {
+        self.plan_with(vulnerability, |name| index.published_versions(name))
+    }
+
+    /// Find the packages whose requirements on the vulnerable package rule out
+    /// its latest patched release, even after a semver-compatible update.
+    ///
+    /// These are the packages to `[patch]` (e.g. with an unreleased revision that
+    /// relaxes the requirement) when [`Fixer::plan_precise_updates`] fails.
+    #[cfg(feature = "git")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+    pub fn find_blockers(
+        &self,
+        vulnerability: &Vulnerability,
+        index: &mut CachedIndex,
+    ) -> Result<Vec<Blocker>, Error> {
+        self.blockers_with(vulnerability, |name| index.published_versions(name))
+    }
+
+    /// Plan updates, looking up published versions with the given function
+    #[cfg(feature = "git")]
+    fn plan_with<F>(
+        &self,
+        vulnerability: &Vulnerability,
+        mut published_versions: F,
+    ) -> Result<Vec<PreciseUpdate>, Error>
+    where
+        F: FnMut(&Name) -> Result<Vec<PublishedVersion>, Error>,
+    {
+        let vulnerable = &vulnerability.package;
+        let candidates = patched_releases(vulnerability, &mut published_versions)?;
+        let dependents = self.dependent_requirements(vulnerable, &mut published_versions)?;
+
+        // Try the lowest patched release first, unless asked for the latest
+        let ordered: Box<dyn Iterator<Item = &Version>> = if self.latest {
+            Box::new(candidates.iter().rev())
+        } else {
+            Box::new(candidates.iter())
+        };
+
+        'candidates: for candidate in ordered {
+            let mut updates = vec![];
+
+            for dependent in &dependents {
+                if dependent.allows(candidate) {
+                    continue;
+                }
+
+                match dependent.bump_allowing(&vulnerable.name, candidate, self.latest) {
+                    Some(bump) => updates.push(PreciseUpdate {
+                        package: dependent.package.clone(),
+                        version: bump.version.clone(),
+                    }),
+                    None => continue 'candidates,
+                }
+            }
+
+            updates.push(PreciseUpdate {
+                package: vulnerable.clone(),
+                version: candidate.clone(),
+            });
+
+            return Ok(updates);
+        }
+
+        let latest = candidates.last().expect("candidates can't be empty");
+        let blockers: Vec<String> = dependents
+            .iter()
+            .filter(|dependent| !dependent.allows(latest))
+            .filter_map(|dependent| {
+                Some(format!(
+                    "{} {} requires {} {}",
+                    dependent.package.name,
+                    dependent.package.version,
+                    vulnerable.name,
+                    dependent.requirement.as_ref()?
+                ))
+            })
+            .collect();
+
+        fail!(
+            ErrorKind::Version,
+            "no patched release of {} satisfies the requirements of the packages depending on it ({}), \
+             and no semver-compatible release of those packages relaxes them",
+            vulnerable.name,
+            blockers.join(", ")
+        );
+    }
+
+    /// Find blockers, looking up published versions with the given function
+    #[cfg(feature = "git")]
+    fn blockers_with<F>(
+        &self,
+        vulnerability: &Vulnerability,
+        mut published_versions: F,
+    ) -> Result<Vec<Blocker>, Error>
+    where
+        F: FnMut(&Name) -> Result<Vec<PublishedVersion>, Error>,
+    {
+        let vulnerable = &vulnerability.package;
+        let candidates = patched_releases(vulnerability, &mut published_versions)?;
+        let latest = candidates.last().expect("candidates can't be empty");
+
+        Ok(self
+            .dependent_requirements(vulnerable, &mut published_versions)?
+            .into_iter()
+            .filter(|dependent| {
+                !dependent.allows(latest)
+                    && dependent
+                        .bump_allowing(&vulnerable.name, latest, false)
+                        .is_none()
+            })
+            .filter_map(|dependent| {
+                Some(Blocker {
+                    package: dependent.package.clone(),
+                    dependency: vulnerable.name.clone(),
+                    requirement: dependent.requirement?,
+                    patched: latest.clone(),
+                })
+            })
+            .collect())
+    }
+
+    /// Find out what every package depending on the vulnerable one
+    /// requires of it, and which (semver-compatible) releases of it could be
+    /// bumped to in order to relax that requirement.
+    #[cfg(feature = "git")]
+    fn dependent_requirements<'a, F>(
+        &'a self,
+        vulnerable: &'a Package,
+        published_versions: &mut F,
+    ) -> Result<Vec<DependentRequirement<'a>>, Error>
+    where
+        F: FnMut(&Name) -> Result<Vec<PublishedVersion>, Error>,
+    {
+        let mut dependents = vec![];
+        for dependent in self.dependents(vulnerable) {
+            // Requirements of packages outside of crates.io (e.g. workspace members)
+            // aren't known here; `cargo update` will report if they're violated.
+            if !dependent
+                .source
+                .as_ref()
+                .is_some_and(|source| source.is_default_registry())
+            {
+                continue;
+            }
+
+            let releases = published_versions(&dependent.name)?;
+            let requirement = releases
+                .iter()
+                .find(|v| v.version == dependent.version)
+                .and_then(|v| v.requirement(&vulnerable.name))
+                .cloned();
+            let compatible = VersionReq {
+                comparators: vec![semver::Comparator {
+                    op: semver::Op::Caret,
+                    major: dependent.version.major,
+                    minor: Some(dependent.version.minor),
+                    patch: Some(dependent.version.patch),
+                    pre: dependent.version.pre.clone(),
+                }],
+            };
+            let mut bumps: Vec<PublishedVersion> = releases
+                .into_iter()
+                .filter(|v| {
+                    !v.yanked && v.version > dependent.version && compatible.matches(&v.version)
+                })
+                .collect();
+            bumps.sort_by(|a, b| a.version.cmp(&b.version));
+
+            dependents.push(DependentRequirement {
+                package: dependent,
+                requirement,
+                bumps,
+            });
+        }
+
+        Ok(dependents)
+    }
+}
+
+/// A package whose requirement on a vulnerable package rules out its patched releases
+#[cfg(feature = "git")]
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Blocker {
+    /// Package in `Cargo.lock` depending on the vulnerable package
+    pub package: Package,
+
+    /// Name of the vulnerable package
+    pub dependency: Name,
+
+    /// Requirement it places on the vulnerable package
+    pub requirement: VersionReq,
+
+    /// Latest patched release of the vulnerable package
+    pub patched: Version,
+}
+
+/// What a package in `Cargo.lock` requires of a vulnerable package it depends on
+#[cfg(feature = "git")]
+struct DependentRequirement<'a> {
+    /// The dependent package
+    package: &'a Package,
+
+    /// Its requirement on the vulnerable package (if known)
+    requirement: Option<VersionReq>,
+
+    /// Semver-compatible releases of the dependent it could be updated to
+    bumps: Vec<PublishedVersion>,
+}
+
+#[cfg(feature = "git")]
+impl DependentRequirement<'_> {
+    /// Does the locked version of the dependent allow the given version?
+    fn allows(&self, version: &Version) -> bool {
+        self.requirement
+            .as_ref()
+            .is_none_or(|req| req.matches(version))
+    }
+
+    /// Find the lowest (or latest) compatible release of the dependent allowing the given version
+    fn bump_allowing(
+        &self,
+        name: &Name,
+        version: &Version,
+        latest: bool,
+    ) -> Option<&PublishedVersion> {
+        let allows = |bump: &&PublishedVersion| {
+            bump.requirement(name)
+                .is_some_and(|req| req.matches(version))
+        };
+
+        if latest {
+            self.bumps.iter().rev().find(allows)
+        } else {
+            self.bumps.iter().find(allows)
+        }
+    }
+}
+
+/// Find the unyanked, patched, stable releases of the vulnerable package newer
+/// than the one in use, sorted in ascending order
+#[cfg(feature = "git")]
+fn patched_releases<F>(
+    vulnerability: &Vulnerability,
+    published_versions: &mut F,
+) -> Result<Vec<Version>, Error>
+where
+    F: FnMut(&Name) -> Result<Vec<PublishedVersion>, Error>,
+{
+    let vulnerable = &vulnerability.package;
+
+    let mut candidates: Vec<Version> = published_versions(&vulnerable.name)?
+        .into_iter()
+        .filter(|v| {
+            !v.yanked
+                && v.version.pre.is_empty()
+                && v.version > vulnerable.version
+                && !vulnerability.versions.is_vulnerable(&v.version)
+        })
+        .map(|v| v.version)
+        .collect();
+    candidates.sort();
+
+    if candidates.is_empty() {
+        fail!(
+            ErrorKind::NotFound,
+            "no unyanked release of {} newer than {} is patched",
+            vulnerable.name,
+            vulnerable.version
+        );
+    }
+
+    Ok(candidates)
 }
 
 /// Returns a Cargo unique identifier for a package.
@@ -75,3 +434,187 @@ fn pkgid(pkg: &Package) -> String {
         None => format!("{}@{}", pkg.name, pkg.version),
     }
 }
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use super::*;
+    use crate::Advisory;
+
+    const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2101"
+package = "base"
+date = "2001-02-03"
+
+[versions]
+patched = [">= 1.2.3, < 2.0.0", ">= 2.1.0"]
+```
+
+# Example advisory
+"#;
+
+    const LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["middle"]
+
+[[package]]
+name = "base"
+version = "1.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "middle"
+version = "0.3.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["base"]
+"#;
+
+    fn release(version: &str, deps: &[(&str, &str)]) -> PublishedVersion {
+        PublishedVersion {
+            version: version.parse().unwrap(),
+            yanked: false,
+            dependencies: deps
+                .iter()
+                .map(|(name, req)| (name.parse().unwrap(), req.parse().unwrap()))
+                .collect(),
+        }
+    }
+
+    fn plan(
+        base: Vec<PublishedVersion>,
+        middle: Vec<PublishedVersion>,
+    ) -> Result<Vec<PreciseUpdate>, Error> {
+        plan_latest(base, middle, false)
+    }
+
+    fn plan_latest(
+        base: Vec<PublishedVersion>,
+        middle: Vec<PublishedVersion>,
+        latest: bool,
+    ) -> Result<Vec<PreciseUpdate>, Error> {
+        let advisory: Advisory = ADVISORY.parse().unwrap();
+        let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+        let package = lockfile
+            .packages
+            .iter()
+            .find(|pkg| pkg.name.as_str() == "base")
+            .unwrap();
+        let vulnerability = Vulnerability::new(&advisory, package);
+        let fixer = Fixer::new(lockfile.clone(), None, None).latest(latest);
+
+        fixer.plan_with(&vulnerability, |name| match name.as_str() {
+            "base" => Ok(base.clone()),
+            "middle" => Ok(middle.clone()),
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn transitive_update() {
+        let updates = plan(
+            vec![release("1.2.0", &[]), release("1.2.3", &[])],
+            vec![release("0.3.0", &[("base", "^1.2")])],
+        )
+        .unwrap();
+
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].package.name.as_str(), "base");
+        assert_eq!(updates[0].version.to_string(), "1.2.3");
+    }
+
+    #[test]
+    fn minimal_and_latest() {
+        let base = vec![
+            release("1.2.0", &[]),
+            release("1.2.3", &[]),
+            release("1.4.0", &[]),
+            release("2.1.0", &[]),
+        ];
+        let middle = vec![release("0.3.0", &[("base", "^1.2")])];
+
+        let updates = plan(base.clone(), middle.clone()).unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].version.to_string(), "1.2.3");
+
+        let updates = plan_latest(base, middle, true).unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].version.to_string(), "1.4.0");
+    }
+
+    #[test]
+    fn parent_bump() {
+        let updates = plan(
+            vec![release("1.2.0", &[]), release("2.1.0", &[])],
+            vec![
+                release("0.3.0", &[("base", "^1.2")]),
+                release("0.3.1", &[("base", "^2.1")]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].package.name.as_str(), "middle");
+        assert_eq!(updates[0].version.to_string(), "0.3.1");
+        assert_eq!(updates[1].package.name.as_str(), "base");
+        assert_eq!(updates[1].version.to_string(), "2.1.0");
+    }
+
+    #[test]
+    fn unfixable() {
+        let mut yanked = release("1.2.3", &[]);
+        yanked.yanked = true;
+        assert_eq!(
+            plan(vec![release("1.2.0", &[]), yanked], vec![])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NotFound
+        );
+
+        // The only compatible fix for `middle` is behind a breaking release
+        assert_eq!(
+            plan(
+                vec![release("1.2.0", &[]), release("2.1.0", &[])],
+                vec![
+                    release("0.3.0", &[("base", "^1.2")]),
+                    release("0.4.0", &[("base", "^2.1")]),
+                ],
+            )
+            .unwrap_err()
+            .kind(),
+            ErrorKind::Version
+        );
+    }
+
+    #[test]
+    fn blockers() {
+        let advisory: Advisory = ADVISORY.parse().unwrap();
+        let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+        let package = lockfile
+            .packages
+            .iter()
+            .find(|pkg| pkg.name.as_str() == "base")
+            .unwrap();
+        let vulnerability = Vulnerability::new(&advisory, package);
+        let fixer = Fixer::new(lockfile.clone(), None, None);
+
+        let blockers = fixer
+            .blockers_with(&vulnerability, |name| match name.as_str() {
+                "base" => Ok(vec![release("1.2.0", &[]), release("2.1.0", &[])]),
+                "middle" => Ok(vec![
+                    release("0.3.0", &[("base", "^1.2")]),
+                    release("0.4.0", &[("base", "^2.1")]),
+                ]),
+                _ => unreachable!(),
+            })
+            .unwrap();
+
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].package.name.as_str(), "middle");
+        assert_eq!(blockers[0].requirement.to_string(), "^1.2");
+        assert_eq!(blockers[0].patched.to_string(), "2.1.0");
+    }
+}
@@ -0,0 +1,121 @@
+//! CISA Known Exploited Vulnerabilities (KEV) catalog
+//!
+//! The [KEV catalog] lists CVEs which CISA has evidence of being actively
+//! exploited in the wild. It's distributed as a JSON file at
+//! <https://www.cisa.gov/sites/default/files/feeds/known_exploited_vulnerabilities.json>,
+//! which `cargo audit` downloads with `--kev`.
+//!
+//! [KEV catalog]: https://www.cisa.gov/known-exploited-vulnerabilities-catalog
+
+use crate::advisory::{self, Id};
+use serde::{Deserialize, Serialize};
+
+/// The CISA KEV catalog
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Catalog {
+    /// Title of the catalog
+    #[serde(default)]
+    pub title: String,
+
+    /// Version of the catalog
+    #[serde(default)]
+    pub catalog_version: String,
+
+    /// Date and time the catalog was released
+    #[serde(default)]
+    pub date_released: String,
+
+    /// Known exploited vulnerabilities
+    #[serde(default)]
+    pub vulnerabilities: Vec<Entry>,
+}
+
+impl Catalog {
+    /// Look up the catalog entry for a given CVE ID
+    pub fn get(&self, id: &Id) -> Option<&Entry> {
+        if !id.is_cve() {
+            return None;
+        }
+
+        self.vulnerabilities
+            .iter()
+            .find(|entry| &entry.cve_id == id)
+    }
+
+    /// Find the catalog entry for any of the CVE IDs an advisory is known by
+    pub fn for_advisory(&self, metadata: &advisory::Metadata) -> Option<&Entry> {
+        std::iter::once(&metadata.id)
+            .chain(metadata.aliases.iter())
+            .find_map(|id| self.get(id))
+    }
+}
+
+/// An entry in the KEV catalog
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// CVE ID of the exploited vulnerability
+    #[serde(rename = "cveID")]
+    pub cve_id: Id,
+
+    /// Name of the vulnerability
+    #[serde(default)]
+    pub vulnerability_name: String,
+
+    /// Date the vulnerability was added to the catalog (`YYYY-MM-DD`)
+    #[serde(default)]
+    pub date_added: String,
+
+    /// Action required to remediate the vulnerability
+    #[serde(default)]
+    pub required_action: String,
+
+    /// Date by which US federal agencies must remediate (`YYYY-MM-DD`)
+    #[serde(default)]
+    pub due_date: String,
+
+    /// Whether the vulnerability is known to be used in ransomware campaigns
+    /// (`Known` or `Unknown`)
+    #[serde(default)]
+    pub known_ransomware_campaign_use: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_JSON: &str = r#"{
+        "title": "CISA Catalog of Known Exploited Vulnerabilities",
+        "catalogVersion": "2024.01.02",
+        "dateReleased": "2024-01-02T15:00:00.0000Z",
+        "count": 1,
+        "vulnerabilities": [
+            {
+                "cveID": "CVE-2021-44228",
+                "vendorProject": "Apache",
+                "product": "Log4j2",
+                "vulnerabilityName": "Apache Log4j2 Remote Code Execution Vulnerability",
+                "dateAdded": "2021-12-10",
+                "shortDescription": "...",
+                "requiredAction": "Apply updates per vendor instructions.",
+                "dueDate": "2021-12-24",
+                "knownRansomwareCampaignUse": "Known",
+                "notes": "",
+                "cwes": ["CWE-20"]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parse_catalog() {
+        let catalog: Catalog = serde_json::from_str(EXAMPLE_JSON).unwrap();
+        assert_eq!(catalog.catalog_version, "2024.01.02");
+
+        let entry = catalog.get(&"CVE-2021-44228".parse().unwrap()).unwrap();
+        assert_eq!(entry.date_added, "2021-12-10");
+        assert_eq!(entry.known_ransomware_campaign_use, "Known");
+
+        assert!(catalog.get(&"CVE-2000-0001".parse().unwrap()).is_none());
+    }
+}
@@ -10,7 +10,9 @@ mod error;
 pub mod advisory;
 mod collection;
 pub mod database;
+pub mod epss;
 mod fixer;
+pub mod kev;
 pub mod osv;
 pub mod report;
 pub mod repository;
@@ -19,15 +21,15 @@ mod warning;
 
 pub mod binary_scanning;
 
-#[cfg(feature = "git")]
-#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[cfg(any(feature = "git", feature = "registry-db"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "git", feature = "registry-db"))))]
 mod cached_index;
 
-#[cfg(feature = "git")]
-#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[cfg(any(feature = "git", feature = "registry-db"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "git", feature = "registry-db"))))]
 pub mod registry {
     //! Support for interacting with the local crates.io registry index
-    pub use super::cached_index::CachedIndex;
+    pub use super::cached_index::{CachedIndex, PublishedVersion};
 }
 
 pub use cargo_lock::{self, Lockfile, SourceId, package};
@@ -45,7 +47,9 @@ pub use crate::{
     warning::{Warning, WarningKind},
 };
 
-pub use crate::fixer::Fixer;
+#[cfg(feature = "git")]
+pub use crate::fixer::Blocker;
+pub use crate::fixer::{Fixer, PreciseUpdate};
 
 #[cfg(feature = "git")]
 pub use crate::repository::git::Repository;
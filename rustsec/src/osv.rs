@@ -7,10 +7,10 @@
 //!
 //! See <https://github.com/dtolnay/semver/issues/172>
 
-#[cfg(feature = "osv-export")]
+#[cfg(feature = "osv")]
 mod advisory;
 
-#[cfg(feature = "osv-export")]
+#[cfg(feature = "osv")]
 pub use advisory::OsvAdvisory;
 
 // The rest are enabled unconditionally because the OSV range format
@@ -20,6 +20,7 @@ mod range;
 mod ranges_for_advisory;
 mod unaffected_range;
 
-pub use range::OsvRange;
+pub use range::{OsvRange, normalize};
+pub(crate) use range::{complement, intersection};
 pub use ranges_for_advisory::ranges_for_advisory;
 pub(crate) use ranges_for_advisory::ranges_for_unvalidated_advisory;
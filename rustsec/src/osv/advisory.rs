@@ -3,14 +3,17 @@
 //! It implements the parts of the [OSV schema](https://ossf.github.io/osv-schema) required for
 //! RustSec.
 
+mod import;
+
 use super::ranges_for_advisory;
 use crate::advisory::Versions;
+#[cfg(feature = "osv-export")]
+use crate::repository::git::{GitModificationTimes, GitPath};
 use crate::{
     Advisory,
-    advisory::{Affected, Category, Id, Informational, affected::FunctionPath},
-    repository::git::{GitModificationTimes, GitPath},
+    advisory::{Affected, Category, CweId, Id, Informational, affected::FunctionPath},
 };
-use cvss::Cvss;
+use cvss::{Cvss, Severity};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::str::FromStr;
 use url::Url;
@@ -19,7 +22,7 @@ const ECOSYSTEM: &str = "crates.io";
 
 /// Security advisory in the format defined by <https://github.com/google/osv>
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[cfg_attr(docsrs, doc(cfg(feature = "osv-export")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "osv")))]
 pub struct OsvAdvisory {
     #[serde(skip_serializing_if = "Option::is_none")]
     schema_version: Option<semver::Version>,
@@ -90,6 +93,7 @@ impl TryFrom<Cvss> for OsvSeverity {
 pub struct OsvAffected {
     pub(crate) package: OsvPackage,
     ecosystem_specific: Option<OsvEcosystemSpecific>,
+    #[serde(default)]
     database_specific: OsvDatabaseSpecific,
     ranges: Option<Vec<OsvJsonRange>>,
     // FIXME deserialize with deserialize_semver_compat
@@ -214,7 +218,7 @@ impl From<Affected> for OsvEcosystemSpecificAffected {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OsvDatabaseSpecific {
     #[serde(default)]
     categories: Vec<Category>,
@@ -226,6 +230,13 @@ pub struct OsvDatabaseSpecific {
 pub struct MainOsvDatabaseSpecific {
     #[serde(default)]
     license: Option<String>,
+    /// Same field name as in the GitHub Advisory Database's OSV export
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cwe_ids: Vec<CweId>,
+    /// Qualitative severity of advisories without a CVSS vector, with the same
+    /// field name as in the GitHub Advisory Database's OSV export
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
 }
 
 impl OsvAdvisory {
@@ -241,11 +252,33 @@ impl OsvAdvisory {
 
     /// Converts a single RustSec advisory to OSV format.
     /// `path` is the path to the advisory file. It must be relative to the git repository root.
+    #[cfg(feature = "osv-export")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "osv-export")))]
     pub fn from_rustsec(
         advisory: Advisory,
         mod_times: &GitModificationTimes,
         path: GitPath<'_>,
     ) -> Self {
+        let modified = mod_times
+            .for_path(path)
+            .format(&time::format_description::well_known::Rfc3339)
+            .expect("well-known format to heap never fails");
+        Self::from_rustsec_modified(advisory, modified)
+    }
+
+    /// Converts a single RustSec advisory to OSV format, given the time it
+    /// was last modified (e.g. taken from the file system, or from the
+    /// headers of the HTTP response it was downloaded with).
+    ///
+    /// Unlike [`OsvAdvisory::from_rustsec`], this doesn't need a git checkout
+    /// of the advisory database.
+    pub fn from_rustsec_at(advisory: Advisory, modified: jiff::Timestamp) -> Self {
+        Self::from_rustsec_modified(advisory, modified.to_string())
+    }
+
+    /// Converts a single RustSec advisory to OSV format, given the RFC 3339
+    /// timestamp of its last modification
+    fn from_rustsec_modified(advisory: Advisory, modified: String) -> Self {
         let metadata = advisory.metadata;
 
         // Assemble the URLs to put into 'references' field
@@ -269,10 +302,7 @@ impl OsvAdvisory {
         OsvAdvisory {
             schema_version: None,
             id: metadata.id,
-            modified: mod_times
-                .for_path(path)
-                .format(&time::format_description::well_known::Rfc3339)
-                .expect("well-known format to heap never fails"),
+            modified,
             published: rustsec_date_to_rfc3339(&metadata.date),
             affected: vec![OsvAffected {
                 package: (&metadata.package).into(),
@@ -303,6 +333,8 @@ impl OsvAdvisory {
             references: osv_references(reference_urls),
             database_specific: MainOsvDatabaseSpecific {
                 license: Some(metadata.license.spdx().to_string()),
+                cwe_ids: metadata.cwe_ids,
+                severity: metadata.severity,
             },
         }
     }
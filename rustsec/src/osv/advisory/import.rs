@@ -0,0 +1,221 @@
+//! Converting OSV advisories into RustSec advisories
+
+use super::{ECOSYSTEM, OsvAdvisory, OsvAffected, OsvReferenceKind, OsvSeverity, OsvTimelineEvent};
+use crate::{
+    Advisory,
+    advisory::{Affected, Date, License, Metadata, Versions},
+    collection::Collection,
+    error::{Error, ErrorKind},
+};
+use cvss::Cvss;
+use semver::{Version, VersionReq};
+use url::Url;
+
+/// Range types whose events are versions of the package
+const VERSION_RANGE_KINDS: &[&str] = &["SEMVER", "ECOSYSTEM"];
+
+/// Where a range of affected versions ends
+enum RangeEnd {
+    /// The first version which isn't affected anymore
+    Fixed(Version),
+
+    /// The last version which is affected
+    LastAffected(Version),
+}
+
+impl OsvAdvisory {
+    /// Convert this OSV advisory into RustSec advisories.
+    ///
+    /// RustSec advisories are about a single crate, so one advisory is
+    /// returned for each crate in the `crates.io` ecosystem this advisory
+    /// affects, all of them with the ID of this advisory. Packages from other
+    /// ecosystems are ignored.
+    pub fn to_advisories(&self) -> Result<Vec<Advisory>, Error> {
+        self.affected
+            .iter()
+            .filter(|affected| affected.package.ecosystem == ECOSYSTEM)
+            .map(|affected| self.to_advisory(affected))
+            .collect()
+    }
+
+    /// Convert this advisory into a RustSec advisory for the given package
+    fn to_advisory(&self, affected: &OsvAffected) -> Result<Advisory, Error> {
+        let package = affected.package.name.parse()?;
+        let database_specific = &affected.database_specific;
+
+        let cvss = match &database_specific.cvss {
+            Some(cvss) => Some(cvss.clone()),
+            None => self.severity.iter().find_map(|severity| {
+                match severity {
+                    OsvSeverity::CVSS_V3(base) => base.to_string(),
+                    OsvSeverity::CVSS_V4(vector) => vector.to_string(),
+                }
+                .parse::<Cvss>()
+                .ok()
+            }),
+        };
+
+        // The links to the crate and to the RustSec advisory are added on export
+        let references = self
+            .references
+            .iter()
+            .filter(|reference| {
+                !matches!(reference.kind, OsvReferenceKind::PACKAGE)
+                    && !reference
+                        .url
+                        .as_str()
+                        .starts_with("https://rustsec.org/advisories/")
+            })
+            .collect::<Vec<_>>();
+
+        // Use the link to the original advisory as the main URL, which is
+        // how the attribution required by e.g. GHSA's CC-BY-4.0 license is given
+        let url = references
+            .iter()
+            .find(|reference| matches!(reference.kind, OsvReferenceKind::ADVISORY))
+            .map(|reference| reference.url.clone());
+
+        let metadata = Metadata {
+            id: self.id.clone(),
+            package,
+            title: self.summary.clone(),
+            description: self.details.clone(),
+            date: parse_date(&self.published)?,
+            aliases: self.aliases.clone(),
+            related: self.related.clone(),
+            collection: Some(Collection::Crates),
+            categories: database_specific.categories.clone(),
+            keywords: vec![],
+            cwe_ids: self.database_specific.cwe_ids.clone(),
+            severity: match cvss {
+                Some(_) => None,
+                None => self.database_specific.severity,
+            },
+            cvss,
+            informational: database_specific.informational.clone(),
+            references: references
+                .iter()
+                .map(|reference| reference.url.clone())
+                .filter(|reference| Some(reference) != url.as_ref())
+                .collect::<Vec<Url>>(),
+            source: None,
+            url,
+            withdrawn: self.withdrawn.as_deref().map(parse_date).transpose()?,
+            license: self
+                .database_specific
+                .license
+                .clone()
+                .map(License::from)
+                .unwrap_or_default(),
+            expect_deleted: false,
+        };
+
+        let affected_items = affected
+            .ecosystem_specific
+            .as_ref()
+            .and_then(|ecosystem_specific| ecosystem_specific.affects.as_ref())
+            .map(|affects| Affected {
+                arch: affects.arch.clone(),
+                os: affects.os.clone(),
+                // The versions in which each function is affected aren't exported
+                functions: affects
+                    .functions
+                    .iter()
+                    .map(|function| (function.clone(), vec![VersionReq::STAR]))
+                    .collect(),
+            })
+            .filter(|affects| *affects != Affected::default());
+
+        Ok(Advisory {
+            metadata,
+            affected: affected_items,
+            versions: versions_for_affected(affected)?,
+        })
+    }
+}
+
+/// Parse the date part of an RFC 3339 timestamp
+fn parse_date(timestamp: &str) -> Result<Date, Error> {
+    timestamp
+        .get(..10)
+        .ok_or_else(|| Error::new(ErrorKind::Parse, format!("invalid timestamp: {timestamp}")))?
+        .parse()
+}
+
+/// Convert the ranges of affected versions into patched and unaffected versions
+fn versions_for_affected(affected: &OsvAffected) -> Result<Versions, Error> {
+    let mut ranges: Vec<(Version, Option<RangeEnd>)> = vec![];
+
+    for range in affected.ranges.iter().flatten() {
+        if !VERSION_RANGE_KINDS.contains(&range.kind.as_str()) {
+            continue;
+        }
+
+        for event in &range.events {
+            match event {
+                OsvTimelineEvent::Introduced(version) => ranges.push((version.clone(), None)),
+                OsvTimelineEvent::Fixed(version) | OsvTimelineEvent::LastAffected(version) => {
+                    let (introduced, end) = ranges
+                        .last_mut()
+                        .filter(|(_, end)| end.is_none())
+                        .ok_or_else(|| {
+                            Error::new(
+                                ErrorKind::Parse,
+                                format!("range of {} ends before it starts", affected.package.name),
+                            )
+                        })?;
+                    if version < introduced {
+                        fail!(
+                            ErrorKind::Parse,
+                            "range of {} ends before it starts",
+                            affected.package.name
+                        );
+                    }
+                    *end = Some(match event {
+                        OsvTimelineEvent::Fixed(_) => RangeEnd::Fixed(version.clone()),
+                        _ => RangeEnd::LastAffected(version.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    ranges.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut patched = vec![];
+    let mut unaffected = vec![];
+
+    // Versions before the first range were never affected
+    if let Some((introduced, _)) = ranges.first()
+        && introduced > &Version::new(0, 0, 0)
+    {
+        unaffected.push(parse_req(&format!("< {introduced}"))?);
+    }
+
+    // Versions between the end of a range and the start of the next one are patched
+    for (i, (_, end)) in ranges.iter().enumerate() {
+        let start = match end {
+            Some(RangeEnd::Fixed(version)) => format!(">= {version}"),
+            Some(RangeEnd::LastAffected(version)) => format!("> {version}"),
+            None => continue,
+        };
+
+        patched.push(parse_req(&match ranges.get(i + 1) {
+            Some((next, _)) => format!("{start}, < {next}"),
+            None => start,
+        })?);
+    }
+
+    Versions::new(patched, unaffected)
+}
+
+/// Parse a version requirement built from the versions of a range
+fn parse_req(req: &str) -> Result<VersionReq, Error> {
+    req.parse().map_err(|e| {
+        Error::with_source(
+            ErrorKind::Parse,
+            format!("invalid version requirement: {req}"),
+            e,
+        )
+    })
+}
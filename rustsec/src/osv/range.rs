@@ -1,4 +1,5 @@
 use semver::Version;
+use std::cmp::Ordering;
 
 /// A range of affected versions.
 ///
@@ -6,7 +7,10 @@ use semver::Version;
 /// in that direction are affected.
 ///
 /// This format is defined by <https://github.com/google/osv>
-#[derive(Debug, Clone)]
+///
+/// The same format is used for the ranges of unaffected versions returned by
+/// [`Versions::unaffected_ranges`](crate::advisory::Versions::unaffected_ranges).
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OsvRange {
     /// Inclusive
     pub introduced: Option<Version>,
@@ -25,4 +29,164 @@ impl OsvRange {
             Some(end_v) => v < end_v,
         })
     }
+
+    /// Returns true if no version is within this range
+    pub fn is_empty(&self) -> bool {
+        matches!((&self.introduced, &self.fixed), (Some(start), Some(end)) if start >= end)
+    }
+}
+
+/// Sorts the given ranges, removing the empty ones and merging the ones which
+/// overlap or are adjacent, so that each version is in at most one range.
+pub fn normalize(mut ranges: Vec<OsvRange>) -> Vec<OsvRange> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by(|a, b| cmp_start(&a.introduced, &b.introduced));
+
+    let mut result: Vec<OsvRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match result.last_mut() {
+            // Ranges are sorted by start, so this one overlaps or follows the previous one
+            Some(last) if starts_before_end(&range.introduced, &last.fixed) => {
+                if cmp_end(&range.fixed, &last.fixed) == Ordering::Greater {
+                    last.fixed = range.fixed;
+                }
+            }
+            _ => result.push(range),
+        }
+    }
+    result
+}
+
+/// Returns the ranges of versions which are in none of the given normalized ranges
+pub(crate) fn complement(ranges: &[OsvRange]) -> Vec<OsvRange> {
+    let mut result = Vec::new();
+    let mut start = None;
+
+    for range in ranges {
+        if let Some(introduced) = &range.introduced {
+            result.push(OsvRange {
+                introduced: start,
+                fixed: Some(introduced.clone()),
+            });
+        }
+        match &range.fixed {
+            Some(fixed) => start = Some(fixed.clone()),
+            // All the remaining versions are within this range
+            None => return result,
+        }
+    }
+
+    result.push(OsvRange {
+        introduced: start,
+        fixed: None,
+    });
+    result
+}
+
+/// Returns the normalized ranges of versions which are within both sets of ranges
+pub(crate) fn intersection(a: &[OsvRange], b: &[OsvRange]) -> Vec<OsvRange> {
+    let mut result = Vec::new();
+
+    for range_a in a {
+        for range_b in b {
+            let introduced = match cmp_start(&range_a.introduced, &range_b.introduced) {
+                Ordering::Less => range_b.introduced.clone(),
+                _ => range_a.introduced.clone(),
+            };
+            let fixed = match cmp_end(&range_a.fixed, &range_b.fixed) {
+                Ordering::Greater => range_b.fixed.clone(),
+                _ => range_a.fixed.clone(),
+            };
+            result.push(OsvRange { introduced, fixed });
+        }
+    }
+
+    normalize(result)
+}
+
+/// Compares start bounds, where `None` is lower than any version
+fn cmp_start(a: &Option<Version>, b: &Option<Version>) -> Ordering {
+    // `Option` already orders `None` first
+    a.cmp(b)
+}
+
+/// Compares end bounds, where `None` is greater than any version
+fn cmp_end(a: &Option<Version>, b: &Option<Version>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.cmp(b),
+    }
+}
+
+/// Returns true if a range with the given start overlaps with or is adjacent
+/// to a range with the given end, assuming it doesn't start before the latter
+fn starts_before_end(start: &Option<Version>, end: &Option<Version>) -> bool {
+    match (start, end) {
+        (Some(start), Some(end)) => start <= end,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OsvRange;
+    use crate::advisory::Versions;
+
+    /// Parse a list of version requirements
+    fn versions(patched: &[&str], unaffected: &[&str]) -> Versions {
+        let reqs = |reqs: &[&str]| reqs.iter().map(|req| req.parse().unwrap()).collect();
+        Versions::new(reqs(patched), reqs(unaffected)).unwrap()
+    }
+
+    /// Create a range from optional bounds
+    fn range(introduced: Option<&str>, fixed: Option<&str>) -> OsvRange {
+        OsvRange {
+            introduced: introduced.map(|v| v.parse().unwrap()),
+            fixed: fixed.map(|v| v.parse().unwrap()),
+        }
+    }
+
+    #[test]
+    fn unaffected_ranges() {
+        let versions = versions(&[">= 1.2.0"], &["< 1.0.0"]);
+        assert_eq!(
+            versions.affected_ranges(),
+            [range(Some("1.0.0"), Some("1.2.0"))]
+        );
+        assert_eq!(
+            versions.unaffected_ranges(),
+            [range(None, Some("1.0.0")), range(Some("1.2.0"), None)]
+        );
+
+        // Everything is affected when nothing is patched
+        let versions = self::versions(&[], &[]);
+        assert_eq!(versions.affected_ranges(), [range(None, None)]);
+        assert!(versions.unaffected_ranges().is_empty());
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        let a = versions(&[">= 1.5.0"], &["< 1.0.0"]);
+        let b = versions(&[">= 2.0.0"], &["< 1.2.0"]);
+        assert!(a.overlaps(&b));
+        assert_eq!(a.intersection(&b), [range(Some("1.2.0"), Some("1.5.0"))]);
+        assert_eq!(a.union(&b), [range(Some("1.0.0"), Some("2.0.0"))]);
+
+        // Adjacent ranges don't overlap, but are merged
+        let c = versions(&[">= 1.0.0"], &[]);
+        assert!(!a.overlaps(&c));
+        assert!(a.intersection(&c).is_empty());
+        assert_eq!(a.union(&c), [range(None, Some("1.5.0"))]);
+
+        let d = versions(&[">= 3.0.0"], &["< 2.5.0"]);
+        assert_eq!(
+            a.union(&d),
+            [
+                range(Some("1.0.0"), Some("1.5.0")),
+                range(Some("2.5.0"), Some("3.0.0"))
+            ]
+        );
+    }
 }
@@ -5,7 +5,7 @@
 
 use crate::{
     Lockfile, Map, advisory,
-    database::{Database, Query},
+    database::{Database, Query, SourceMatching},
     map,
     platforms::target::{Arch, OS},
     vulnerability::Vulnerability,
@@ -13,8 +13,11 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "git")]
+use crate::{error::Error, registry::CachedIndex};
+
 /// Vulnerability report for a given lockfile
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Report {
     /// Information about the advisory database
     #[cfg(feature = "git")]
@@ -56,8 +59,50 @@ impl Report {
     }
 }
 
+#[cfg(feature = "git")]
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+impl Report {
+    /// Add warnings for the packages from crates.io in the given lockfile
+    /// which have been yanked, according to `index`.
+    ///
+    /// Returns the errors for packages whose status couldn't be determined.
+    pub fn check_yanked(&mut self, index: &mut CachedIndex, lockfile: &Lockfile) -> Vec<Error> {
+        let packages = lockfile.packages.iter().filter(|package| {
+            package
+                .source
+                .as_ref()
+                .is_some_and(|source| source.is_default_registry())
+        });
+
+        let mut errors = vec![];
+        let mut yanked = vec![];
+
+        for result in index.find_yanked(packages) {
+            match result {
+                Ok(package) => yanked.push(Warning::new(
+                    warning::WarningKind::Yanked,
+                    package,
+                    None,
+                    None,
+                    None,
+                )),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if !yanked.is_empty() {
+            self.warnings
+                .entry(warning::WarningKind::Yanked)
+                .or_default()
+                .append(&mut yanked);
+        }
+
+        errors
+    }
+}
+
 /// Options to use when generating the report
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct Settings {
     /// CPU architecture
     pub target_arch: Vec<Arch>,
@@ -73,6 +118,10 @@ pub struct Settings {
 
     /// Types of informational advisories to generate warnings for
     pub informational_warnings: Vec<advisory::Informational>,
+
+    /// How advisories are matched against packages which aren't from crates.io
+    #[serde(default)]
+    pub source_matching: SourceMatching,
 }
 
 impl Settings {
@@ -82,7 +131,8 @@ impl Settings {
     pub fn query(&self) -> Query {
         let mut query = Query::crate_scope()
             .target_arch(self.target_arch.clone())
-            .target_os(self.target_os.clone());
+            .target_os(self.target_os.clone())
+            .source_matching(self.source_matching);
 
         if let Some(severity) = self.severity {
             query = query.severity(severity);
@@ -95,7 +145,7 @@ impl Settings {
 /// Information about the advisory database
 #[cfg(feature = "git")]
 #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DatabaseInfo {
     /// Number of advisories in the database
     #[serde(rename = "advisory-count")]
@@ -123,7 +173,7 @@ impl DatabaseInfo {
 }
 
 /// Information about `Cargo.lock`
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct LockfileInfo {
     /// Number of dependencies in the lock file
     #[serde(rename = "dependency-count")]
@@ -140,7 +190,7 @@ impl LockfileInfo {
 }
 
 /// Information about detected vulnerabilities
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct VulnerabilityInfo {
     /// Were any vulnerabilities found?
     pub found: bool,
@@ -4,3 +4,6 @@ pub mod signature;
 
 #[cfg(feature = "git")]
 pub mod git;
+
+#[cfg(feature = "registry-db")]
+pub mod registry;
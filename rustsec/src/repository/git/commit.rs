@@ -48,6 +48,11 @@ impl Commit {
             )
         })?;
 
+        Self::from_gix(commit)
+    }
+
+    /// Get information about the given commit
+    pub(crate) fn from_gix(commit: gix::Commit<'_>) -> Result<Self, Error> {
         // Since we are pulling multiple pieces from the commit it's better to do this once
         let cref = commit.decode().map_err(|err| {
             Error::with_source(
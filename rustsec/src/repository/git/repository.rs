@@ -242,6 +242,86 @@ impl Repository {
         Commit::from_repo_head(self)
     }
 
+    /// Read the Markdown files (i.e. advisories and drafts) in the given
+    /// collections at the given revision (e.g. a commit hash, a tag or `HEAD~1`),
+    /// regardless of what is checked out.
+    ///
+    /// Returns the commit the revision points to, along with the path of each
+    /// file relative to the root of the repository and its contents.
+    pub(crate) fn markdown_files_at(
+        &self,
+        rev: &str,
+        collections: &[&str],
+    ) -> Result<(Commit, Vec<(PathBuf, String)>), Error> {
+        let commit = self
+            .repo
+            .rev_parse_single(rev)
+            .map_err(|err| {
+                Error::with_source(ErrorKind::Repo, format!("unable to find '{rev}'"), err)
+            })?
+            .object()
+            .map_err(|err| {
+                Error::with_source(ErrorKind::Repo, format!("unable to read '{rev}'"), err)
+            })?
+            .peel_to_commit()
+            .map_err(|err| {
+                Error::with_source(ErrorKind::Repo, format!("'{rev}' isn't a commit"), err)
+            })?;
+
+        let mut recorder = gix::traverse::tree::Recorder::default();
+        commit
+            .tree()
+            .map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Repo,
+                    format!("unable to find the tree of '{rev}'"),
+                    err,
+                )
+            })?
+            .traverse()
+            .breadthfirst(&mut recorder)
+            .map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Repo,
+                    format!("unable to traverse the tree of '{rev}'"),
+                    err,
+                )
+            })?;
+
+        let mut files = vec![];
+        for entry in recorder.records {
+            if !entry.mode.is_blob() || !entry.filepath.ends_with(b".md") {
+                continue;
+            }
+            let path = gix::path::from_bstring(entry.filepath);
+            if !path
+                .iter()
+                .next()
+                .is_some_and(|dir| collections.iter().any(|collection| dir == *collection))
+            {
+                continue;
+            }
+
+            let blob = self.repo.find_object(entry.oid).map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Repo,
+                    format!("unable to read {} at '{rev}'", path.display()),
+                    err,
+                )
+            })?;
+            let contents = String::from_utf8(blob.detach().data).map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Parse,
+                    format!("{} isn't valid UTF-8 at '{rev}'", path.display()),
+                    err,
+                )
+            })?;
+            files.push((path, contents));
+        }
+
+        Ok((Commit::from_gix(commit)?, files))
+    }
+
     /// Path to the local checkout of a git repository
     pub fn path(&self) -> &Path {
         // Safety: Would fail if this is a bare repo, which we aren't
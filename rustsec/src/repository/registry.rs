@@ -0,0 +1,826 @@
+//! Advisory databases published as packages on crates.io
+//!
+//! Some environments mirror crates.io but can't reach git hosting. For those,
+//! the advisory database can be published as a crate whose contents mirror
+//! the layout of the [advisory-db] git repository (i.e. a `crates/` directory
+//! containing advisories), then fetched through the regular registry.
+//!
+//! RustSec doesn't publish such a crate, so there is no default one: the name
+//! of the crate must always be given, and whoever owns it on crates.io must be
+//! trusted. The package is verified against the checksum in the crates.io
+//! index, which only guarantees that it is the one its owner published.
+//!
+//! The [`Registry`] is located like cargo does, so that a source replacing
+//! crates.io (e.g. a mirror) or a registry from `[registries]` in
+//! `.cargo/config.toml` is used instead of crates.io itself.
+//!
+//! [advisory-db]: https://github.com/RustSec/advisory-db
+
+use std::{
+    fs,
+    io::Read,
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
+
+use tame_index::{
+    IndexLocation, IndexUrl, KrateName,
+    external::reqwest,
+    index::{IndexConfig, RemoteSparseIndex, SparseIndex, local::validate_checksum},
+    krate::Chksum,
+    utils::flock::LockOptions,
+};
+
+#[cfg(feature = "async")]
+use tame_index::IndexKrate;
+
+/// Registry the advisory-db package is fetched from, located through the
+/// cargo configuration
+#[cfg_attr(docsrs, doc(cfg(feature = "registry-db")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Registry {
+    /// URL of the sparse index of the registry (with the `sparse+` prefix)
+    index_url: String,
+}
+
+impl Registry {
+    /// crates.io, or the source replacing it in the cargo configuration of the
+    /// current directory and `$CARGO_HOME`
+    pub fn crates_io() -> Result<Self, Error> {
+        Self::from_config(None, None)
+    }
+
+    /// Locate the registry named `name` in `[registries]` (or crates.io if
+    /// `None`), honoring source replacement.
+    ///
+    /// The cargo configuration is read from `config_root` and its parent
+    /// directories (by default, the current directory), then `$CARGO_HOME`.
+    /// Only sparse registries are supported.
+    pub fn from_config(name: Option<&str>, config_root: Option<&Path>) -> Result<Self, Error> {
+        let config_root = config_root
+            .map(|root| {
+                tame_index::PathBuf::from_path_buf(root.to_owned()).map_err(|root| {
+                    Error::new(
+                        ErrorKind::BadParam,
+                        format!("path is not valid UTF-8: {}", root.display()),
+                    )
+                })
+            })
+            .transpose()?;
+
+        let index_url = match name {
+            Some(name) => IndexUrl::for_registry_name(config_root, None, name),
+            None => IndexUrl::crates_io(config_root, None, None),
+        }
+        .map_err(Error::from_tame)?;
+
+        let index_url = match index_url {
+            // crates.io is always available through its sparse index
+            IndexUrl::CratesIoSparse | IndexUrl::CratesIoGit => {
+                tame_index::CRATES_IO_HTTP_INDEX.to_owned()
+            }
+            IndexUrl::NonCratesIo(url) if index_url_is_sparse(&url) => url.into_owned(),
+            other => fail!(
+                ErrorKind::Registry,
+                "unsupported registry index {}: only sparse registries are supported",
+                other.as_str()
+            ),
+        };
+
+        Ok(Self { index_url })
+    }
+
+    /// URL of the index of the registry
+    pub fn index_url(&self) -> &str {
+        &self.index_url
+    }
+
+    /// Sparse index of the registry
+    fn index(&self) -> Result<SparseIndex, Error> {
+        let index_url = match self.index_url.as_str() {
+            tame_index::CRATES_IO_HTTP_INDEX => IndexUrl::CratesIoSparse,
+            url => IndexUrl::NonCratesIo(url.into()),
+        };
+        SparseIndex::new(IndexLocation::new(index_url)).map_err(Error::from_tame)
+    }
+}
+
+/// Is the index at `url` a sparse one? (i.e. `sparse+http(s)://`)
+fn index_url_is_sparse(url: &str) -> bool {
+    url.starts_with("sparse+http")
+}
+
+/// Parse the `config.json` of an index, which gives the URL packages are
+/// downloaded from
+fn parse_index_config(config: &[u8], config_url: &str) -> Result<IndexConfig, Error> {
+    serde_json::from_slice(config).map_err(|err| {
+        Error::with_source(
+            ErrorKind::Registry,
+            format!("invalid index configuration at {config_url}"),
+            err,
+        )
+    })
+}
+
+use crate::{
+    cached_index::acquire_cargo_package_lock,
+    error::{Error, ErrorKind},
+};
+
+/// Directory under `~/.cargo` where the unpacked advisory-db package will be kept
+const ADVISORY_DB_DIRECTORY: &str = "advisory-db-package";
+
+/// File recording which version of the package is unpacked
+const VERSION_FILE: &str = ".package-version";
+
+/// Advisory database unpacked from a crates.io package
+#[cfg_attr(docsrs, doc(cfg(feature = "registry-db")))]
+#[derive(Clone, Debug)]
+pub struct RegistryPackage {
+    /// Path to the unpacked package
+    path: PathBuf,
+
+    /// Version of the unpacked package
+    version: String,
+}
+
+impl RegistryPackage {
+    /// Location of the default unpacked advisory-db package
+    pub fn default_path() -> PathBuf {
+        home::cargo_home()
+            .unwrap_or_else(|err| {
+                panic!("Error locating Cargo home directory: {err}");
+            })
+            .join(ADVISORY_DB_DIRECTORY)
+    }
+
+    /// Fetch the latest version of the given crate from `registry` and unpack
+    /// it into `into_path`, unless that version is already unpacked there.
+    ///
+    /// ## Locking
+    ///
+    /// This function will wait for up to `lock_timeout` for the filesystem lock on the
+    /// destination directory as well as the Cargo package lock.
+    /// It will fail with [`rustsec::Error::LockTimeout`](Error) if either lock is still held
+    /// after that time.
+    ///
+    /// If `lock_timeout` is set to `std::time::Duration::from_secs(0)`, it will not wait at all,
+    /// and instead return an error immediately if it fails to aquire the lock.
+    pub fn fetch<P: Into<PathBuf>>(
+        registry: &Registry,
+        crate_name: &str,
+        into_path: P,
+        lock_timeout: Duration,
+    ) -> Result<Self, Error> {
+        let path = into_path.into();
+        Self::create_parent(&path)?;
+
+        let lock_path = Self::lock_path(&path)?;
+        let lock_opts = LockOptions::new(&lock_path).exclusive(false);
+        let _lock = if lock_timeout == Duration::from_secs(0) {
+            lock_opts.try_lock()
+        } else {
+            lock_opts.lock(|_| Some(lock_timeout))
+        }
+        .map_err(Error::from_tame)?;
+
+        let krate_name: KrateName<'_> = crate_name.try_into().map_err(Error::from_tame)?;
+
+        let client = reqwest::blocking::Client::builder()
+            .build()
+            .map_err(|err| Error::from_tame(err.into()))?;
+
+        let index = RemoteSparseIndex::new(registry.index()?, client.clone());
+
+        let krate = {
+            let cargo_lock = acquire_cargo_package_lock(lock_timeout).map_err(Error::from_tame)?;
+            index
+                .krate(krate_name, true, &cargo_lock)
+                .map_err(Error::from_tame)?
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::NotFound,
+                        format!(
+                            "no such crate in index {}: {crate_name}",
+                            registry.index_url()
+                        ),
+                    )
+                })?
+        };
+
+        let latest = krate.highest_normal_version().ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("no published versions of {crate_name} found"),
+            )
+        })?;
+        let version = latest.version.to_string();
+
+        // Avoid downloading the package again if this version is already unpacked
+        if let Ok(existing) = Self::open(&path)
+            && existing.version == version
+        {
+            return Ok(existing);
+        }
+
+        // The index cache may not have the configuration if cargo never used
+        // this registry, so it's always requested
+        let config_url = format!("{}config.json", index.index.url());
+        let config = client
+            .get(&config_url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Registry,
+                    format!("failed to download {config_url}"),
+                    err,
+                )
+            })?;
+        let index_config = parse_index_config(&config, &config_url)?;
+        let url = latest.download_url(&index_config).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Registry,
+                format!("couldn't determine download URL for {crate_name} {version}"),
+            )
+        })?;
+
+        let tarball = client
+            .get(&url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .map_err(|err| {
+                Error::with_source(
+                    ErrorKind::Registry,
+                    format!("failed to download {url}"),
+                    err,
+                )
+            })?;
+
+        Self::install(
+            tarball.as_ref(),
+            &latest.checksum,
+            crate_name,
+            path,
+            version,
+        )
+    }
+
+    /// Fetch the latest version of the given crate from `registry` through
+    /// `client`, and unpack it into `into_path` unless that version is already
+    /// unpacked there.
+    ///
+    /// This is the async counterpart of [`RegistryPackage::fetch`]. Requests
+    /// are made through `client`, so any async HTTP stack can be used. The
+    /// downloaded package is small, so unpacking it isn't offloaded to a
+    /// blocking thread.
+    ///
+    /// ## Locking
+    ///
+    /// Instead of waiting for the filesystem lock on the destination directory,
+    /// this fails with [`rustsec::Error::LockTimeout`](Error) right away if
+    /// the lock is held by another process. The Cargo package lock isn't taken,
+    /// as the local crates.io index cache isn't updated.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn fetch_async<C: HttpClient, P: Into<PathBuf>>(
+        client: &C,
+        registry: &Registry,
+        crate_name: &str,
+        into_path: P,
+    ) -> Result<Self, Error> {
+        let path = into_path.into();
+        Self::create_parent(&path)?;
+
+        let lock_path = Self::lock_path(&path)?;
+        let _lock = LockOptions::new(&lock_path)
+            .exclusive(false)
+            .try_lock()
+            .map_err(Error::from_tame)?;
+
+        let krate_name: KrateName<'_> = crate_name.try_into().map_err(Error::from_tame)?;
+        let index = registry.index()?;
+
+        let entry = client.get(&index.crate_url(krate_name)).await?;
+        let krate = IndexKrate::from_slice(&entry).map_err(Error::from_tame)?;
+
+        let latest = krate.highest_normal_version().ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("no published versions of {crate_name} found"),
+            )
+        })?;
+        let version = latest.version.to_string();
+
+        // Avoid downloading the package again if this version is already unpacked
+        if let Ok(existing) = Self::open(&path)
+            && existing.version == version
+        {
+            return Ok(existing);
+        }
+
+        let config_url = format!("{}config.json", index.url());
+        let index_config = parse_index_config(&client.get(&config_url).await?, &config_url)?;
+        let url = latest.download_url(&index_config).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Registry,
+                format!("couldn't determine download URL for {crate_name} {version}"),
+            )
+        })?;
+
+        let tarball = client.get(&url).await?;
+
+        Self::install(
+            tarball.as_slice(),
+            &latest.checksum,
+            crate_name,
+            path,
+            version,
+        )
+    }
+
+    /// Open a previously unpacked package at the given path
+    pub fn open<P: Into<PathBuf>>(into_path: P) -> Result<Self, Error> {
+        let path = into_path.into();
+        let version = fs::read_to_string(path.join(VERSION_FILE)).map_err(|err| {
+            Error::with_source(
+                ErrorKind::NotFound,
+                format!("no unpacked advisory-db package at '{}'", path.display()),
+                err,
+            )
+        })?;
+
+        Ok(Self {
+            path,
+            version: version.trim().to_owned(),
+        })
+    }
+
+    /// Path to the unpacked advisory database
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Version of the package the advisory database was unpacked from
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Create the parent directory of the unpacked package if needed
+    fn create_parent(path: &Path) -> Result<(), Error> {
+        match path.parent() {
+            Some(parent) if !parent.is_dir() => Ok(fs::create_dir_all(parent)?),
+            Some(_) => Ok(()),
+            None => fail!(ErrorKind::BadParam, "invalid directory: {}", path.display()),
+        }
+    }
+
+    /// Path of the filesystem lock on the unpacked package
+    fn lock_path(path: &Path) -> Result<tame_index::PathBuf, Error> {
+        Ok(tame_index::Path::from_path(path)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::BadParam,
+                    "Path to the advisory DB directory is not valid UTF-8!",
+                )
+            })?
+            .with_extension(".lock"))
+    }
+
+    /// Verify the checksum of a downloaded `.crate` tarball, then unpack it
+    fn install(
+        tarball: &[u8],
+        checksum: &Chksum,
+        crate_name: &str,
+        path: PathBuf,
+        version: String,
+    ) -> Result<Self, Error> {
+        if !validate_checksum::<8192>(tarball, checksum)? {
+            fail!(
+                ErrorKind::Registry,
+                "checksum mismatch for {} {}",
+                crate_name,
+                version
+            );
+        }
+
+        Self::unpack(tarball, &path, &version)?;
+        Ok(Self { path, version })
+    }
+
+    /// Unpack a `.crate` tarball into the given directory, replacing the
+    /// package previously unpacked there.
+    ///
+    /// The package is unpacked into a temporary sibling directory first and
+    /// then moved into place, so an interrupted update never leaves a partially
+    /// unpacked database behind.
+    fn unpack(tarball: impl Read, path: &Path, version: &str) -> Result<(), Error> {
+        let parent = path.parent().ok_or_else(|| {
+            Error::new(
+                ErrorKind::BadParam,
+                format!("invalid directory: {}", path.display()),
+            )
+        })?;
+
+        let staging = tempfile::Builder::new()
+            .prefix(".advisory-db-package")
+            .tempdir_in(parent)?;
+
+        let unpacked = Self::unpack_entries(tarball, staging.path())?;
+        fs::write(unpacked.join(VERSION_FILE), version)?;
+
+        Self::replace(&unpacked, path, staging.path())
+    }
+
+    /// Unpack the entries of a `.crate` tarball into `staging`, returning the
+    /// path of the package's top-level `<name>-<version>/` directory
+    fn unpack_entries(tarball: impl Read, staging: &Path) -> Result<PathBuf, Error> {
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(tarball));
+        archive.set_preserve_permissions(false);
+        archive.set_unpack_xattrs(false);
+
+        let mut root: Option<PathBuf> = None;
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let entry_path = entry.path()?.into_owned();
+
+            // Packages built by `cargo package` only contain regular files and
+            // directories. Links in particular could point outside of the
+            // destination directory.
+            let entry_type = entry.header().entry_type();
+            if !(entry_type.is_file() || entry_type.is_dir()) {
+                fail!(
+                    ErrorKind::Parse,
+                    "unsupported entry in advisory-db package: {}",
+                    entry_path.display()
+                );
+            }
+
+            if !entry_path
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+            {
+                fail!(
+                    ErrorKind::Parse,
+                    "invalid path in advisory-db package: {}",
+                    entry_path.display()
+                );
+            }
+
+            // All entries must be inside the same `<name>-<version>/` directory
+            let entry_root = entry_path.components().next().map(|c| PathBuf::from(&c));
+            match (&root, entry_root) {
+                (_, None) => continue,
+                (None, Some(entry_root)) => root = Some(entry_root),
+                (Some(root), Some(entry_root)) if *root == entry_root => (),
+                (Some(_), Some(_)) => fail!(
+                    ErrorKind::Parse,
+                    "unexpected top-level path in advisory-db package: {}",
+                    entry_path.display()
+                ),
+            }
+
+            if !entry.unpack_in(staging)? {
+                fail!(
+                    ErrorKind::Parse,
+                    "invalid path in advisory-db package: {}",
+                    entry_path.display()
+                );
+            }
+        }
+
+        match root {
+            Some(root) if staging.join(&root).is_dir() => Ok(staging.join(root)),
+            _ => fail!(ErrorKind::Parse, "advisory-db package is empty"),
+        }
+    }
+
+    /// Move a freshly unpacked package into place at `path`.
+    ///
+    /// Only a directory previously created by this code (i.e. one containing
+    /// a version file) or an empty one is ever replaced, so that pointing the
+    /// database path at e.g. a git checkout of the advisory database doesn't
+    /// delete it.
+    fn replace(unpacked: &Path, path: &Path, staging: &Path) -> Result<(), Error> {
+        if !path.exists() {
+            return Ok(fs::rename(unpacked, path)?);
+        }
+
+        let is_package = path.join(VERSION_FILE).is_file();
+        if !is_package && fs::read_dir(path)?.next().is_some() {
+            fail!(
+                ErrorKind::BadParam,
+                "refusing to replace '{}': not an unpacked advisory-db package",
+                path.display()
+            );
+        }
+
+        // Move the old package into the staging directory, which is deleted
+        // when dropped
+        let previous = staging.join("previous");
+        fs::rename(path, &previous)?;
+
+        if let Err(err) = fs::rename(unpacked, path) {
+            let _ = fs::rename(&previous, path);
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// HTTP client used by [`RegistryPackage::fetch_async`]
+///
+/// This allows services embedding `rustsec` to fetch the advisory database
+/// with the async HTTP stack they already use. An implementation for
+/// [`reqwest::Client`] is provided.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait HttpClient {
+    /// Send a `GET` request to `url` and return the body of the response.
+    ///
+    /// Responses with a status other than success must be returned as errors.
+    fn get(&self, url: &str) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
+}
+
+#[cfg(feature = "async")]
+impl HttpClient for reqwest::Client {
+    async fn get(&self, url: &str) -> Result<Vec<u8>, Error> {
+        let download = async {
+            let response = self.get(url).send().await?.error_for_status()?;
+            response.bytes().await
+        };
+
+        download.await.map(Vec::from).map_err(|err| {
+            Error::with_source(
+                ErrorKind::Registry,
+                format!("failed to download {url}"),
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use std::{
+        pin::pin,
+        sync::Mutex,
+        task::{Context, Poll, Waker},
+    };
+
+    /// Build a gzipped tarball from `(path, entry type, contents)` entries,
+    /// writing the paths verbatim so that invalid ones can be tested
+    fn tarball(entries: &[(&str, tar::EntryType, &str)]) -> Vec<u8> {
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        let mut builder = tar::Builder::new(encoder);
+
+        for (path, entry_type, contents) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.as_old_mut().name[..path.len()].copy_from_slice(path.as_bytes());
+            header.set_entry_type(*entry_type);
+            header.set_mode(0o644);
+            if entry_type.is_symlink() {
+                header.set_link_name(contents).unwrap();
+                header.set_size(0);
+            } else {
+                header.set_size(contents.len() as u64);
+            }
+            header.set_cksum();
+            let data = if entry_type.is_file() {
+                contents.as_bytes()
+            } else {
+                &[]
+            };
+            builder.append(&header, data).unwrap();
+        }
+
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[cfg(feature = "async")]
+    const CRATE_NAME: &str = "rustsec-advisory-db";
+
+    const ADVISORY: &str = "rustsec-advisory-db-0.2.0/crates/foo/RUSTSEC-2020-0001.md";
+
+    #[test]
+    fn unpack() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db");
+        let package = tarball(&[
+            ("rustsec-advisory-db-0.2.0/", tar::EntryType::Directory, ""),
+            (ADVISORY, tar::EntryType::Regular, "advisory"),
+        ]);
+
+        RegistryPackage::unpack(package.as_slice(), &path, "0.2.0").unwrap();
+        assert_eq!(
+            fs::read_to_string(path.join("crates/foo/RUSTSEC-2020-0001.md")).unwrap(),
+            "advisory"
+        );
+        assert_eq!(RegistryPackage::open(&path).unwrap().version(), "0.2.0");
+
+        // A newer version replaces the previously unpacked one
+        let package = tarball(&[(
+            "rustsec-advisory-db-0.3.0/crates/bar/RUSTSEC-2020-0002.md",
+            tar::EntryType::Regular,
+            "advisory",
+        )]);
+        RegistryPackage::unpack(package.as_slice(), &path, "0.3.0").unwrap();
+        assert!(!path.join("crates/foo").exists());
+        assert!(path.join("crates/bar/RUSTSEC-2020-0002.md").is_file());
+        assert_eq!(RegistryPackage::open(&path).unwrap().version(), "0.3.0");
+
+        // Only the unpacked package is left next to it
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn unpack_rejects_escaping_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db");
+
+        let packages = [
+            tarball(&[(
+                "rustsec-advisory-db-0.2.0/crates",
+                tar::EntryType::Symlink,
+                "../../..",
+            )]),
+            tarball(&[(
+                "rustsec-advisory-db-0.2.0/../../escaped",
+                tar::EntryType::Regular,
+                "advisory",
+            )]),
+            tarball(&[("/tmp/escaped", tar::EntryType::Regular, "advisory")]),
+            tarball(&[
+                (ADVISORY, tar::EntryType::Regular, "advisory"),
+                ("other/escaped", tar::EntryType::Regular, "advisory"),
+            ]),
+        ];
+
+        for package in packages {
+            let err = RegistryPackage::unpack(package.as_slice(), &path, "0.2.0").unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Parse);
+            assert!(!path.exists());
+        }
+
+        // Nothing is left behind in the parent directory
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn unpack_keeps_unrelated_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db");
+        fs::create_dir_all(path.join(".git")).unwrap();
+
+        let package = tarball(&[(ADVISORY, tar::EntryType::Regular, "advisory")]);
+        let err = RegistryPackage::unpack(package.as_slice(), &path, "0.2.0").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadParam);
+        assert!(path.join(".git").is_dir());
+        assert!(!path.join("crates").exists());
+    }
+
+    /// Serves a fake crates.io from memory, recording the requested URLs
+    #[cfg(feature = "async")]
+    #[derive(Default)]
+    struct FakeCratesIo {
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[cfg(feature = "async")]
+    impl HttpClient for FakeCratesIo {
+        async fn get(&self, url: &str) -> Result<Vec<u8>, Error> {
+            self.requests.lock().unwrap().push(url.to_owned());
+
+            let body = if url.ends_with("/rustsec-advisory-db") {
+                format!(
+                    r#"{{"name":"rustsec-advisory-db","vers":"0.2.0","deps":[],"cksum":"{}","features":{{}},"yanked":false}}"#,
+                    "0".repeat(64)
+                )
+            } else if url == "https://mirror.example/index/config.json" {
+                r#"{"dl":"https://mirror.example/crates"}"#.to_owned()
+            } else if url.ends_with("/config.json") {
+                r#"{"dl":"https://static.crates.io/crates","api":"https://crates.io"}"#.to_owned()
+            } else {
+                "not a tarball".to_owned()
+            };
+
+            Ok(body.into_bytes())
+        }
+    }
+
+    /// Run a future which never waits, as the fake client answers right away
+    #[cfg(feature = "async")]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    /// Write a `.cargo/config.toml` to a new directory, to locate registries from
+    fn cargo_config(config: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".cargo")).unwrap();
+        fs::write(dir.path().join(".cargo/config.toml"), config).unwrap();
+        dir
+    }
+
+    /// Cargo configuration replacing crates.io with a mirror
+    const MIRROR_CONFIG: &str = r#"
+[source.crates-io]
+replace-with = "mirror"
+
+[source.mirror]
+registry = "sparse+https://mirror.example/index/"
+"#;
+
+    #[test]
+    fn registry_from_config() {
+        let config = cargo_config(MIRROR_CONFIG);
+        let registry = Registry::from_config(None, Some(config.path())).unwrap();
+        assert_eq!(registry.index_url(), "sparse+https://mirror.example/index/");
+
+        let config = cargo_config(
+            r#"
+[registries.internal]
+index = "sparse+https://internal.example/index/"
+
+[registries.git]
+index = "https://git.example/index"
+"#,
+        );
+        let registry = Registry::from_config(Some("internal"), Some(config.path())).unwrap();
+        assert_eq!(
+            registry.index_url(),
+            "sparse+https://internal.example/index/"
+        );
+
+        let err = Registry::from_config(Some("git"), Some(config.path())).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Registry);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn fetch_async() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db");
+        let client = FakeCratesIo::default();
+        let registry = Registry::from_config(None, Some(cargo_config("").path())).unwrap();
+
+        // The tarball doesn't match the checksum from the index
+        let err = block_on(RegistryPackage::fetch_async(
+            &client, &registry, CRATE_NAME, &path,
+        ))
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Registry);
+        assert_eq!(client.requests.lock().unwrap().len(), 3);
+        assert_eq!(
+            client.requests.lock().unwrap()[2],
+            "https://static.crates.io/crates/rustsec-advisory-db/0.2.0/download"
+        );
+
+        // Nothing is downloaded when the latest version is already unpacked
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join(VERSION_FILE), "0.2.0").unwrap();
+        client.requests.lock().unwrap().clear();
+
+        let package = block_on(RegistryPackage::fetch_async(
+            &client, &registry, CRATE_NAME, &path,
+        ))
+        .unwrap();
+        assert_eq!(package.version(), "0.2.0");
+        assert_eq!(client.requests.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn fetch_async_from_replaced_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("advisory-db");
+        let client = FakeCratesIo::default();
+        let config = cargo_config(MIRROR_CONFIG);
+        let registry = Registry::from_config(None, Some(config.path())).unwrap();
+
+        let err = block_on(RegistryPackage::fetch_async(
+            &client, &registry, CRATE_NAME, &path,
+        ))
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Registry);
+
+        // Everything is fetched from the mirror, including the package
+        assert_eq!(
+            *client.requests.lock().unwrap(),
+            [
+                "https://mirror.example/index/ru/st/rustsec-advisory-db",
+                "https://mirror.example/index/config.json",
+                "https://mirror.example/crates/rustsec-advisory-db/0.2.0/download",
+            ]
+        );
+    }
+}
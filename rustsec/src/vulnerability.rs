@@ -3,6 +3,8 @@
 
 use crate::{
     advisory::{self, Advisory, affected::FunctionPath},
+    binary_scanning::Confidence,
+    epss, kev,
     package::Package,
 };
 use serde::{Deserialize, Serialize};
@@ -21,6 +23,18 @@ pub struct Vulnerability {
 
     /// Vulnerable package
     pub package: Package,
+
+    /// Exploit Prediction Scoring System score (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epss: Option<epss::Score>,
+
+    /// Entry in the CISA Known Exploited Vulnerabilities catalog (if listed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kev: Option<kev::Entry>,
+
+    /// How reliably the package was recovered (if found in a compiled binary)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<Confidence>,
 }
 
 impl Vulnerability {
@@ -31,6 +45,9 @@ impl Vulnerability {
             versions: advisory.versions.clone(),
             affected: advisory.affected.clone(),
             package: package.clone(),
+            epss: None,
+            kev: None,
+            confidence: None,
         }
     }
 
@@ -40,16 +57,12 @@ impl Vulnerability {
             if affected.functions.is_empty() {
                 None
             } else {
-                let mut result = vec![];
-                for (path, versions) in &affected.functions {
-                    if versions
-                        .iter()
-                        .any(|req| req.matches(&self.package.version.clone()))
-                    {
-                        result.push(path.clone());
-                    }
-                }
-                Some(result)
+                Some(
+                    affected
+                        .functions_for(&self.package.version)
+                        .cloned()
+                        .collect(),
+                )
             }
         })
     }
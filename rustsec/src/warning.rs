@@ -1,12 +1,12 @@
 //! Warnings sourced from the Advisory DB
 
 use crate::error::{Error, ErrorKind};
-use crate::{advisory, package::Package};
+use crate::{advisory, binary_scanning::Confidence, package::Package};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
 /// Warnings sourced from the Advisory DB
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Warning {
     /// Kind of warning
     pub kind: WarningKind,
@@ -22,6 +22,10 @@ pub struct Warning {
 
     /// Versions impacted by this warning
     pub versions: Option<advisory::Versions>,
+
+    /// How reliably the package was recovered (if found in a compiled binary)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<Confidence>,
 }
 
 impl Warning {
@@ -39,6 +43,7 @@ impl Warning {
             advisory,
             affected,
             versions,
+            confidence: None,
         }
     }
 
@@ -61,6 +66,11 @@ impl Warning {
     pub fn is_yanked(&self) -> bool {
         self.kind == WarningKind::Yanked
     }
+
+    /// Is this a warning about a vulnerable C library linked into a binary?
+    pub fn is_bundled_library(&self) -> bool {
+        self.kind == WarningKind::BundledLibrary
+    }
 }
 
 /// Kinds of warnings
@@ -82,6 +92,10 @@ pub enum WarningKind {
     /// Yanked packages
     #[serde(rename = "yanked")]
     Yanked,
+
+    /// Vulnerable C libraries linked into a binary (e.g. by `-sys` crates)
+    #[serde(rename = "bundled-library")]
+    BundledLibrary,
 }
 
 impl WarningKind {
@@ -92,6 +106,7 @@ impl WarningKind {
             Self::Unmaintained => "unmaintained",
             Self::Unsound => "unsound",
             Self::Yanked => "yanked",
+            Self::BundledLibrary => "bundled-library",
         }
     }
 }
@@ -105,6 +120,7 @@ impl FromStr for WarningKind {
             "unmaintained" => WarningKind::Unmaintained,
             "unsound" => WarningKind::Unsound,
             "yanked" => WarningKind::Yanked,
+            "bundled-library" => WarningKind::BundledLibrary,
             other => fail!(ErrorKind::Parse, "invalid warning type: {}", other),
         })
     }
@@ -99,6 +99,27 @@ fn parse_cvss_vector_string() {
     assert_eq!(cvss.score().value(), 10.0);
 }
 
+/// Advisories with only a qualitative severity rating
+#[test]
+fn parse_qualitative_severity() {
+    let markdown = std::fs::read_to_string("./tests/support/example_advisory_v4.md")
+        .unwrap()
+        .replace(
+            r#"cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H""#,
+            r#"severity = "moderate""#,
+        );
+    let advisory = markdown.parse::<rustsec::Advisory>().unwrap();
+    assert!(advisory.metadata.cvss.is_none());
+    assert_eq!(
+        advisory.severity().unwrap(),
+        rustsec::advisory::Severity::Medium
+    );
+
+    let written = advisory.to_markdown();
+    assert!(written.contains("\nseverity = \"medium\"\n"));
+    assert_eq!(written.parse::<rustsec::Advisory>().unwrap(), advisory);
+}
+
 /// Parsing of patched version reqs
 #[test]
 fn parse_patched_version_reqs() {
@@ -108,3 +129,41 @@ fn parse_patched_version_reqs() {
     assert!(req.matches(&"1.2.3".parse().unwrap()));
     assert!(req.matches(&"1.2.4".parse().unwrap()));
 }
+
+/// Writing advisories back to Markdown
+#[test]
+fn write_markdown() {
+    for case in ["v3", "v4", "v4_from_ghsa", "v4_unknown_license"] {
+        let advisory = load_advisory(case);
+        let markdown = advisory.to_markdown();
+        assert_eq!(markdown.parse::<rustsec::Advisory>().unwrap(), advisory);
+    }
+
+    assert_eq!(
+        load_advisory("v4").to_markdown(),
+        r#"```toml
+id = "RUSTSEC-2001-2101"
+package = "base"
+date = "2001-02-03"
+url = "https://www.youtube.com/watch?v=jQE66WA2s-A"
+categories = ["code-execution", "privilege-escalation"]
+keywords = ["how", "are", "you", "gentlemen"]
+aliases = ["CVE-2001-2101"]
+cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H"
+
+[affected]
+arch = ["x86"]
+os = ["windows"]
+functions = { "base::belongs::All" = ["< 1.2.3"] }
+
+[versions]
+patched = [">= 1.2.3"]
+unaffected = ["^0.1.2"]
+```
+
+# All your base are belong to us
+
+You have no chance to survive. Make your time.
+"#
+    );
+}
@@ -0,0 +1,169 @@
+//! Tests for recovering dependencies from WebAssembly modules and static libraries
+
+#![cfg(feature = "binary-scanning")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use std::io::Write;
+
+use rustsec::binary_scanning::{
+    BinaryFormat, BinaryReport, Confidence, load_deps_from_binary, scan_binary,
+};
+
+/// ID of the WebAssembly custom section
+const CUSTOM_SECTION: u8 = 0;
+
+/// ID of the WebAssembly data section
+const DATA_SECTION: u8 = 11;
+
+/// Encode an unsigned LEB128 integer
+fn leb128(mut value: usize) -> Vec<u8> {
+    let mut out = vec![];
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Build a WebAssembly module with the given sections
+fn wasm_module(sections: &[(u8, Vec<u8>)]) -> Vec<u8> {
+    let mut module = b"\0asm\x01\0\0\0".to_vec();
+    for (id, contents) in sections {
+        module.push(*id);
+        module.extend(leb128(contents.len()));
+        module.extend(contents);
+    }
+    module
+}
+
+/// Contents of a custom section
+fn custom_section(name: &str, data: &[u8]) -> Vec<u8> {
+    let mut contents = leb128(name.len());
+    contents.extend(name.as_bytes());
+    contents.extend(data);
+    contents
+}
+
+/// Contents of a data section with a single segment
+fn data_section(data: &[u8]) -> Vec<u8> {
+    // One active segment for memory 0 at offset `i32.const 0`
+    let mut contents = vec![1, 0, 0x41, 0, 0x0b];
+    contents.extend(leb128(data.len()));
+    contents.extend(data);
+    contents
+}
+
+/// Build a static library (an `ar` archive) with the given members
+fn static_library(members: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut archive = b"!<arch>\n".to_vec();
+    for (name, data) in members {
+        let header = format!(
+            "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            format!("{name}/"),
+            0,
+            0,
+            0,
+            644,
+            data.len()
+        );
+        archive.extend(header.as_bytes());
+        archive.extend(*data);
+        if data.len() % 2 == 1 {
+            archive.push(b'\n');
+        }
+    }
+    archive
+}
+
+fn package_names(report: BinaryReport) -> Vec<String> {
+    match report {
+        BinaryReport::Complete(lockfile) | BinaryReport::Incomplete(lockfile) => lockfile
+            .packages
+            .iter()
+            .map(|package| format!("{} {}", package.name, package.version))
+            .collect(),
+        BinaryReport::None => vec![],
+    }
+}
+
+#[test]
+fn wasm_module_with_audit_data() {
+    let json = br#"{"packages":[
+        {"name":"app","version":"0.1.0","source":"local","dependencies":[1],"root":true},
+        {"name":"smallvec","version":"1.6.0","source":"crates.io"}
+    ]}"#;
+    let mut encoder = flate2::write::ZlibEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(json).unwrap();
+    let audit_data = encoder.finish().unwrap();
+
+    let module = wasm_module(&[(CUSTOM_SECTION, custom_section(".dep-v0", &audit_data))]);
+    let (format, report) = load_deps_from_binary(&module, None).unwrap();
+
+    assert_eq!(format, BinaryFormat::Wasm);
+    assert!(matches!(report, BinaryReport::Complete(_)));
+    assert_eq!(package_names(report), ["app 0.1.0", "smallvec 1.6.0"]);
+}
+
+#[test]
+fn wasm_module_with_panic_messages() {
+    let module = wasm_module(&[(
+        DATA_SECTION,
+        data_section(
+            b"/home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/smallvec-1.6.0/src/lib.rs\
+            capacity overflow",
+        ),
+    )]);
+    let (format, report) = load_deps_from_binary(&module, None).unwrap();
+
+    assert_eq!(format, BinaryFormat::Wasm);
+    assert!(matches!(report, BinaryReport::Incomplete(_)));
+    assert_eq!(package_names(report), ["smallvec 1.6.0"]);
+}
+
+#[test]
+fn static_library_with_panic_messages() {
+    let library = static_library(&[
+        (
+            "lib.rmeta",
+            b"vendor/smallvec-1.6.0/src/lib.rs\0vendor/smallvec-1.6.0/src/lib.rs",
+        ),
+        (
+            "app-1a2b3c.o",
+            b"\x7fELF\x02\x01\x01\0/cargo/registry/src/index.crates.io-6f17d22bba15001f/memchr-2.5.0/src/lib.rs",
+        ),
+    ]);
+    let (format, report) = load_deps_from_binary(&library, None).unwrap();
+
+    assert_eq!(format, BinaryFormat::Elf64);
+    assert!(matches!(report, BinaryReport::Incomplete(_)));
+    assert_eq!(package_names(report), ["memchr 2.5.0", "smallvec 1.6.0"]);
+}
+
+#[test]
+fn confidence_of_recovered_dependencies() {
+    let library = static_library(&[
+        ("lib.rmeta", b"vendor/smallvec-1.6.0/src/lib.rs"),
+        (
+            "app-1a2b3c.o",
+            b"\x7fELF\x02\x01\x01\0/cargo/registry/src/index.crates.io-6f17d22bba15001f/memchr-2.5.0/src/lib.rs",
+        ),
+    ]);
+    let scanned = scan_binary(&library, None).unwrap();
+
+    let confidence = scanned
+        .confidence
+        .iter()
+        .map(|(dependency, confidence)| (dependency.name.as_str(), *confidence))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        confidence,
+        [
+            ("memchr", Confidence::PanicMessage),
+            ("smallvec", Confidence::SourcePath)
+        ]
+    );
+}
@@ -154,3 +154,29 @@ fn draft_id_mismatch() {
         &[]
     );
 }
+
+/// A qualitative severity can't be given along with a CVSS vector
+#[test]
+fn severity_with_cvss() {
+    let both = VALID_ADVISORY_MD.replace("\ncvss = ", "\nseverity = \"high\"\ncvss = ");
+    let lint = Linter::lint_string(&both, None).unwrap();
+    assert_eq!(
+        lint.errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "invalid key `severity` in [advisory]: \
+             Field `severity` is only allowed for advisories without a `cvss` vector"
+        ]
+    );
+
+    let severity_only = VALID_ADVISORY_MD.replace(
+        "cvss = \"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H\"",
+        "severity = \"high\"",
+    );
+    assert_eq!(
+        Linter::lint_string(&severity_only, None).unwrap().errors(),
+        &[]
+    );
+}
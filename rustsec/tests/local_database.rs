@@ -0,0 +1,239 @@
+//! Tests for looking up advisories in a local database
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    Database, Lockfile,
+    advisory::Id,
+    database::{AliasGraph, Query, SourceMatching},
+    report::Settings,
+};
+use std::path::Path;
+
+/// Load the example database from the filesystem
+fn load_database() -> Database {
+    Database::open(Path::new("./tests/support/advisory-db")).unwrap()
+}
+
+/// IDs of the given advisories
+fn ids(advisories: Vec<&rustsec::Advisory>) -> Vec<&str> {
+    advisories
+        .into_iter()
+        .map(|advisory| advisory.id().as_str())
+        .collect()
+}
+
+#[test]
+fn find_by_alias() {
+    let db = load_database();
+
+    let cve: Id = "CVE-2001-2101".parse().unwrap();
+    let mut found = ids(db.find_by_alias(&cve));
+    found.sort_unstable();
+    assert_eq!(found, ["RUSTSEC-2001-2101", "RUSTSEC-2002-0001"]);
+
+    let ghsa: Id = "GHSA-4fjv-pmhg-3rfg".parse().unwrap();
+    assert_eq!(ids(db.find_by_alias(&ghsa)), ["RUSTSEC-2002-0001"]);
+
+    // Advisory IDs aren't aliases of themselves
+    let id: Id = "RUSTSEC-2003-0001".parse().unwrap();
+    assert!(db.find_by_alias(&id).is_empty());
+}
+
+#[test]
+fn search() {
+    let db = load_database();
+
+    // Both have the word in their title, but only one in its keywords too
+    assert_eq!(
+        ids(db.search("Buffer")),
+        ["RUSTSEC-2002-0001", "RUSTSEC-2003-0001"]
+    );
+
+    // Advisories matching more of the words rank first
+    assert_eq!(
+        ids(db.search("nonce buffer")),
+        ["RUSTSEC-2003-0001", "RUSTSEC-2002-0001"]
+    );
+
+    // Keywords are searched too
+    assert_eq!(ids(db.search("gentlemen")), ["RUSTSEC-2001-2101"]);
+    assert!(db.search("heartbleed").is_empty());
+}
+
+#[test]
+fn query_cwe_ids() {
+    let db = load_database();
+
+    let use_after_free = Query::new().cwe_ids(vec!["CWE-416".parse().unwrap()]);
+    assert_eq!(ids(db.query(&use_after_free)), ["RUSTSEC-2002-0001"]);
+
+    // Advisories involving any of the weaknesses match
+    let either = Query::new().cwe_ids(vec!["CWE-323".parse().unwrap(), "CWE-416".parse().unwrap()]);
+    assert_eq!(db.query(&either).len(), 2);
+
+    let advisory = db.get(&"RUSTSEC-2003-0001".parse().unwrap()).unwrap();
+    assert_eq!(advisory.metadata.cwe_ids[0].number(), 323);
+}
+
+#[test]
+fn toolchain_vulnerabilities() {
+    let db = load_database();
+
+    let vulns = db.toolchain_vulnerabilities(&"1.58.0".parse().unwrap());
+    assert_eq!(vulns.len(), 1);
+    assert_eq!(vulns[0].advisory.id.as_str(), "RUSTSEC-2007-0001");
+    assert_eq!(vulns[0].package.name.as_str(), "std");
+    assert_eq!(vulns[0].package.version.to_string(), "1.58.0");
+
+    assert!(
+        db.toolchain_vulnerabilities(&"1.58.1".parse().unwrap())
+            .is_empty()
+    );
+}
+
+/// Lockfile with packages from an alternative registry and a git repository
+const OTHER_SOURCES_LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "base"
+version = "1.0.0"
+source = "sparse+https://registry.example.com/index/"
+
+[[package]]
+name = "other"
+version = "0.1.0"
+source = "git+https://github.com/example/other#0123456789abcdef0123456789abcdef01234567"
+"#;
+
+#[test]
+fn source_matching() {
+    let db = load_database();
+    let lockfile: Lockfile = OTHER_SOURCES_LOCKFILE.parse().unwrap();
+    let vulnerable_ids = |db: &Database, source_matching| {
+        let settings = Settings {
+            source_matching,
+            ..Default::default()
+        };
+        let mut ids = db
+            .audit_lockfile(&lockfile, &settings)
+            .vulnerabilities
+            .list
+            .into_iter()
+            .map(|vuln| vuln.advisory.id.to_string())
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+
+    // crates.io advisories only match packages from elsewhere by name
+    assert!(vulnerable_ids(&db, SourceMatching::Exact).is_empty());
+    assert_eq!(
+        vulnerable_ids(&db, SourceMatching::Name),
+        [
+            "RUSTSEC-2001-2101",
+            "RUSTSEC-2002-0001",
+            "RUSTSEC-2003-0001"
+        ]
+    );
+
+    // Advisories published for the registry's own copy of `base` take precedence
+    let mut advisories = db.iter().cloned().collect::<Vec<_>>();
+    let mut registry_advisory = db
+        .get(&"RUSTSEC-2003-0001".parse().unwrap())
+        .unwrap()
+        .clone();
+    registry_advisory.metadata.id = "RUSTSEC-2006-0001".parse().unwrap();
+    registry_advisory.metadata.source = Some(
+        "sparse+https://registry.example.com/index/"
+            .parse()
+            .unwrap(),
+    );
+    advisories.push(registry_advisory);
+    let db = Database::from_advisories(advisories).unwrap();
+
+    assert_eq!(
+        vulnerable_ids(&db, SourceMatching::Exact),
+        ["RUSTSEC-2006-0001"]
+    );
+    assert_eq!(
+        vulnerable_ids(&db, SourceMatching::Name),
+        ["RUSTSEC-2002-0001", "RUSTSEC-2006-0001"]
+    );
+}
+
+#[test]
+fn open_lazy() {
+    let db = load_database();
+    let lazy = Database::open_lazy(Path::new("./tests/support/advisory-db")).unwrap();
+    let id = "RUSTSEC-2002-0001".parse().unwrap();
+
+    let advisory = lazy.get(&id).unwrap();
+    assert!(advisory.metadata.description.is_empty());
+    assert_eq!(
+        lazy.description(advisory).unwrap(),
+        db.get(&id).unwrap().metadata.description
+    );
+
+    // Only titles and keywords are searchable
+    assert_eq!(ids(lazy.search("resizing")), ["RUSTSEC-2002-0001"]);
+    assert!(lazy.search("borrowed").is_empty());
+}
+
+#[test]
+fn diff() {
+    let old = load_database();
+    assert!(old.diff(&old).is_empty());
+
+    let advisories = old.iter().cloned().filter_map(|mut advisory| {
+        match advisory.id().as_str() {
+            "RUSTSEC-2001-2101" => return None,
+            "RUSTSEC-2002-0001" => advisory.metadata.title = "Use-after-free".to_owned(),
+            "RUSTSEC-2003-0001" => {
+                advisory.metadata.withdrawn = Some("2003-08-09".parse().unwrap())
+            }
+            _ => (),
+        }
+        Some(advisory)
+    });
+    let mut new = advisories.collect::<Vec<_>>();
+    let mut added = new[0].clone();
+    added.metadata.id = "RUSTSEC-2006-0001".parse().unwrap();
+    new.push(added);
+    let new = Database::from_advisories(new).unwrap();
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.added, ["RUSTSEC-2006-0001".parse::<Id>().unwrap()]);
+    assert_eq!(diff.modified, ["RUSTSEC-2002-0001".parse::<Id>().unwrap()]);
+    assert_eq!(diff.withdrawn, ["RUSTSEC-2003-0001".parse::<Id>().unwrap()]);
+    assert_eq!(diff.removed, ["RUSTSEC-2001-2101".parse::<Id>().unwrap()]);
+}
+
+#[test]
+fn resolve_aliases() {
+    let db = load_database();
+    let graph = AliasGraph::from(&db);
+
+    // The GHSA is only listed by one advisory, but shares a CVE with another
+    let ghsa: Id = "GHSA-4fjv-pmhg-3rfg".parse().unwrap();
+    let aliases = graph.resolve(&ghsa);
+    assert_eq!(
+        aliases.ids.iter().map(Id::as_str).collect::<Vec<_>>(),
+        [
+            "RUSTSEC-2001-2101",
+            "RUSTSEC-2002-0001",
+            "CVE-2001-2101",
+            "GHSA-4fjv-pmhg-3rfg"
+        ]
+    );
+    assert_eq!(aliases.conflicts, graph.conflicts());
+    assert_eq!(aliases.conflicts.len(), 1);
+    assert_eq!(aliases.conflicts[0].alias.as_str(), "CVE-2001-2101");
+    assert_eq!(aliases.conflicts[0].advisories.len(), 2);
+
+    let id: Id = "RUSTSEC-2003-0001".parse().unwrap();
+    let aliases = graph.resolve(&id);
+    assert_eq!(aliases.ids.into_iter().collect::<Vec<_>>(), [id]);
+    assert!(aliases.conflicts.is_empty());
+}
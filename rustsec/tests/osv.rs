@@ -0,0 +1,93 @@
+//! Tests for importing OSV advisories
+
+#![cfg(feature = "osv")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{Database, Lockfile, Version, advisory::License, osv::OsvAdvisory, report::Settings};
+use std::path::Path;
+
+/// Load the example OSV advisories from the filesystem
+fn load_database() -> Database {
+    Database::open_osv(Path::new("./tests/support/osv")).unwrap()
+}
+
+#[test]
+fn import_osv() {
+    let db = load_database();
+
+    // One advisory for each crate
+    let advisories = db.iter().collect::<Vec<_>>();
+    assert_eq!(advisories.len(), 2);
+
+    let advisory = db.get(&"GHSA-6xqf-9m3p-c2vq".parse().unwrap()).unwrap();
+    assert_eq!(advisory.metadata.package.as_str(), "parser");
+    assert_eq!(advisory.metadata.date.as_str(), "2023-03-01");
+    assert_eq!(advisory.metadata.aliases[0].as_str(), "CVE-2023-12345");
+    assert_eq!(advisory.metadata.license, License::CcBy40);
+    assert_eq!(
+        advisory.metadata.url.as_ref().unwrap().as_str(),
+        "https://github.com/advisories/GHSA-6xqf-9m3p-c2vq"
+    );
+    assert_eq!(advisory.metadata.references.len(), 1);
+    assert!(advisory.metadata.cvss.is_some());
+    assert_eq!(advisory.metadata.cwe_ids, ["CWE-400".parse().unwrap()]);
+
+    let vulnerable = |advisory: &rustsec::Advisory, version: &str| {
+        advisory
+            .versions
+            .is_vulnerable(&version.parse::<Version>().unwrap())
+    };
+    assert!(vulnerable(advisory, "0.4.1"));
+    assert!(!vulnerable(advisory, "0.4.2"));
+    assert!(vulnerable(advisory, "0.5.0"));
+    assert!(!vulnerable(advisory, "0.5.1"));
+
+    let derive = advisories
+        .iter()
+        .find(|advisory| advisory.metadata.package.as_str() == "parser-derive")
+        .unwrap();
+    assert!(!vulnerable(derive, "1.0.0"));
+    assert!(vulnerable(derive, "1.3.0"));
+    assert!(!vulnerable(derive, "1.3.1"));
+}
+
+#[test]
+fn audit_with_osv() {
+    let db = load_database();
+    let lockfile: Lockfile = r#"
+version = 3
+
+[[package]]
+name = "parser-derive"
+version = "1.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "0000000000000000000000000000000000000000000000000000000000000000"
+"#
+    .parse()
+    .unwrap();
+
+    let report = db.audit_lockfile(&lockfile, &Settings::default());
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(
+        report.vulnerabilities.list[0].advisory.id.as_str(),
+        "GHSA-6xqf-9m3p-c2vq"
+    );
+}
+
+#[test]
+fn export_without_git() {
+    let advisory = rustsec::Advisory::load_file("./tests/support/example_advisory_v4.md").unwrap();
+    let modified = "2024-05-06T07:08:09Z".parse().unwrap();
+    let osv = OsvAdvisory::from_rustsec_at(advisory.clone(), modified);
+
+    let json = serde_json::to_value(&osv).unwrap();
+    assert_eq!(json["modified"], "2024-05-06T07:08:09Z");
+
+    // Converting back gives the same ranges of affected versions
+    let imported = osv.to_advisories().unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(
+        imported[0].versions.affected_ranges(),
+        advisory.versions.affected_ranges()
+    );
+}
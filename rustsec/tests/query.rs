@@ -3,7 +3,11 @@
 #![warn(rust_2018_idioms, unused_qualifications)]
 
 use platforms::target::{Arch, OS};
-use rustsec::{advisory::Severity, database::Query, package};
+use rustsec::{
+    advisory::{Category, Severity, affected::FunctionPath},
+    database::Query,
+    package,
+};
 
 /// Load example advisory from the filesystem
 fn load_advisory() -> rustsec::Advisory {
@@ -63,3 +67,93 @@ fn matches_target_arch() {
     let query_normal = Query::new().target_arch(vec![Arch::Mips, Arch::Mips64]);
     assert!(!query_normal.matches(&advisory));
 }
+
+#[test]
+fn matches_categories() {
+    let advisory = load_advisory();
+
+    let query_matches =
+        Query::new().categories(vec![Category::CodeExecution, Category::MemoryCorruption]);
+    assert!(query_matches.matches(&advisory));
+
+    let query_normal = Query::new().categories(vec![Category::CryptoFailure]);
+    assert!(!query_normal.matches(&advisory));
+}
+
+#[test]
+fn matches_keywords() {
+    let advisory = load_advisory();
+
+    let query_matches = Query::new().keywords(vec!["gentlemen".parse().unwrap()]);
+    assert!(query_matches.matches(&advisory));
+
+    let query_normal = Query::new().keywords(vec!["ssl".parse().unwrap()]);
+    assert!(!query_normal.matches(&advisory));
+}
+
+#[test]
+fn matches_as_of() {
+    let mut advisory = load_advisory();
+
+    let query_matches = Query::new().as_of("2001-02-03".parse().unwrap());
+    assert!(query_matches.matches(&advisory));
+
+    let query_normal = Query::new().as_of("2001-02-02".parse().unwrap());
+    assert!(!query_normal.matches(&advisory));
+
+    // Advisories withdrawn later still matched back then
+    advisory.metadata.withdrawn = Some("2005-06-07".parse().unwrap());
+    let query_matches = Query::new()
+        .withdrawn(false)
+        .as_of("2004-01-01".parse().unwrap());
+    assert!(query_matches.matches(&advisory));
+
+    let query_normal = Query::new()
+        .withdrawn(false)
+        .as_of("2005-06-07".parse().unwrap());
+    assert!(!query_normal.matches(&advisory));
+}
+
+#[test]
+fn matches_withdrawn() {
+    let mut advisory = load_advisory();
+    advisory.metadata.withdrawn = Some("2005-06-07".parse().unwrap());
+    assert!(advisory.withdrawn());
+
+    let query_normal = Query::new().withdrawn(false);
+    assert!(!query_normal.matches(&advisory));
+    assert!(query_normal.clone().include_withdrawn().matches(&advisory));
+
+    advisory.metadata.withdrawn = None;
+    assert!(query_normal.include_withdrawn().matches(&advisory));
+    assert!(!Query::new().withdrawn(true).matches(&advisory));
+}
+
+#[test]
+fn matches_function() {
+    let advisory = load_advisory();
+    let path = |path: &str| path.parse::<FunctionPath>().unwrap();
+
+    // Items within the affected type, and the module containing it, match too
+    for function in [
+        "base::belongs::All",
+        "base::belongs::All::to_us",
+        "base::belongs",
+    ] {
+        let query_matches = Query::new().function(path(function));
+        assert!(query_matches.matches(&advisory), "{function}");
+    }
+
+    let query_normal = Query::new().function(path("base::belongs::Some"));
+    assert!(!query_normal.matches(&advisory));
+
+    // The function is only vulnerable in some of the affected versions
+    let query_matches = Query::new()
+        .function(path("base::belongs::All"))
+        .package_version("1.2.2".parse().unwrap());
+    assert!(query_matches.matches(&advisory));
+
+    let affected = advisory.affected.as_ref().unwrap();
+    assert_eq!(affected.functions_for(&"1.2.2".parse().unwrap()).count(), 1);
+    assert_eq!(affected.functions_for(&"1.2.3".parse().unwrap()).count(), 0);
+}
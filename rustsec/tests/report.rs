@@ -0,0 +1,87 @@
+//! Tests for serializing and deserializing reports
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    Database, Lockfile, Report, Vulnerability, Warning, advisory::Informational, report::Settings,
+};
+use std::path::Path;
+
+/// Lockfile with vulnerable versions of the crates in the example database
+const LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "base"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "0000000000000000000000000000000000000000000000000000000000000000"
+
+[[package]]
+name = "other"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "0000000000000000000000000000000000000000000000000000000000000000"
+"#;
+
+/// Load the example database from the filesystem
+fn load_database() -> Database {
+    Database::open(Path::new("./tests/support/advisory-db")).unwrap()
+}
+
+/// Generate a report with both vulnerabilities and warnings
+fn generate_report() -> Report {
+    let db = load_database();
+    let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+    let settings = Settings {
+        informational_warnings: vec![Informational::Unmaintained],
+        ..Default::default()
+    };
+
+    db.audit_lockfile(&lockfile, &settings)
+}
+
+#[test]
+fn round_trip() {
+    let report = generate_report();
+    // The withdrawn advisory for `base` isn't reported
+    assert_eq!(report.vulnerabilities.count, 3);
+    assert_eq!(report.warnings.values().flatten().count(), 1);
+
+    let json = serde_json::to_string(&report).unwrap();
+    let parsed: Report = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, report);
+
+    for vulnerability in &report.vulnerabilities.list {
+        let json = serde_json::to_string(vulnerability).unwrap();
+        let parsed: Vulnerability = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, vulnerability);
+    }
+
+    for warning in report.warnings.values().flatten() {
+        let json = serde_json::to_string(warning).unwrap();
+        let parsed: Warning = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, warning);
+    }
+}
+
+#[test]
+fn audit_lockfile_path() {
+    let db = load_database();
+
+    let report = db
+        .audit_lockfile_path(
+            Path::new("./tests/support/local_cargo.lock"),
+            &Settings::default(),
+        )
+        .unwrap();
+    assert!(!report.vulnerabilities.found);
+
+    assert!(
+        db.audit_lockfile_path(
+            Path::new("./tests/support/missing.lock"),
+            &Settings::default()
+        )
+        .is_err()
+    );
+}